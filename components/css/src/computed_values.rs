@@ -1,14 +1,31 @@
 use std::collections::HashMap;
+use std::rc::Rc;
 
+use crate::logical_geometry::{LogicalAxis, LogicalCorner, LogicalSide, PhysicalAxis, PhysicalCorner, PhysicalSide};
+use crate::media_queries::device::Device;
+use crate::properties::custom_properties::CustomPropertiesMap;
 use crate::properties::declaration::PropertyDeclaration;
 use crate::properties::longhand_id::LonghandId;
 use crate::properties::longhands;
 use crate::properties::longhands::display::Display;
 use crate::properties::longhands::font_size::DEFAULT_FONT_SIZE;
-use crate::values::computed::length::{LengthPercentageOrAuto, MaxSize, NonNegativeLengthPercentage, Size};
+use crate::stylesheets::origin::CascadeLevel;
+use crate::values::computed::border::BorderCornerRadius;
+use crate::values::computed::grid::{GridTemplateComponent, ImplicitGridTracks};
+use crate::values::computed::length::{
+	FlexBasis, LengthPercentageOrAuto, LengthPercentageOrNormal, MaxSize, NonNegativeLength,
+	NonNegativeLengthNumberOrNormal, NonNegativeLengthOrAuto, NonNegativeLengthOrNone, NonNegativeLengthPercentage,
+	NonNegativeLengthPercentageOrNormal, Size,
+};
 use crate::values::computed::line::LineWidth;
+use crate::values::computed::number::IntegerAuto;
+use crate::values::computed::text::{TextIndent, VerticalAlign};
+use crate::values::generics::number::NonNegative;
 use crate::values::specified::color::RGBA;
-use crate::values::specified::layout::LineStyle;
+use crate::values::specified::counter::CounterWithInteger;
+use crate::values::specified::grid::{GridAutoFlow, GridLine, GridTemplateAreas};
+use crate::values::specified::layout::{LineStyle, Overflow};
+use crate::values::specified::length::{NonNegativeLengthOrNumberRect, Pair};
 use crate::values::CSSFloat;
 
 #[derive(Debug)]
@@ -20,6 +37,16 @@ pub struct Box {
 	pub height: Size,
 	pub min_height: Size,
 	pub max_height: MaxSize,
+	pub box_sizing: longhands::box_sizing::BoxSizing,
+	pub aspect_ratio: longhands::aspect_ratio::AspectRatio,
+	pub float: longhands::float::Float,
+	pub clear: longhands::clear::Clear,
+	pub overflow_x: Overflow,
+	pub overflow_y: Overflow,
+	pub visibility: longhands::visibility::Visibility,
+	pub vertical_align: VerticalAlign,
+	pub object_fit: longhands::object_fit::ObjectFit,
+	pub image_rendering: longhands::image_rendering::ImageRendering,
 }
 
 impl Default for Box {
@@ -32,6 +59,16 @@ impl Default for Box {
 			height: Size::Auto,
 			min_height: Size::Auto,
 			max_height: MaxSize::None,
+			box_sizing: longhands::box_sizing::initial_value(),
+			aspect_ratio: longhands::aspect_ratio::initial_value(),
+			float: longhands::float::initial_value(),
+			clear: longhands::clear::initial_value(),
+			overflow_x: longhands::overflow_x::initial_value(),
+			overflow_y: longhands::overflow_y::initial_value(),
+			visibility: longhands::visibility::initial_value(),
+			vertical_align: VerticalAlign::default(),
+			object_fit: longhands::object_fit::initial_value(),
+			image_rendering: longhands::image_rendering::initial_value(),
 		}
 	}
 }
@@ -97,6 +134,15 @@ pub struct Border {
 	pub border_right: BorderEdge,
 	pub border_bottom: BorderEdge,
 	pub border_left: BorderEdge,
+	pub border_top_left_radius: BorderCornerRadius,
+	pub border_top_right_radius: BorderCornerRadius,
+	pub border_bottom_right_radius: BorderCornerRadius,
+	pub border_bottom_left_radius: BorderCornerRadius,
+	pub border_image_source: longhands::border_image_source::BorderImageSource,
+	pub border_image_slice: longhands::border_image_slice::BorderImageSlice,
+	pub border_image_width: longhands::border_image_width::BorderImageWidth,
+	pub border_image_outset: NonNegativeLengthOrNumberRect,
+	pub border_image_repeat: longhands::border_image_repeat::BorderImageRepeat,
 }
 
 impl Default for Border {
@@ -106,6 +152,15 @@ impl Default for Border {
 			border_right: Default::default(),
 			border_bottom: Default::default(),
 			border_left: Default::default(),
+			border_top_left_radius: BorderCornerRadius::zero(),
+			border_top_right_radius: BorderCornerRadius::zero(),
+			border_bottom_right_radius: BorderCornerRadius::zero(),
+			border_bottom_left_radius: BorderCornerRadius::zero(),
+			border_image_source: longhands::border_image_source::initial_value(),
+			border_image_slice: longhands::border_image_slice::initial_value(),
+			border_image_width: longhands::border_image_width::initial_value(),
+			border_image_outset: longhands::border_image_outset::initial_value(),
+			border_image_repeat: longhands::border_image_repeat::initial_value(),
 		}
 	}
 }
@@ -113,16 +168,46 @@ impl Default for Border {
 #[derive(Debug)]
 pub struct Text {
 	pub color: RGBA,
-	pub font_families: Vec<String>,
-	pub font_size: CSSFloat,
+	pub line_height: NonNegativeLengthNumberOrNormal,
+	pub letter_spacing: LengthPercentageOrNormal,
+	pub word_spacing: LengthPercentageOrNormal,
+	pub text_align: longhands::text_align::TextAlign,
+	pub text_indent: TextIndent,
+	pub text_justify: longhands::text_justify::TextJustify,
+	pub text_transform: longhands::text_transform::TextTransform,
+	pub text_decoration_line: longhands::text_decoration_line::TextDecorationLine,
+	pub text_overflow: longhands::text_overflow::TextOverflow,
+	pub text_shadow: longhands::text_shadow::TextShadow,
+	pub text_rendering: longhands::text_rendering::TextRendering,
+	pub white_space: longhands::white_space::WhiteSpace,
+	pub word_break: longhands::word_break::WordBreak,
+	pub overflow_wrap: longhands::overflow_wrap::OverflowWrap,
+	pub direction: longhands::direction::Direction,
+	pub writing_mode: longhands::writing_mode::WritingMode,
+	pub unicode_bidi: longhands::unicode_bidi::UnicodeBidi,
 }
 
 impl Default for Text {
 	fn default() -> Self {
 		Self {
 			color: RGBA::transparent(),
-			font_families: vec![],
-			font_size: DEFAULT_FONT_SIZE,
+			line_height: NonNegativeLengthNumberOrNormal::Normal,
+			letter_spacing: LengthPercentageOrNormal::Normal,
+			word_spacing: LengthPercentageOrNormal::Normal,
+			text_align: longhands::text_align::initial_value(),
+			text_indent: TextIndent::default(),
+			text_justify: longhands::text_justify::initial_value(),
+			text_transform: longhands::text_transform::initial_value(),
+			text_decoration_line: longhands::text_decoration_line::initial_value(),
+			text_overflow: longhands::text_overflow::initial_value(),
+			text_shadow: longhands::text_shadow::initial_value(),
+			text_rendering: longhands::text_rendering::initial_value(),
+			white_space: longhands::white_space::initial_value(),
+			word_break: longhands::word_break::initial_value(),
+			overflow_wrap: longhands::overflow_wrap::initial_value(),
+			direction: longhands::direction::initial_value(),
+			writing_mode: longhands::writing_mode::initial_value(),
+			unicode_bidi: longhands::unicode_bidi::initial_value(),
 		}
 	}
 }
@@ -130,12 +215,343 @@ impl Default for Text {
 #[derive(Debug)]
 pub struct Background {
 	pub background_color: RGBA,
+	pub background_attachment: longhands::background_attachment::BackgroundAttachment,
+	pub background_clip: longhands::background_clip::BackgroundClip,
+	pub background_image: longhands::background_image::BackgroundImage,
+	pub background_origin: longhands::background_origin::BackgroundOrigin,
+	pub background_position_x: longhands::background_position_x::BackgroundPositionX,
+	pub background_position_y: longhands::background_position_y::BackgroundPositionY,
+	pub background_repeat: longhands::background_repeat::BackgroundRepeat,
+	pub background_size: longhands::background_size::BackgroundSize,
 }
 
 impl Default for Background {
 	fn default() -> Self {
 		Self {
 			background_color: RGBA::transparent(),
+			background_attachment: longhands::background_attachment::initial_value(),
+			background_clip: longhands::background_clip::initial_value(),
+			background_image: longhands::background_image::initial_value(),
+			background_origin: longhands::background_origin::initial_value(),
+			background_position_x: longhands::background_position_x::initial_value(),
+			background_position_y: longhands::background_position_y::initial_value(),
+			background_repeat: longhands::background_repeat::initial_value(),
+			background_size: longhands::background_size::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Font {
+	pub font_families: Vec<String>,
+	pub font_size: CSSFloat,
+	pub font_style: longhands::font_style::FontStyle,
+	pub font_weight: CSSFloat,
+	pub font_stretch: longhands::font_stretch::FontStretch,
+	pub font_variant_caps: longhands::font_variant_caps::FontVariantCaps,
+}
+
+impl Default for Font {
+	fn default() -> Self {
+		Self {
+			font_families: vec![],
+			font_size: DEFAULT_FONT_SIZE,
+			font_style: longhands::font_style::initial_value(),
+			font_weight: 400.0,
+			font_stretch: longhands::font_stretch::initial_value(),
+			font_variant_caps: longhands::font_variant_caps::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Position {
+	pub position: longhands::position::Position,
+	pub top: LengthPercentageOrAuto,
+	pub right: LengthPercentageOrAuto,
+	pub bottom: LengthPercentageOrAuto,
+	pub left: LengthPercentageOrAuto,
+	pub z_index: IntegerAuto,
+}
+
+impl Default for Position {
+	fn default() -> Self {
+		Self {
+			position: longhands::position::initial_value(),
+			top: LengthPercentageOrAuto::Auto,
+			right: LengthPercentageOrAuto::Auto,
+			bottom: LengthPercentageOrAuto::Auto,
+			left: LengthPercentageOrAuto::Auto,
+			z_index: IntegerAuto::Auto,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Flex {
+	pub flex_direction: longhands::flex_direction::FlexDirection,
+	pub flex_wrap: longhands::flex_wrap::FlexWrap,
+	pub flex_basis: FlexBasis,
+	pub flex_grow: CSSFloat,
+	pub flex_shrink: CSSFloat,
+	pub order: i32,
+	pub align_content: longhands::align_content::AlignContent,
+	pub align_items: longhands::align_items::AlignItems,
+	pub align_self: longhands::align_self::AlignSelf,
+	pub justify_content: longhands::justify_content::JustifyContent,
+	pub justify_items: longhands::justify_items::JustifyItems,
+	pub justify_self: longhands::justify_self::JustifySelf,
+}
+
+impl Default for Flex {
+	fn default() -> Self {
+		Self {
+			flex_direction: longhands::flex_direction::initial_value(),
+			flex_wrap: longhands::flex_wrap::initial_value(),
+			flex_basis: FlexBasis::Width(Size::Auto),
+			flex_grow: 0.0,
+			flex_shrink: 1.0,
+			order: 0,
+			align_content: longhands::align_content::initial_value(),
+			align_items: longhands::align_items::initial_value(),
+			align_self: longhands::align_self::initial_value(),
+			justify_content: longhands::justify_content::initial_value(),
+			justify_items: longhands::justify_items::initial_value(),
+			justify_self: longhands::justify_self::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Grid {
+	pub grid_template_columns: GridTemplateComponent,
+	pub grid_template_rows: GridTemplateComponent,
+	pub grid_template_areas: GridTemplateAreas,
+	pub grid_auto_columns: ImplicitGridTracks,
+	pub grid_auto_rows: ImplicitGridTracks,
+	pub grid_auto_flow: GridAutoFlow,
+	pub grid_row_start: GridLine,
+	pub grid_row_end: GridLine,
+	pub grid_column_start: GridLine,
+	pub grid_column_end: GridLine,
+}
+
+impl Default for Grid {
+	fn default() -> Self {
+		Self {
+			grid_template_columns: GridTemplateComponent::None,
+			grid_template_rows: GridTemplateComponent::None,
+			grid_template_areas: GridTemplateAreas::None,
+			grid_auto_columns: Default::default(),
+			grid_auto_rows: Default::default(),
+			grid_auto_flow: longhands::grid_auto_flow::initial_value(),
+			grid_row_start: GridLine::auto(),
+			grid_row_end: GridLine::auto(),
+			grid_column_start: GridLine::auto(),
+			grid_column_end: GridLine::auto(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Effects {
+	pub opacity: CSSFloat,
+	pub box_shadow: longhands::box_shadow::BoxShadow,
+	pub filter: longhands::filter::Filter,
+	pub mix_blend_mode: longhands::mix_blend_mode::MixBlendMode,
+	pub clip: longhands::clip::Clip,
+}
+
+impl Default for Effects {
+	fn default() -> Self {
+		Self {
+			opacity: 1.0,
+			box_shadow: longhands::box_shadow::initial_value(),
+			filter: longhands::filter::initial_value(),
+			mix_blend_mode: longhands::mix_blend_mode::initial_value(),
+			clip: longhands::clip::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Ui {
+	pub cursor: longhands::cursor::Cursor,
+	pub pointer_events: longhands::pointer_events::PointerEvents,
+}
+
+impl Default for Ui {
+	fn default() -> Self {
+		Self {
+			cursor: longhands::cursor::initial_value(),
+			pointer_events: longhands::pointer_events::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Transform {
+	pub transform: longhands::transform::Transform,
+	pub transform_origin: longhands::transform_origin::TransformOrigin,
+	pub transform_style: longhands::transform_style::TransformStyle,
+	pub perspective: NonNegativeLengthOrNone,
+	pub perspective_origin: longhands::perspective_origin::PerspectiveOrigin,
+	pub backface_visibility: longhands::backface_visibility::BackfaceVisibility,
+	pub rotate: longhands::rotate::Rotate,
+	pub scale: longhands::scale::Scale,
+	pub translate: longhands::translate::Translate,
+}
+
+impl Default for Transform {
+	fn default() -> Self {
+		Self {
+			transform: longhands::transform::initial_value(),
+			transform_origin: longhands::transform_origin::initial_value(),
+			transform_style: longhands::transform_style::initial_value(),
+			perspective: NonNegativeLengthOrNone::None,
+			perspective_origin: longhands::perspective_origin::initial_value(),
+			backface_visibility: longhands::backface_visibility::initial_value(),
+			rotate: longhands::rotate::initial_value(),
+			scale: longhands::scale::initial_value(),
+			translate: longhands::translate::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Animation {
+	pub animation_delay: longhands::animation_delay::AnimationDelay,
+	pub animation_direction: longhands::animation_direction::AnimationDirection,
+	pub animation_duration: longhands::animation_duration::AnimationDuration,
+	pub animation_fill_mode: longhands::animation_fill_mode::AnimationFillMode,
+	pub animation_iteration_count: longhands::animation_iteration_count::AnimationIterationCount,
+	pub animation_name: longhands::animation_name::AnimationName,
+	pub animation_play_state: longhands::animation_play_state::AnimationPlayState,
+	pub animation_timing_function: longhands::animation_timing_function::AnimationTimingFunction,
+	pub transition_delay: longhands::transition_delay::TransitionDelay,
+	pub transition_duration: longhands::transition_duration::TransitionDuration,
+	pub transition_property: longhands::transition_property::TransitionProperty,
+	pub transition_timing_function: longhands::transition_timing_function::TransitionTimingFunction,
+}
+
+impl Default for Animation {
+	fn default() -> Self {
+		Self {
+			animation_delay: longhands::animation_delay::initial_value(),
+			animation_direction: longhands::animation_direction::initial_value(),
+			animation_duration: longhands::animation_duration::initial_value(),
+			animation_fill_mode: longhands::animation_fill_mode::initial_value(),
+			animation_iteration_count: longhands::animation_iteration_count::initial_value(),
+			animation_name: longhands::animation_name::initial_value(),
+			animation_play_state: longhands::animation_play_state::initial_value(),
+			animation_timing_function: longhands::animation_timing_function::initial_value(),
+			transition_delay: longhands::transition_delay::initial_value(),
+			transition_duration: longhands::transition_duration::initial_value(),
+			transition_property: longhands::transition_property::initial_value(),
+			transition_timing_function: longhands::transition_timing_function::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct List {
+	pub list_style_image: longhands::list_style_image::ListStyleImage,
+	pub list_style_position: longhands::list_style_position::ListStylePosition,
+	pub list_style_type: longhands::list_style_type::ListStyleType,
+	pub quotes: longhands::quotes::Quotes,
+}
+
+impl Default for List {
+	fn default() -> Self {
+		Self {
+			list_style_image: longhands::list_style_image::initial_value(),
+			list_style_position: longhands::list_style_position::initial_value(),
+			list_style_type: longhands::list_style_type::initial_value(),
+			quotes: longhands::quotes::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Counters {
+	pub content: longhands::content::Content,
+	pub counter_increment: CounterWithInteger,
+	pub counter_reset: longhands::counter_reset::CounterReset,
+	pub counter_set: CounterWithInteger,
+}
+
+impl Default for Counters {
+	fn default() -> Self {
+		Self {
+			content: longhands::content::initial_value(),
+			counter_increment: longhands::counter_increment::initial_value(),
+			counter_reset: longhands::counter_reset::initial_value(),
+			counter_set: longhands::counter_set::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Table {
+	pub border_collapse: longhands::border_collapse::BorderCollapse,
+	pub border_spacing: Pair<NonNegativeLength>,
+	pub caption_side: longhands::caption_side::CaptionSide,
+	pub empty_cells: longhands::empty_cells::EmptyCells,
+	pub table_layout: longhands::table_layout::TableLayout,
+}
+
+impl Default for Table {
+	fn default() -> Self {
+		Self {
+			border_collapse: longhands::border_collapse::initial_value(),
+			border_spacing: Pair::new(NonNegative(0.0), NonNegative(0.0)),
+			caption_side: longhands::caption_side::initial_value(),
+			empty_cells: longhands::empty_cells::initial_value(),
+			table_layout: longhands::table_layout::initial_value(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Column {
+	pub column_count: longhands::column_count::ColumnCount,
+	pub column_gap: NonNegativeLengthPercentageOrNormal,
+	pub row_gap: NonNegativeLengthPercentageOrNormal,
+	pub column_width: NonNegativeLengthOrAuto,
+	pub column_rule_color: RGBA,
+	pub column_rule_style: LineStyle,
+	pub column_rule_width: LineWidth,
+}
+
+impl Default for Column {
+	fn default() -> Self {
+		Self {
+			column_count: longhands::column_count::initial_value(),
+			column_gap: NonNegativeLengthPercentageOrNormal::Normal,
+			row_gap: NonNegativeLengthPercentageOrNormal::Normal,
+			column_width: NonNegativeLengthOrAuto::Auto,
+			column_rule_color: RGBA::transparent(),
+			column_rule_style: LineStyle::None,
+			column_rule_width: LineWidth::Medium,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct Outline {
+	pub outline_color: RGBA,
+	pub outline_style: longhands::outline_style::OutlineStyle,
+	pub outline_width: LineWidth,
+	pub outline_offset: CSSFloat,
+}
+
+impl Default for Outline {
+	fn default() -> Self {
+		Self {
+			outline_color: RGBA::transparent(),
+			outline_style: longhands::outline_style::initial_value(),
+			outline_width: LineWidth::Medium,
+			outline_offset: 0.0,
 		}
 	}
 }
@@ -148,6 +564,20 @@ pub struct ComputedValues {
 	margin: Margin,
 	padding: Padding,
 	border: Border,
+	font: Font,
+	position: Position,
+	flex: Flex,
+	grid: Grid,
+	effects: Effects,
+	ui: Ui,
+	transform: Transform,
+	animation: Animation,
+	list: List,
+	counters: Counters,
+	table: Table,
+	column: Column,
+	outline: Outline,
+	custom_properties: Option<Rc<CustomPropertiesMap>>,
 }
 
 impl Default for ComputedValues {
@@ -159,6 +589,20 @@ impl Default for ComputedValues {
 			margin: Default::default(),
 			padding: Default::default(),
 			border: Default::default(),
+			font: Default::default(),
+			position: Default::default(),
+			flex: Default::default(),
+			grid: Default::default(),
+			effects: Default::default(),
+			ui: Default::default(),
+			transform: Default::default(),
+			animation: Default::default(),
+			list: Default::default(),
+			counters: Default::default(),
+			table: Default::default(),
+			column: Default::default(),
+			outline: Default::default(),
+			custom_properties: None,
 		}
 	}
 }
@@ -229,19 +673,19 @@ impl ComputedValues {
 	}
 
 	pub fn get_font_families(&self) -> &Vec<String> {
-		&self.text.font_families
+		&self.font.font_families
 	}
 
 	pub fn set_font_families(&mut self, font_families: Vec<String>) {
-		self.text.font_families = font_families;
+		self.font.font_families = font_families;
 	}
 
 	pub fn get_font_size(&self) -> CSSFloat {
-		self.text.font_size
+		self.font.font_size
 	}
 
 	pub fn set_font_size(&mut self, value: CSSFloat) {
-		self.text.font_size = value;
+		self.font.font_size = value;
 	}
 
 	pub fn get_background_color(&self) -> &RGBA {
@@ -411,17 +855,1269 @@ impl ComputedValues {
 	pub fn set_border_left_width(&mut self, value: LineWidth) {
 		self.border.border_left.width = value;
 	}
-}
 
-pub struct StyleContext<'a, 'b, 'c, 'd> {
-	pub author_data: HashMap<LonghandId, PropertyCascade<'a>>,
-	pub useragent_data: HashMap<LonghandId, PropertyCascade<'d>>,
-	pub computed_values: &'b mut ComputedValues,
-	pub parent_style: &'c ComputedValues,
-}
+	pub fn get_box_sizing(&self) -> &longhands::box_sizing::BoxSizing {
+		&self.box_.box_sizing
+	}
 
-pub struct PropertyCascade<'a> {
-	pub specificity: u32,
-	pub importance: bool,
-	pub property: &'a PropertyDeclaration,
+	pub fn set_box_sizing(&mut self, value: longhands::box_sizing::BoxSizing) {
+		self.box_.box_sizing = value;
+	}
+
+	pub fn get_aspect_ratio(&self) -> &longhands::aspect_ratio::AspectRatio {
+		&self.box_.aspect_ratio
+	}
+
+	pub fn set_aspect_ratio(&mut self, value: longhands::aspect_ratio::AspectRatio) {
+		self.box_.aspect_ratio = value;
+	}
+
+	pub fn get_float(&self) -> &longhands::float::Float {
+		&self.box_.float
+	}
+
+	pub fn set_float(&mut self, value: longhands::float::Float) {
+		self.box_.float = value;
+	}
+
+	pub fn get_clear(&self) -> &longhands::clear::Clear {
+		&self.box_.clear
+	}
+
+	pub fn set_clear(&mut self, value: longhands::clear::Clear) {
+		self.box_.clear = value;
+	}
+
+	pub fn get_overflow_x(&self) -> &Overflow {
+		&self.box_.overflow_x
+	}
+
+	pub fn set_overflow_x(&mut self, value: Overflow) {
+		self.box_.overflow_x = value;
+	}
+
+	pub fn get_overflow_y(&self) -> &Overflow {
+		&self.box_.overflow_y
+	}
+
+	pub fn set_overflow_y(&mut self, value: Overflow) {
+		self.box_.overflow_y = value;
+	}
+
+	pub fn get_visibility(&self) -> &longhands::visibility::Visibility {
+		&self.box_.visibility
+	}
+
+	pub fn set_visibility(&mut self, value: longhands::visibility::Visibility) {
+		self.box_.visibility = value;
+	}
+
+	pub fn get_vertical_align(&self) -> &VerticalAlign {
+		&self.box_.vertical_align
+	}
+
+	pub fn set_vertical_align(&mut self, value: VerticalAlign) {
+		self.box_.vertical_align = value;
+	}
+
+	pub fn get_object_fit(&self) -> &longhands::object_fit::ObjectFit {
+		&self.box_.object_fit
+	}
+
+	pub fn set_object_fit(&mut self, value: longhands::object_fit::ObjectFit) {
+		self.box_.object_fit = value;
+	}
+
+	pub fn get_image_rendering(&self) -> &longhands::image_rendering::ImageRendering {
+		&self.box_.image_rendering
+	}
+
+	pub fn set_image_rendering(&mut self, value: longhands::image_rendering::ImageRendering) {
+		self.box_.image_rendering = value;
+	}
+
+	pub fn get_position(&self) -> &longhands::position::Position {
+		&self.position.position
+	}
+
+	pub fn set_position(&mut self, value: longhands::position::Position) {
+		self.position.position = value;
+	}
+
+	pub fn get_top(&self) -> &LengthPercentageOrAuto {
+		&self.position.top
+	}
+
+	pub fn set_top(&mut self, value: LengthPercentageOrAuto) {
+		self.position.top = value;
+	}
+
+	pub fn get_right(&self) -> &LengthPercentageOrAuto {
+		&self.position.right
+	}
+
+	pub fn set_right(&mut self, value: LengthPercentageOrAuto) {
+		self.position.right = value;
+	}
+
+	pub fn get_bottom(&self) -> &LengthPercentageOrAuto {
+		&self.position.bottom
+	}
+
+	pub fn set_bottom(&mut self, value: LengthPercentageOrAuto) {
+		self.position.bottom = value;
+	}
+
+	pub fn get_left(&self) -> &LengthPercentageOrAuto {
+		&self.position.left
+	}
+
+	pub fn set_left(&mut self, value: LengthPercentageOrAuto) {
+		self.position.left = value;
+	}
+
+	pub fn get_z_index(&self) -> &IntegerAuto {
+		&self.position.z_index
+	}
+
+	pub fn set_z_index(&mut self, value: IntegerAuto) {
+		self.position.z_index = value;
+	}
+
+	pub fn get_flex_direction(&self) -> &longhands::flex_direction::FlexDirection {
+		&self.flex.flex_direction
+	}
+
+	pub fn set_flex_direction(&mut self, value: longhands::flex_direction::FlexDirection) {
+		self.flex.flex_direction = value;
+	}
+
+	pub fn get_flex_wrap(&self) -> &longhands::flex_wrap::FlexWrap {
+		&self.flex.flex_wrap
+	}
+
+	pub fn set_flex_wrap(&mut self, value: longhands::flex_wrap::FlexWrap) {
+		self.flex.flex_wrap = value;
+	}
+
+	pub fn get_flex_basis(&self) -> &FlexBasis {
+		&self.flex.flex_basis
+	}
+
+	pub fn set_flex_basis(&mut self, value: FlexBasis) {
+		self.flex.flex_basis = value;
+	}
+
+	pub fn get_flex_grow(&self) -> CSSFloat {
+		self.flex.flex_grow
+	}
+
+	pub fn set_flex_grow(&mut self, value: CSSFloat) {
+		self.flex.flex_grow = value;
+	}
+
+	pub fn get_flex_shrink(&self) -> CSSFloat {
+		self.flex.flex_shrink
+	}
+
+	pub fn set_flex_shrink(&mut self, value: CSSFloat) {
+		self.flex.flex_shrink = value;
+	}
+
+	pub fn get_order(&self) -> i32 {
+		self.flex.order
+	}
+
+	pub fn set_order(&mut self, value: i32) {
+		self.flex.order = value;
+	}
+
+	pub fn get_align_content(&self) -> &longhands::align_content::AlignContent {
+		&self.flex.align_content
+	}
+
+	pub fn set_align_content(&mut self, value: longhands::align_content::AlignContent) {
+		self.flex.align_content = value;
+	}
+
+	pub fn get_align_items(&self) -> &longhands::align_items::AlignItems {
+		&self.flex.align_items
+	}
+
+	pub fn set_align_items(&mut self, value: longhands::align_items::AlignItems) {
+		self.flex.align_items = value;
+	}
+
+	pub fn get_align_self(&self) -> &longhands::align_self::AlignSelf {
+		&self.flex.align_self
+	}
+
+	pub fn set_align_self(&mut self, value: longhands::align_self::AlignSelf) {
+		self.flex.align_self = value;
+	}
+
+	pub fn get_justify_content(&self) -> &longhands::justify_content::JustifyContent {
+		&self.flex.justify_content
+	}
+
+	pub fn set_justify_content(&mut self, value: longhands::justify_content::JustifyContent) {
+		self.flex.justify_content = value;
+	}
+
+	pub fn get_justify_items(&self) -> &longhands::justify_items::JustifyItems {
+		&self.flex.justify_items
+	}
+
+	pub fn set_justify_items(&mut self, value: longhands::justify_items::JustifyItems) {
+		self.flex.justify_items = value;
+	}
+
+	pub fn get_justify_self(&self) -> &longhands::justify_self::JustifySelf {
+		&self.flex.justify_self
+	}
+
+	pub fn set_justify_self(&mut self, value: longhands::justify_self::JustifySelf) {
+		self.flex.justify_self = value;
+	}
+
+	pub fn get_grid_template_columns(&self) -> &GridTemplateComponent {
+		&self.grid.grid_template_columns
+	}
+
+	pub fn set_grid_template_columns(&mut self, value: GridTemplateComponent) {
+		self.grid.grid_template_columns = value;
+	}
+
+	pub fn get_grid_template_rows(&self) -> &GridTemplateComponent {
+		&self.grid.grid_template_rows
+	}
+
+	pub fn set_grid_template_rows(&mut self, value: GridTemplateComponent) {
+		self.grid.grid_template_rows = value;
+	}
+
+	pub fn get_grid_template_areas(&self) -> &GridTemplateAreas {
+		&self.grid.grid_template_areas
+	}
+
+	pub fn set_grid_template_areas(&mut self, value: GridTemplateAreas) {
+		self.grid.grid_template_areas = value;
+	}
+
+	pub fn get_grid_auto_columns(&self) -> &ImplicitGridTracks {
+		&self.grid.grid_auto_columns
+	}
+
+	pub fn set_grid_auto_columns(&mut self, value: ImplicitGridTracks) {
+		self.grid.grid_auto_columns = value;
+	}
+
+	pub fn get_grid_auto_rows(&self) -> &ImplicitGridTracks {
+		&self.grid.grid_auto_rows
+	}
+
+	pub fn set_grid_auto_rows(&mut self, value: ImplicitGridTracks) {
+		self.grid.grid_auto_rows = value;
+	}
+
+	pub fn get_grid_auto_flow(&self) -> &GridAutoFlow {
+		&self.grid.grid_auto_flow
+	}
+
+	pub fn set_grid_auto_flow(&mut self, value: GridAutoFlow) {
+		self.grid.grid_auto_flow = value;
+	}
+
+	pub fn get_grid_row_start(&self) -> &GridLine {
+		&self.grid.grid_row_start
+	}
+
+	pub fn set_grid_row_start(&mut self, value: GridLine) {
+		self.grid.grid_row_start = value;
+	}
+
+	pub fn get_grid_row_end(&self) -> &GridLine {
+		&self.grid.grid_row_end
+	}
+
+	pub fn set_grid_row_end(&mut self, value: GridLine) {
+		self.grid.grid_row_end = value;
+	}
+
+	pub fn get_grid_column_start(&self) -> &GridLine {
+		&self.grid.grid_column_start
+	}
+
+	pub fn set_grid_column_start(&mut self, value: GridLine) {
+		self.grid.grid_column_start = value;
+	}
+
+	pub fn get_grid_column_end(&self) -> &GridLine {
+		&self.grid.grid_column_end
+	}
+
+	pub fn set_grid_column_end(&mut self, value: GridLine) {
+		self.grid.grid_column_end = value;
+	}
+
+	pub fn get_opacity(&self) -> CSSFloat {
+		self.effects.opacity
+	}
+
+	pub fn set_opacity(&mut self, value: CSSFloat) {
+		self.effects.opacity = value;
+	}
+
+	pub fn get_box_shadow(&self) -> &longhands::box_shadow::BoxShadow {
+		&self.effects.box_shadow
+	}
+
+	pub fn set_box_shadow(&mut self, value: longhands::box_shadow::BoxShadow) {
+		self.effects.box_shadow = value;
+	}
+
+	pub fn get_filter(&self) -> &longhands::filter::Filter {
+		&self.effects.filter
+	}
+
+	pub fn set_filter(&mut self, value: longhands::filter::Filter) {
+		self.effects.filter = value;
+	}
+
+	pub fn get_mix_blend_mode(&self) -> &longhands::mix_blend_mode::MixBlendMode {
+		&self.effects.mix_blend_mode
+	}
+
+	pub fn set_mix_blend_mode(&mut self, value: longhands::mix_blend_mode::MixBlendMode) {
+		self.effects.mix_blend_mode = value;
+	}
+
+	pub fn get_clip(&self) -> &longhands::clip::Clip {
+		&self.effects.clip
+	}
+
+	pub fn set_clip(&mut self, value: longhands::clip::Clip) {
+		self.effects.clip = value;
+	}
+
+	pub fn get_cursor(&self) -> &longhands::cursor::Cursor {
+		&self.ui.cursor
+	}
+
+	pub fn set_cursor(&mut self, value: longhands::cursor::Cursor) {
+		self.ui.cursor = value;
+	}
+
+	pub fn get_pointer_events(&self) -> &longhands::pointer_events::PointerEvents {
+		&self.ui.pointer_events
+	}
+
+	pub fn set_pointer_events(&mut self, value: longhands::pointer_events::PointerEvents) {
+		self.ui.pointer_events = value;
+	}
+
+	pub fn get_transform(&self) -> &longhands::transform::Transform {
+		&self.transform.transform
+	}
+
+	pub fn set_transform(&mut self, value: longhands::transform::Transform) {
+		self.transform.transform = value;
+	}
+
+	pub fn get_transform_origin(&self) -> &longhands::transform_origin::TransformOrigin {
+		&self.transform.transform_origin
+	}
+
+	pub fn set_transform_origin(&mut self, value: longhands::transform_origin::TransformOrigin) {
+		self.transform.transform_origin = value;
+	}
+
+	pub fn get_transform_style(&self) -> &longhands::transform_style::TransformStyle {
+		&self.transform.transform_style
+	}
+
+	pub fn set_transform_style(&mut self, value: longhands::transform_style::TransformStyle) {
+		self.transform.transform_style = value;
+	}
+
+	pub fn get_perspective(&self) -> &NonNegativeLengthOrNone {
+		&self.transform.perspective
+	}
+
+	pub fn set_perspective(&mut self, value: NonNegativeLengthOrNone) {
+		self.transform.perspective = value;
+	}
+
+	pub fn get_perspective_origin(&self) -> &longhands::perspective_origin::PerspectiveOrigin {
+		&self.transform.perspective_origin
+	}
+
+	pub fn set_perspective_origin(&mut self, value: longhands::perspective_origin::PerspectiveOrigin) {
+		self.transform.perspective_origin = value;
+	}
+
+	pub fn get_backface_visibility(&self) -> &longhands::backface_visibility::BackfaceVisibility {
+		&self.transform.backface_visibility
+	}
+
+	pub fn set_backface_visibility(&mut self, value: longhands::backface_visibility::BackfaceVisibility) {
+		self.transform.backface_visibility = value;
+	}
+
+	pub fn get_rotate(&self) -> &longhands::rotate::Rotate {
+		&self.transform.rotate
+	}
+
+	pub fn set_rotate(&mut self, value: longhands::rotate::Rotate) {
+		self.transform.rotate = value;
+	}
+
+	pub fn get_scale(&self) -> &longhands::scale::Scale {
+		&self.transform.scale
+	}
+
+	pub fn set_scale(&mut self, value: longhands::scale::Scale) {
+		self.transform.scale = value;
+	}
+
+	pub fn get_translate(&self) -> &longhands::translate::Translate {
+		&self.transform.translate
+	}
+
+	pub fn set_translate(&mut self, value: longhands::translate::Translate) {
+		self.transform.translate = value;
+	}
+
+	pub fn get_animation_delay(&self) -> &longhands::animation_delay::AnimationDelay {
+		&self.animation.animation_delay
+	}
+
+	pub fn set_animation_delay(&mut self, value: longhands::animation_delay::AnimationDelay) {
+		self.animation.animation_delay = value;
+	}
+
+	pub fn get_animation_direction(&self) -> &longhands::animation_direction::AnimationDirection {
+		&self.animation.animation_direction
+	}
+
+	pub fn set_animation_direction(&mut self, value: longhands::animation_direction::AnimationDirection) {
+		self.animation.animation_direction = value;
+	}
+
+	pub fn get_animation_duration(&self) -> &longhands::animation_duration::AnimationDuration {
+		&self.animation.animation_duration
+	}
+
+	pub fn set_animation_duration(&mut self, value: longhands::animation_duration::AnimationDuration) {
+		self.animation.animation_duration = value;
+	}
+
+	pub fn get_animation_fill_mode(&self) -> &longhands::animation_fill_mode::AnimationFillMode {
+		&self.animation.animation_fill_mode
+	}
+
+	pub fn set_animation_fill_mode(&mut self, value: longhands::animation_fill_mode::AnimationFillMode) {
+		self.animation.animation_fill_mode = value;
+	}
+
+	pub fn get_animation_iteration_count(&self) -> &longhands::animation_iteration_count::AnimationIterationCount {
+		&self.animation.animation_iteration_count
+	}
+
+	pub fn set_animation_iteration_count(
+		&mut self,
+		value: longhands::animation_iteration_count::AnimationIterationCount,
+	) {
+		self.animation.animation_iteration_count = value;
+	}
+
+	pub fn get_animation_name(&self) -> &longhands::animation_name::AnimationName {
+		&self.animation.animation_name
+	}
+
+	pub fn set_animation_name(&mut self, value: longhands::animation_name::AnimationName) {
+		self.animation.animation_name = value;
+	}
+
+	pub fn get_animation_play_state(&self) -> &longhands::animation_play_state::AnimationPlayState {
+		&self.animation.animation_play_state
+	}
+
+	pub fn set_animation_play_state(&mut self, value: longhands::animation_play_state::AnimationPlayState) {
+		self.animation.animation_play_state = value;
+	}
+
+	pub fn get_animation_timing_function(&self) -> &longhands::animation_timing_function::AnimationTimingFunction {
+		&self.animation.animation_timing_function
+	}
+
+	pub fn set_animation_timing_function(
+		&mut self,
+		value: longhands::animation_timing_function::AnimationTimingFunction,
+	) {
+		self.animation.animation_timing_function = value;
+	}
+
+	pub fn get_transition_delay(&self) -> &longhands::transition_delay::TransitionDelay {
+		&self.animation.transition_delay
+	}
+
+	pub fn set_transition_delay(&mut self, value: longhands::transition_delay::TransitionDelay) {
+		self.animation.transition_delay = value;
+	}
+
+	pub fn get_transition_duration(&self) -> &longhands::transition_duration::TransitionDuration {
+		&self.animation.transition_duration
+	}
+
+	pub fn set_transition_duration(&mut self, value: longhands::transition_duration::TransitionDuration) {
+		self.animation.transition_duration = value;
+	}
+
+	pub fn get_transition_property(&self) -> &longhands::transition_property::TransitionProperty {
+		&self.animation.transition_property
+	}
+
+	pub fn set_transition_property(&mut self, value: longhands::transition_property::TransitionProperty) {
+		self.animation.transition_property = value;
+	}
+
+	pub fn get_transition_timing_function(&self) -> &longhands::transition_timing_function::TransitionTimingFunction {
+		&self.animation.transition_timing_function
+	}
+
+	pub fn set_transition_timing_function(
+		&mut self,
+		value: longhands::transition_timing_function::TransitionTimingFunction,
+	) {
+		self.animation.transition_timing_function = value;
+	}
+
+	pub fn get_list_style_image(&self) -> &longhands::list_style_image::ListStyleImage {
+		&self.list.list_style_image
+	}
+
+	pub fn set_list_style_image(&mut self, value: longhands::list_style_image::ListStyleImage) {
+		self.list.list_style_image = value;
+	}
+
+	pub fn get_list_style_position(&self) -> &longhands::list_style_position::ListStylePosition {
+		&self.list.list_style_position
+	}
+
+	pub fn set_list_style_position(&mut self, value: longhands::list_style_position::ListStylePosition) {
+		self.list.list_style_position = value;
+	}
+
+	pub fn get_list_style_type(&self) -> &longhands::list_style_type::ListStyleType {
+		&self.list.list_style_type
+	}
+
+	pub fn set_list_style_type(&mut self, value: longhands::list_style_type::ListStyleType) {
+		self.list.list_style_type = value;
+	}
+
+	pub fn get_quotes(&self) -> &longhands::quotes::Quotes {
+		&self.list.quotes
+	}
+
+	pub fn set_quotes(&mut self, value: longhands::quotes::Quotes) {
+		self.list.quotes = value;
+	}
+
+	pub fn get_content(&self) -> &longhands::content::Content {
+		&self.counters.content
+	}
+
+	pub fn set_content(&mut self, value: longhands::content::Content) {
+		self.counters.content = value;
+	}
+
+	pub fn get_counter_increment(&self) -> &CounterWithInteger {
+		&self.counters.counter_increment
+	}
+
+	pub fn set_counter_increment(&mut self, value: CounterWithInteger) {
+		self.counters.counter_increment = value;
+	}
+
+	pub fn get_counter_reset(&self) -> &longhands::counter_reset::CounterReset {
+		&self.counters.counter_reset
+	}
+
+	pub fn set_counter_reset(&mut self, value: longhands::counter_reset::CounterReset) {
+		self.counters.counter_reset = value;
+	}
+
+	pub fn get_counter_set(&self) -> &CounterWithInteger {
+		&self.counters.counter_set
+	}
+
+	pub fn set_counter_set(&mut self, value: CounterWithInteger) {
+		self.counters.counter_set = value;
+	}
+
+	pub fn get_border_collapse(&self) -> &longhands::border_collapse::BorderCollapse {
+		&self.table.border_collapse
+	}
+
+	pub fn set_border_collapse(&mut self, value: longhands::border_collapse::BorderCollapse) {
+		self.table.border_collapse = value;
+	}
+
+	pub fn get_border_spacing(&self) -> &Pair<NonNegativeLength> {
+		&self.table.border_spacing
+	}
+
+	pub fn set_border_spacing(&mut self, value: Pair<NonNegativeLength>) {
+		self.table.border_spacing = value;
+	}
+
+	pub fn get_caption_side(&self) -> &longhands::caption_side::CaptionSide {
+		&self.table.caption_side
+	}
+
+	pub fn set_caption_side(&mut self, value: longhands::caption_side::CaptionSide) {
+		self.table.caption_side = value;
+	}
+
+	pub fn get_empty_cells(&self) -> &longhands::empty_cells::EmptyCells {
+		&self.table.empty_cells
+	}
+
+	pub fn set_empty_cells(&mut self, value: longhands::empty_cells::EmptyCells) {
+		self.table.empty_cells = value;
+	}
+
+	pub fn get_table_layout(&self) -> &longhands::table_layout::TableLayout {
+		&self.table.table_layout
+	}
+
+	pub fn set_table_layout(&mut self, value: longhands::table_layout::TableLayout) {
+		self.table.table_layout = value;
+	}
+
+	pub fn get_column_count(&self) -> &longhands::column_count::ColumnCount {
+		&self.column.column_count
+	}
+
+	pub fn set_column_count(&mut self, value: longhands::column_count::ColumnCount) {
+		self.column.column_count = value;
+	}
+
+	pub fn get_column_gap(&self) -> &NonNegativeLengthPercentageOrNormal {
+		&self.column.column_gap
+	}
+
+	pub fn set_column_gap(&mut self, value: NonNegativeLengthPercentageOrNormal) {
+		self.column.column_gap = value;
+	}
+
+	pub fn get_row_gap(&self) -> &NonNegativeLengthPercentageOrNormal {
+		&self.column.row_gap
+	}
+
+	pub fn set_row_gap(&mut self, value: NonNegativeLengthPercentageOrNormal) {
+		self.column.row_gap = value;
+	}
+
+	pub fn get_column_width(&self) -> &NonNegativeLengthOrAuto {
+		&self.column.column_width
+	}
+
+	pub fn set_column_width(&mut self, value: NonNegativeLengthOrAuto) {
+		self.column.column_width = value;
+	}
+
+	pub fn get_column_rule_color(&self) -> &RGBA {
+		&self.column.column_rule_color
+	}
+
+	pub fn set_column_rule_color(&mut self, value: RGBA) {
+		self.column.column_rule_color = value;
+	}
+
+	pub fn get_column_rule_style(&self) -> &LineStyle {
+		&self.column.column_rule_style
+	}
+
+	pub fn set_column_rule_style(&mut self, value: LineStyle) {
+		self.column.column_rule_style = value;
+	}
+
+	pub fn get_column_rule_width(&self) -> &LineWidth {
+		&self.column.column_rule_width
+	}
+
+	pub fn set_column_rule_width(&mut self, value: LineWidth) {
+		self.column.column_rule_width = value;
+	}
+
+	pub fn get_outline_color(&self) -> &RGBA {
+		&self.outline.outline_color
+	}
+
+	pub fn set_outline_color(&mut self, value: RGBA) {
+		self.outline.outline_color = value;
+	}
+
+	pub fn get_outline_style(&self) -> &longhands::outline_style::OutlineStyle {
+		&self.outline.outline_style
+	}
+
+	pub fn set_outline_style(&mut self, value: longhands::outline_style::OutlineStyle) {
+		self.outline.outline_style = value;
+	}
+
+	pub fn get_outline_width(&self) -> &LineWidth {
+		&self.outline.outline_width
+	}
+
+	pub fn set_outline_width(&mut self, value: LineWidth) {
+		self.outline.outline_width = value;
+	}
+
+	pub fn get_outline_offset(&self) -> CSSFloat {
+		self.outline.outline_offset
+	}
+
+	pub fn set_outline_offset(&mut self, value: CSSFloat) {
+		self.outline.outline_offset = value;
+	}
+
+	pub fn get_line_height(&self) -> &NonNegativeLengthNumberOrNormal {
+		&self.text.line_height
+	}
+
+	pub fn set_line_height(&mut self, value: NonNegativeLengthNumberOrNormal) {
+		self.text.line_height = value;
+	}
+
+	pub fn get_letter_spacing(&self) -> &LengthPercentageOrNormal {
+		&self.text.letter_spacing
+	}
+
+	pub fn set_letter_spacing(&mut self, value: LengthPercentageOrNormal) {
+		self.text.letter_spacing = value;
+	}
+
+	pub fn get_word_spacing(&self) -> &LengthPercentageOrNormal {
+		&self.text.word_spacing
+	}
+
+	pub fn set_word_spacing(&mut self, value: LengthPercentageOrNormal) {
+		self.text.word_spacing = value;
+	}
+
+	pub fn get_text_align(&self) -> &longhands::text_align::TextAlign {
+		&self.text.text_align
+	}
+
+	pub fn set_text_align(&mut self, value: longhands::text_align::TextAlign) {
+		self.text.text_align = value;
+	}
+
+	pub fn get_text_indent(&self) -> &TextIndent {
+		&self.text.text_indent
+	}
+
+	pub fn set_text_indent(&mut self, value: TextIndent) {
+		self.text.text_indent = value;
+	}
+
+	pub fn get_text_justify(&self) -> &longhands::text_justify::TextJustify {
+		&self.text.text_justify
+	}
+
+	pub fn set_text_justify(&mut self, value: longhands::text_justify::TextJustify) {
+		self.text.text_justify = value;
+	}
+
+	pub fn get_text_transform(&self) -> &longhands::text_transform::TextTransform {
+		&self.text.text_transform
+	}
+
+	pub fn set_text_transform(&mut self, value: longhands::text_transform::TextTransform) {
+		self.text.text_transform = value;
+	}
+
+	pub fn get_text_decoration_line(&self) -> &longhands::text_decoration_line::TextDecorationLine {
+		&self.text.text_decoration_line
+	}
+
+	pub fn set_text_decoration_line(&mut self, value: longhands::text_decoration_line::TextDecorationLine) {
+		self.text.text_decoration_line = value;
+	}
+
+	pub fn get_text_overflow(&self) -> &longhands::text_overflow::TextOverflow {
+		&self.text.text_overflow
+	}
+
+	pub fn set_text_overflow(&mut self, value: longhands::text_overflow::TextOverflow) {
+		self.text.text_overflow = value;
+	}
+
+	pub fn get_text_shadow(&self) -> &longhands::text_shadow::TextShadow {
+		&self.text.text_shadow
+	}
+
+	pub fn set_text_shadow(&mut self, value: longhands::text_shadow::TextShadow) {
+		self.text.text_shadow = value;
+	}
+
+	pub fn get_text_rendering(&self) -> &longhands::text_rendering::TextRendering {
+		&self.text.text_rendering
+	}
+
+	pub fn set_text_rendering(&mut self, value: longhands::text_rendering::TextRendering) {
+		self.text.text_rendering = value;
+	}
+
+	pub fn get_white_space(&self) -> &longhands::white_space::WhiteSpace {
+		&self.text.white_space
+	}
+
+	pub fn set_white_space(&mut self, value: longhands::white_space::WhiteSpace) {
+		self.text.white_space = value;
+	}
+
+	pub fn get_word_break(&self) -> &longhands::word_break::WordBreak {
+		&self.text.word_break
+	}
+
+	pub fn set_word_break(&mut self, value: longhands::word_break::WordBreak) {
+		self.text.word_break = value;
+	}
+
+	pub fn get_overflow_wrap(&self) -> &longhands::overflow_wrap::OverflowWrap {
+		&self.text.overflow_wrap
+	}
+
+	pub fn set_overflow_wrap(&mut self, value: longhands::overflow_wrap::OverflowWrap) {
+		self.text.overflow_wrap = value;
+	}
+
+	pub fn get_direction(&self) -> &longhands::direction::Direction {
+		&self.text.direction
+	}
+
+	pub fn set_direction(&mut self, value: longhands::direction::Direction) {
+		self.text.direction = value;
+	}
+
+	pub fn get_writing_mode(&self) -> &longhands::writing_mode::WritingMode {
+		&self.text.writing_mode
+	}
+
+	pub fn set_writing_mode(&mut self, value: longhands::writing_mode::WritingMode) {
+		self.text.writing_mode = value;
+	}
+
+	pub fn get_unicode_bidi(&self) -> &longhands::unicode_bidi::UnicodeBidi {
+		&self.text.unicode_bidi
+	}
+
+	pub fn set_unicode_bidi(&mut self, value: longhands::unicode_bidi::UnicodeBidi) {
+		self.text.unicode_bidi = value;
+	}
+
+	pub fn get_font_style(&self) -> &longhands::font_style::FontStyle {
+		&self.font.font_style
+	}
+
+	pub fn set_font_style(&mut self, value: longhands::font_style::FontStyle) {
+		self.font.font_style = value;
+	}
+
+	pub fn get_font_weight(&self) -> CSSFloat {
+		self.font.font_weight
+	}
+
+	pub fn set_font_weight(&mut self, value: CSSFloat) {
+		self.font.font_weight = value;
+	}
+
+	pub fn get_font_stretch(&self) -> &longhands::font_stretch::FontStretch {
+		&self.font.font_stretch
+	}
+
+	pub fn set_font_stretch(&mut self, value: longhands::font_stretch::FontStretch) {
+		self.font.font_stretch = value;
+	}
+
+	pub fn get_font_variant_caps(&self) -> &longhands::font_variant_caps::FontVariantCaps {
+		&self.font.font_variant_caps
+	}
+
+	pub fn set_font_variant_caps(&mut self, value: longhands::font_variant_caps::FontVariantCaps) {
+		self.font.font_variant_caps = value;
+	}
+
+	pub fn get_background_attachment(&self) -> &longhands::background_attachment::BackgroundAttachment {
+		&self.background.background_attachment
+	}
+
+	pub fn set_background_attachment(&mut self, value: longhands::background_attachment::BackgroundAttachment) {
+		self.background.background_attachment = value;
+	}
+
+	pub fn get_background_clip(&self) -> &longhands::background_clip::BackgroundClip {
+		&self.background.background_clip
+	}
+
+	pub fn set_background_clip(&mut self, value: longhands::background_clip::BackgroundClip) {
+		self.background.background_clip = value;
+	}
+
+	pub fn get_background_image(&self) -> &longhands::background_image::BackgroundImage {
+		&self.background.background_image
+	}
+
+	pub fn set_background_image(&mut self, value: longhands::background_image::BackgroundImage) {
+		self.background.background_image = value;
+	}
+
+	pub fn get_background_origin(&self) -> &longhands::background_origin::BackgroundOrigin {
+		&self.background.background_origin
+	}
+
+	pub fn set_background_origin(&mut self, value: longhands::background_origin::BackgroundOrigin) {
+		self.background.background_origin = value;
+	}
+
+	pub fn get_background_position_x(&self) -> &longhands::background_position_x::BackgroundPositionX {
+		&self.background.background_position_x
+	}
+
+	pub fn set_background_position_x(&mut self, value: longhands::background_position_x::BackgroundPositionX) {
+		self.background.background_position_x = value;
+	}
+
+	pub fn get_background_position_y(&self) -> &longhands::background_position_y::BackgroundPositionY {
+		&self.background.background_position_y
+	}
+
+	pub fn set_background_position_y(&mut self, value: longhands::background_position_y::BackgroundPositionY) {
+		self.background.background_position_y = value;
+	}
+
+	pub fn get_background_repeat(&self) -> &longhands::background_repeat::BackgroundRepeat {
+		&self.background.background_repeat
+	}
+
+	pub fn set_background_repeat(&mut self, value: longhands::background_repeat::BackgroundRepeat) {
+		self.background.background_repeat = value;
+	}
+
+	pub fn get_background_size(&self) -> &longhands::background_size::BackgroundSize {
+		&self.background.background_size
+	}
+
+	pub fn set_background_size(&mut self, value: longhands::background_size::BackgroundSize) {
+		self.background.background_size = value;
+	}
+
+	pub fn get_border_top_left_radius(&self) -> &BorderCornerRadius {
+		&self.border.border_top_left_radius
+	}
+
+	pub fn set_border_top_left_radius(&mut self, value: BorderCornerRadius) {
+		self.border.border_top_left_radius = value;
+	}
+
+	pub fn get_border_top_right_radius(&self) -> &BorderCornerRadius {
+		&self.border.border_top_right_radius
+	}
+
+	pub fn set_border_top_right_radius(&mut self, value: BorderCornerRadius) {
+		self.border.border_top_right_radius = value;
+	}
+
+	pub fn get_border_bottom_right_radius(&self) -> &BorderCornerRadius {
+		&self.border.border_bottom_right_radius
+	}
+
+	pub fn set_border_bottom_right_radius(&mut self, value: BorderCornerRadius) {
+		self.border.border_bottom_right_radius = value;
+	}
+
+	pub fn get_border_bottom_left_radius(&self) -> &BorderCornerRadius {
+		&self.border.border_bottom_left_radius
+	}
+
+	pub fn set_border_bottom_left_radius(&mut self, value: BorderCornerRadius) {
+		self.border.border_bottom_left_radius = value;
+	}
+
+	pub fn get_border_image_source(&self) -> &longhands::border_image_source::BorderImageSource {
+		&self.border.border_image_source
+	}
+
+	pub fn set_border_image_source(&mut self, value: longhands::border_image_source::BorderImageSource) {
+		self.border.border_image_source = value;
+	}
+
+	pub fn get_border_image_slice(&self) -> &longhands::border_image_slice::BorderImageSlice {
+		&self.border.border_image_slice
+	}
+
+	pub fn set_border_image_slice(&mut self, value: longhands::border_image_slice::BorderImageSlice) {
+		self.border.border_image_slice = value;
+	}
+
+	pub fn get_border_image_width(&self) -> &longhands::border_image_width::BorderImageWidth {
+		&self.border.border_image_width
+	}
+
+	pub fn set_border_image_width(&mut self, value: longhands::border_image_width::BorderImageWidth) {
+		self.border.border_image_width = value;
+	}
+
+	pub fn get_border_image_outset(&self) -> &NonNegativeLengthOrNumberRect {
+		&self.border.border_image_outset
+	}
+
+	pub fn set_border_image_outset(&mut self, value: NonNegativeLengthOrNumberRect) {
+		self.border.border_image_outset = value;
+	}
+
+	pub fn get_border_image_repeat(&self) -> &longhands::border_image_repeat::BorderImageRepeat {
+		&self.border.border_image_repeat
+	}
+
+	pub fn set_border_image_repeat(&mut self, value: longhands::border_image_repeat::BorderImageRepeat) {
+		self.border.border_image_repeat = value;
+	}
+
+	pub fn physical_side(&self, side: LogicalSide) -> PhysicalSide {
+		side.to_physical(self.get_writing_mode(), self.get_direction())
+	}
+
+	pub fn physical_corner(&self, corner: LogicalCorner) -> PhysicalCorner {
+		corner.to_physical(self.get_writing_mode(), self.get_direction())
+	}
+
+	pub fn physical_axis(&self, axis: LogicalAxis) -> PhysicalAxis {
+		axis.to_physical(self.get_writing_mode())
+	}
+
+	pub fn get_margin(&self, side: PhysicalSide) -> &LengthPercentageOrAuto {
+		match side {
+			PhysicalSide::Top => self.get_margin_top(),
+			PhysicalSide::Right => self.get_margin_right(),
+			PhysicalSide::Bottom => self.get_margin_bottom(),
+			PhysicalSide::Left => self.get_margin_left(),
+		}
+	}
+
+	pub fn set_margin(&mut self, side: PhysicalSide, value: LengthPercentageOrAuto) {
+		match side {
+			PhysicalSide::Top => self.set_margin_top(value),
+			PhysicalSide::Right => self.set_margin_right(value),
+			PhysicalSide::Bottom => self.set_margin_bottom(value),
+			PhysicalSide::Left => self.set_margin_left(value),
+		}
+	}
+
+	pub fn get_padding(&self, side: PhysicalSide) -> &NonNegativeLengthPercentage {
+		match side {
+			PhysicalSide::Top => self.get_padding_top(),
+			PhysicalSide::Right => self.get_padding_right(),
+			PhysicalSide::Bottom => self.get_padding_bottom(),
+			PhysicalSide::Left => self.get_padding_left(),
+		}
+	}
+
+	pub fn set_padding(&mut self, side: PhysicalSide, value: NonNegativeLengthPercentage) {
+		match side {
+			PhysicalSide::Top => self.set_padding_top(value),
+			PhysicalSide::Right => self.set_padding_right(value),
+			PhysicalSide::Bottom => self.set_padding_bottom(value),
+			PhysicalSide::Left => self.set_padding_left(value),
+		}
+	}
+
+	pub fn get_inset(&self, side: PhysicalSide) -> &LengthPercentageOrAuto {
+		match side {
+			PhysicalSide::Top => self.get_top(),
+			PhysicalSide::Right => self.get_right(),
+			PhysicalSide::Bottom => self.get_bottom(),
+			PhysicalSide::Left => self.get_left(),
+		}
+	}
+
+	pub fn set_inset(&mut self, side: PhysicalSide, value: LengthPercentageOrAuto) {
+		match side {
+			PhysicalSide::Top => self.set_top(value),
+			PhysicalSide::Right => self.set_right(value),
+			PhysicalSide::Bottom => self.set_bottom(value),
+			PhysicalSide::Left => self.set_left(value),
+		}
+	}
+
+	pub fn get_border_color(&self, side: PhysicalSide) -> &RGBA {
+		match side {
+			PhysicalSide::Top => self.get_border_top_color(),
+			PhysicalSide::Right => self.get_border_right_color(),
+			PhysicalSide::Bottom => self.get_border_bottom_color(),
+			PhysicalSide::Left => self.get_border_left_color(),
+		}
+	}
+
+	pub fn set_border_color(&mut self, side: PhysicalSide, value: RGBA) {
+		match side {
+			PhysicalSide::Top => self.set_border_top_color(value),
+			PhysicalSide::Right => self.set_border_right_color(value),
+			PhysicalSide::Bottom => self.set_border_bottom_color(value),
+			PhysicalSide::Left => self.set_border_left_color(value),
+		}
+	}
+
+	pub fn get_border_style(&self, side: PhysicalSide) -> &LineStyle {
+		match side {
+			PhysicalSide::Top => self.get_border_top_style(),
+			PhysicalSide::Right => self.get_border_right_style(),
+			PhysicalSide::Bottom => self.get_border_bottom_style(),
+			PhysicalSide::Left => self.get_border_left_style(),
+		}
+	}
+
+	pub fn set_border_style(&mut self, side: PhysicalSide, value: LineStyle) {
+		match side {
+			PhysicalSide::Top => self.set_border_top_style(value),
+			PhysicalSide::Right => self.set_border_right_style(value),
+			PhysicalSide::Bottom => self.set_border_bottom_style(value),
+			PhysicalSide::Left => self.set_border_left_style(value),
+		}
+	}
+
+	pub fn get_border_width(&self, side: PhysicalSide) -> &LineWidth {
+		match side {
+			PhysicalSide::Top => self.get_border_top_width(),
+			PhysicalSide::Right => self.get_border_right_width(),
+			PhysicalSide::Bottom => self.get_border_bottom_width(),
+			PhysicalSide::Left => self.get_border_left_width(),
+		}
+	}
+
+	pub fn set_border_width(&mut self, side: PhysicalSide, value: LineWidth) {
+		match side {
+			PhysicalSide::Top => self.set_border_top_width(value),
+			PhysicalSide::Right => self.set_border_right_width(value),
+			PhysicalSide::Bottom => self.set_border_bottom_width(value),
+			PhysicalSide::Left => self.set_border_left_width(value),
+		}
+	}
+
+	pub fn get_border_radius(&self, corner: PhysicalCorner) -> &BorderCornerRadius {
+		match corner {
+			PhysicalCorner::TopLeft => self.get_border_top_left_radius(),
+			PhysicalCorner::TopRight => self.get_border_top_right_radius(),
+			PhysicalCorner::BottomRight => self.get_border_bottom_right_radius(),
+			PhysicalCorner::BottomLeft => self.get_border_bottom_left_radius(),
+		}
+	}
+
+	pub fn set_border_radius(&mut self, corner: PhysicalCorner, value: BorderCornerRadius) {
+		match corner {
+			PhysicalCorner::TopLeft => self.set_border_top_left_radius(value),
+			PhysicalCorner::TopRight => self.set_border_top_right_radius(value),
+			PhysicalCorner::BottomRight => self.set_border_bottom_right_radius(value),
+			PhysicalCorner::BottomLeft => self.set_border_bottom_left_radius(value),
+		}
+	}
+
+	pub fn get_size(&self, axis: PhysicalAxis) -> &Size {
+		match axis {
+			PhysicalAxis::Horizontal => self.get_width(),
+			PhysicalAxis::Vertical => self.get_height(),
+		}
+	}
+
+	pub fn set_size(&mut self, axis: PhysicalAxis, value: Size) {
+		match axis {
+			PhysicalAxis::Horizontal => self.set_width(value),
+			PhysicalAxis::Vertical => self.set_height(value),
+		}
+	}
+
+	pub fn get_min_size(&self, axis: PhysicalAxis) -> &Size {
+		match axis {
+			PhysicalAxis::Horizontal => self.get_min_width(),
+			PhysicalAxis::Vertical => self.get_min_height(),
+		}
+	}
+
+	pub fn set_min_size(&mut self, axis: PhysicalAxis, value: Size) {
+		match axis {
+			PhysicalAxis::Horizontal => self.set_min_width(value),
+			PhysicalAxis::Vertical => self.set_min_height(value),
+		}
+	}
+
+	pub fn get_max_size(&self, axis: PhysicalAxis) -> &MaxSize {
+		match axis {
+			PhysicalAxis::Horizontal => self.get_max_width(),
+			PhysicalAxis::Vertical => self.get_max_height(),
+		}
+	}
+
+	pub fn set_max_size(&mut self, axis: PhysicalAxis, value: MaxSize) {
+		match axis {
+			PhysicalAxis::Horizontal => self.set_max_width(value),
+			PhysicalAxis::Vertical => self.set_max_height(value),
+		}
+	}
+
+	pub fn get_overflow(&self, axis: PhysicalAxis) -> &Overflow {
+		match axis {
+			PhysicalAxis::Horizontal => self.get_overflow_x(),
+			PhysicalAxis::Vertical => self.get_overflow_y(),
+		}
+	}
+
+	pub fn set_overflow(&mut self, axis: PhysicalAxis, value: Overflow) {
+		match axis {
+			PhysicalAxis::Horizontal => self.set_overflow_x(value),
+			PhysicalAxis::Vertical => self.set_overflow_y(value),
+		}
+	}
+
+	pub fn get_custom_properties(&self) -> Option<&Rc<CustomPropertiesMap>> {
+		self.custom_properties.as_ref()
+	}
+
+	pub fn set_custom_properties(&mut self, value: Option<Rc<CustomPropertiesMap>>) {
+		self.custom_properties = value;
+	}
+}
+
+pub struct StyleContext<'a, 'b, 'c, 'd> {
+	/// The winning declaration of each origin for every longhand.
+	pub cascade_data: HashMap<LonghandId, Vec<PropertyCascade<'a>>>,
+	pub computed_values: &'b mut ComputedValues,
+	pub parent_style: &'c ComputedValues,
+	/// Viewport-percentage lengths are resolved against its viewport.
+	pub device: &'d Device,
+	/// The computed font size of the root element, `rem` lengths are resolved against it.
+	pub root_font_size: CSSFloat,
+}
+
+pub struct PropertyCascade<'a> {
+	pub priority: CascadePriority,
+	pub property: &'a PropertyDeclaration,
+}
+
+/// The position of a declaration in the cascade, a declaration with a higher priority wins.
+/// Fields are compared in order: origin and importance, whether it comes from a style attribute,
+/// cascade layer, specificity and then order of appearance.
+///
+/// https://drafts.csswg.org/css-cascade-5/#cascade-sort
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct CascadePriority {
+	pub level: CascadeLevel,
+	pub style_attribute: bool,
+	pub layer_order: u32,
+	pub specificity: u32,
+	pub source_order: u32,
 }
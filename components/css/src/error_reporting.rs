@@ -43,6 +43,10 @@ pub enum ContextualParseError<'a> {
 	InvalidMediaRule(&'a str, ParseError<'a>),
 	/// A value was not recognized.
 	UnsupportedValue(&'a str, ParseError<'a>),
+	/// The stylesheet of an @import rule couldn't be loaded.
+	UnloadableImport(&'a str, String),
+	/// An @import rule refers to a stylesheet which is already being imported.
+	CyclicImport(&'a str),
 }
 
 impl<'a> fmt::Display for ContextualParseError<'a> {
@@ -178,6 +182,10 @@ impl<'a> fmt::Display for ContextualParseError<'a> {
 				parse_error_to_str(err, f)
 			},
 			ContextualParseError::UnsupportedValue(_value, ref err) => parse_error_to_str(err, f),
+			ContextualParseError::UnloadableImport(url, ref reason) => {
+				write!(f, "Failed to load @import '{}': {}", url, reason)
+			},
+			ContextualParseError::CyclicImport(url) => write!(f, "Cyclic @import of '{}'", url),
 		}
 	}
 }
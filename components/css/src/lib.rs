@@ -5,9 +5,11 @@ pub mod computed_values;
 pub mod css_writer;
 pub mod element_state;
 pub mod error_reporting;
+pub mod logical_geometry;
 pub mod media_queries;
 pub mod parser;
 pub mod properties;
+pub mod selector_map;
 pub mod selectors;
 pub mod str;
 pub mod stylesheets;
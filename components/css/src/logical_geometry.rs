@@ -0,0 +1,116 @@
+use crate::properties::longhands::direction::Direction;
+use crate::properties::longhands::writing_mode::WritingMode;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PhysicalSide {
+	Top,
+	Right,
+	Bottom,
+	Left,
+}
+
+impl PhysicalSide {
+	pub fn opposite(self) -> Self {
+		match self {
+			PhysicalSide::Top => PhysicalSide::Bottom,
+			PhysicalSide::Right => PhysicalSide::Left,
+			PhysicalSide::Bottom => PhysicalSide::Top,
+			PhysicalSide::Left => PhysicalSide::Right,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogicalSide {
+	BlockStart,
+	BlockEnd,
+	InlineStart,
+	InlineEnd,
+}
+
+impl LogicalSide {
+	/// https://drafts.csswg.org/css-writing-modes/#logical-to-physical
+	pub fn to_physical(self, writing_mode: &WritingMode, direction: &Direction) -> PhysicalSide {
+		let (block_start, inline_start) = match writing_mode {
+			WritingMode::HorizontalTb => (PhysicalSide::Top, PhysicalSide::Left),
+			WritingMode::VerticalRl | WritingMode::SidewaysRl => (PhysicalSide::Right, PhysicalSide::Top),
+			WritingMode::VerticalLr => (PhysicalSide::Left, PhysicalSide::Top),
+			WritingMode::SidewaysLr => (PhysicalSide::Left, PhysicalSide::Bottom),
+		};
+		let inline_start = match direction {
+			Direction::Ltr => inline_start,
+			Direction::Rtl => inline_start.opposite(),
+		};
+		match self {
+			LogicalSide::BlockStart => block_start,
+			LogicalSide::BlockEnd => block_start.opposite(),
+			LogicalSide::InlineStart => inline_start,
+			LogicalSide::InlineEnd => inline_start.opposite(),
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PhysicalCorner {
+	TopLeft,
+	TopRight,
+	BottomRight,
+	BottomLeft,
+}
+
+/// A corner named by its block side first and its inline side second, `start-end` is block-start inline-end.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogicalCorner {
+	StartStart,
+	StartEnd,
+	EndStart,
+	EndEnd,
+}
+
+impl LogicalCorner {
+	/// https://drafts.csswg.org/css-backgrounds-4/#corner-sizing-side-shorthands
+	pub fn to_physical(self, writing_mode: &WritingMode, direction: &Direction) -> PhysicalCorner {
+		let (block, inline) = match self {
+			LogicalCorner::StartStart => (LogicalSide::BlockStart, LogicalSide::InlineStart),
+			LogicalCorner::StartEnd => (LogicalSide::BlockStart, LogicalSide::InlineEnd),
+			LogicalCorner::EndStart => (LogicalSide::BlockEnd, LogicalSide::InlineStart),
+			LogicalCorner::EndEnd => (LogicalSide::BlockEnd, LogicalSide::InlineEnd),
+		};
+		let block = block.to_physical(writing_mode, direction);
+		let inline = inline.to_physical(writing_mode, direction);
+		match (block, inline) {
+			(PhysicalSide::Top, PhysicalSide::Left) | (PhysicalSide::Left, PhysicalSide::Top) => {
+				PhysicalCorner::TopLeft
+			},
+			(PhysicalSide::Top, PhysicalSide::Right) | (PhysicalSide::Right, PhysicalSide::Top) => {
+				PhysicalCorner::TopRight
+			},
+			(PhysicalSide::Bottom, PhysicalSide::Right) | (PhysicalSide::Right, PhysicalSide::Bottom) => {
+				PhysicalCorner::BottomRight
+			},
+			_ => PhysicalCorner::BottomLeft,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PhysicalAxis {
+	Horizontal,
+	Vertical,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogicalAxis {
+	Block,
+	Inline,
+}
+
+impl LogicalAxis {
+	pub fn to_physical(self, writing_mode: &WritingMode) -> PhysicalAxis {
+		let horizontal = matches!(writing_mode, WritingMode::HorizontalTb);
+		match (self, horizontal) {
+			(LogicalAxis::Block, true) | (LogicalAxis::Inline, false) => PhysicalAxis::Vertical,
+			(LogicalAxis::Block, false) | (LogicalAxis::Inline, true) => PhysicalAxis::Horizontal,
+		}
+	}
+}
@@ -0,0 +1,65 @@
+use euclid::Size2D;
+
+use super::media_features::{Hover, Pointer, PrefersColorScheme};
+use crate::values::{CSSFloat, CSSPixel};
+
+/// The device media queries are evaluated against.
+///
+/// <https://drafts.csswg.org/mediaqueries/#media>
+#[derive(Clone, Debug)]
+pub struct Device {
+	/// The size of the viewport, used by `width`, `height` and viewport-percentage lengths.
+	viewport_size: Size2D<f32, CSSPixel>,
+	/// The size of the rendering surface of the output device.
+	device_size: Size2D<f32, CSSPixel>,
+	/// The number of device pixels per CSS pixel.
+	device_pixel_ratio: CSSFloat,
+	color_scheme: PrefersColorScheme,
+	/// The primary input mechanism.
+	pointer: Pointer,
+	hover: Hover,
+}
+
+impl Device {
+	pub fn new(
+		viewport_size: Size2D<f32, CSSPixel>,
+		device_size: Size2D<f32, CSSPixel>,
+		device_pixel_ratio: CSSFloat,
+		color_scheme: PrefersColorScheme,
+		pointer: Pointer,
+		hover: Hover,
+	) -> Self {
+		Device {
+			viewport_size,
+			device_size,
+			device_pixel_ratio,
+			color_scheme,
+			pointer,
+			hover,
+		}
+	}
+
+	pub fn viewport_size(&self) -> Size2D<f32, CSSPixel> {
+		self.viewport_size
+	}
+
+	pub fn device_size(&self) -> Size2D<f32, CSSPixel> {
+		self.device_size
+	}
+
+	pub fn device_pixel_ratio(&self) -> CSSFloat {
+		self.device_pixel_ratio
+	}
+
+	pub fn color_scheme(&self) -> &PrefersColorScheme {
+		&self.color_scheme
+	}
+
+	pub fn pointer(&self) -> &Pointer {
+		&self.pointer
+	}
+
+	pub fn hover(&self) -> &Hover {
+		&self.hover
+	}
+}
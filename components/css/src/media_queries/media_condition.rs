@@ -2,6 +2,7 @@ use std::fmt::Write;
 
 use cssparser::{match_ignore_ascii_case, Parser, _cssparser_internal_to_lowercase};
 
+use super::device::Device;
 use super::media_feature_expression::MediaFeatureExpression;
 use crate::css_writer::ToCss;
 use crate::parser::ParseError;
@@ -154,6 +155,19 @@ impl MediaCondition {
 			Ok(MediaCondition::InParens(Box::new(media_condition)))
 		})
 	}
+
+	/// https://drafts.csswg.org/mediaqueries/#evaluating
+	pub fn matches(&self, device: &Device) -> bool {
+		match self {
+			MediaCondition::Feature(feature) => feature.matches(device),
+			MediaCondition::Not(media_condition) => !media_condition.matches(device),
+			MediaCondition::Operation(medias, Operator::And) => medias.iter().all(|media| media.matches(device)),
+			MediaCondition::Operation(medias, Operator::Or) => medias.iter().any(|media| media.matches(device)),
+			MediaCondition::InParens(media_condition) => media_condition.matches(device),
+			// unknown syntax evaluates to false instead of "unknown"
+			MediaCondition::GeneralEnclosed(_) => false,
+		}
+	}
 }
 
 impl ToCss for MediaCondition {
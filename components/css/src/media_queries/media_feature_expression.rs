@@ -3,6 +3,7 @@ use std::fmt::Write;
 
 use cssparser::{CowRcStr, Parser, ParserState, Token};
 
+use super::device::Device;
 use super::media_condition::MediaCondition;
 use super::media_features::{
 	DisplayMode, Enumerated, ForcedColors, Hover, MediaFeatureDescription, OverflowBlock, OverflowInline, Pointer,
@@ -286,6 +287,93 @@ impl MediaFeatureExpression {
 
 		feature_range.map(|feature_range| (feature_range, cur_state))
 	}
+
+	/// Evaluate the expression against the device, a feature without value is evaluated in
+	/// boolean context and matches when its value is not zero or `none`.
+	/// https://drafts.csswg.org/mediaqueries/#mq-boolean-context
+	pub fn matches(&self, device: &Device) -> bool {
+		let viewport_size = device.viewport_size();
+		let device_size = device.device_size();
+		let actual = match self.feature().name {
+			"width" => viewport_size.width,
+			"height" => viewport_size.height,
+			"aspect-ratio" => viewport_size.width / viewport_size.height,
+			"device-width" => device_size.width,
+			"device-height" => device_size.height,
+			"device-aspect-ratio" => device_size.width / device_size.height,
+			"resolution" => device.device_pixel_ratio(),
+			// a bitmap screen with 8 bits per color component and no color lookup table
+			"grid" => 0.0,
+			"color" => 8.0,
+			"color-index" | "monochrome" => 0.0,
+			_ => return self.matches_enumerated(device),
+		};
+		match &self.value {
+			Some(value) => match value.to_number(device) {
+				Some(expected) => self.compare(actual, expected),
+				None => false,
+			},
+			None => actual != 0.0,
+		}
+	}
+
+	fn matches_enumerated(&self, device: &Device) -> bool {
+		let value = match &self.value {
+			Some(MediaExpressionValue::Enumerated(value)) => value,
+			Some(_) => return false,
+			None => {
+				return match self.feature().name {
+					"scan" | "prefers-reduced-motion" | "prefers-contrast" | "forced-colors" => false,
+					"pointer" | "any-pointer" => *device.pointer() != Pointer::None,
+					"hover" | "any-hover" => *device.hover() != Hover::None,
+					_ => true,
+				}
+			},
+		};
+		let viewport_size = device.viewport_size();
+		match value {
+			MediaExpressionValueEnumerated::Orientation(orientation) => {
+				let actual = if viewport_size.height >= viewport_size.width {
+					Orientation::Portrait
+				} else {
+					Orientation::Landscape
+				};
+				*orientation == actual
+			},
+			MediaExpressionValueEnumerated::DisplayMode(mode) => *mode == DisplayMode::Browser,
+			// scan only applies to interlaced or progressive tv
+			MediaExpressionValueEnumerated::Scan(_) => false,
+			MediaExpressionValueEnumerated::PrefersReducedMotion(motion) => {
+				*motion == PrefersReducedMotion::NoPreference
+			},
+			MediaExpressionValueEnumerated::PrefersContrast(contrast) => *contrast == PrefersContrast::NoPreference,
+			MediaExpressionValueEnumerated::ForcedColors(colors) => *colors == ForcedColors::None,
+			MediaExpressionValueEnumerated::OverflowBlock(block) => *block == OverflowBlock::Scroll,
+			MediaExpressionValueEnumerated::OverflowInline(inline) => *inline == OverflowInline::Scroll,
+			MediaExpressionValueEnumerated::PrefersColorScheme(scheme) => scheme == device.color_scheme(),
+			MediaExpressionValueEnumerated::Pointer(pointer) | MediaExpressionValueEnumerated::AnyPointer(pointer) => {
+				pointer == device.pointer()
+			},
+			MediaExpressionValueEnumerated::Hover(hover) | MediaExpressionValueEnumerated::AnyHover(hover) => {
+				hover == device.hover()
+			},
+		}
+	}
+
+	/// https://drafts.csswg.org/mediaqueries/#mq-range-context
+	fn compare(&self, actual: CSSFloat, expected: CSSFloat) -> bool {
+		match self.range_or_operator {
+			Some(RangeOrOperator::Range(Range::Min)) | Some(RangeOrOperator::Operator(Operator::GreaterThanEqual)) => {
+				actual >= expected
+			},
+			Some(RangeOrOperator::Range(Range::Max)) | Some(RangeOrOperator::Operator(Operator::LessThanEqual)) => {
+				actual <= expected
+			},
+			Some(RangeOrOperator::Operator(Operator::GreaterThan)) => actual > expected,
+			Some(RangeOrOperator::Operator(Operator::LessThan)) => actual < expected,
+			Some(RangeOrOperator::Operator(Operator::Equal)) | None => actual == expected,
+		}
+	}
 }
 
 impl ToCss for MediaFeatureExpression {
@@ -380,6 +468,23 @@ impl MediaExpressionValue {
 			},
 		})
 	}
+
+	/// The numeric value used in range context, lengths are in px and resolutions in dppx.
+	fn to_number(&self, device: &Device) -> Option<CSSFloat> {
+		Some(match self {
+			MediaExpressionValue::Length(length) => length.to_computed_value_with_device(device),
+			MediaExpressionValue::Integer(value) => *value as CSSFloat,
+			MediaExpressionValue::BoolInteger(value) => *value as u32 as CSSFloat,
+			MediaExpressionValue::Float(value) => *value,
+			MediaExpressionValue::NumberRatio(Ratio(first, second)) => first.get() / second.get(),
+			MediaExpressionValue::Resolution(resolution) => match resolution {
+				Resolution::Dpi(value) => value / 96.0,
+				Resolution::Dppx(value) => *value,
+				Resolution::Dpcm(value) => value * 2.54 / 96.0,
+			},
+			MediaExpressionValue::Enumerated(_) | MediaExpressionValue::Ident(_) => return None,
+		})
+	}
 }
 
 impl ToCss for MediaExpressionValue {
@@ -2,6 +2,7 @@ use std::fmt::Write;
 
 use cssparser::{Delimiter, Parser, Token};
 
+use super::device::Device;
 use super::media_query::MediaQuery;
 use crate::css_writer::ToCss;
 use crate::error_reporting::ContextualParseError;
@@ -60,6 +61,12 @@ impl MediaList {
 	pub fn is_empty(&self) -> bool {
 		self.media_queries.is_empty()
 	}
+
+	/// An empty list matches every device, otherwise at least one of its queries has to match.
+	/// https://drafts.csswg.org/mediaqueries/#mq-list
+	pub fn evaluate(&self, device: &Device) -> bool {
+		self.is_empty() || self.media_queries.iter().any(|media_query| media_query.matches(device))
+	}
 }
 
 impl ToCss for MediaList {
@@ -3,6 +3,7 @@ use std::fmt::Write;
 
 use cssparser::{Parser, Token};
 
+use super::device::Device;
 use super::media_condition::MediaCondition;
 use crate::css_writer::ToCss;
 use crate::parser::ParseError;
@@ -120,6 +121,25 @@ impl MediaQuery {
 				})
 			})
 	}
+
+	/// The media type matches `all` and `screen`, a `not` qualifier negates the whole query.
+	/// https://drafts.csswg.org/mediaqueries/#mq-not
+	pub fn matches(&self, device: &Device) -> bool {
+		let media_type_matches = match &self.media_type {
+			MediaQueryType::All => true,
+			MediaQueryType::Concrete(MediaType(ident)) => ident.0.eq_ignore_ascii_case("screen"),
+		};
+		let matches = media_type_matches
+			&& self
+				.condition
+				.as_ref()
+				.map_or(true, |media_condition| media_condition.matches(device));
+		if self.qualifier == Some(Qualifier::Not) {
+			!matches
+		} else {
+			matches
+		}
+	}
 }
 
 impl ToCss for MediaQuery {
@@ -1,3 +1,4 @@
+pub mod device;
 pub mod media_condition;
 pub mod media_feature_expression;
 pub mod media_features;
@@ -1,3 +1,20 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use cssparser::{Delimiter, Parser, ParserInput, SourcePosition, ToCss, Token, TokenSerializationType};
+
+use crate::parser::ParseError;
+use crate::properties::property_id::CSSWideKeyword;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+
+/// A custom property name, without the `--` prefix.
+pub type Name = String;
+
+/// The computed custom properties of an element, every custom property is inherited.
+///
+/// <https://drafts.csswg.org/css-variables/#defining-variables>
+pub type CustomPropertiesMap = HashMap<Name, Rc<VariableValue>>;
+
 /// Parse a custom property name.
 ///
 /// <https://drafts.csswg.org/css-variables/#typedef-custom-property-name>
@@ -8,3 +25,419 @@ pub fn parse_name(s: &str) -> Result<&str, ()> {
 		Err(())
 	}
 }
+
+/// A token stream which is the value of a custom property, either as specified
+/// (it may contain var() references) or after var() substitution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableValue {
+	css: String,
+	first_token_type: TokenSerializationType,
+	last_token_type: TokenSerializationType,
+	/// Custom properties referenced by var() functions, empty once substituted.
+	references: HashSet<Name>,
+}
+
+impl VariableValue {
+	fn empty() -> Self {
+		VariableValue {
+			css: String::new(),
+			first_token_type: TokenSerializationType::nothing(),
+			last_token_type: TokenSerializationType::nothing(),
+			references: HashSet::new(),
+		}
+	}
+
+	/// Parse the value of a custom property.
+	///
+	/// <https://drafts.csswg.org/css-variables/#defining-variables>
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let mut references = HashSet::new();
+		let (first_token_type, css, last_token_type) =
+			parse_self_contained_declaration_value(input, Some(&mut references))?;
+		Ok(VariableValue {
+			css,
+			first_token_type,
+			last_token_type,
+			references,
+		})
+	}
+
+	pub fn css(&self) -> &str {
+		&self.css
+	}
+
+	pub fn has_references(&self) -> bool {
+		!self.references.is_empty()
+	}
+
+	fn push(
+		&mut self,
+		css: &str,
+		css_first_token_type: TokenSerializationType,
+		css_last_token_type: TokenSerializationType,
+	) {
+		// This happens e.g. between two subsequent var() functions: `var(--a)var(--b)`.
+		if css.is_empty() {
+			return;
+		}
+
+		self.first_token_type.set_if_nothing(css_first_token_type);
+		// Two tokens which would be merged when serialized next to each other
+		// (like `10` and `px`) are separated by an empty comment.
+		if self.last_token_type.needs_separator_when_before(css_first_token_type) {
+			self.css.push_str("/**/")
+		}
+		self.css.push_str(css);
+		self.last_token_type = css_last_token_type;
+	}
+
+	fn push_from(
+		&mut self,
+		input: &Parser,
+		position: (SourcePosition, TokenSerializationType),
+		last_token_type: TokenSerializationType,
+	) {
+		self.push(input.slice_from(position.0), position.1, last_token_type)
+	}
+
+	fn push_variable(&mut self, variable: &VariableValue) {
+		debug_assert!(variable.references.is_empty());
+		self.push(&variable.css, variable.first_token_type, variable.last_token_type)
+	}
+}
+
+impl ToCss for VariableValue {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		dest.write_str(&self.css)
+	}
+}
+
+/// The value of a `--*` declaration.
+#[derive(Clone, Debug)]
+pub enum CustomDeclarationValue {
+	Value(Rc<VariableValue>),
+	CSSWideKeyword(CSSWideKeyword),
+}
+
+impl ToCss for CustomDeclarationValue {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			CustomDeclarationValue::Value(value) => value.to_css(dest),
+			CustomDeclarationValue::CSSWideKeyword(keyword) => keyword.to_css(dest),
+		}
+	}
+}
+
+/// Parse the value of a non-custom property which failed to parse and contains var() functions,
+/// the value is kept as is until it is substituted at computed value time.
+pub fn parse_non_custom_with_var<'i, 't>(
+	input: &mut Parser<'i, 't>,
+) -> Result<(TokenSerializationType, String), ParseError<'i>> {
+	let (first_token_type, css, _) = parse_self_contained_declaration_value(input, None)?;
+	Ok((first_token_type, css))
+}
+
+fn parse_self_contained_declaration_value<'i, 't>(
+	input: &mut Parser<'i, 't>,
+	references: Option<&mut HashSet<Name>>,
+) -> Result<(TokenSerializationType, String, TokenSerializationType), ParseError<'i>> {
+	input.skip_whitespace();
+	let start_position = input.position();
+	let (first_token_type, last_token_type) = parse_declaration_value(input, references)?;
+	let css = input.slice_from(start_position).trim_end().to_string();
+	Ok((first_token_type, css, last_token_type))
+}
+
+/// <https://drafts.csswg.org/css-syntax-3/#typedef-declaration-value>
+fn parse_declaration_value<'i, 't>(
+	input: &mut Parser<'i, 't>,
+	references: Option<&mut HashSet<Name>>,
+) -> Result<(TokenSerializationType, TokenSerializationType), ParseError<'i>> {
+	input.parse_until_before(Delimiter::Bang | Delimiter::Semicolon, |input| {
+		parse_declaration_value_block(input, references)
+	})
+}
+
+/// Like parse_declaration_value, but accept `!` and `;` since they are only invalid at the top level.
+fn parse_declaration_value_block<'i, 't>(
+	input: &mut Parser<'i, 't>,
+	mut references: Option<&mut HashSet<Name>>,
+) -> Result<(TokenSerializationType, TokenSerializationType), ParseError<'i>> {
+	let mut first_token_type = TokenSerializationType::nothing();
+	let mut last_token_type = TokenSerializationType::nothing();
+	loop {
+		let token = match input.next_including_whitespace_and_comments() {
+			Ok(token) => token.clone(),
+			Err(_) => return Ok((first_token_type, last_token_type)),
+		};
+		first_token_type.set_if_nothing(token.serialization_type());
+		let token_type = match token {
+			Token::BadUrl(url) => {
+				return Err(input.new_custom_error(StyleParseErrorKind::BadUrlInDeclarationValueBlock(url)));
+			},
+			Token::BadString(string) => {
+				return Err(input.new_custom_error(StyleParseErrorKind::BadStringInDeclarationValueBlock(string)));
+			},
+			Token::CloseParenthesis => {
+				return Err(
+					input.new_custom_error(StyleParseErrorKind::UnbalancedCloseParenthesisInDeclarationValueBlock)
+				);
+			},
+			Token::CloseSquareBracket => {
+				return Err(
+					input.new_custom_error(StyleParseErrorKind::UnbalancedCloseSquareBracketInDeclarationValueBlock)
+				);
+			},
+			Token::CloseCurlyBracket => {
+				return Err(
+					input.new_custom_error(StyleParseErrorKind::UnbalancedCloseCurlyBracketInDeclarationValueBlock)
+				);
+			},
+			Token::Function(ref name) => {
+				if name.eq_ignore_ascii_case("var") {
+					let args_start = input.state();
+					input
+						.parse_nested_block(|input| parse_var_function(input, references.as_mut().map(|r| &mut **r)))?;
+					input.reset(&args_start);
+				}
+				input.parse_nested_block(|input| {
+					parse_declaration_value_block(input, references.as_mut().map(|r| &mut **r))
+				})?;
+				Token::CloseParenthesis.serialization_type()
+			},
+			Token::ParenthesisBlock | Token::CurlyBracketBlock | Token::SquareBracketBlock => {
+				input.parse_nested_block(|input| {
+					parse_declaration_value_block(input, references.as_mut().map(|r| &mut **r))
+				})?;
+				// It's the same type for CloseCurlyBracket and CloseSquareBracket.
+				Token::CloseParenthesis.serialization_type()
+			},
+			// Whitespaces don't change how the surrounding tokens are serialized.
+			Token::WhiteSpace(_) => continue,
+			token => token.serialization_type(),
+		};
+		last_token_type = token_type;
+	}
+}
+
+/// <https://drafts.csswg.org/css-variables/#using-variables>
+fn parse_var_function<'i, 't>(
+	input: &mut Parser<'i, 't>,
+	references: Option<&mut HashSet<Name>>,
+) -> Result<(), ParseError<'i>> {
+	let name = input.expect_ident_cloned()?;
+	let name = parse_name(&name)
+		.map_err(|()| input.new_custom_error(StyleParseErrorKind::UnexpectedValue(name.clone())))?
+		.to_string();
+	if input.try_parse(|input| input.expect_comma()).is_ok() {
+		parse_declaration_value(input, None)?;
+	}
+	if let Some(references) = references {
+		references.insert(name);
+	}
+	Ok(())
+}
+
+/// Replace every var() function in `css` by the value of the custom property it references,
+/// or by its fallback when that custom property is not defined.
+///
+/// <https://drafts.csswg.org/css-variables/#substitute-a-var>
+pub fn substitute(
+	css: &str,
+	first_token_type: TokenSerializationType,
+	custom_properties: Option<&CustomPropertiesMap>,
+) -> Result<String, ()> {
+	let empty = CustomPropertiesMap::new();
+	let custom_properties = custom_properties.unwrap_or(&empty);
+	substitute_value(css, first_token_type, custom_properties).map(|value| value.css)
+}
+
+fn substitute_value(
+	css: &str,
+	first_token_type: TokenSerializationType,
+	custom_properties: &CustomPropertiesMap,
+) -> Result<VariableValue, ()> {
+	let mut substituted = VariableValue::empty();
+	let mut input = ParserInput::new(css);
+	let mut input = Parser::new(&mut input);
+	let mut position = (input.position(), first_token_type);
+	let last_token_type =
+		substitute_block(&mut input, &mut position, &mut substituted, custom_properties).map_err(|_| ())?;
+	substituted.push_from(&input, position, last_token_type);
+	Ok(substituted)
+}
+
+fn substitute_block<'i, 't>(
+	input: &mut Parser<'i, 't>,
+	position: &mut (SourcePosition, TokenSerializationType),
+	substituted: &mut VariableValue,
+	custom_properties: &CustomPropertiesMap,
+) -> Result<TokenSerializationType, ParseError<'i>> {
+	let mut last_token_type = TokenSerializationType::nothing();
+	let mut set_position_at_next_iteration = false;
+	loop {
+		let before_this_token = input.position();
+		let next = input.next_including_whitespace_and_comments();
+		if set_position_at_next_iteration {
+			*position = (
+				before_this_token,
+				match next {
+					Ok(token) => token.serialization_type(),
+					Err(_) => TokenSerializationType::nothing(),
+				},
+			);
+			set_position_at_next_iteration = false;
+		}
+		let token = match next {
+			Ok(token) => token.clone(),
+			Err(_) => break,
+		};
+		match token {
+			Token::Function(ref name) if name.eq_ignore_ascii_case("var") => {
+				substituted.push(input.slice(position.0..before_this_token), position.1, last_token_type);
+				input.parse_nested_block(|input| {
+					// parse_var_function() already made sure the name is valid
+					let name = input.expect_ident_cloned()?;
+					let name = parse_name(&name).unwrap_or_default();
+					// a value which still has references is part of a cycle
+					match custom_properties.get(name).filter(|value| !value.has_references()) {
+						Some(value) => {
+							last_token_type = value.last_token_type;
+							substituted.push_variable(value);
+							// Skip over the fallback, parse_nested_block fails if the input is not consumed.
+							while input.next().is_ok() {}
+						},
+						None => {
+							input.expect_comma()?;
+							let after_comma = input.state();
+							let first_token_type = input.next_including_whitespace_and_comments().map_or_else(
+								|_| TokenSerializationType::nothing(),
+								|token| token.serialization_type(),
+							);
+							input.reset(&after_comma);
+							let mut position = (after_comma.position(), first_token_type);
+							last_token_type = substitute_block(input, &mut position, substituted, custom_properties)?;
+							substituted.push_from(input, position, last_token_type);
+						},
+					}
+					Ok(())
+				})?;
+				set_position_at_next_iteration = true
+			},
+			Token::Function(_) | Token::ParenthesisBlock | Token::CurlyBracketBlock | Token::SquareBracketBlock => {
+				input.parse_nested_block(|input| substitute_block(input, position, substituted, custom_properties))?;
+				// It's the same type for CloseCurlyBracket and CloseSquareBracket.
+				last_token_type = Token::CloseParenthesis.serialization_type();
+			},
+			_ => last_token_type = token.serialization_type(),
+		}
+	}
+	Ok(last_token_type)
+}
+
+/// Compute the custom properties of an element from the cascaded `--*` declarations
+/// and the custom properties inherited from its parent.
+///
+/// <https://drafts.csswg.org/css-variables/#cycles>
+pub fn cascade<'a, I>(inherited: Option<&Rc<CustomPropertiesMap>>, declarations: I) -> Option<Rc<CustomPropertiesMap>>
+where
+	I: Iterator<Item = (&'a Name, &'a CustomDeclarationValue)>,
+{
+	let mut declarations = declarations.peekable();
+	if declarations.peek().is_none() {
+		return inherited.cloned();
+	}
+
+	let mut custom_properties = inherited.map_or_else(CustomPropertiesMap::new, |map| (**map).clone());
+	for (name, value) in declarations {
+		match value {
+			CustomDeclarationValue::Value(value) => {
+				custom_properties.insert(name.clone(), value.clone());
+			},
+			CustomDeclarationValue::CSSWideKeyword(CSSWideKeyword::Initial) => {
+				custom_properties.remove(name);
+			},
+			// custom properties are inherited, so unset is the same as inherit
+			CustomDeclarationValue::CSSWideKeyword(_) => {},
+		}
+	}
+
+	let names: Vec<Name> = custom_properties
+		.iter()
+		.filter(|(_, value)| value.has_references())
+		.map(|(name, _)| name.clone())
+		.collect();
+	let mut substitutor = Substitutor {
+		custom_properties: &mut custom_properties,
+		visiting: Vec::new(),
+		resolved: HashSet::new(),
+		cyclic: HashSet::new(),
+	};
+	for name in names.iter() {
+		substitutor.resolve(name);
+	}
+
+	if custom_properties.is_empty() {
+		None
+	} else {
+		Some(Rc::new(custom_properties))
+	}
+}
+
+/// Substitute var() functions of custom properties in dependency order, properties
+/// which are part of a reference cycle (or reference an undefined property without
+/// fallback) are invalid at computed-value time and removed.
+struct Substitutor<'a> {
+	custom_properties: &'a mut CustomPropertiesMap,
+	visiting: Vec<Name>,
+	resolved: HashSet<Name>,
+	cyclic: HashSet<Name>,
+}
+
+impl<'a> Substitutor<'a> {
+	fn resolve(&mut self, name: &Name) {
+		if self.resolved.contains(name) {
+			return;
+		}
+		if let Some(index) = self.visiting.iter().position(|visiting| visiting == name) {
+			// every property from the referenced one to the top of the stack is part of the cycle
+			for visiting in self.visiting[index..].iter() {
+				self.cyclic.insert(visiting.clone());
+			}
+			return;
+		}
+		let value = match self.custom_properties.get(name) {
+			Some(value) if value.has_references() => value.clone(),
+			_ => {
+				self.resolved.insert(name.clone());
+				return;
+			},
+		};
+
+		self.visiting.push(name.clone());
+		for reference in value.references.iter() {
+			self.resolve(reference);
+		}
+		self.visiting.pop();
+		self.resolved.insert(name.clone());
+
+		if self.cyclic.contains(name) {
+			self.custom_properties.remove(name);
+			return;
+		}
+		match substitute_value(&value.css, value.first_token_type, self.custom_properties) {
+			Ok(substituted) => {
+				self.custom_properties.insert(name.clone(), Rc::new(substituted));
+			},
+			Err(()) => {
+				self.custom_properties.remove(name);
+			},
+		}
+	}
+}
@@ -1,10 +1,18 @@
-use cssparser::{Parser, ToCss};
+use std::rc::Rc;
+
+use common::not_reached;
+use common::url::BrowserUrl;
+use cssparser::{Parser, ParserInput, ToCss, TokenSerializationType};
+use selectors::context::QuirksMode;
 
 use crate::parser::ParseError;
+use crate::properties::custom_properties::{self, CustomDeclarationValue, CustomPropertiesMap, VariableValue};
 use crate::properties::declaration_block::SourcePropertyDeclaration;
 use crate::properties::longhand_id::LonghandId;
 use crate::properties::property_id::{CSSWideKeyword, PropertyId};
 use crate::properties::shorthand_id::ShorthandId;
+use crate::stylesheets::css_rule::CssRuleType;
+use crate::stylesheets::origin::Origin;
 use crate::stylesheets::stylesheet::ParserContext;
 use crate::values::specified::number::Integer;
 use crate::{properties, values};
@@ -369,8 +377,41 @@ pub enum PropertyDeclaration {
 	Right(values::specified::length::LengthPercentageOrAuto),
 	/// `top`
 	Top(values::specified::length::LengthPercentageOrAuto),
+	/// `grid-template-columns`
+	GridTemplateColumns(values::specified::grid::GridTemplateComponent),
+	/// `grid-template-rows`
+	GridTemplateRows(values::specified::grid::GridTemplateComponent),
+	/// `grid-template-areas`
+	GridTemplateAreas(values::specified::grid::GridTemplateAreas),
+	/// `grid-auto-columns`
+	GridAutoColumns(values::specified::grid::ImplicitGridTracks),
+	/// `grid-auto-rows`
+	GridAutoRows(values::specified::grid::ImplicitGridTracks),
+	/// `grid-auto-flow`
+	GridAutoFlow(values::specified::grid::GridAutoFlow),
+	/// `grid-row-start`
+	GridRowStart(values::specified::grid::GridLine),
+	/// `grid-row-end`
+	GridRowEnd(values::specified::grid::GridLine),
+	/// `grid-column-start`
+	GridColumnStart(values::specified::grid::GridLine),
+	/// `grid-column-end`
+	GridColumnEnd(values::specified::grid::GridLine),
+	/// `row-gap`
+	RowGap(values::specified::length::NonNegativeLengthPercentageOrNormal),
+	/// `justify-items`
+	JustifyItems(properties::longhands::justify_items::JustifyItems),
+	/// `justify-self`
+	JustifySelf(properties::longhands::justify_self::JustifySelf),
+	ColumnRuleColor(values::specified::color::Color),
+	ColumnRuleStyle(values::specified::layout::LineStyle),
+	ColumnRuleWidth(values::specified::line::LineWidth),
 	/// A CSS-wide keyword.
 	CSSWideKeyword(WideKeywordDeclaration),
+	/// A longhand whose value contains var() functions, parsed after substitution.
+	WithVariables(VariableDeclaration),
+	/// A custom property declaration.
+	Custom(CustomDeclaration),
 }
 
 impl PropertyDeclaration {
@@ -380,6 +421,8 @@ impl PropertyDeclaration {
 		Self::CSSWideKeyword(WideKeywordDeclaration { id, keyword })
 	}
 
+	/// Returns the longhand of this declaration, custom properties don't have one,
+	/// use `id()` when the declaration can be a custom property.
 	pub fn longhand_id(&self) -> LonghandId {
 		match *self {
 			PropertyDeclaration::AlignContent(..) => LonghandId::AlignContent,
@@ -561,12 +604,33 @@ impl PropertyDeclaration {
 			PropertyDeclaration::CounterIncrement(..) => LonghandId::CounterIncrement,
 			PropertyDeclaration::CounterReset(..) => LonghandId::CounterReset,
 			PropertyDeclaration::CounterSet(..) => LonghandId::CounterSet,
+			PropertyDeclaration::GridTemplateColumns(..) => LonghandId::GridTemplateColumns,
+			PropertyDeclaration::GridTemplateRows(..) => LonghandId::GridTemplateRows,
+			PropertyDeclaration::GridTemplateAreas(..) => LonghandId::GridTemplateAreas,
+			PropertyDeclaration::GridAutoColumns(..) => LonghandId::GridAutoColumns,
+			PropertyDeclaration::GridAutoRows(..) => LonghandId::GridAutoRows,
+			PropertyDeclaration::GridAutoFlow(..) => LonghandId::GridAutoFlow,
+			PropertyDeclaration::GridRowStart(..) => LonghandId::GridRowStart,
+			PropertyDeclaration::GridRowEnd(..) => LonghandId::GridRowEnd,
+			PropertyDeclaration::GridColumnStart(..) => LonghandId::GridColumnStart,
+			PropertyDeclaration::GridColumnEnd(..) => LonghandId::GridColumnEnd,
+			PropertyDeclaration::RowGap(..) => LonghandId::RowGap,
+			PropertyDeclaration::JustifyItems(..) => LonghandId::JustifyItems,
+			PropertyDeclaration::JustifySelf(..) => LonghandId::JustifySelf,
+			PropertyDeclaration::ColumnRuleColor(..) => LonghandId::ColumnRuleColor,
+			PropertyDeclaration::ColumnRuleStyle(..) => LonghandId::ColumnRuleStyle,
+			PropertyDeclaration::ColumnRuleWidth(..) => LonghandId::ColumnRuleWidth,
 			PropertyDeclaration::CSSWideKeyword(ref declaration) => declaration.id,
+			PropertyDeclaration::WithVariables(ref declaration) => declaration.id,
+			PropertyDeclaration::Custom(..) => not_reached!(),
 		}
 	}
 
 	pub fn id(&self) -> PropertyId {
-		PropertyId::Longhand(self.longhand_id())
+		match self {
+			PropertyDeclaration::Custom(declaration) => PropertyId::Custom(declaration.name.clone()),
+			_ => PropertyId::Longhand(self.longhand_id()),
+		}
 	}
 
 	/// The `context` parameter controls this:
@@ -590,8 +654,16 @@ impl PropertyDeclaration {
 
 		let non_custom_id = id.non_custom_id();
 		match id {
-			PropertyId::Custom(_property_name) => {
-				todo!()
+			PropertyId::Custom(property_name) => {
+				let value = match input.try_parse(CSSWideKeyword::parse) {
+					Ok(keyword) => CustomDeclarationValue::CSSWideKeyword(keyword),
+					Err(()) => CustomDeclarationValue::Value(Rc::new(VariableValue::parse(input)?)),
+				};
+				declarations.push(PropertyDeclaration::Custom(CustomDeclaration {
+					name: property_name,
+					value,
+				}));
+				return Ok(());
 			},
 			PropertyId::Longhand(id) => {
 				input.skip_whitespace(); // Unnecessary for correctness, but may help try() rewind less.
@@ -600,7 +672,29 @@ impl PropertyDeclaration {
 					.map(|keyword| PropertyDeclaration::css_wide_keyword(id, keyword))
 					.or_else(|()| {
 						input.look_for_var_or_env_functions();
-						input.parse_entirely(|input| id.parse_value(context, input))
+						let start = input.state();
+						input
+							.parse_entirely(|input| id.parse_value(context, input))
+							.or_else(|err| {
+								while let Ok(_) = input.next() {} // Look for var() after the error.
+								if !input.seen_var_or_env_functions() {
+									return Err(err);
+								}
+
+								input.reset(&start);
+								let (first_token_type, css) = custom_properties::parse_non_custom_with_var(input)?;
+								Ok(PropertyDeclaration::WithVariables(VariableDeclaration {
+									id,
+									value: Rc::new(UnparsedValue {
+										css,
+										first_token_type,
+										from_shorthand: None,
+										origin: context.stylesheet_origin,
+										quirks_mode: context.quirks_mode,
+										url_data: context.url_data.cloned(),
+									}),
+								}))
+							})
 					})
 					.map(|declaration| declarations.push(declaration))?;
 			},
@@ -616,6 +710,7 @@ impl PropertyDeclaration {
 					}
 				} else {
 					input.look_for_var_or_env_functions();
+					let start = input.state();
 					// Not using parse_entirely here: each
 					// all::parse_into function needs to do so
 					// *before* pushing to `declarations`.
@@ -625,7 +720,23 @@ impl PropertyDeclaration {
 							return Err(err);
 						}
 
-						todo!()
+						input.reset(&start);
+						let (first_token_type, css) = custom_properties::parse_non_custom_with_var(input)?;
+						let unparsed = Rc::new(UnparsedValue {
+							css,
+							first_token_type,
+							from_shorthand: Some(id),
+							origin: context.stylesheet_origin,
+							quirks_mode: context.quirks_mode,
+							url_data: context.url_data.cloned(),
+						});
+						for longhand in id.longhands() {
+							declarations.push(PropertyDeclaration::WithVariables(VariableDeclaration {
+								id: longhand,
+								value: unparsed.clone(),
+							}));
+						}
+						Ok(())
 					})?;
 				}
 			},
@@ -820,7 +931,25 @@ impl ToCss for PropertyDeclaration {
 			PropertyDeclaration::MarginTop(property) => property.to_css(dest),
 			PropertyDeclaration::Right(property) => property.to_css(dest),
 			PropertyDeclaration::Top(property) => property.to_css(dest),
+			PropertyDeclaration::GridTemplateColumns(property) => property.to_css(dest),
+			PropertyDeclaration::GridTemplateRows(property) => property.to_css(dest),
+			PropertyDeclaration::GridTemplateAreas(property) => property.to_css(dest),
+			PropertyDeclaration::GridAutoColumns(property) => property.to_css(dest),
+			PropertyDeclaration::GridAutoRows(property) => property.to_css(dest),
+			PropertyDeclaration::GridAutoFlow(property) => property.to_css(dest),
+			PropertyDeclaration::GridRowStart(property) => property.to_css(dest),
+			PropertyDeclaration::GridRowEnd(property) => property.to_css(dest),
+			PropertyDeclaration::GridColumnStart(property) => property.to_css(dest),
+			PropertyDeclaration::GridColumnEnd(property) => property.to_css(dest),
+			PropertyDeclaration::RowGap(property) => property.to_css(dest),
+			PropertyDeclaration::JustifyItems(property) => property.to_css(dest),
+			PropertyDeclaration::JustifySelf(property) => property.to_css(dest),
+			PropertyDeclaration::ColumnRuleColor(property) => property.to_css(dest),
+			PropertyDeclaration::ColumnRuleStyle(property) => property.to_css(dest),
+			PropertyDeclaration::ColumnRuleWidth(property) => property.to_css(dest),
 			PropertyDeclaration::CSSWideKeyword(property) => property.to_css(dest),
+			PropertyDeclaration::WithVariables(property) => property.to_css(dest),
+			PropertyDeclaration::Custom(property) => property.to_css(dest),
 		}
 	}
 }
@@ -863,6 +992,93 @@ impl ToCss for WideKeywordDeclaration {
 	}
 }
 
+/// A value of a longhand or shorthand which contains var() functions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnparsedValue {
+	css: String,
+	first_token_type: TokenSerializationType,
+	/// The shorthand this value was specified for, if any.
+	from_shorthand: Option<ShorthandId>,
+	/// The origin, quirks mode and url of the stylesheet, the value is parsed with them once the variables are
+	/// substituted.
+	origin: Origin,
+	quirks_mode: QuirksMode,
+	url_data: Option<BrowserUrl>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableDeclaration {
+	pub id: LonghandId,
+	pub value: Rc<UnparsedValue>,
+}
+
+impl VariableDeclaration {
+	/// Substitute var() functions with the element's custom properties and parse the result,
+	/// a value which can't be parsed afterward is invalid at computed-value time and behaves as `unset`.
+	///
+	/// <https://drafts.csswg.org/css-variables/#invalid-at-computed-value-time>
+	pub fn substitute_variables(&self, custom_properties: Option<&CustomPropertiesMap>) -> PropertyDeclaration {
+		let invalid_at_computed_value_time = || PropertyDeclaration::css_wide_keyword(self.id, CSSWideKeyword::Unset);
+		let css = match custom_properties::substitute(&self.value.css, self.value.first_token_type, custom_properties) {
+			Ok(css) => css,
+			Err(()) => return invalid_at_computed_value_time(),
+		};
+
+		let mut context = ParserContext::new(
+			self.value.origin,
+			Some(CssRuleType::Style),
+			self.value.quirks_mode,
+			None,
+		);
+		context.url_data = self.value.url_data.as_ref();
+		let mut input = ParserInput::new(&css);
+		let mut input = Parser::new(&mut input);
+		if let Ok(keyword) = input.try_parse(CSSWideKeyword::parse) {
+			return PropertyDeclaration::css_wide_keyword(self.id, keyword);
+		}
+		match self.value.from_shorthand {
+			None => input
+				.parse_entirely(|input| self.id.parse_value(&context, input))
+				.unwrap_or_else(|_| invalid_at_computed_value_time()),
+			Some(shorthand) => {
+				let mut declarations = SourcePropertyDeclaration::new();
+				match input.parse_entirely(|input| shorthand.parse_into(&mut declarations, &context, input)) {
+					Ok(()) => declarations
+						.drain()
+						.find(|declaration| declaration.longhand_id() == self.id)
+						.unwrap_or_else(invalid_at_computed_value_time),
+					Err(_) => invalid_at_computed_value_time(),
+				}
+			},
+		}
+	}
+}
+
+impl ToCss for VariableDeclaration {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		dest.write_str(&self.value.css)
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct CustomDeclaration {
+	/// The name of the custom property, without the `--` prefix.
+	pub name: custom_properties::Name,
+	pub value: CustomDeclarationValue,
+}
+
+impl ToCss for CustomDeclaration {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		self.value.to_css(dest)
+	}
+}
+
 macro_rules! property_keywords_impl {
     ( $input:tt,
         $($name:path, $value:expr),+,
@@ -133,16 +133,10 @@ impl<'a> Iterator for PropertyIterator<'a> {
 	type Item = (bool, &'a PropertyDeclaration);
 
 	fn next(&mut self) -> Option<Self::Item> {
-		if let Some(property) = self.source.declarations.get(self.index) {
-			self.index += 1;
-			if let Some(importance) = self.source.declarations_importance.get(self.index) {
-				Some((importance, property))
-			} else {
-				Some((false, property))
-			}
-		} else {
-			None
-		}
+		let property = self.source.declarations.get(self.index)?;
+		let importance = self.source.declarations_importance.get(self.index).unwrap_or(false);
+		self.index += 1;
+		Some((importance, property))
 	}
 }
 
@@ -160,7 +154,10 @@ impl ToCss for PropertyDeclarationBlock {
 				match id {
 					PropertyId::Longhand(longhand) => longhand.to_css(dest)?,
 					PropertyId::Shorthand(shorthand) => shorthand.to_css(dest)?,
-					PropertyId::Custom(custom) => custom.to_css(dest)?,
+					PropertyId::Custom(custom) => {
+						dest.write_str("--")?;
+						dest.write_str(&custom)?
+					},
 				}
 				dest.write_str(": ")?;
 				dest.write_str(&cssparser::ToCss::to_css_string(declaration))?;
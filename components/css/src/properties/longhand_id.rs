@@ -6,8 +6,9 @@ use cssparser::Parser;
 use num_enum::TryFromPrimitive;
 
 use super::property_id::{NonCustomPropertyId, NonCustomPropertyIterator};
-use crate::computed_values::StyleContext;
+use crate::computed_values::{ComputedValues, StyleContext};
 use crate::css_writer::{CssWriter, ToCss};
+use crate::logical_geometry::{LogicalAxis, LogicalCorner, LogicalSide, PhysicalAxis, PhysicalCorner, PhysicalSide};
 use crate::parser::ParseError;
 use crate::properties::declaration::PropertyDeclaration;
 use crate::properties::longhands;
@@ -378,6 +379,38 @@ pub enum LonghandId {
 	CounterSet = 177,
 	/// object-fit
 	ObjectFit = 178,
+	/// grid-template-columns
+	GridTemplateColumns = 179,
+	/// grid-template-rows
+	GridTemplateRows = 180,
+	/// grid-template-areas
+	GridTemplateAreas = 181,
+	/// grid-auto-columns
+	GridAutoColumns = 182,
+	/// grid-auto-rows
+	GridAutoRows = 183,
+	/// grid-auto-flow
+	GridAutoFlow = 184,
+	/// grid-row-start
+	GridRowStart = 185,
+	/// grid-row-end
+	GridRowEnd = 186,
+	/// grid-column-start
+	GridColumnStart = 187,
+	/// grid-column-end
+	GridColumnEnd = 188,
+	/// row-gap
+	RowGap = 189,
+	/// justify-items
+	JustifyItems = 190,
+	/// justify-self
+	JustifySelf = 191,
+	/// column-rule-color
+	ColumnRuleColor = 192,
+	/// column-rule-style
+	ColumnRuleStyle = 193,
+	/// column-rule-width
+	ColumnRuleWidth = 194,
 }
 
 impl ToCss for LonghandId {
@@ -547,7 +580,7 @@ impl LonghandId {
 		static BOX_SHADOW: &'static [ShorthandId] = &[ShorthandId::All];
 		static CLIP: &'static [ShorthandId] = &[ShorthandId::All];
 		static COLOR: &'static [ShorthandId] = &[ShorthandId::All];
-		static COLUMN_GAP: &'static [ShorthandId] = &[ShorthandId::All];
+		static COLUMN_GAP: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Gap];
 		static COLUMN_WIDTH: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Columns];
 		static CONTENT: &'static [ShorthandId] = &[ShorthandId::All];
 		static COUNTER_INCREMENT: &'static [ShorthandId] = &[ShorthandId::All];
@@ -724,6 +757,22 @@ impl LonghandId {
 		static MARGIN_TOP: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Margin];
 		static RIGHT: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Inset];
 		static TOP: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Inset];
+		static GRID_TEMPLATE_COLUMNS: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::GridTemplate, ShorthandId::Grid];
+		static GRID_TEMPLATE_ROWS: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::GridTemplate, ShorthandId::Grid];
+		static GRID_TEMPLATE_AREAS: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::GridTemplate, ShorthandId::Grid];
+		static GRID_AUTO_COLUMNS: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Grid];
+		static GRID_AUTO_ROWS: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Grid];
+		static GRID_AUTO_FLOW: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Grid];
+		static GRID_ROW_START: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::GridRow, ShorthandId::GridArea];
+		static GRID_ROW_END: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::GridRow, ShorthandId::GridArea];
+		static GRID_COLUMN_START: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::GridColumn, ShorthandId::GridArea];
+		static GRID_COLUMN_END: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::GridColumn, ShorthandId::GridArea];
+		static ROW_GAP: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::Gap];
+		static JUSTIFY_ITEMS: &'static [ShorthandId] = &[ShorthandId::All];
+		static JUSTIFY_SELF: &'static [ShorthandId] = &[ShorthandId::All];
+		static COLUMN_RULE_COLOR: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::ColumnRule];
+		static COLUMN_RULE_STYLE: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::ColumnRule];
+		static COLUMN_RULE_WIDTH: &'static [ShorthandId] = &[ShorthandId::All, ShorthandId::ColumnRule];
 
 		NonCustomPropertyIterator {
 			iter: match *self {
@@ -906,6 +955,22 @@ impl LonghandId {
 				LonghandId::MarginTop => MARGIN_TOP,
 				LonghandId::Right => RIGHT,
 				LonghandId::Top => TOP,
+				LonghandId::GridTemplateColumns => GRID_TEMPLATE_COLUMNS,
+				LonghandId::GridTemplateRows => GRID_TEMPLATE_ROWS,
+				LonghandId::GridTemplateAreas => GRID_TEMPLATE_AREAS,
+				LonghandId::GridAutoColumns => GRID_AUTO_COLUMNS,
+				LonghandId::GridAutoRows => GRID_AUTO_ROWS,
+				LonghandId::GridAutoFlow => GRID_AUTO_FLOW,
+				LonghandId::GridRowStart => GRID_ROW_START,
+				LonghandId::GridRowEnd => GRID_ROW_END,
+				LonghandId::GridColumnStart => GRID_COLUMN_START,
+				LonghandId::GridColumnEnd => GRID_COLUMN_END,
+				LonghandId::RowGap => ROW_GAP,
+				LonghandId::JustifyItems => JUSTIFY_ITEMS,
+				LonghandId::JustifySelf => JUSTIFY_SELF,
+				LonghandId::ColumnRuleColor => COLUMN_RULE_COLOR,
+				LonghandId::ColumnRuleStyle => COLUMN_RULE_STYLE,
+				LonghandId::ColumnRuleWidth => COLUMN_RULE_WIDTH,
 			}
 			.iter(),
 		}
@@ -1096,44 +1161,223 @@ impl LonghandId {
 			LonghandId::Right => longhands::right::parse_declared,
 			LonghandId::Top => longhands::top::parse_declared,
 			LonghandId::CounterSet => longhands::counter_set::parse_declared,
+			LonghandId::GridTemplateColumns => longhands::grid_template_columns::parse_declared,
+			LonghandId::GridTemplateRows => longhands::grid_template_rows::parse_declared,
+			LonghandId::GridTemplateAreas => longhands::grid_template_areas::parse_declared,
+			LonghandId::GridAutoColumns => longhands::grid_auto_columns::parse_declared,
+			LonghandId::GridAutoRows => longhands::grid_auto_rows::parse_declared,
+			LonghandId::GridAutoFlow => longhands::grid_auto_flow::parse_declared,
+			LonghandId::GridRowStart => longhands::grid_row_start::parse_declared,
+			LonghandId::GridRowEnd => longhands::grid_row_end::parse_declared,
+			LonghandId::GridColumnStart => longhands::grid_column_start::parse_declared,
+			LonghandId::GridColumnEnd => longhands::grid_column_end::parse_declared,
+			LonghandId::RowGap => longhands::row_gap::parse_declared,
+			LonghandId::JustifyItems => longhands::justify_items::parse_declared,
+			LonghandId::JustifySelf => longhands::justify_self::parse_declared,
+			LonghandId::ColumnRuleColor => longhands::column_rule_color::parse_declared,
+			LonghandId::ColumnRuleStyle => longhands::column_rule_style::parse_declared,
+			LonghandId::ColumnRuleWidth => longhands::column_rule_width::parse_declared,
 		};
 		parser_func(context, input)
 	}
 
 	pub fn cascade<'a>(&self, declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
 		let cascade_func = match self {
+			LonghandId::AlignContent => longhands::align_content::cascade_property,
+			LonghandId::AlignItems => longhands::align_items::cascade_property,
+			LonghandId::AlignSelf => longhands::align_self::cascade_property,
+			LonghandId::AnimationDelay => longhands::animation_delay::cascade_property,
+			LonghandId::AnimationDirection => longhands::animation_direction::cascade_property,
+			LonghandId::AnimationDuration => longhands::animation_duration::cascade_property,
+			LonghandId::AnimationFillMode => longhands::animation_fill_mode::cascade_property,
+			LonghandId::AnimationIterationCount => longhands::animation_iteration_count::cascade_property,
+			LonghandId::AnimationName => longhands::animation_name::cascade_property,
+			LonghandId::AnimationPlayState => longhands::animation_play_state::cascade_property,
+			LonghandId::AnimationTimingFunction => longhands::animation_timing_function::cascade_property,
+			LonghandId::AspectRatio => longhands::aspect_ratio::cascade_property,
+			LonghandId::BackfaceVisibility => longhands::backface_visibility::cascade_property,
+			LonghandId::BackgroundAttachment => longhands::background_attachment::cascade_property,
+			LonghandId::BackgroundClip => longhands::background_clip::cascade_property,
 			LonghandId::BackgroundColor => longhands::background_color::cascade_property,
+			LonghandId::BackgroundImage => longhands::background_image::cascade_property,
+			LonghandId::BackgroundOrigin => longhands::background_origin::cascade_property,
+			LonghandId::BackgroundPositionX => longhands::background_position_x::cascade_property,
+			LonghandId::BackgroundPositionY => longhands::background_position_y::cascade_property,
+			LonghandId::BackgroundRepeat => longhands::background_repeat::cascade_property,
+			LonghandId::BackgroundSize => longhands::background_size::cascade_property,
+			LonghandId::BlockSize => longhands::block_size::cascade_property,
+			LonghandId::BorderBlockEndColor => longhands::border_block_end_color::cascade_property,
+			LonghandId::BorderBlockEndStyle => longhands::border_block_end_style::cascade_property,
+			LonghandId::BorderBlockEndWidth => longhands::border_block_end_width::cascade_property,
+			LonghandId::BorderBlockStartColor => longhands::border_block_start_color::cascade_property,
+			LonghandId::BorderBlockStartStyle => longhands::border_block_start_style::cascade_property,
+			LonghandId::BorderBlockStartWidth => longhands::border_block_start_width::cascade_property,
 			LonghandId::BorderBottomColor => longhands::border_bottom_color::cascade_property,
+			LonghandId::BorderBottomLeftRadius => longhands::border_bottom_left_radius::cascade_property,
+			LonghandId::BorderBottomRightRadius => longhands::border_bottom_right_radius::cascade_property,
 			LonghandId::BorderBottomStyle => longhands::border_bottom_style::cascade_property,
 			LonghandId::BorderBottomWidth => longhands::border_bottom_width::cascade_property,
+			LonghandId::BorderCollapse => longhands::border_collapse::cascade_property,
+			LonghandId::BorderEndEndRadius => longhands::border_end_end_radius::cascade_property,
+			LonghandId::BorderEndStartRadius => longhands::border_end_start_radius::cascade_property,
+			LonghandId::BorderImageOutset => longhands::border_image_outset::cascade_property,
+			LonghandId::BorderImageRepeat => longhands::border_image_repeat::cascade_property,
+			LonghandId::BorderImageSlice => longhands::border_image_slice::cascade_property,
+			LonghandId::BorderImageSource => longhands::border_image_source::cascade_property,
+			LonghandId::BorderImageWidth => longhands::border_image_width::cascade_property,
+			LonghandId::BorderInlineEndColor => longhands::border_inline_end_color::cascade_property,
+			LonghandId::BorderInlineEndStyle => longhands::border_inline_end_style::cascade_property,
+			LonghandId::BorderInlineEndWidth => longhands::border_inline_end_width::cascade_property,
+			LonghandId::BorderInlineStartColor => longhands::border_inline_start_color::cascade_property,
+			LonghandId::BorderInlineStartStyle => longhands::border_inline_start_style::cascade_property,
+			LonghandId::BorderInlineStartWidth => longhands::border_inline_start_width::cascade_property,
 			LonghandId::BorderLeftColor => longhands::border_left_color::cascade_property,
 			LonghandId::BorderLeftStyle => longhands::border_left_style::cascade_property,
 			LonghandId::BorderLeftWidth => longhands::border_left_width::cascade_property,
 			LonghandId::BorderRightColor => longhands::border_right_color::cascade_property,
 			LonghandId::BorderRightStyle => longhands::border_right_style::cascade_property,
 			LonghandId::BorderRightWidth => longhands::border_right_width::cascade_property,
+			LonghandId::BorderSpacing => longhands::border_spacing::cascade_property,
+			LonghandId::BorderStartEndRadius => longhands::border_start_end_radius::cascade_property,
+			LonghandId::BorderStartStartRadius => longhands::border_start_start_radius::cascade_property,
 			LonghandId::BorderTopColor => longhands::border_top_color::cascade_property,
+			LonghandId::BorderTopLeftRadius => longhands::border_top_left_radius::cascade_property,
+			LonghandId::BorderTopRightRadius => longhands::border_top_right_radius::cascade_property,
 			LonghandId::BorderTopStyle => longhands::border_top_style::cascade_property,
 			LonghandId::BorderTopWidth => longhands::border_top_width::cascade_property,
+			LonghandId::Bottom => longhands::bottom::cascade_property,
+			LonghandId::BoxShadow => longhands::box_shadow::cascade_property,
+			LonghandId::BoxSizing => longhands::box_sizing::cascade_property,
+			LonghandId::CaptionSide => longhands::caption_side::cascade_property,
+			LonghandId::Clear => longhands::clear::cascade_property,
+			LonghandId::Clip => longhands::clip::cascade_property,
 			LonghandId::Color => longhands::color::cascade_property,
+			LonghandId::ColumnCount => longhands::column_count::cascade_property,
+			LonghandId::ColumnGap => longhands::column_gap::cascade_property,
+			LonghandId::ColumnWidth => longhands::column_width::cascade_property,
+			LonghandId::Content => longhands::content::cascade_property,
+			LonghandId::CounterIncrement => longhands::counter_increment::cascade_property,
+			LonghandId::CounterReset => longhands::counter_reset::cascade_property,
+			LonghandId::CounterSet => longhands::counter_set::cascade_property,
+			LonghandId::Cursor => longhands::cursor::cascade_property,
+			LonghandId::Direction => longhands::direction::cascade_property,
 			LonghandId::Display => longhands::display::cascade_property,
+			LonghandId::EmptyCells => longhands::empty_cells::cascade_property,
+			LonghandId::Filter => longhands::filter::cascade_property,
+			LonghandId::FlexBasis => longhands::flex_basis::cascade_property,
+			LonghandId::FlexDirection => longhands::flex_direction::cascade_property,
+			LonghandId::FlexGrow => longhands::flex_grow::cascade_property,
+			LonghandId::FlexShrink => longhands::flex_shrink::cascade_property,
+			LonghandId::FlexWrap => longhands::flex_wrap::cascade_property,
+			LonghandId::Float => longhands::float::cascade_property,
 			LonghandId::FontFamily => longhands::font_family::cascade_property,
 			LonghandId::FontSize => longhands::font_size::cascade_property,
+			LonghandId::FontStretch => longhands::font_stretch::cascade_property,
+			LonghandId::FontStyle => longhands::font_style::cascade_property,
+			LonghandId::FontVariantCaps => longhands::font_variant_caps::cascade_property,
+			LonghandId::FontWeight => longhands::font_weight::cascade_property,
 			LonghandId::Height => longhands::height::cascade_property,
+			LonghandId::ImageRendering => longhands::image_rendering::cascade_property,
+			LonghandId::InlineSize => longhands::inline_size::cascade_property,
+			LonghandId::InsetBlockEnd => longhands::inset_block_end::cascade_property,
+			LonghandId::InsetBlockStart => longhands::inset_block_start::cascade_property,
+			LonghandId::InsetInlineEnd => longhands::inset_inline_end::cascade_property,
+			LonghandId::InsetInlineStart => longhands::inset_inline_start::cascade_property,
+			LonghandId::JustifyContent => longhands::justify_content::cascade_property,
+			LonghandId::Left => longhands::left::cascade_property,
+			LonghandId::LetterSpacing => longhands::letter_spacing::cascade_property,
+			LonghandId::LineHeight => longhands::line_height::cascade_property,
+			LonghandId::ListStyleImage => longhands::list_style_image::cascade_property,
+			LonghandId::ListStylePosition => longhands::list_style_position::cascade_property,
+			LonghandId::ListStyleType => longhands::list_style_type::cascade_property,
+			LonghandId::MarginBlockEnd => longhands::margin_block_end::cascade_property,
+			LonghandId::MarginBlockStart => longhands::margin_block_start::cascade_property,
 			LonghandId::MarginBottom => longhands::margin_bottom::cascade_property,
+			LonghandId::MarginInlineEnd => longhands::margin_inline_end::cascade_property,
+			LonghandId::MarginInlineStart => longhands::margin_inline_start::cascade_property,
 			LonghandId::MarginLeft => longhands::margin_left::cascade_property,
 			LonghandId::MarginRight => longhands::margin_right::cascade_property,
 			LonghandId::MarginTop => longhands::margin_top::cascade_property,
-			LonghandId::MinWidth => longhands::min_width::cascade_property,
-			LonghandId::MinHeight => longhands::min_height::cascade_property,
-			LonghandId::MaxWidth => longhands::max_width::cascade_property,
+			LonghandId::MaxBlockSize => longhands::max_block_size::cascade_property,
 			LonghandId::MaxHeight => longhands::max_height::cascade_property,
+			LonghandId::MaxInlineSize => longhands::max_inline_size::cascade_property,
+			LonghandId::MaxWidth => longhands::max_width::cascade_property,
+			LonghandId::MinBlockSize => longhands::min_block_size::cascade_property,
+			LonghandId::MinHeight => longhands::min_height::cascade_property,
+			LonghandId::MinInlineSize => longhands::min_inline_size::cascade_property,
+			LonghandId::MinWidth => longhands::min_width::cascade_property,
+			LonghandId::MixBlendMode => longhands::mix_blend_mode::cascade_property,
+			LonghandId::ObjectFit => longhands::object_fit::cascade_property,
+			LonghandId::Opacity => longhands::opacity::cascade_property,
+			LonghandId::Order => longhands::order::cascade_property,
+			LonghandId::OutlineColor => longhands::outline_color::cascade_property,
+			LonghandId::OutlineOffset => longhands::outline_offset::cascade_property,
+			LonghandId::OutlineStyle => longhands::outline_style::cascade_property,
+			LonghandId::OutlineWidth => longhands::outline_width::cascade_property,
+			LonghandId::OverflowBlock => longhands::overflow_block::cascade_property,
+			LonghandId::OverflowInline => longhands::overflow_inline::cascade_property,
+			LonghandId::OverflowWrap => longhands::overflow_wrap::cascade_property,
+			LonghandId::OverflowX => longhands::overflow_x::cascade_property,
+			LonghandId::OverflowY => longhands::overflow_y::cascade_property,
+			LonghandId::PaddingBlockEnd => longhands::padding_block_end::cascade_property,
+			LonghandId::PaddingBlockStart => longhands::padding_block_start::cascade_property,
 			LonghandId::PaddingBottom => longhands::padding_bottom::cascade_property,
+			LonghandId::PaddingInlineEnd => longhands::padding_inline_end::cascade_property,
+			LonghandId::PaddingInlineStart => longhands::padding_inline_start::cascade_property,
 			LonghandId::PaddingLeft => longhands::padding_left::cascade_property,
 			LonghandId::PaddingRight => longhands::padding_right::cascade_property,
 			LonghandId::PaddingTop => longhands::padding_top::cascade_property,
+			LonghandId::Perspective => longhands::perspective::cascade_property,
+			LonghandId::PerspectiveOrigin => longhands::perspective_origin::cascade_property,
+			LonghandId::PointerEvents => longhands::pointer_events::cascade_property,
+			LonghandId::Position => longhands::position::cascade_property,
+			LonghandId::Quotes => longhands::quotes::cascade_property,
+			LonghandId::Right => longhands::right::cascade_property,
+			LonghandId::Rotate => longhands::rotate::cascade_property,
+			LonghandId::Scale => longhands::scale::cascade_property,
+			LonghandId::TableLayout => longhands::table_layout::cascade_property,
+			LonghandId::TextAlign => longhands::text_align::cascade_property,
+			LonghandId::TextDecorationLine => longhands::text_decoration_line::cascade_property,
+			LonghandId::TextIndent => longhands::text_indent::cascade_property,
+			LonghandId::TextJustify => longhands::text_justify::cascade_property,
+			LonghandId::TextOverflow => longhands::text_overflow::cascade_property,
+			LonghandId::TextRendering => longhands::text_rendering::cascade_property,
+			LonghandId::TextShadow => longhands::text_shadow::cascade_property,
+			LonghandId::TextTransform => longhands::text_transform::cascade_property,
+			LonghandId::Top => longhands::top::cascade_property,
+			LonghandId::Transform => longhands::transform::cascade_property,
+			LonghandId::TransformOrigin => longhands::transform_origin::cascade_property,
+			LonghandId::TransformStyle => longhands::transform_style::cascade_property,
+			LonghandId::TransitionDelay => longhands::transition_delay::cascade_property,
+			LonghandId::TransitionDuration => longhands::transition_duration::cascade_property,
+			LonghandId::TransitionProperty => longhands::transition_property::cascade_property,
+			LonghandId::TransitionTimingFunction => longhands::transition_timing_function::cascade_property,
+			LonghandId::Translate => longhands::translate::cascade_property,
+			LonghandId::UnicodeBidi => longhands::unicode_bidi::cascade_property,
+			LonghandId::VerticalAlign => longhands::vertical_align::cascade_property,
+			LonghandId::Visibility => longhands::visibility::cascade_property,
+			LonghandId::WhiteSpace => longhands::white_space::cascade_property,
 			LonghandId::Width => longhands::width::cascade_property,
-			_ => return,
+			LonghandId::WordBreak => longhands::word_break::cascade_property,
+			LonghandId::WordSpacing => longhands::word_spacing::cascade_property,
+			LonghandId::WritingMode => longhands::writing_mode::cascade_property,
+			LonghandId::ZIndex => longhands::z_index::cascade_property,
+			LonghandId::GridTemplateColumns => longhands::grid_template_columns::cascade_property,
+			LonghandId::GridTemplateRows => longhands::grid_template_rows::cascade_property,
+			LonghandId::GridTemplateAreas => longhands::grid_template_areas::cascade_property,
+			LonghandId::GridAutoColumns => longhands::grid_auto_columns::cascade_property,
+			LonghandId::GridAutoRows => longhands::grid_auto_rows::cascade_property,
+			LonghandId::GridAutoFlow => longhands::grid_auto_flow::cascade_property,
+			LonghandId::GridRowStart => longhands::grid_row_start::cascade_property,
+			LonghandId::GridRowEnd => longhands::grid_row_end::cascade_property,
+			LonghandId::GridColumnStart => longhands::grid_column_start::cascade_property,
+			LonghandId::GridColumnEnd => longhands::grid_column_end::cascade_property,
+			LonghandId::RowGap => longhands::row_gap::cascade_property,
+			LonghandId::JustifyItems => longhands::justify_items::cascade_property,
+			LonghandId::JustifySelf => longhands::justify_self::cascade_property,
+			LonghandId::ColumnRuleColor => longhands::column_rule_color::cascade_property,
+			LonghandId::ColumnRuleStyle => longhands::column_rule_style::cascade_property,
+			LonghandId::ColumnRuleWidth => longhands::column_rule_width::cascade_property,
 		};
 		cascade_func(declaration, context);
 	}
@@ -1156,6 +1400,146 @@ impl LonghandId {
 		)
 	}
 
+	/// Logical properties are resolved against the element's own writing mode and direction, they share
+	/// the cascade of the physical property they map to, see `to_physical`.
+	pub fn is_logical_property(&self) -> bool {
+		matches!(
+			*self,
+			LonghandId::MarginBlockStart
+				| LonghandId::PaddingBlockStart
+				| LonghandId::InsetBlockStart
+				| LonghandId::BorderBlockStartColor
+				| LonghandId::BorderBlockStartStyle
+				| LonghandId::BorderBlockStartWidth
+				| LonghandId::MarginBlockEnd
+				| LonghandId::PaddingBlockEnd
+				| LonghandId::InsetBlockEnd
+				| LonghandId::BorderBlockEndColor
+				| LonghandId::BorderBlockEndStyle
+				| LonghandId::BorderBlockEndWidth
+				| LonghandId::MarginInlineStart
+				| LonghandId::PaddingInlineStart
+				| LonghandId::InsetInlineStart
+				| LonghandId::BorderInlineStartColor
+				| LonghandId::BorderInlineStartStyle
+				| LonghandId::BorderInlineStartWidth
+				| LonghandId::MarginInlineEnd
+				| LonghandId::PaddingInlineEnd
+				| LonghandId::InsetInlineEnd
+				| LonghandId::BorderInlineEndColor
+				| LonghandId::BorderInlineEndStyle
+				| LonghandId::BorderInlineEndWidth
+				| LonghandId::BorderStartStartRadius
+				| LonghandId::BorderStartEndRadius
+				| LonghandId::BorderEndStartRadius
+				| LonghandId::BorderEndEndRadius
+				| LonghandId::BlockSize
+				| LonghandId::MinBlockSize
+				| LonghandId::MaxBlockSize
+				| LonghandId::OverflowBlock
+				| LonghandId::InlineSize
+				| LonghandId::MinInlineSize
+				| LonghandId::MaxInlineSize
+				| LonghandId::OverflowInline
+		)
+	}
+
+	/// The physical property which a logical property maps to with the writing mode and direction of
+	/// `computed_values`, other properties are returned as they are.
+	/// https://drafts.csswg.org/css-logical/#box
+	pub fn to_physical(&self, computed_values: &ComputedValues) -> LonghandId {
+		let side = |side: LogicalSide| computed_values.physical_side(side);
+		let axis = |axis: LogicalAxis| computed_values.physical_axis(axis);
+		let corner = |corner: LogicalCorner| computed_values.physical_corner(corner);
+		let margin = |side: PhysicalSide| match side {
+			PhysicalSide::Top => LonghandId::MarginTop,
+			PhysicalSide::Right => LonghandId::MarginRight,
+			PhysicalSide::Bottom => LonghandId::MarginBottom,
+			PhysicalSide::Left => LonghandId::MarginLeft,
+		};
+		let padding = |side: PhysicalSide| match side {
+			PhysicalSide::Top => LonghandId::PaddingTop,
+			PhysicalSide::Right => LonghandId::PaddingRight,
+			PhysicalSide::Bottom => LonghandId::PaddingBottom,
+			PhysicalSide::Left => LonghandId::PaddingLeft,
+		};
+		let inset = |side: PhysicalSide| match side {
+			PhysicalSide::Top => LonghandId::Top,
+			PhysicalSide::Right => LonghandId::Right,
+			PhysicalSide::Bottom => LonghandId::Bottom,
+			PhysicalSide::Left => LonghandId::Left,
+		};
+		let border_color = |side: PhysicalSide| match side {
+			PhysicalSide::Top => LonghandId::BorderTopColor,
+			PhysicalSide::Right => LonghandId::BorderRightColor,
+			PhysicalSide::Bottom => LonghandId::BorderBottomColor,
+			PhysicalSide::Left => LonghandId::BorderLeftColor,
+		};
+		let border_style = |side: PhysicalSide| match side {
+			PhysicalSide::Top => LonghandId::BorderTopStyle,
+			PhysicalSide::Right => LonghandId::BorderRightStyle,
+			PhysicalSide::Bottom => LonghandId::BorderBottomStyle,
+			PhysicalSide::Left => LonghandId::BorderLeftStyle,
+		};
+		let border_width = |side: PhysicalSide| match side {
+			PhysicalSide::Top => LonghandId::BorderTopWidth,
+			PhysicalSide::Right => LonghandId::BorderRightWidth,
+			PhysicalSide::Bottom => LonghandId::BorderBottomWidth,
+			PhysicalSide::Left => LonghandId::BorderLeftWidth,
+		};
+		let border_radius = |corner: PhysicalCorner| match corner {
+			PhysicalCorner::TopLeft => LonghandId::BorderTopLeftRadius,
+			PhysicalCorner::TopRight => LonghandId::BorderTopRightRadius,
+			PhysicalCorner::BottomRight => LonghandId::BorderBottomRightRadius,
+			PhysicalCorner::BottomLeft => LonghandId::BorderBottomLeftRadius,
+		};
+		let size = |axis: PhysicalAxis, horizontal: LonghandId, vertical: LonghandId| match axis {
+			PhysicalAxis::Horizontal => horizontal,
+			PhysicalAxis::Vertical => vertical,
+		};
+		match *self {
+			LonghandId::MarginBlockStart => margin(side(LogicalSide::BlockStart)),
+			LonghandId::MarginBlockEnd => margin(side(LogicalSide::BlockEnd)),
+			LonghandId::MarginInlineStart => margin(side(LogicalSide::InlineStart)),
+			LonghandId::MarginInlineEnd => margin(side(LogicalSide::InlineEnd)),
+			LonghandId::PaddingBlockStart => padding(side(LogicalSide::BlockStart)),
+			LonghandId::PaddingBlockEnd => padding(side(LogicalSide::BlockEnd)),
+			LonghandId::PaddingInlineStart => padding(side(LogicalSide::InlineStart)),
+			LonghandId::PaddingInlineEnd => padding(side(LogicalSide::InlineEnd)),
+			LonghandId::InsetBlockStart => inset(side(LogicalSide::BlockStart)),
+			LonghandId::InsetBlockEnd => inset(side(LogicalSide::BlockEnd)),
+			LonghandId::InsetInlineStart => inset(side(LogicalSide::InlineStart)),
+			LonghandId::InsetInlineEnd => inset(side(LogicalSide::InlineEnd)),
+			LonghandId::BorderBlockStartColor => border_color(side(LogicalSide::BlockStart)),
+			LonghandId::BorderBlockEndColor => border_color(side(LogicalSide::BlockEnd)),
+			LonghandId::BorderInlineStartColor => border_color(side(LogicalSide::InlineStart)),
+			LonghandId::BorderInlineEndColor => border_color(side(LogicalSide::InlineEnd)),
+			LonghandId::BorderBlockStartStyle => border_style(side(LogicalSide::BlockStart)),
+			LonghandId::BorderBlockEndStyle => border_style(side(LogicalSide::BlockEnd)),
+			LonghandId::BorderInlineStartStyle => border_style(side(LogicalSide::InlineStart)),
+			LonghandId::BorderInlineEndStyle => border_style(side(LogicalSide::InlineEnd)),
+			LonghandId::BorderBlockStartWidth => border_width(side(LogicalSide::BlockStart)),
+			LonghandId::BorderBlockEndWidth => border_width(side(LogicalSide::BlockEnd)),
+			LonghandId::BorderInlineStartWidth => border_width(side(LogicalSide::InlineStart)),
+			LonghandId::BorderInlineEndWidth => border_width(side(LogicalSide::InlineEnd)),
+			LonghandId::BorderStartStartRadius => border_radius(corner(LogicalCorner::StartStart)),
+			LonghandId::BorderStartEndRadius => border_radius(corner(LogicalCorner::StartEnd)),
+			LonghandId::BorderEndStartRadius => border_radius(corner(LogicalCorner::EndStart)),
+			LonghandId::BorderEndEndRadius => border_radius(corner(LogicalCorner::EndEnd)),
+			LonghandId::BlockSize => size(axis(LogicalAxis::Block), LonghandId::Width, LonghandId::Height),
+			LonghandId::MinBlockSize => size(axis(LogicalAxis::Block), LonghandId::MinWidth, LonghandId::MinHeight),
+			LonghandId::MaxBlockSize => size(axis(LogicalAxis::Block), LonghandId::MaxWidth, LonghandId::MaxHeight),
+			LonghandId::OverflowBlock => size(axis(LogicalAxis::Block), LonghandId::OverflowX, LonghandId::OverflowY),
+			LonghandId::InlineSize => size(axis(LogicalAxis::Inline), LonghandId::Width, LonghandId::Height),
+			LonghandId::MinInlineSize => size(axis(LogicalAxis::Inline), LonghandId::MinWidth, LonghandId::MinHeight),
+			LonghandId::MaxInlineSize => size(axis(LogicalAxis::Inline), LonghandId::MaxWidth, LonghandId::MaxHeight),
+			LonghandId::OverflowInline => {
+				size(axis(LogicalAxis::Inline), LonghandId::OverflowX, LonghandId::OverflowY)
+			},
+			id => id,
+		}
+	}
+
 	pub fn ids(phase: PhaseOrder) -> LonghandIdPhaseIterator {
 		LonghandIdPhaseIterator { index: 0, phase }
 	}
@@ -1164,6 +1548,7 @@ impl LonghandId {
 #[derive(PartialEq, Eq)]
 pub enum PhaseOrder {
 	Early,
+	/// Every other physical property, logical properties are cascaded with them.
 	Other,
 	All,
 }
@@ -1183,7 +1568,7 @@ impl LonghandIdPhaseIterator {
 					break;
 				} else if self.phase == PhaseOrder::Early && id.is_early_property() {
 					break;
-				} else if self.phase == PhaseOrder::Other && !id.is_early_property() {
+				} else if self.phase == PhaseOrder::Other && !id.is_early_property() && !id.is_logical_property() {
 					break;
 				}
 			} else {
@@ -1208,7 +1593,7 @@ impl Iterator for LonghandIdPhaseIterator {
 /// A set of longhand properties
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct LonghandIdSet {
-	storage: [u32; (179 - 1 + 32) / 32],
+	storage: [u32; (195 - 1 + 32) / 32],
 }
 
 impl LonghandIdSet {
@@ -1216,7 +1601,7 @@ impl LonghandIdSet {
 	#[inline]
 	pub fn new() -> LonghandIdSet {
 		LonghandIdSet {
-			storage: [0; (179 - 1 + 32) / 32],
+			storage: [0; (195 - 1 + 32) / 32],
 		}
 	}
 
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::position::{BaselinePosition, ContentDistribution, ContentPosition, OverflowPosition};
 
 /// https://drafts.csswg.org/css-align-3/#propdef-align-content
@@ -61,6 +65,21 @@ impl ToCss for AlignContent {
 	}
 }
 
+pub fn initial_value() -> AlignContent {
+	AlignContent::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_align_content().clone(),
+		initial_value(),
+		LonghandId::AlignContent,
+		PropertyDeclaration::AlignContent(value) => value.clone()
+	);
+	context.computed_values.set_align_content(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
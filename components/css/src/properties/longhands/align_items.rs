@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::position::{BaselinePosition, OverflowPosition, SelfPosition};
 
 /// https://drafts.csswg.org/css-align-3/#align-items-property
@@ -61,6 +65,21 @@ impl ToCss for AlignItems {
 	}
 }
 
+pub fn initial_value() -> AlignItems {
+	AlignItems::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_align_items().clone(),
+		initial_value(),
+		LonghandId::AlignItems,
+		PropertyDeclaration::AlignItems(value) => value.clone()
+	);
+	context.computed_values.set_align_items(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::position::{BaselinePosition, OverflowPosition, SelfPosition};
 
 /// https://drafts.csswg.org/css-align-3/#align-self-property
@@ -64,6 +68,21 @@ impl ToCss for AlignSelf {
 	}
 }
 
+pub fn initial_value() -> AlignSelf {
+	AlignSelf::Auto
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_align_self().clone(),
+		initial_value(),
+		LonghandId::AlignSelf,
+		PropertyDeclaration::AlignSelf(value) => value.clone()
+	);
+	context.computed_values.set_align_self(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 #[derive(Clone, Debug)]
 pub enum SingleAnimationDirection {
@@ -42,6 +46,23 @@ impl ToCss for AnimationDirection {
 		dest.write_str(&directions.join(", "))
 	}
 }
+pub fn initial_value() -> AnimationDirection {
+	AnimationDirection {
+		directions: vec![SingleAnimationDirection::Normal],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_animation_direction().clone(),
+		initial_value(),
+		LonghandId::AnimationDirection,
+		PropertyDeclaration::AnimationDirection(value) => value.clone()
+	);
+	context.computed_values.set_animation_direction(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 #[derive(Clone, Debug)]
 pub enum SingleAnimationFillMode {
@@ -43,6 +47,23 @@ impl ToCss for AnimationFillMode {
 	}
 }
 
+pub fn initial_value() -> AnimationFillMode {
+	AnimationFillMode {
+		fill_modes: vec![SingleAnimationFillMode::None],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_animation_fill_mode().clone(),
+		initial_value(),
+		LonghandId::AnimationFillMode,
+		PropertyDeclaration::AnimationFillMode(value) => value.clone()
+	);
+	context.computed_values.set_animation_fill_mode(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
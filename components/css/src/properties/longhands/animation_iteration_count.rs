@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss, Token};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::number::Number;
 
 #[derive(Clone, Debug)]
@@ -59,6 +63,23 @@ impl ToCss for AnimationIterationCount {
 	}
 }
 
+pub fn initial_value() -> AnimationIterationCount {
+	AnimationIterationCount {
+		iteration_count: vec![SingleAnimationIterationCount::Number(Number::new(1.0))],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_animation_iteration_count().clone(),
+		initial_value(),
+		LonghandId::AnimationIterationCount,
+		PropertyDeclaration::AnimationIterationCount(value) => value.clone()
+	);
+	context.computed_values.set_animation_iteration_count(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
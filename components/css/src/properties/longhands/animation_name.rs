@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
 use crate::values::animation::KeyframesName;
+use crate::values::computed;
 
 /// https://drafts.csswg.org/css-animations-1/#animation-name
 #[derive(Clone, Debug)]
@@ -42,6 +46,21 @@ impl ToCss for AnimationName {
 	}
 }
 
+pub fn initial_value() -> AnimationName {
+	AnimationName { names: vec![None] }
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_animation_name().clone(),
+		initial_value(),
+		LonghandId::AnimationName,
+		PropertyDeclaration::AnimationName(value) => value.clone()
+	);
+	context.computed_values.set_animation_name(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
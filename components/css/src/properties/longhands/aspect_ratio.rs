@@ -1,10 +1,14 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::css_writer::write_elements;
 use crate::parser::{parse_in_any_order, parse_item_if_missing, ParseError};
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::percentage::Ratio;
 
 /// https://drafts.csswg.org/css-sizing-4/#aspect-ratio
@@ -52,6 +56,24 @@ impl ToCss for AspectRatio {
 	}
 }
 
+pub fn initial_value() -> AspectRatio {
+	AspectRatio {
+		auto: true,
+		ratio: None,
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_aspect_ratio().clone(),
+		initial_value(),
+		LonghandId::AspectRatio,
+		PropertyDeclaration::AspectRatio(value) => value.clone()
+	);
+	context.computed_values.set_aspect_ratio(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 #[derive(Clone, Debug)]
 pub enum Attachment {
@@ -41,6 +45,23 @@ impl ToCss for BackgroundAttachment {
 	}
 }
 
+pub fn initial_value() -> BackgroundAttachment {
+	BackgroundAttachment {
+		attachments: vec![Attachment::Scroll],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_background_attachment().clone(),
+		initial_value(),
+		LonghandId::BackgroundAttachment,
+		PropertyDeclaration::BackgroundAttachment(value) => value.clone()
+	);
+	context.computed_values.set_background_attachment(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::layout::Box;
 
 /// https://drafts.csswg.org/css-backgrounds/#background-clip
@@ -28,6 +32,23 @@ impl ToCss for BackgroundClip {
 	}
 }
 
+pub fn initial_value() -> BackgroundClip {
+	BackgroundClip {
+		boxes: vec![Box::BorderBox],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_background_clip().clone(),
+		initial_value(),
+		LonghandId::BackgroundClip,
+		PropertyDeclaration::BackgroundClip(value) => value.clone()
+	);
+	context.computed_values.set_background_clip(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::image::Image;
 
 #[derive(Clone, Debug)]
@@ -60,6 +64,23 @@ impl ToCss for BackgroundImage {
 	}
 }
 
+pub fn initial_value() -> BackgroundImage {
+	BackgroundImage {
+		images: vec![BgImage::None],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_background_image().clone(),
+		initial_value(),
+		LonghandId::BackgroundImage,
+		PropertyDeclaration::BackgroundImage(value) => value.clone()
+	);
+	context.computed_values.set_background_image(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
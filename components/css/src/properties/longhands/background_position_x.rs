@@ -1,10 +1,14 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::str::convert_options_to_string;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthPercentage;
 
 #[derive(Clone, Debug)]
@@ -110,6 +114,26 @@ impl ToCss for BackgroundPositionX {
 	}
 }
 
+pub fn initial_value() -> BackgroundPositionX {
+	BackgroundPositionX {
+		positions: vec![HorizontalPositionComponent::PositionX(HorizontalPosition {
+			keyword: None,
+			length: Some(LengthPercentage::zero()),
+		})],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_background_position_x().clone(),
+		initial_value(),
+		LonghandId::BackgroundPositionX,
+		PropertyDeclaration::BackgroundPositionX(value) => value.clone()
+	);
+	context.computed_values.set_background_position_x(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
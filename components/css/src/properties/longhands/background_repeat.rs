@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::Pair;
 
 #[derive(Clone, Debug)]
@@ -84,6 +88,26 @@ impl ToCss for BackgroundRepeat {
 	}
 }
 
+pub fn initial_value() -> BackgroundRepeat {
+	BackgroundRepeat {
+		repeat: vec![Pair::new(
+			BackgroundRepeatKeyword::Repeat,
+			BackgroundRepeatKeyword::Repeat,
+		)],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_background_repeat().clone(),
+		initial_value(),
+		LonghandId::BackgroundRepeat,
+		PropertyDeclaration::BackgroundRepeat(value) => value.clone()
+	);
+	context.computed_values.set_background_repeat(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
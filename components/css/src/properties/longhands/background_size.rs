@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthPercentageOrAuto;
 
 #[derive(Clone, Debug)]
@@ -84,6 +88,26 @@ impl ToCss for BackgroundSize {
 	}
 }
 
+pub fn initial_value() -> BackgroundSize {
+	BackgroundSize {
+		size: vec![BgSize::ExplicitSize {
+			width: LengthPercentageOrAuto::Auto,
+			height: LengthPercentageOrAuto::Auto,
+		}],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_background_size().clone(),
+		initial_value(),
+		LonghandId::BackgroundSize,
+		PropertyDeclaration::BackgroundSize(value) => value.clone()
+	);
+	context.computed_values.set_background_size(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
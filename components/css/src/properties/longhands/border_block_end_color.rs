@@ -1,9 +1,33 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
+use crate::logical_geometry::LogicalSide;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::specified::color::Color;
+use crate::values::computed;
+use crate::values::specified::color::{Color, RGBA};
+
+pub fn initial_value() -> RGBA {
+	RGBA::transparent()
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	if declaration.is_none() {
+		return;
+	}
+	let side = context.computed_values.physical_side(LogicalSide::BlockEnd);
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_border_color(side).clone(),
+		initial_value(),
+		LonghandId::BorderBlockEndColor,
+		PropertyDeclaration::BorderBlockEndColor(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_border_color(side, computed_value);
+}
 
 /// https://drafts.csswg.org/css-logical/#propdef-border-block-end-color
 pub fn parse_declared<'i, 't>(
@@ -1,9 +1,29 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::border::BorderCornerRadius;
+use crate::values::specified::length::NonNegativeLengthPercentage;
+
+pub fn initial_value() -> BorderCornerRadius {
+	BorderCornerRadius::new(NonNegativeLengthPercentage::zero(), NonNegativeLengthPercentage::zero())
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_border_bottom_right_radius().clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::BorderBottomRightRadius,
+		PropertyDeclaration::BorderBottomRightRadius(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_border_bottom_right_radius(computed_value);
+}
 
 /// https://drafts.csswg.org/css-backgrounds/#propdef-border-bottom-right-radius
 pub fn parse_declared<'i, 't>(
@@ -1,9 +1,31 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::specified::length::NonNegativeLengthOrNumberRect;
+use crate::values::computed;
+use crate::values::generics::length::Rect;
+use crate::values::specified::length::{NonNegativeLengthOrNumber, NonNegativeLengthOrNumberRect};
+use crate::values::specified::number::NonNegativeNumber;
+
+pub fn initial_value() -> NonNegativeLengthOrNumberRect {
+	let outset = NonNegativeLengthOrNumber::Number(NonNegativeNumber::new(0.0));
+	Rect(outset.clone(), outset.clone(), outset.clone(), outset)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_border_image_outset().clone(),
+		initial_value(),
+		LonghandId::BorderImageOutset,
+		PropertyDeclaration::BorderImageOutset(value) => value.clone()
+	);
+	context.computed_values.set_border_image_outset(computed_value);
+}
 
 /// https://drafts.csswg.org/css-backgrounds/#the-border-image-outset
 pub fn parse_declared<'i, 't>(
@@ -1,10 +1,15 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::generics::length::Rect;
 use crate::values::specified::number::NonNegativeNumberOrPercentage;
+use crate::values::specified::percentage::Percentage;
 
 /// https://drafts.csswg.org/css-backgrounds/#the-border-image-slice
 #[derive(Clone, Debug)]
@@ -39,6 +44,25 @@ impl ToCss for BorderImageSlice {
 	}
 }
 
+pub fn initial_value() -> BorderImageSlice {
+	let offset = NonNegativeNumberOrPercentage::Percentage(Percentage::new(1.0));
+	BorderImageSlice {
+		offsets: Rect(offset.clone(), offset.clone(), offset.clone(), offset),
+		fill: false,
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_border_image_slice().clone(),
+		initial_value(),
+		LonghandId::BorderImageSlice,
+		PropertyDeclaration::BorderImageSlice(value) => value.clone()
+	);
+	context.computed_values.set_border_image_slice(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::image::Image;
 
 /// https://drafts.csswg.org/css-backgrounds/#the-border-image-source
@@ -38,6 +42,21 @@ impl ToCss for BorderImageSource {
 	}
 }
 
+pub fn initial_value() -> BorderImageSource {
+	BorderImageSource::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_border_image_source().clone(),
+		initial_value(),
+		LonghandId::BorderImageSource,
+		PropertyDeclaration::BorderImageSource(value) => value.clone()
+	);
+	context.computed_values.set_border_image_source(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
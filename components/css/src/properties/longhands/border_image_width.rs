@@ -1,10 +1,15 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::generics::length::Rect;
 use crate::values::specified::length::NonNegativeLengthPercentageNumberOrAuto;
+use crate::values::specified::number::NonNegativeNumber;
 
 /// https://drafts.csswg.org/css-backgrounds/#border-image-width
 pub type BorderImageWidth = Rect<NonNegativeLengthPercentageNumberOrAuto>;
@@ -15,6 +20,22 @@ impl BorderImageWidth {
 	}
 }
 
+pub fn initial_value() -> BorderImageWidth {
+	let width = NonNegativeLengthPercentageNumberOrAuto::Number(NonNegativeNumber::new(1.0));
+	Rect(width.clone(), width.clone(), width.clone(), width)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_border_image_width().clone(),
+		initial_value(),
+		LonghandId::BorderImageWidth,
+		PropertyDeclaration::BorderImageWidth(value) => value.clone()
+	);
+	context.computed_values.set_border_image_width(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
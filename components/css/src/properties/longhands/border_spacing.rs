@@ -1,9 +1,13 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::specified::length::{NonNegativeLength, Pair};
+use crate::values::computed;
+use crate::values::specified::length::{Length, NonNegativeLength, Pair};
 
 /// https://drafts.csswg.org/css2/#separated-borders
 pub type BorderSpacing = Pair<NonNegativeLength>;
@@ -12,6 +16,28 @@ impl BorderSpacing {
 	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<BorderSpacing, ParseError<'i>> {
 		Pair::parse_with(input, |input| NonNegativeLength::parse(input))
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> Pair<computed::length::NonNegativeLength> {
+		Pair::new(self.0.to_computed_value(context), self.1.to_computed_value(context))
+	}
+}
+
+pub fn initial_value() -> BorderSpacing {
+	BorderSpacing::new(
+		NonNegativeLength::new(Length::zero()),
+		NonNegativeLength::new(Length::zero()),
+	)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_border_spacing().clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::BorderSpacing,
+		PropertyDeclaration::BorderSpacing(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_border_spacing(computed_value);
 }
 
 pub fn parse_declared<'i, 't>(
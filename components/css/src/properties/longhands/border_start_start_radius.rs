@@ -1,9 +1,34 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
+use crate::logical_geometry::LogicalCorner;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::border::BorderCornerRadius;
+use crate::values::specified::length::NonNegativeLengthPercentage;
+
+pub fn initial_value() -> BorderCornerRadius {
+	BorderCornerRadius::new(NonNegativeLengthPercentage::zero(), NonNegativeLengthPercentage::zero())
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	if declaration.is_none() {
+		return;
+	}
+	let corner = context.computed_values.physical_corner(LogicalCorner::StartStart);
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_border_radius(corner).clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::BorderStartStartRadius,
+		PropertyDeclaration::BorderStartStartRadius(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_border_radius(corner, computed_value);
+}
 
 /// https://drafts.csswg.org/css-logical/#propdef-border-start-start-radius
 pub fn parse_declared<'i, 't>(
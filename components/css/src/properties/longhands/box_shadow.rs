@@ -1,10 +1,14 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::{parse_in_any_order, parse_item_if_missing, ParseError};
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::str::convert_options_to_string;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::color::Color;
 use crate::values::specified::length::{Length, NonNegativeLength};
 
@@ -120,6 +124,21 @@ impl ToCss for BoxShadow {
 	}
 }
 
+pub fn initial_value() -> BoxShadow {
+	BoxShadow::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_box_shadow().clone(),
+		initial_value(),
+		LonghandId::BoxShadow,
+		PropertyDeclaration::BoxShadow(value) => value.clone()
+	);
+	context.computed_values.set_box_shadow(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
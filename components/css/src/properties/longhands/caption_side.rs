@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 /// https://drafts.csswg.org/css-logical/#caption-side
 #[derive(Clone, Debug)]
@@ -17,6 +21,21 @@ property_keywords_impl! { CaptionSide,
 	CaptionSide::Bottom, "bottom",
 }
 
+pub fn initial_value() -> CaptionSide {
+	CaptionSide::Top
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_caption_side().clone(),
+		initial_value(),
+		LonghandId::CaptionSide,
+		PropertyDeclaration::CaptionSide(value) => value.clone()
+	);
+	context.computed_values.set_caption_side(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthOrAuto;
 
 /// https://drafts.fxtf.org/css-masking/#clip-property
@@ -50,6 +54,26 @@ impl ToCss for Clip {
 	}
 }
 
+pub fn initial_value() -> Clip {
+	Clip {
+		top: LengthOrAuto::Auto,
+		right: LengthOrAuto::Auto,
+		bottom: LengthOrAuto::Auto,
+		left: LengthOrAuto::Auto,
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_clip().clone(),
+		initial_value(),
+		LonghandId::Clip,
+		PropertyDeclaration::Clip(value) => value.clone()
+	);
+	context.computed_values.set_clip(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::number::Integer;
 
 #[derive(Clone, Debug)]
@@ -37,6 +41,21 @@ impl ToCss for ColumnCount {
 	}
 }
 
+pub fn initial_value() -> ColumnCount {
+	ColumnCount::Auto
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_column_count().clone(),
+		initial_value(),
+		LonghandId::ColumnCount,
+		PropertyDeclaration::ColumnCount(value) => value.clone()
+	);
+	context.computed_values.set_column_count(computed_value);
+}
+
 /// https://drafts.csswg.org/css-multicol/#cc
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
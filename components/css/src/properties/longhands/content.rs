@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::parser::{parse_repeated, ParseError};
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::counter::Counter;
 use crate::values::specified::image::Image;
 use crate::values::specified::leader::Leader;
@@ -235,6 +239,21 @@ impl ToCss for Content {
 	}
 }
 
+pub fn initial_value() -> Content {
+	Content::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_content().clone(),
+		initial_value(),
+		LonghandId::Content,
+		PropertyDeclaration::Content(value) => value.clone()
+	);
+	context.computed_values.set_content(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
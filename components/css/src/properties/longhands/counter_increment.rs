@@ -1,10 +1,29 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::counter::CounterWithInteger;
 
+pub fn initial_value() -> CounterWithInteger {
+	CounterWithInteger::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_counter_increment().clone(),
+		initial_value(),
+		LonghandId::CounterIncrement,
+		PropertyDeclaration::CounterIncrement(value) => value.clone()
+	);
+	context.computed_values.set_counter_increment(computed_value);
+}
+
 /// https://drafts.csswg.org/css-lists/#propdef-counter-increment
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
@@ -1,8 +1,12 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::generics::counter::{GenericCounterOrNone, GenericReversedCounter};
 use crate::values::specified::number::Integer;
 
@@ -17,6 +21,21 @@ impl CounterReset {
 	}
 }
 
+pub fn initial_value() -> CounterReset {
+	CounterReset::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_counter_reset().clone(),
+		initial_value(),
+		LonghandId::CounterReset,
+		PropertyDeclaration::CounterReset(value) => value.clone()
+	);
+	context.computed_values.set_counter_reset(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 /// https://drafts.csswg.org/css-writing-modes/#direction
 #[derive(Clone, Debug)]
@@ -17,6 +21,21 @@ property_keywords_impl! { Direction,
 	Direction::Rtl, "rtl",
 }
 
+pub fn initial_value() -> Direction {
+	Direction::Ltr
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_direction().clone(),
+		initial_value(),
+		LonghandId::Direction,
+		PropertyDeclaration::Direction(value) => value.clone()
+	);
+	context.computed_values.set_direction(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::{parse_repeated, ParseError};
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::angle::Angle;
 use crate::values::specified::color::Color;
 use crate::values::specified::length::{Length, NonNegativeLength};
@@ -287,6 +291,21 @@ impl ToCss for Filter {
 	}
 }
 
+pub fn initial_value() -> Filter {
+	Filter::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_filter().clone(),
+		initial_value(),
+		LonghandId::Filter,
+		PropertyDeclaration::Filter(value) => value.clone()
+	);
+	context.computed_values.set_filter(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
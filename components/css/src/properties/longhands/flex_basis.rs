@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::Size;
 
 /// https://drafts.csswg.org/css-flexbox/#flex-basis-property
@@ -24,6 +28,13 @@ impl FlexBasis {
 				Ok(FlexBasis::Width(size))
 			})
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::length::FlexBasis {
+		match self {
+			FlexBasis::Content => computed::length::FlexBasis::Content,
+			FlexBasis::Width(size) => computed::length::FlexBasis::Width(size.to_computed_value(context)),
+		}
+	}
 }
 
 impl ToCss for FlexBasis {
@@ -38,6 +49,21 @@ impl ToCss for FlexBasis {
 	}
 }
 
+pub fn initial_value() -> FlexBasis {
+	FlexBasis::Width(Size::Auto)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_flex_basis().clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::FlexBasis,
+		PropertyDeclaration::FlexBasis(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_flex_basis(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,10 +1,29 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::number::NonNegativeNumber;
 
+pub fn initial_value() -> NonNegativeNumber {
+	NonNegativeNumber::new(0.0)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_flex_grow(),
+		initial_value().get(),
+		LonghandId::FlexGrow,
+		PropertyDeclaration::FlexGrow(value) => value.get()
+	);
+	context.computed_values.set_flex_grow(computed_value);
+}
+
 /// https://drafts.csswg.org/css-flexbox/#flex-grow-property
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
@@ -60,6 +60,18 @@ impl FamilyName {
 				Ok(FamilyName::Ident(idents))
 			})
 	}
+
+	/// The family name without quotes, idents are separated by a space.
+	pub fn name(&self) -> String {
+		match self {
+			FamilyName::String(value) => value.clone(),
+			FamilyName::Ident(idents) => idents
+				.iter()
+				.map(|ident| ident.0.clone())
+				.collect::<Vec<String>>()
+				.join(" "),
+		}
+	}
 }
 
 impl ToCss for FamilyName {
@@ -9,7 +9,7 @@ use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
 use crate::values::specified::length::LengthPercentage;
-use crate::values::{computed, CSSFloat};
+use crate::values::{computed, CSSFloat, Pixel};
 
 pub static DEFAULT_FONT_SIZE: f32 = 16.0;
 
@@ -97,6 +97,10 @@ impl FontSize {
 				LengthPercentage::Percentage(percentage) => {
 					context.parent_style.get_font_size() * percentage.to_value(&(0.0..1.0))
 				},
+				LengthPercentage::Calc(calc) => calc
+					.to_computed_value(context)
+					.to_used_value(Pixel::new(context.parent_style.get_font_size()))
+					.get(),
 			},
 		}
 	}
@@ -120,6 +124,11 @@ pub fn initial_value() -> FontSize {
 }
 
 pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	// font-relative lengths in `font-size` refer to the font size of the parent
+	// https://drafts.csswg.org/css-values/#font-relative-lengths
+	context
+		.computed_values
+		.set_font_size(context.parent_style.get_font_size());
 	let computed_value = computed::from_inherited_property!(
 		declaration,
 		context.parent_style.get_font_size(),
@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::angle::Angle;
 
 /// https://drafts.csswg.org/css-fonts/#font-style-prop
@@ -50,6 +54,21 @@ impl ToCss for FontStyle {
 	}
 }
 
+pub fn initial_value() -> FontStyle {
+	FontStyle::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_font_style().clone(),
+		initial_value(),
+		LonghandId::FontStyle,
+		PropertyDeclaration::FontStyle(value) => value.clone()
+	);
+	context.computed_values.set_font_style(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
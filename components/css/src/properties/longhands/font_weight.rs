@@ -1,10 +1,14 @@
 use cssparser::{Parser, ToCss, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
 use crate::values::specified::number::NonNegativeNumber;
+use crate::values::{computed, CSSFloat};
 
 /// https://drafts.csswg.org/css-fonts/#font-weight-prop
 #[derive(Clone, Debug)]
@@ -39,6 +43,23 @@ impl FontWeight {
 				Ok(FontWeight::Weight(value))
 			})
 	}
+
+	/// `bolder` and `lighter` are relative to the weight of the parent.
+	/// https://drafts.csswg.org/css-fonts/#relative-weights
+	pub fn to_computed_value(&self, context: &StyleContext) -> CSSFloat {
+		let parent_weight = context.parent_style.get_font_weight();
+		match self {
+			FontWeight::Weight(value) => value.get(),
+			FontWeight::Normal => 400.0,
+			FontWeight::Bold => 700.0,
+			FontWeight::Bolder if parent_weight < 350.0 => 400.0,
+			FontWeight::Bolder if parent_weight < 550.0 => 700.0,
+			FontWeight::Bolder => 900.0,
+			FontWeight::Lighter if parent_weight < 550.0 => 100.0,
+			FontWeight::Lighter if parent_weight < 750.0 => 400.0,
+			FontWeight::Lighter => 700.0,
+		}
+	}
 }
 
 impl ToCss for FontWeight {
@@ -56,6 +77,21 @@ impl ToCss for FontWeight {
 	}
 }
 
+pub fn initial_value() -> FontWeight {
+	FontWeight::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_font_weight(),
+		initial_value().to_computed_value(context),
+		LonghandId::FontWeight,
+		PropertyDeclaration::FontWeight(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_font_weight(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
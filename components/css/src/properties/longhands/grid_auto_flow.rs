@@ -0,0 +1,36 @@
+use cssparser::Parser;
+
+use crate::computed_values::StyleContext;
+use crate::parser::ParseError;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
+use crate::values::specified::grid::{AutoFlow, GridAutoFlow};
+
+pub fn initial_value() -> GridAutoFlow {
+	GridAutoFlow {
+		auto_flow: AutoFlow::Row,
+		dense: false,
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_grid_auto_flow().clone(),
+		initial_value(),
+		LonghandId::GridAutoFlow,
+		PropertyDeclaration::GridAutoFlow(value) => value.clone()
+	);
+	context.computed_values.set_grid_auto_flow(computed_value);
+}
+
+/// https://drafts.csswg.org/css-grid/#propdef-grid-auto-flow
+pub fn parse_declared<'i, 't>(
+	_context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<PropertyDeclaration, ParseError<'i>> {
+	GridAutoFlow::parse(input).map(PropertyDeclaration::GridAutoFlow)
+}
@@ -0,0 +1,33 @@
+use cssparser::Parser;
+
+use crate::computed_values::StyleContext;
+use crate::parser::ParseError;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
+use crate::values::specified::grid::GridLine;
+
+pub fn initial_value() -> GridLine {
+	GridLine::auto()
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_grid_column_start().clone(),
+		initial_value(),
+		LonghandId::GridColumnStart,
+		PropertyDeclaration::GridColumnStart(value) => value.clone()
+	);
+	context.computed_values.set_grid_column_start(computed_value);
+}
+
+/// https://drafts.csswg.org/css-grid/#propdef-grid-column-start
+pub fn parse_declared<'i, 't>(
+	_context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<PropertyDeclaration, ParseError<'i>> {
+	GridLine::parse(input).map(PropertyDeclaration::GridColumnStart)
+}
@@ -0,0 +1,33 @@
+use cssparser::Parser;
+
+use crate::computed_values::StyleContext;
+use crate::parser::ParseError;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
+use crate::values::specified::grid::GridTemplateAreas;
+
+pub fn initial_value() -> GridTemplateAreas {
+	GridTemplateAreas::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_grid_template_areas().clone(),
+		initial_value(),
+		LonghandId::GridTemplateAreas,
+		PropertyDeclaration::GridTemplateAreas(value) => value.clone()
+	);
+	context.computed_values.set_grid_template_areas(computed_value);
+}
+
+/// https://drafts.csswg.org/css-grid/#propdef-grid-template-areas
+pub fn parse_declared<'i, 't>(
+	_context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<PropertyDeclaration, ParseError<'i>> {
+	GridTemplateAreas::parse(input).map(PropertyDeclaration::GridTemplateAreas)
+}
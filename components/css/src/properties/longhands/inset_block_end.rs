@@ -1,10 +1,34 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
+use crate::logical_geometry::LogicalSide;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthPercentageOrAuto;
 
+pub fn initial_value() -> LengthPercentageOrAuto {
+	LengthPercentageOrAuto::Auto
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	if declaration.is_none() {
+		return;
+	}
+	let side = context.computed_values.physical_side(LogicalSide::BlockEnd);
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_inset(side).clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::InsetBlockEnd,
+		PropertyDeclaration::InsetBlockEnd(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_inset(side, computed_value);
+}
+
 /// https://drafts.csswg.org/css-logical/#propdef-inset-block-end
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
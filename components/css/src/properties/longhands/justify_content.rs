@@ -1,28 +1,55 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
-/// https://drafts.csswg.org/css-flexbox-1/#justify-content-property
+/// https://drafts.csswg.org/css-align-3/#propdef-justify-content
 #[derive(Clone, Debug)]
 pub enum JustifyContent {
+	Normal,
+	Start,
+	End,
 	FlexStart,
 	Stretch,
 	FlexEnd,
 	Center,
 	SpaceBetween,
 	SpaceAround,
+	SpaceEvenly,
 }
 
 property_keywords_impl! { JustifyContent,
+	JustifyContent::Normal, "normal",
+	JustifyContent::Start, "start",
+	JustifyContent::End, "end",
 	JustifyContent::FlexStart, "flex-start",
 	JustifyContent::Stretch, "stretch",
 	JustifyContent::FlexEnd, "flex-end",
 	JustifyContent::Center, "center",
 	JustifyContent::SpaceBetween, "space-between",
 	JustifyContent::SpaceAround, "space-around",
+	JustifyContent::SpaceEvenly, "space-evenly",
+}
+
+pub fn initial_value() -> JustifyContent {
+	JustifyContent::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_justify_content().clone(),
+		initial_value(),
+		LonghandId::JustifyContent,
+		PropertyDeclaration::JustifyContent(value) => value.clone()
+	);
+	context.computed_values.set_justify_content(computed_value);
 }
 
 pub fn parse_declared<'i, 't>(
@@ -0,0 +1,91 @@
+use cssparser::{match_ignore_ascii_case, Parser, ToCss, _cssparser_internal_to_lowercase};
+
+use crate::computed_values::StyleContext;
+use crate::parser::ParseError;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
+use crate::values::specified::position::{BaselinePosition, OverflowPosition, SelfPosition};
+
+/// https://drafts.csswg.org/css-align-3/#justify-items-property
+#[derive(Clone, Debug)]
+pub enum JustifyItems {
+	Legacy,
+	Normal,
+	Stretch,
+	Baseline(BaselinePosition),
+	Overflow(Option<OverflowPosition>, SelfPosition),
+}
+
+impl JustifyItems {
+	pub fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		input
+			.try_parse(|input| {
+				let location = input.current_source_location();
+				let ident = input.expect_ident()?;
+				Ok(match_ignore_ascii_case! { ident,
+					"legacy" => JustifyItems::Legacy,
+					"normal" => JustifyItems::Normal,
+					"stretch" => JustifyItems::Stretch,
+					_ => return Err(location.new_custom_error(StyleParseErrorKind::UnexpectedValue(ident.clone())))
+				})
+			})
+			.or_else(|_err: ParseError<'i>| {
+				input.try_parse(|input| {
+					let baseline = BaselinePosition::parse(context, input)?;
+					Ok(JustifyItems::Baseline(baseline))
+				})
+			})
+			.or_else(|_err: ParseError<'i>| {
+				let overflow = input.try_parse(|input| OverflowPosition::parse(input)).ok();
+				let content = SelfPosition::parse(input)?;
+				Ok(JustifyItems::Overflow(overflow, content))
+			})
+	}
+}
+
+impl ToCss for JustifyItems {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			JustifyItems::Legacy => dest.write_str("legacy"),
+			JustifyItems::Normal => dest.write_str("normal"),
+			JustifyItems::Stretch => dest.write_str("stretch"),
+			JustifyItems::Baseline(value) => value.to_css(dest),
+			JustifyItems::Overflow(overflow, content) => dest.write_fmt(format_args!(
+				"{}{}",
+				overflow
+					.as_ref()
+					.map_or("".to_string(), |v| std::format!("{} ", v.to_css_string())),
+				content.to_css_string()
+			)),
+		}
+	}
+}
+
+pub fn initial_value() -> JustifyItems {
+	JustifyItems::Legacy
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_justify_items().clone(),
+		initial_value(),
+		LonghandId::JustifyItems,
+		PropertyDeclaration::JustifyItems(value) => value.clone()
+	);
+	context.computed_values.set_justify_items(computed_value);
+}
+
+pub fn parse_declared<'i, 't>(
+	context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<PropertyDeclaration, ParseError<'i>> {
+	JustifyItems::parse(context, input).map(PropertyDeclaration::JustifyItems)
+}
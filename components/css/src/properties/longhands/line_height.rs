@@ -1,13 +1,32 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::NonNegativeLengthPercentageNumberOrNormal;
 
 /// https://drafts.csswg.org/css-inline/#line-height-property
 pub type LineHeight = NonNegativeLengthPercentageNumberOrNormal;
 
+pub fn initial_value() -> LineHeight {
+	LineHeight::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_line_height().clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::LineHeight,
+		PropertyDeclaration::LineHeight(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_line_height(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::image::Image;
 
 /// https://drafts.csswg.org/css-lists/#image-markers
@@ -38,6 +42,21 @@ impl ToCss for ListStyleImage {
 	}
 }
 
+pub fn initial_value() -> ListStyleImage {
+	ListStyleImage::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_list_style_image().clone(),
+		initial_value(),
+		LonghandId::ListStyleImage,
+		PropertyDeclaration::ListStyleImage(value) => value.clone()
+	);
+	context.computed_values.set_list_style_image(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
 use crate::values::specified::counter::CounterStyle;
+use crate::values::{computed, CustomIdent};
 
 #[derive(Clone, Debug)]
 pub enum ListStyleType {
@@ -45,6 +49,21 @@ impl ToCss for ListStyleType {
 	}
 }
 
+pub fn initial_value() -> ListStyleType {
+	ListStyleType::Style(CounterStyle::Name(CustomIdent("disc".to_string())))
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_list_style_type().clone(),
+		initial_value(),
+		LonghandId::ListStyleType,
+		PropertyDeclaration::ListStyleType(value) => value.clone()
+	);
+	context.computed_values.set_list_style_type(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
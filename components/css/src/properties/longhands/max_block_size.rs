@@ -1,10 +1,34 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
+use crate::logical_geometry::LogicalAxis;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::Size;
 
+pub fn initial_value() -> Size {
+	Size::Auto
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	if declaration.is_none() {
+		return;
+	}
+	let axis = context.computed_values.physical_axis(LogicalAxis::Block);
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_max_size(axis).clone(),
+		initial_value().to_computed_value(context).into(),
+		LonghandId::MaxBlockSize,
+		PropertyDeclaration::MaxBlockSize(value) => value.to_computed_value(context).into()
+	);
+	context.computed_values.set_max_size(axis, computed_value);
+}
+
 /// https://drafts.csswg.org/css-logical/#propdef-max-block-size
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
@@ -69,6 +69,9 @@ pub mod clip;
 pub mod color;
 pub mod column_count;
 pub mod column_gap;
+pub mod column_rule_color;
+pub mod column_rule_style;
+pub mod column_rule_width;
 pub mod column_width;
 pub mod content;
 pub mod counter_increment;
@@ -91,6 +94,16 @@ pub mod font_stretch;
 pub mod font_style;
 pub mod font_variant_caps;
 pub mod font_weight;
+pub mod grid_auto_columns;
+pub mod grid_auto_flow;
+pub mod grid_auto_rows;
+pub mod grid_column_end;
+pub mod grid_column_start;
+pub mod grid_row_end;
+pub mod grid_row_start;
+pub mod grid_template_areas;
+pub mod grid_template_columns;
+pub mod grid_template_rows;
 pub mod height;
 pub mod image_rendering;
 pub mod inline_size;
@@ -99,6 +112,8 @@ pub mod inset_block_start;
 pub mod inset_inline_end;
 pub mod inset_inline_start;
 pub mod justify_content;
+pub mod justify_items;
+pub mod justify_self;
 pub mod left;
 pub mod letter_spacing;
 pub mod line_height;
@@ -149,6 +164,7 @@ pub mod position;
 pub mod quotes;
 pub mod right;
 pub mod rotate;
+pub mod row_gap;
 pub mod scale;
 pub mod table_layout;
 pub mod text_align;
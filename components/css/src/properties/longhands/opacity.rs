@@ -1,13 +1,32 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::specified::number::NumberOrPercentage;
+use crate::values::computed;
+use crate::values::specified::number::{Number, NumberOrPercentage};
 
 /// https://drafts.csswg.org/css-color/#transparency
 pub type Opacity = NumberOrPercentage;
 
+pub fn initial_value() -> Opacity {
+	Opacity::Number(Number::new(1.0))
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_opacity(),
+		initial_value().to_number(),
+		LonghandId::Opacity,
+		PropertyDeclaration::Opacity(value) => value.to_number().max(0.0).min(1.0)
+	);
+	context.computed_values.set_opacity(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,10 +1,25 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::number::Integer;
 
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_order(),
+		0,
+		LonghandId::Order,
+		PropertyDeclaration::Order(value) => value.get()
+	);
+	context.computed_values.set_order(computed_value);
+}
+
 /// https://drafts.csswg.org/css-display/#order-property
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
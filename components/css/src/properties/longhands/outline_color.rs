@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::specified::color::Color;
+use crate::values::computed;
+use crate::values::specified::color::{Color, RGBA};
 
 /// https://drafts.csswg.org/css-ui/#outline-color
 #[derive(Clone, Debug)]
@@ -24,6 +28,14 @@ impl OutlineColor {
 				Ok(OutlineColor::Color(color))
 			})
 	}
+
+	/// Inverting the colors behind the outline isn't supported, `invert` falls back to `currentcolor`.
+	pub fn to_computed_value(&self, context: &StyleContext) -> RGBA {
+		match self {
+			OutlineColor::Invert => Color::CurrentColor.to_computed_value(context),
+			OutlineColor::Color(color) => color.to_computed_value(context),
+		}
+	}
 }
 
 impl ToCss for OutlineColor {
@@ -38,6 +50,21 @@ impl ToCss for OutlineColor {
 	}
 }
 
+pub fn initial_value() -> OutlineColor {
+	OutlineColor::Color(Color::CurrentColor)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_outline_color().clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::OutlineColor,
+		PropertyDeclaration::OutlineColor(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_outline_color(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
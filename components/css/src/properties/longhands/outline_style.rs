@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::layout::LineStyle;
 
 /// https://drafts.csswg.org/css-ui/#outline-style
@@ -42,6 +46,21 @@ impl ToCss for OutlineStyle {
 	}
 }
 
+pub fn initial_value() -> OutlineStyle {
+	OutlineStyle::BorderStyle(LineStyle::None)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_outline_style().clone(),
+		initial_value(),
+		LonghandId::OutlineStyle,
+		PropertyDeclaration::OutlineStyle(value) => value.clone()
+	);
+	context.computed_values.set_outline_style(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
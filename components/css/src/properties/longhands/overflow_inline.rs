@@ -1,10 +1,34 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
+use crate::logical_geometry::LogicalAxis;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::layout::Overflow;
 
+pub fn initial_value() -> Overflow {
+	Overflow::Visible
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	if declaration.is_none() {
+		return;
+	}
+	let axis = context.computed_values.physical_axis(LogicalAxis::Inline);
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_overflow(axis).clone(),
+		initial_value(),
+		LonghandId::OverflowInline,
+		PropertyDeclaration::OverflowInline(value) => value.clone()
+	);
+	context.computed_values.set_overflow(axis, computed_value);
+}
+
 /// https://drafts.csswg.org/css-overflow/#propdef-overflow-inline
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
@@ -1,9 +1,30 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::specified::position::Position;
+use crate::values::computed;
+use crate::values::specified::position::{HorizontalPosition, Position, VerticalPosition};
+
+pub type PerspectiveOrigin = Position;
+
+pub fn initial_value() -> PerspectiveOrigin {
+	PerspectiveOrigin::new(HorizontalPosition::Center, VerticalPosition::Center)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_perspective_origin().clone(),
+		initial_value(),
+		LonghandId::PerspectiveOrigin,
+		PropertyDeclaration::PerspectiveOrigin(value) => value.clone()
+	);
+	context.computed_values.set_perspective_origin(computed_value);
+}
 
 /// https://drafts.csswg.org/css-transforms-2/#perspective-origin-property
 pub fn parse_declared<'i, 't>(
@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::parser::{parse_repeated, ParseError};
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 /// https://drafts.csswg.org/css-content/#quotes-property
 #[derive(Clone, Debug)]
@@ -56,6 +60,21 @@ impl ToCss for Quotes {
 	}
 }
 
+pub fn initial_value() -> Quotes {
+	Quotes::Auto
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_quotes().clone(),
+		initial_value(),
+		LonghandId::Quotes,
+		PropertyDeclaration::Quotes(value) => value.clone()
+	);
+	context.computed_values.set_quotes(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss, Token, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::parser::{parse_in_any_order, parse_item_if_missing, ParseError};
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::angle::Angle;
 use crate::values::specified::number::Number;
 
@@ -115,6 +119,21 @@ impl ToCss for Rotate {
 	}
 }
 
+pub fn initial_value() -> Rotate {
+	Rotate::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_rotate().clone(),
+		initial_value(),
+		LonghandId::Rotate,
+		PropertyDeclaration::Rotate(value) => value.clone()
+	);
+	context.computed_values.set_rotate(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::number::NumberOrPercentage;
 
 /// https://drafts.csswg.org/css-transforms-2/#propdef-scale
@@ -50,6 +54,21 @@ impl ToCss for Scale {
 	}
 }
 
+pub fn initial_value() -> Scale {
+	Scale::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_scale().clone(),
+		initial_value(),
+		LonghandId::Scale,
+		PropertyDeclaration::Scale(value) => value.clone()
+	);
+	context.computed_values.set_scale(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,10 +1,14 @@
 use cssparser::{Parser, ToCss, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::css_writer::write_elements;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 bitflags! {
 	#[repr(C)]
@@ -86,6 +90,21 @@ impl ToCss for TextDecorationLine {
 	}
 }
 
+pub fn initial_value() -> TextDecorationLine {
+	TextDecorationLine::NONE
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_text_decoration_line().clone(),
+		initial_value(),
+		LonghandId::TextDecorationLine,
+		PropertyDeclaration::TextDecorationLine(value) => value.clone()
+	);
+	context.computed_values.set_text_decoration_line(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
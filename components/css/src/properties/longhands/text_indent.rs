@@ -1,10 +1,14 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::css_writer::write_elements;
 use crate::parser::{parse_in_any_order, parse_item_if_missing, ParseError};
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthPercentage;
 
 /// https://drafts.csswg.org/css-text/#text-indent-property
@@ -48,6 +52,14 @@ impl TextIndent {
 			Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError))
 		}
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::text::TextIndent {
+		computed::text::TextIndent {
+			indent: self.indent.to_computed_value(context),
+			hanging: self.hanging,
+			each_line: self.each_line,
+		}
+	}
 }
 
 impl ToCss for TextIndent {
@@ -61,6 +73,25 @@ impl ToCss for TextIndent {
 		write_elements(dest, &[indent.as_deref(), hanging, each_line], ' ')
 	}
 }
+pub fn initial_value() -> TextIndent {
+	TextIndent {
+		indent: LengthPercentage::zero(),
+		hanging: false,
+		each_line: false,
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_text_indent().clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::TextIndent,
+		PropertyDeclaration::TextIndent(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_text_indent(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
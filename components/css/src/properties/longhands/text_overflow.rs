@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthPercentage;
 
 #[derive(Clone, Debug)]
@@ -91,6 +95,24 @@ impl ToCss for TextOverflow {
 	}
 }
 
+pub fn initial_value() -> TextOverflow {
+	TextOverflow {
+		first: TextOverflowSide::Clip,
+		second: None,
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_text_overflow().clone(),
+		initial_value(),
+		LonghandId::TextOverflow,
+		PropertyDeclaration::TextOverflow(value) => value.clone()
+	);
+	context.computed_values.set_text_overflow(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
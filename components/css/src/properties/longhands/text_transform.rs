@@ -1,10 +1,14 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::css_writer::write_elements;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 bitflags! {
 	 #[repr(C)]
@@ -96,6 +100,21 @@ impl ToCss for TextTransform {
 	}
 }
 
+pub fn initial_value() -> TextTransform {
+	TextTransform::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_text_transform().clone(),
+		initial_value(),
+		LonghandId::TextTransform,
+		PropertyDeclaration::TextTransform(value) => value.clone()
+	);
+	context.computed_values.set_text_transform(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
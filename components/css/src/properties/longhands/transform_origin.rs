@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
 
+use crate::computed_values::StyleContext;
 use crate::parser::{parse_in_any_order, parse_item_if_missing, ParseError};
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthPercentage;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -221,6 +225,25 @@ impl ToCss for TransformOrigin {
 	}
 }
 
+pub fn initial_value() -> TransformOrigin {
+	TransformOrigin {
+		x: LengthPercentageOrKeyword::Keyword(OffsetKeyword::Center),
+		y: LengthPercentageOrKeyword::Keyword(OffsetKeyword::Center),
+		z: LengthPercentageOrKeyword::LengthPercentage(LengthPercentage::zero()),
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_transform_origin().clone(),
+		initial_value(),
+		LonghandId::TransformOrigin,
+		PropertyDeclaration::TransformOrigin(value) => value.clone()
+	);
+	context.computed_values.set_transform_origin(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
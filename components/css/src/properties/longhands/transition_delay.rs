@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::time::Time;
 
 /// https://drafts.csswg.org/css-transitions/#transition-delay-property
@@ -28,6 +32,23 @@ impl ToCss for TransitionDelay {
 	}
 }
 
+pub fn initial_value() -> TransitionDelay {
+	TransitionDelay {
+		delays: vec![Time::zero()],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_transition_delay().clone(),
+		initial_value(),
+		LonghandId::TransitionDelay,
+		PropertyDeclaration::TransitionDelay(value) => value.clone()
+	);
+	context.computed_values.set_transition_delay(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
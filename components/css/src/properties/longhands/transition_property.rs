@@ -1,9 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::CustomIdent;
+use crate::values::{computed, CustomIdent};
 
 #[derive(Clone, Debug)]
 pub enum SingleTransitionProperty {
@@ -74,6 +77,23 @@ impl ToCss for TransitionProperty {
 	}
 }
 
+pub fn initial_value() -> TransitionProperty {
+	TransitionProperty {
+		properties: vec![SingleTransitionProperty::All],
+	}
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_transition_property().clone(),
+		initial_value(),
+		LonghandId::TransitionProperty,
+		PropertyDeclaration::TransitionProperty(value) => value.clone()
+	);
+	context.computed_values.set_transition_property(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
 	input: &mut Parser<'i, 't>,
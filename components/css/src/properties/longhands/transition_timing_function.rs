@@ -1,9 +1,13 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::specified::easing::EasingFunction;
+use crate::values::computed;
+use crate::values::specified::easing::{EasingFunction, EasingKeyword};
 
 /// https://drafts.csswg.org/css-transitions/#transition-timing-function-property
 #[derive(Clone, Debug)]
@@ -26,6 +30,21 @@ impl ToCss for TransitionTimingFunction {
 	}
 }
 
+pub fn initial_value() -> TransitionTimingFunction {
+	TransitionTimingFunction(vec![EasingFunction::Keyword(EasingKeyword::Ease)])
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_transition_timing_function().clone(),
+		initial_value(),
+		LonghandId::TransitionTimingFunction,
+		PropertyDeclaration::TransitionTimingFunction(value) => value.clone()
+	);
+	context.computed_values.set_transition_timing_function(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
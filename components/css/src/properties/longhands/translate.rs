@@ -1,8 +1,12 @@
 use cssparser::{Parser, ToCss};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::{Length, LengthPercentage};
 
 /// https://drafts.csswg.org/css-transforms-2/#individual-transforms
@@ -54,6 +58,21 @@ impl ToCss for Translate {
 	}
 }
 
+pub fn initial_value() -> Translate {
+	Translate::None
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_translate().clone(),
+		initial_value(),
+		LonghandId::Translate,
+		PropertyDeclaration::Translate(value) => value.clone()
+	);
+	context.computed_values.set_translate(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,9 +1,13 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 
 /// https://drafts.csswg.org/css-writing-modes/#unicode-bidi
 #[derive(Clone, Debug)]
@@ -22,7 +26,22 @@ property_keywords_impl! { UnicodeBidi,
 	UnicodeBidi::Isolate, "isolate",
 	UnicodeBidi::BidiOverride, "bidi-override",
 	UnicodeBidi::IsolateOverride, "isolate-override",
-	UnicodeBidi::Plaintext, "plantext",
+	UnicodeBidi::Plaintext, "plaintext",
+}
+
+pub fn initial_value() -> UnicodeBidi {
+	UnicodeBidi::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_unicode_bidi().clone(),
+		initial_value(),
+		LonghandId::UnicodeBidi,
+		PropertyDeclaration::UnicodeBidi(value) => value.clone()
+	);
+	context.computed_values.set_unicode_bidi(computed_value);
 }
 
 pub fn parse_declared<'i, 't>(
@@ -1,12 +1,16 @@
 use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration};
+use crate::properties::declaration::{property_keywords_impl, PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthPercentage;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[repr(u8)]
 pub enum VerticalAlignKeyword {
 	Baseline,
@@ -49,6 +53,15 @@ impl VerticalAlign {
 				Ok(VerticalAlign::LengthPercentage(value))
 			})
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::text::VerticalAlign {
+		match self {
+			VerticalAlign::Keyword(keyword) => computed::text::VerticalAlign::Keyword(*keyword),
+			VerticalAlign::LengthPercentage(value) => {
+				computed::text::VerticalAlign::LengthPercentage(value.to_computed_value(context))
+			},
+		}
+	}
 }
 
 impl ToCss for VerticalAlign {
@@ -63,6 +76,21 @@ impl ToCss for VerticalAlign {
 	}
 }
 
+pub fn initial_value() -> VerticalAlign {
+	VerticalAlign::Keyword(VerticalAlignKeyword::Baseline)
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_vertical_align().clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::VerticalAlign,
+		PropertyDeclaration::VerticalAlign(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_vertical_align(computed_value);
+}
+
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
 	input: &mut Parser<'i, 't>,
@@ -1,10 +1,29 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::length::LengthPercentageOrNormal;
 
+pub fn initial_value() -> LengthPercentageOrNormal {
+	LengthPercentageOrNormal::Normal
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_inherited_property!(
+		declaration,
+		context.parent_style.get_word_spacing().clone(),
+		initial_value().to_computed_value(context),
+		LonghandId::WordSpacing,
+		PropertyDeclaration::WordSpacing(value) => value.to_computed_value(context)
+	);
+	context.computed_values.set_word_spacing(computed_value);
+}
+
 /// https://drafts.csswg.org/css-text/#word-spacing-property
 pub fn parse_declared<'i, 't>(
 	_context: &ParserContext,
@@ -1,10 +1,29 @@
 use cssparser::Parser;
 
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
-use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use crate::properties::longhand_id::LonghandId;
+use crate::properties::property_id::CSSWideKeyword;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::computed;
 use crate::values::specified::number::IntegerAuto;
 
+pub fn initial_value() -> IntegerAuto {
+	IntegerAuto::Auto
+}
+
+pub fn cascade_property<'a>(declaration: Option<&PropertyDeclaration>, context: &'a mut StyleContext) {
+	let computed_value = computed::from_non_inherited_property!(
+		declaration,
+		context.parent_style.get_z_index().clone(),
+		initial_value().to_computed_value(),
+		LonghandId::ZIndex,
+		PropertyDeclaration::ZIndex(value) => value.to_computed_value()
+	);
+	context.computed_values.set_z_index(computed_value);
+}
+
 /// https://drafts.csswg.org/css2/#z-index
 pub fn parse_declared<'i, 't>(
 	context: &ParserContext,
@@ -212,6 +212,22 @@ impl PropertyId {
 				"margin-top" => StaticId::Longhand(LonghandId::MarginTop),
 				"right" => StaticId::Longhand(LonghandId::Right),
 				"top" => StaticId::Longhand(LonghandId::Top),
+				"grid-template-columns" => StaticId::Longhand(LonghandId::GridTemplateColumns),
+				"grid-template-rows" => StaticId::Longhand(LonghandId::GridTemplateRows),
+				"grid-template-areas" => StaticId::Longhand(LonghandId::GridTemplateAreas),
+				"grid-auto-columns" => StaticId::Longhand(LonghandId::GridAutoColumns),
+				"grid-auto-rows" => StaticId::Longhand(LonghandId::GridAutoRows),
+				"grid-auto-flow" => StaticId::Longhand(LonghandId::GridAutoFlow),
+				"grid-row-start" => StaticId::Longhand(LonghandId::GridRowStart),
+				"grid-row-end" => StaticId::Longhand(LonghandId::GridRowEnd),
+				"grid-column-start" => StaticId::Longhand(LonghandId::GridColumnStart),
+				"grid-column-end" => StaticId::Longhand(LonghandId::GridColumnEnd),
+				"row-gap" => StaticId::Longhand(LonghandId::RowGap),
+				"justify-items" => StaticId::Longhand(LonghandId::JustifyItems),
+				"justify-self" => StaticId::Longhand(LonghandId::JustifySelf),
+				"column-rule-color" => StaticId::Longhand(LonghandId::ColumnRuleColor),
+				"column-rule-style" => StaticId::Longhand(LonghandId::ColumnRuleStyle),
+				"column-rule-width" => StaticId::Longhand(LonghandId::ColumnRuleWidth),
 				"background" => StaticId::Shorthand(ShorthandId::Background),
 				"background-position" => StaticId::Shorthand(ShorthandId::BackgroundPosition),
 				"border-color" => StaticId::Shorthand(ShorthandId::BorderColor),
@@ -257,6 +273,13 @@ impl PropertyId {
 				"inset-inline" => StaticId::Shorthand(ShorthandId::InsetInline),
 				"text-decoration" => StaticId::Shorthand(ShorthandId::TextDecoration),
 				"all" => StaticId::Shorthand(ShorthandId::All),
+				"gap" => StaticId::Shorthand(ShorthandId::Gap),
+				"grid" => StaticId::Shorthand(ShorthandId::Grid),
+				"grid-area" => StaticId::Shorthand(ShorthandId::GridArea),
+				"grid-column" => StaticId::Shorthand(ShorthandId::GridColumn),
+				"grid-row" => StaticId::Shorthand(ShorthandId::GridRow),
+				"grid-template" => StaticId::Shorthand(ShorthandId::GridTemplate),
+				"column-rule" => StaticId::Shorthand(ShorthandId::ColumnRule),
 			}
 		}
 
@@ -339,7 +362,7 @@ impl ToCss for PropertyId {
 pub struct NonCustomPropertyId(usize);
 
 /// The length of all the non-custom properties.
-pub const NON_CUSTOM_PROPERTY_ID_COUNT: usize = 225;
+pub const NON_CUSTOM_PROPERTY_ID_COUNT: usize = 248;
 
 impl NonCustomPropertyId {
 	/// Returns the underlying index, used for use counter.
@@ -530,6 +553,22 @@ impl NonCustomPropertyId {
 			"top",
 			"counter-set",
 			"object-fit",
+			"grid-template-columns",
+			"grid-template-rows",
+			"grid-template-areas",
+			"grid-auto-columns",
+			"grid-auto-rows",
+			"grid-auto-flow",
+			"grid-row-start",
+			"grid-row-end",
+			"grid-column-start",
+			"grid-column-end",
+			"row-gap",
+			"justify-items",
+			"justify-self",
+			"column-rule-color",
+			"column-rule-style",
+			"column-rule-width",
 			"background",
 			"background-position",
 			"border-color",
@@ -575,6 +614,13 @@ impl NonCustomPropertyId {
 			"inset-inline",
 			"text-decoration",
 			"all",
+			"gap",
+			"grid",
+			"grid-area",
+			"grid-column",
+			"grid-row",
+			"grid-template",
+			"column-rule",
 			"word-wrap",
 		];
 		MAP[self.0]
@@ -597,8 +643,8 @@ impl NonCustomPropertyId {
 			5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
 			5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
 			5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 7, 7, 7, 7, 7, 7, 7, 7, 5, 5, 5,
-			5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 1, 5, 5, 5, 5, 7, 5, 5, 5, 5, 5,
-			5, 5, 5, 5, 5, 5, 5, 5, 5,
+			5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+			5, 5, 5, 5, 5, 1, 5, 5, 5, 5, 7, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
 		];
 		match rule_type {
 			CssRuleType::Style => MAP[self.0] & 1 != 0,
@@ -616,11 +662,11 @@ impl NonCustomPropertyId {
 	#[inline]
 	pub fn to_property_id(self) -> PropertyId {
 		use std::mem::transmute;
-		if self.0 < 179 {
+		if self.0 < 195 {
 			return unsafe { PropertyId::Longhand(transmute(self.0 as u16)) };
 		}
-		if self.0 < 224 {
-			return unsafe { PropertyId::Shorthand(transmute((self.0 - 179) as u16)) };
+		if self.0 < 247 {
+			return unsafe { PropertyId::Shorthand(transmute((self.0 - 195) as u16)) };
 		}
 		not_reached!()
 	}
@@ -636,7 +682,7 @@ impl From<LonghandId> for NonCustomPropertyId {
 impl From<ShorthandId> for NonCustomPropertyId {
 	#[inline]
 	fn from(id: ShorthandId) -> Self {
-		NonCustomPropertyId((id as usize) + 179)
+		NonCustomPropertyId((id as usize) + 195)
 	}
 }
 
@@ -106,6 +106,20 @@ pub enum ShorthandId {
 	TextDecoration = 43,
 	/// all
 	All = 44,
+	/// gap
+	Gap = 45,
+	/// grid
+	Grid = 46,
+	/// grid-area
+	GridArea = 47,
+	/// grid-column
+	GridColumn = 48,
+	/// grid-row
+	GridRow = 49,
+	/// grid-template
+	GridTemplate = 50,
+	/// column-rule
+	ColumnRule = 51,
 }
 
 impl ToCss for ShorthandId {
@@ -319,6 +333,13 @@ impl ShorthandId {
 		static INSET_BLOCK: &'static [LonghandId] = &[LonghandId::InsetBlockStart, LonghandId::InsetBlockEnd];
 		static INSET_INLINE: &'static [LonghandId] = &[LonghandId::InsetInlineStart, LonghandId::InsetInlineEnd];
 		static TEXT_DECORATION: &'static [LonghandId] = &[LonghandId::TextDecorationLine];
+		static GAP: &'static [LonghandId] = &[LonghandId::RowGap, LonghandId::ColumnGap];
+		static GRID: &'static [LonghandId] = &[LonghandId::GridTemplateRows, LonghandId::GridTemplateColumns, LonghandId::GridTemplateAreas, LonghandId::GridAutoRows, LonghandId::GridAutoColumns, LonghandId::GridAutoFlow];
+		static GRID_AREA: &'static [LonghandId] = &[LonghandId::GridRowStart, LonghandId::GridColumnStart, LonghandId::GridRowEnd, LonghandId::GridColumnEnd];
+		static GRID_COLUMN: &'static [LonghandId] = &[LonghandId::GridColumnStart, LonghandId::GridColumnEnd];
+		static GRID_ROW: &'static [LonghandId] = &[LonghandId::GridRowStart, LonghandId::GridRowEnd];
+		static GRID_TEMPLATE: &'static [LonghandId] = &[LonghandId::GridTemplateRows, LonghandId::GridTemplateColumns, LonghandId::GridTemplateAreas];
+		static COLUMN_RULE: &'static [LonghandId] = &[LonghandId::ColumnRuleWidth, LonghandId::ColumnRuleStyle, LonghandId::ColumnRuleColor];
 		static ALL: &'static [LonghandId] = &[
 			LonghandId::BorderBlockStartColor,
 			LonghandId::BorderBlockStartStyle,
@@ -495,6 +516,22 @@ impl ShorthandId {
 			LonghandId::TableLayout,
 			LonghandId::TextOverflow,
 			LonghandId::TextDecorationLine,
+			LonghandId::GridTemplateColumns,
+			LonghandId::GridTemplateRows,
+			LonghandId::GridTemplateAreas,
+			LonghandId::GridAutoColumns,
+			LonghandId::GridAutoRows,
+			LonghandId::GridAutoFlow,
+			LonghandId::GridRowStart,
+			LonghandId::GridRowEnd,
+			LonghandId::GridColumnStart,
+			LonghandId::GridColumnEnd,
+			LonghandId::RowGap,
+			LonghandId::JustifyItems,
+			LonghandId::JustifySelf,
+			LonghandId::ColumnRuleColor,
+			LonghandId::ColumnRuleStyle,
+			LonghandId::ColumnRuleWidth,
 		];
 		NonCustomPropertyIterator {
 			iter: match *self {
@@ -543,6 +580,13 @@ impl ShorthandId {
 				ShorthandId::InsetInline => INSET_INLINE,
 				ShorthandId::TextDecoration => TEXT_DECORATION,
 				ShorthandId::All => ALL,
+				ShorthandId::Gap => GAP,
+				ShorthandId::Grid => GRID,
+				ShorthandId::GridArea => GRID_AREA,
+				ShorthandId::GridColumn => GRID_COLUMN,
+				ShorthandId::GridRow => GRID_ROW,
+				ShorthandId::GridTemplate => GRID_TEMPLATE,
+				ShorthandId::ColumnRule => COLUMN_RULE,
 			}
 			.iter(),
 		}
@@ -573,7 +617,7 @@ impl ShorthandId {
 			return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
 		}
 
-		static PARSE_INTO: [ParseIntoFn; 45] = [
+		static PARSE_INTO: [ParseIntoFn; 52] = [
 			shorthands::background::parse_into,
 			shorthands::background_position::parse_into,
 			shorthands::border_color::parse_into,
@@ -619,6 +663,13 @@ impl ShorthandId {
 			shorthands::inset_inline::parse_into,
 			shorthands::text_decoration::parse_into,
 			unreachable,
+			shorthands::gap::parse_into,
+			shorthands::grid::parse_into,
+			shorthands::grid_area::parse_into,
+			shorthands::grid_column::parse_into,
+			shorthands::grid_row::parse_into,
+			shorthands::grid_template::parse_into,
+			shorthands::column_rule::parse_into,
 		];
 
 		(PARSE_INTO[*self as usize])(declarations, context, input)
@@ -0,0 +1,56 @@
+use cssparser::Parser;
+
+use crate::parser::{parse_in_any_order, parse_item_if_missing, ParseError};
+use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration_block::SourcePropertyDeclaration;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::specified::color::Color;
+use crate::values::specified::layout::LineStyle;
+use crate::values::specified::line::LineWidth;
+
+pub struct Longhands {
+	pub column_rule_width: LineWidth,
+	pub column_rule_style: LineStyle,
+	pub column_rule_color: Color,
+}
+
+/// https://drafts.csswg.org/css-multicol/#column-rule
+/// <'column-rule-width'> || <'column-rule-style'> || <'column-rule-color'>
+pub fn parse_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	let mut column_rule_width = None;
+	let mut column_rule_style = None;
+	let mut column_rule_color = None;
+	parse_in_any_order(
+		input,
+		&mut [
+			&mut |input| parse_item_if_missing(input, &mut column_rule_width, &mut |_, input| LineWidth::parse(input)),
+			&mut |input| parse_item_if_missing(input, &mut column_rule_style, &mut |_, input| LineStyle::parse(input)),
+			&mut |input| parse_item_if_missing(input, &mut column_rule_color, &mut |_, input| Color::parse(input)),
+		],
+	);
+	if column_rule_width.is_none() && column_rule_style.is_none() && column_rule_color.is_none() {
+		return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+	}
+	Ok(Longhands {
+		column_rule_width: column_rule_width.unwrap_or(LineWidth::Medium),
+		column_rule_style: column_rule_style.unwrap_or(LineStyle::None),
+		column_rule_color: column_rule_color.unwrap_or(Color::CurrentColor),
+	})
+}
+
+/// Parse the given shorthand and fill the result into the
+/// `declarations` vector.
+pub fn parse_into<'i, 't>(
+	declarations: &mut SourcePropertyDeclaration,
+	context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<(), ParseError<'i>> {
+	input
+		.parse_entirely(|input| parse_value(context, input))
+		.map(|longhands| {
+			declarations.push(PropertyDeclaration::ColumnRuleWidth(longhands.column_rule_width));
+			declarations.push(PropertyDeclaration::ColumnRuleStyle(longhands.column_rule_style));
+			declarations.push(PropertyDeclaration::ColumnRuleColor(longhands.column_rule_color));
+		})
+}
@@ -4,16 +4,51 @@ use crate::parser::ParseError;
 use crate::properties::declaration::PropertyDeclaration;
 use crate::properties::declaration_block::SourcePropertyDeclaration;
 use crate::properties::longhands::column_count::ColumnCount;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
-use crate::values::specified::length::NonNegativeLengthOrAuto;
+use crate::values::generics::length::GenericLengthPercentageOrAuto;
+use crate::values::specified::length::{NonNegativeLength, NonNegativeLengthOrAuto};
+use crate::values::specified::number::Integer;
 
 pub struct Longhands {
 	pub column_width: NonNegativeLengthOrAuto,
 	pub column_count: ColumnCount,
 }
 
-pub fn parse_value<'i, 't>(_context: &ParserContext, _input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
-	todo!()
+/// https://drafts.csswg.org/css-multicol/#columns
+/// <'column-width'> || <'column-count'>
+pub fn parse_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	let mut column_count = None;
+	let mut column_width = None;
+	let mut autos = 0;
+	loop {
+		if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+			// `auto` is ambiguous, it resets whichever longhand is left unspecified
+			autos += 1;
+			continue;
+		}
+		if column_count.is_none() {
+			if let Ok(value) = input.try_parse(|input| Integer::parse_from(context, input, 1)) {
+				column_count = Some(ColumnCount::Integer(value));
+				continue;
+			}
+		}
+		if column_width.is_none() {
+			if let Ok(value) = input.try_parse(|input| NonNegativeLength::parse(input)) {
+				column_width = Some(GenericLengthPercentageOrAuto::LengthPercentage(value));
+				continue;
+			}
+		}
+		break;
+	}
+	let values = autos + column_count.iter().len() + column_width.iter().len();
+	if values == 0 || values > 2 {
+		return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+	}
+	Ok(Longhands {
+		column_width: column_width.unwrap_or(NonNegativeLengthOrAuto::Auto),
+		column_count: column_count.unwrap_or(ColumnCount::Auto),
+	})
 }
 
 /// Parse the given shorthand and fill the result into the
@@ -4,7 +4,9 @@ use crate::parser::ParseError;
 use crate::properties::declaration::PropertyDeclaration;
 use crate::properties::declaration_block::SourcePropertyDeclaration;
 use crate::properties::longhands::flex_basis::FlexBasis;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::specified::length::{NonNegativeLengthPercentage, Size};
 use crate::values::specified::number::NonNegativeNumber;
 
 pub struct Longhands {
@@ -13,8 +15,46 @@ pub struct Longhands {
 	pub flex_basis: FlexBasis,
 }
 
-pub fn parse_value<'i, 't>(_context: &ParserContext, _input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
-	todo!()
+/// https://drafts.csswg.org/css-flexbox/#flex-property
+/// none | [ <'flex-grow'> <'flex-shrink'>? || <'flex-basis'> ]
+pub fn parse_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+		return Ok(Longhands {
+			flex_grow: NonNegativeNumber::new(0.0),
+			flex_shrink: NonNegativeNumber::new(0.0),
+			flex_basis: FlexBasis::Width(Size::Auto),
+		});
+	}
+	let mut flex_grow = None;
+	let mut flex_shrink = None;
+	let mut flex_basis = None;
+	loop {
+		if flex_grow.is_none() {
+			if let Ok(value) = input.try_parse(|input| NonNegativeNumber::parse(input)) {
+				flex_grow = Some(value);
+				flex_shrink = input.try_parse(|input| NonNegativeNumber::parse(input)).ok();
+				continue;
+			}
+		}
+		if flex_basis.is_none() {
+			if let Ok(value) = input.try_parse(|input| FlexBasis::parse(input)) {
+				flex_basis = Some(value);
+				continue;
+			}
+		}
+		break;
+	}
+	if flex_grow.is_none() && flex_basis.is_none() {
+		return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+	}
+	Ok(Longhands {
+		flex_grow: flex_grow.unwrap_or(NonNegativeNumber::new(1.0)),
+		flex_shrink: flex_shrink.unwrap_or(NonNegativeNumber::new(1.0)),
+		// an omitted flex-basis is 0 rather than its initial value
+		flex_basis: flex_basis.unwrap_or(FlexBasis::Width(Size::LengthPercentage(
+			NonNegativeLengthPercentage::zero(),
+		))),
+	})
 }
 
 /// Parse the given shorthand and fill the result into the
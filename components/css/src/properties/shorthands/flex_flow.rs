@@ -1,10 +1,11 @@
 use cssparser::Parser;
 
-use crate::parser::ParseError;
+use crate::parser::{parse_in_any_order, parse_item_if_missing, ParseError};
 use crate::properties::declaration::PropertyDeclaration;
 use crate::properties::declaration_block::SourcePropertyDeclaration;
 use crate::properties::longhands::flex_direction::FlexDirection;
 use crate::properties::longhands::flex_wrap::FlexWrap;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::stylesheets::stylesheet::ParserContext;
 
 pub struct Longhands {
@@ -12,8 +13,25 @@ pub struct Longhands {
 	pub flex_wrap: FlexWrap,
 }
 
-pub fn parse_value<'i, 't>(_context: &ParserContext, _input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
-	todo!()
+/// https://drafts.csswg.org/css-flexbox/#flex-flow-property
+/// <'flex-direction'> || <'flex-wrap'>
+pub fn parse_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	let mut flex_direction = None;
+	let mut flex_wrap = None;
+	parse_in_any_order(
+		input,
+		&mut [
+			&mut |input| parse_item_if_missing(input, &mut flex_direction, &mut |_, input| FlexDirection::parse(input)),
+			&mut |input| parse_item_if_missing(input, &mut flex_wrap, &mut |_, input| FlexWrap::parse(input)),
+		],
+	);
+	if flex_direction.is_none() && flex_wrap.is_none() {
+		return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+	}
+	Ok(Longhands {
+		flex_direction: flex_direction.unwrap_or(FlexDirection::Row),
+		flex_wrap: flex_wrap.unwrap_or(FlexWrap::Nowrap),
+	})
 }
 
 /// Parse the given shorthand and fill the result into the
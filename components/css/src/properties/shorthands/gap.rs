@@ -0,0 +1,37 @@
+use cssparser::Parser;
+
+use crate::parser::ParseError;
+use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration_block::SourcePropertyDeclaration;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::specified::length::NonNegativeLengthPercentageOrNormal;
+
+pub struct Longhands {
+	pub row_gap: NonNegativeLengthPercentageOrNormal,
+	pub column_gap: NonNegativeLengthPercentageOrNormal,
+}
+
+/// https://drafts.csswg.org/css-align-3/#gap-shorthand
+/// <'row-gap'> <'column-gap'>?
+pub fn parse_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	let row_gap = NonNegativeLengthPercentageOrNormal::parse(input)?;
+	let column_gap = input
+		.try_parse(|input| NonNegativeLengthPercentageOrNormal::parse(input))
+		.unwrap_or_else(|_| row_gap.clone());
+	Ok(Longhands { row_gap, column_gap })
+}
+
+/// Parse the given shorthand and fill the result into the
+/// `declarations` vector.
+pub fn parse_into<'i, 't>(
+	declarations: &mut SourcePropertyDeclaration,
+	context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<(), ParseError<'i>> {
+	input
+		.parse_entirely(|input| parse_value(context, input))
+		.map(|longhands| {
+			declarations.push(PropertyDeclaration::RowGap(longhands.row_gap));
+			declarations.push(PropertyDeclaration::ColumnGap(longhands.column_gap));
+		})
+}
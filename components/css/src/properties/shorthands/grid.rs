@@ -0,0 +1,106 @@
+use cssparser::Parser;
+
+use super::grid_template;
+use crate::parser::ParseError;
+use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration_block::SourcePropertyDeclaration;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::specified::grid::{
+	AutoFlow, GridAutoFlow, GridTemplateAreas, GridTemplateComponent, ImplicitGridTracks,
+};
+
+pub struct Longhands {
+	pub grid_template_rows: GridTemplateComponent,
+	pub grid_template_columns: GridTemplateComponent,
+	pub grid_template_areas: GridTemplateAreas,
+	pub grid_auto_rows: ImplicitGridTracks,
+	pub grid_auto_columns: ImplicitGridTracks,
+	pub grid_auto_flow: GridAutoFlow,
+}
+
+/// auto-flow && dense?
+fn parse_auto_flow<'i, 't>(input: &mut Parser<'i, 't>, auto_flow: AutoFlow) -> Result<GridAutoFlow, ParseError<'i>> {
+	let mut has_auto_flow = false;
+	let mut dense = false;
+	loop {
+		if !has_auto_flow && input.try_parse(|input| input.expect_ident_matching("auto-flow")).is_ok() {
+			has_auto_flow = true;
+			continue;
+		}
+		if !dense && input.try_parse(|input| input.expect_ident_matching("dense")).is_ok() {
+			dense = true;
+			continue;
+		}
+		break;
+	}
+	if !has_auto_flow {
+		return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+	}
+	Ok(GridAutoFlow { auto_flow, dense })
+}
+
+/// https://drafts.csswg.org/css-grid/#grid-shorthand
+/// <'grid-template'> |
+/// <'grid-template-rows'> / [ auto-flow && dense? ] <'grid-auto-columns'>? |
+/// [ auto-flow && dense? ] <'grid-auto-rows'>? / <'grid-template-columns'>
+pub fn parse_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	if let Ok(template) = input.try_parse(|input| -> Result<_, ParseError<'i>> {
+		let template = grid_template::parse_value(context, input)?;
+		input.expect_exhausted()?;
+		Ok(template)
+	}) {
+		return Ok(Longhands {
+			grid_template_rows: template.grid_template_rows,
+			grid_template_columns: template.grid_template_columns,
+			grid_template_areas: template.grid_template_areas,
+			grid_auto_rows: ImplicitGridTracks::default(),
+			grid_auto_columns: ImplicitGridTracks::default(),
+			grid_auto_flow: crate::properties::longhands::grid_auto_flow::initial_value(),
+		});
+	}
+	if let Ok(grid_auto_flow) = input.try_parse(|input| parse_auto_flow(input, AutoFlow::Row)) {
+		let grid_auto_rows = input.try_parse(ImplicitGridTracks::parse).unwrap_or_default();
+		input.expect_delim('/')?;
+		let grid_template_columns = GridTemplateComponent::parse(input)?;
+		return Ok(Longhands {
+			grid_template_rows: GridTemplateComponent::None,
+			grid_template_columns,
+			grid_template_areas: GridTemplateAreas::None,
+			grid_auto_rows,
+			grid_auto_columns: ImplicitGridTracks::default(),
+			grid_auto_flow,
+		});
+	}
+	let grid_template_rows = GridTemplateComponent::parse(input)?;
+	input.expect_delim('/')?;
+	let grid_auto_flow = parse_auto_flow(input, AutoFlow::Column)?;
+	let grid_auto_columns = input.try_parse(ImplicitGridTracks::parse).unwrap_or_default();
+	Ok(Longhands {
+		grid_template_rows,
+		grid_template_columns: GridTemplateComponent::None,
+		grid_template_areas: GridTemplateAreas::None,
+		grid_auto_rows: ImplicitGridTracks::default(),
+		grid_auto_columns,
+		grid_auto_flow,
+	})
+}
+
+/// Parse the given shorthand and fill the result into the
+/// `declarations` vector.
+pub fn parse_into<'i, 't>(
+	declarations: &mut SourcePropertyDeclaration,
+	context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<(), ParseError<'i>> {
+	input
+		.parse_entirely(|input| parse_value(context, input))
+		.map(|longhands| {
+			declarations.push(PropertyDeclaration::GridTemplateRows(longhands.grid_template_rows));
+			declarations.push(PropertyDeclaration::GridTemplateColumns(longhands.grid_template_columns));
+			declarations.push(PropertyDeclaration::GridTemplateAreas(longhands.grid_template_areas));
+			declarations.push(PropertyDeclaration::GridAutoRows(longhands.grid_auto_rows));
+			declarations.push(PropertyDeclaration::GridAutoColumns(longhands.grid_auto_columns));
+			declarations.push(PropertyDeclaration::GridAutoFlow(longhands.grid_auto_flow));
+		})
+}
@@ -0,0 +1,50 @@
+use cssparser::Parser;
+
+use crate::parser::ParseError;
+use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration_block::SourcePropertyDeclaration;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::specified::grid::GridLine;
+
+pub struct Longhands {
+	pub grid_row_start: GridLine,
+	pub grid_column_start: GridLine,
+	pub grid_row_end: GridLine,
+	pub grid_column_end: GridLine,
+}
+
+/// https://drafts.csswg.org/css-grid/#propdef-grid-area
+/// <grid-line> [ / <grid-line> ]{0,3}
+pub fn parse_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	let mut lines = vec![GridLine::parse(input)?];
+	while lines.len() < 4 && input.try_parse(|input| input.expect_delim('/')).is_ok() {
+		lines.push(GridLine::parse(input)?);
+	}
+	let grid_row_start = lines[0].clone();
+	let grid_column_start = lines.get(1).cloned().unwrap_or_else(|| grid_row_start.omitted_counterpart());
+	let grid_row_end = lines.get(2).cloned().unwrap_or_else(|| grid_row_start.omitted_counterpart());
+	let grid_column_end = lines.get(3).cloned().unwrap_or_else(|| grid_column_start.omitted_counterpart());
+	Ok(Longhands {
+		grid_row_start,
+		grid_column_start,
+		grid_row_end,
+		grid_column_end,
+	})
+}
+
+/// Parse the given shorthand and fill the result into the
+/// `declarations` vector.
+pub fn parse_into<'i, 't>(
+	declarations: &mut SourcePropertyDeclaration,
+	context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<(), ParseError<'i>> {
+	input
+		.parse_entirely(|input| parse_value(context, input))
+		.map(|longhands| {
+			declarations.push(PropertyDeclaration::GridRowStart(longhands.grid_row_start));
+			declarations.push(PropertyDeclaration::GridColumnStart(longhands.grid_column_start));
+			declarations.push(PropertyDeclaration::GridRowEnd(longhands.grid_row_end));
+			declarations.push(PropertyDeclaration::GridColumnEnd(longhands.grid_column_end));
+		})
+}
@@ -0,0 +1,42 @@
+use cssparser::Parser;
+
+use crate::parser::ParseError;
+use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration_block::SourcePropertyDeclaration;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::specified::grid::GridLine;
+
+pub struct Longhands {
+	pub grid_column_start: GridLine,
+	pub grid_column_end: GridLine,
+}
+
+/// https://drafts.csswg.org/css-grid/#propdef-grid-column
+/// <grid-line> [ / <grid-line> ]?
+pub fn parse_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	let grid_column_start = GridLine::parse(input)?;
+	let grid_column_end = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+		GridLine::parse(input)?
+	} else {
+		grid_column_start.omitted_counterpart()
+	};
+	Ok(Longhands {
+		grid_column_start,
+		grid_column_end,
+	})
+}
+
+/// Parse the given shorthand and fill the result into the
+/// `declarations` vector.
+pub fn parse_into<'i, 't>(
+	declarations: &mut SourcePropertyDeclaration,
+	context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<(), ParseError<'i>> {
+	input
+		.parse_entirely(|input| parse_value(context, input))
+		.map(|longhands| {
+			declarations.push(PropertyDeclaration::GridColumnStart(longhands.grid_column_start));
+			declarations.push(PropertyDeclaration::GridColumnEnd(longhands.grid_column_end));
+		})
+}
@@ -0,0 +1,42 @@
+use cssparser::Parser;
+
+use crate::parser::ParseError;
+use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration_block::SourcePropertyDeclaration;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::specified::grid::GridLine;
+
+pub struct Longhands {
+	pub grid_row_start: GridLine,
+	pub grid_row_end: GridLine,
+}
+
+/// https://drafts.csswg.org/css-grid/#propdef-grid-row
+/// <grid-line> [ / <grid-line> ]?
+pub fn parse_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	let grid_row_start = GridLine::parse(input)?;
+	let grid_row_end = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+		GridLine::parse(input)?
+	} else {
+		grid_row_start.omitted_counterpart()
+	};
+	Ok(Longhands {
+		grid_row_start,
+		grid_row_end,
+	})
+}
+
+/// Parse the given shorthand and fill the result into the
+/// `declarations` vector.
+pub fn parse_into<'i, 't>(
+	declarations: &mut SourcePropertyDeclaration,
+	context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<(), ParseError<'i>> {
+	input
+		.parse_entirely(|input| parse_value(context, input))
+		.map(|longhands| {
+			declarations.push(PropertyDeclaration::GridRowStart(longhands.grid_row_start));
+			declarations.push(PropertyDeclaration::GridRowEnd(longhands.grid_row_end));
+		})
+}
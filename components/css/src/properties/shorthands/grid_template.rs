@@ -0,0 +1,98 @@
+use cssparser::Parser;
+
+use crate::parser::ParseError;
+use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration_block::SourcePropertyDeclaration;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+use crate::stylesheets::stylesheet::ParserContext;
+use crate::values::generics::grid::{parse_line_names, GenericTrackListValue};
+use crate::values::specified::grid::{GridTemplateAreas, GridTemplateComponent, TemplateAreas, TrackList, TrackSize};
+
+pub struct Longhands {
+	pub grid_template_rows: GridTemplateComponent,
+	pub grid_template_columns: GridTemplateComponent,
+	pub grid_template_areas: GridTemplateAreas,
+}
+
+/// https://drafts.csswg.org/css-grid/#explicit-grid-shorthand
+/// none |
+/// [ <'grid-template-rows'> / <'grid-template-columns'> ] |
+/// [ <line-names>? <string> <track-size>? <line-names>? ]+ [ / <explicit-track-list> ]?
+pub fn parse_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Longhands, ParseError<'i>> {
+	if let Ok((grid_template_rows, grid_template_columns)) = input.try_parse(|input| -> Result<_, ParseError<'i>> {
+		let rows = GridTemplateComponent::parse(input)?;
+		input.expect_delim('/')?;
+		let columns = GridTemplateComponent::parse(input)?;
+		Ok((rows, columns))
+	}) {
+		return Ok(Longhands {
+			grid_template_rows,
+			grid_template_columns,
+			grid_template_areas: GridTemplateAreas::None,
+		});
+	}
+	if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+		return Ok(Longhands {
+			grid_template_rows: GridTemplateComponent::None,
+			grid_template_columns: GridTemplateComponent::None,
+			grid_template_areas: GridTemplateAreas::None,
+		});
+	}
+
+	let mut strings = vec![];
+	let mut values = vec![];
+	let mut line_names = vec![vec![]];
+	while let Ok((names, string)) = input.try_parse(|input| -> Result<_, ParseError<'i>> {
+		let names = input.try_parse(parse_line_names).unwrap_or_default();
+		let string = input.expect_string()?.as_ref().to_owned();
+		Ok((names, string))
+	}) {
+		// the trailing names of a row and the leading names of the next row name the same line
+		line_names.last_mut().unwrap().extend(names);
+		strings.push(string);
+		values.push(GenericTrackListValue::TrackSize(
+			input.try_parse(TrackSize::parse).unwrap_or_default(),
+		));
+		line_names.push(input.try_parse(parse_line_names).unwrap_or_default());
+	}
+	if strings.is_empty() {
+		return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+	}
+	let areas = TemplateAreas::from_strings(strings)
+		.map_err(|_| input.new_custom_error(StyleParseErrorKind::UnspecifiedError))?;
+	let grid_template_columns = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+		let columns = TrackList::parse(input)?;
+		// <explicit-track-list> doesn't accept repeat()
+		if columns
+			.values
+			.iter()
+			.any(|value| matches!(value, GenericTrackListValue::TrackRepeat(_)))
+		{
+			return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+		}
+		GridTemplateComponent::TrackList(Box::new(columns))
+	} else {
+		GridTemplateComponent::None
+	};
+	Ok(Longhands {
+		grid_template_rows: GridTemplateComponent::TrackList(Box::new(TrackList { values, line_names })),
+		grid_template_columns,
+		grid_template_areas: GridTemplateAreas::Areas(Box::new(areas)),
+	})
+}
+
+/// Parse the given shorthand and fill the result into the
+/// `declarations` vector.
+pub fn parse_into<'i, 't>(
+	declarations: &mut SourcePropertyDeclaration,
+	context: &ParserContext,
+	input: &mut Parser<'i, 't>,
+) -> Result<(), ParseError<'i>> {
+	input
+		.parse_entirely(|input| parse_value(context, input))
+		.map(|longhands| {
+			declarations.push(PropertyDeclaration::GridTemplateRows(longhands.grid_template_rows));
+			declarations.push(PropertyDeclaration::GridTemplateColumns(longhands.grid_template_columns));
+			declarations.push(PropertyDeclaration::GridTemplateAreas(longhands.grid_template_areas));
+		})
+}
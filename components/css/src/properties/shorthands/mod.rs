@@ -23,11 +23,18 @@ pub mod border_right;
 pub mod border_style;
 pub mod border_top;
 pub mod border_width;
+pub mod column_rule;
 pub mod columns;
 pub mod flex;
 pub mod flex_flow;
 pub mod font;
 pub mod font_variant;
+pub mod gap;
+pub mod grid;
+pub mod grid_area;
+pub mod grid_column;
+pub mod grid_row;
+pub mod grid_template;
 pub mod inset;
 pub mod inset_block;
 pub mod inset_inline;
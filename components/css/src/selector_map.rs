@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use selectors::context::QuirksMode;
+use selectors::parser::{Component, Selector};
+
+use crate::selectors::select::Selectors;
+use crate::stylist::Rule;
+use crate::values::Ident;
+use crate::LocalName;
+
+/// Rules bucketed by their rightmost compound selector, so an element is only matched against
+/// the rules which could apply to it.
+///
+/// A rule is put in the bucket of the id of its rightmost compound selector, otherwise a class,
+/// otherwise the local name. Rules without any of them (like `*` or `:hover`) are always tried.
+#[derive(Debug, Default)]
+pub struct SelectorMap {
+	id_hash: HashMap<Ident, Vec<Rule>>,
+	class_hash: HashMap<Ident, Vec<Rule>>,
+	local_name_hash: HashMap<LocalName, Vec<Rule>>,
+	other: Vec<Rule>,
+	count: usize,
+}
+
+enum Bucket {
+	Id(Ident),
+	Class(Ident),
+	LocalName(LocalName),
+	Universal,
+}
+
+impl SelectorMap {
+	pub fn insert(&mut self, rule: Rule, quirks_mode: QuirksMode) {
+		self.count += 1;
+		match find_bucket(&rule.selector) {
+			Bucket::Id(id) => self.id_hash.entry(key(&id.0, quirks_mode)).or_default().push(rule),
+			Bucket::Class(class) => self
+				.class_hash
+				.entry(key(&class.0, quirks_mode))
+				.or_default()
+				.push(rule),
+			Bucket::LocalName(local_name) => self.local_name_hash.entry(local_name).or_default().push(rule),
+			Bucket::Universal => self.other.push(rule),
+		}
+	}
+
+	/// Call `f` with every rule which may match an element with the given id, classes and local name.
+	pub fn for_each_candidate<F>(
+		&self,
+		id: Option<&str>,
+		classes: &[String],
+		local_name: &html5ever::LocalName,
+		quirks_mode: QuirksMode,
+		mut f: F,
+	) where
+		F: FnMut(&Rule),
+	{
+		if let Some(rules) = id.and_then(|id| self.id_hash.get(&key(id, quirks_mode))) {
+			rules.iter().for_each(&mut f);
+		}
+		for (index, class) in classes.iter().enumerate() {
+			// a class listed twice would add its rules twice
+			if classes[..index].contains(class) {
+				continue;
+			}
+			if let Some(rules) = self.class_hash.get(&key(class, quirks_mode)) {
+				rules.iter().for_each(&mut f);
+			}
+		}
+		if let Some(rules) = self.local_name_hash.get(local_name) {
+			rules.iter().for_each(&mut f);
+		}
+		self.other.iter().for_each(&mut f);
+	}
+
+	pub fn len(&self) -> usize {
+		self.count
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.count == 0
+	}
+
+	/// All rules in source order.
+	pub fn iter(&self) -> impl Iterator<Item = &Rule> {
+		let mut rules: Vec<&Rule> = self
+			.id_hash
+			.values()
+			.chain(self.class_hash.values())
+			.chain(self.local_name_hash.values())
+			.flatten()
+			.chain(self.other.iter())
+			.collect();
+		rules.sort_by_key(|rule| rule.source_order);
+		rules.into_iter()
+	}
+}
+
+fn find_bucket(selector: &Selector<Selectors>) -> Bucket {
+	let mut bucket = Bucket::Universal;
+	for component in selector.iter() {
+		match component {
+			Component::ID(id) => return Bucket::Id(id.clone()),
+			Component::Class(class) => {
+				if !matches!(bucket, Bucket::Class(_)) {
+					bucket = Bucket::Class(class.clone());
+				}
+			},
+			// a mixed case name only matches non html elements case sensitively
+			Component::LocalName(local_name) if local_name.name == local_name.lower_name => {
+				if matches!(bucket, Bucket::Universal) {
+					bucket = Bucket::LocalName(local_name.lower_name.clone());
+				}
+			},
+			_ => {},
+		}
+	}
+	bucket
+}
+
+/// Ids and classes are matched ASCII case-insensitively in quirks mode.
+fn key(name: &str, quirks_mode: QuirksMode) -> Ident {
+	if quirks_mode == QuirksMode::Quirks {
+		Ident(name.to_ascii_lowercase())
+	} else {
+		Ident::from(name)
+	}
+}
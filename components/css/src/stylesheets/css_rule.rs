@@ -1,6 +1,9 @@
 use core::fmt;
 
+use super::font_face_rule::FontFaceRule;
+use super::import_rule::ImportRule;
 use super::keyframe_rule::KeyframesRule;
+use super::layer_rule::{LayerBlockRule, LayerStatementRule};
 use super::media_rule::MediaRule;
 use super::namespace_rule::NamespaceRule;
 use super::page_rule::PageRule;
@@ -14,18 +17,24 @@ use crate::css_writer::ToCss;
 #[derive(Clone)]
 pub enum CssRule {
 	Namespace(NamespaceRule),
+	Import(ImportRule),
 	Style(StyleRule),
 	Media(MediaRule),
+	FontFace(FontFaceRule),
 	Keyframes(KeyframesRule),
 	Supports(SupportsRule),
 	Page(PageRule),
+	LayerBlock(LayerBlockRule),
+	LayerStatement(LayerStatementRule),
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CssRuleType {
 	// https://drafts.csswg.org/cssom/#the-cssrule-interface
 	Style = 1,
+	Import = 3,
 	Media = 4,
+	FontFace = 5,
 	Page = 6,
 	// https://drafts.csswg.org/css-animations-1/#interface-cssrule-idl
 	Keyframes = 7,
@@ -33,11 +42,15 @@ pub enum CssRuleType {
 	Namespace = 10,
 	// https://drafts.csswg.org/css-conditional-3/#extentions-to-cssrule-interface
 	Supports = 12,
+	// https://drafts.csswg.org/css-cascade-5/#extensions-to-cssrule-interface
+	LayerBlock = 16,
+	LayerStatement = 17,
 }
 
 impl CssRule {
 	pub fn rule_state(&self) -> State {
 		match *self {
+			CssRule::Import(..) => State::Imports,
 			CssRule::Namespace(..) => State::Namespaces,
 			_ => State::Body,
 		}
@@ -51,11 +64,15 @@ impl ToCss for CssRule {
 	{
 		match &self {
 			CssRule::Namespace(namespace) => namespace.to_css(dest),
+			CssRule::Import(import) => import.to_css(dest),
 			CssRule::Style(style) => style.to_css(dest),
 			CssRule::Media(media_rule) => media_rule.to_css(dest),
+			CssRule::FontFace(font_face) => font_face.to_css(dest),
 			CssRule::Keyframes(keyframes) => keyframes.to_css(dest),
 			CssRule::Supports(supports) => supports.to_css(dest),
 			CssRule::Page(page) => page.to_css(dest),
+			CssRule::LayerBlock(layer) => layer.to_css(dest),
+			CssRule::LayerStatement(layer) => layer.to_css(dest),
 		}
 	}
 }
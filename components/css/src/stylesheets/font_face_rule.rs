@@ -0,0 +1,351 @@
+use std::fmt::Write;
+
+use cssparser::{
+	_cssparser_internal_to_lowercase, match_ignore_ascii_case, AtRuleParser, CowRcStr, DeclarationListParser,
+	DeclarationParser, Parser, SourceLocation, ToCss, Token, UnicodeRange,
+};
+
+use super::rule_parser::StyleParseErrorKind;
+use super::stylesheet::ParserContext;
+use crate::css_writer::CssWriter;
+use crate::error_reporting::ContextualParseError;
+use crate::parser::ParseError;
+use crate::properties::longhands::font_family::FamilyName;
+use crate::values::specified::angle::Angle;
+use crate::values::specified::number::Number;
+use crate::values::url::CssUrl;
+use crate::values::CSSFloat;
+
+/// A [`@font-face`][ff] rule.
+///
+/// [ff]: https://drafts.csswg.org/css-fonts/#at-font-face-rule
+#[derive(Clone, Debug)]
+pub struct FontFaceRule {
+	/// The name used by `font-family` to refer to this face.
+	pub family: Option<FamilyName>,
+	/// The sources to load the face from, in order of preference.
+	pub sources: Option<Vec<Source>>,
+	pub weight: Option<FontWeightRange>,
+	pub style: Option<FontFaceStyle>,
+	/// The characters this face is used for, all characters if there is none.
+	pub unicode_range: Option<Vec<UnicodeRange>>,
+	pub display: Option<FontDisplay>,
+	/// The line and column of the rule's source code.
+	pub source_location: SourceLocation,
+}
+
+impl FontFaceRule {
+	/// A face without family or sources can't be used, so it is ignored.
+	pub fn is_valid(&self) -> bool {
+		self.family.is_some() && self.sources.as_ref().map_or(false, |sources| !sources.is_empty())
+	}
+
+	/// Whether `ch` is covered by `unicode-range`.
+	pub fn contains_char(&self, ch: char) -> bool {
+		match &self.unicode_range {
+			Some(ranges) => ranges
+				.iter()
+				.any(|range| range.start <= ch as u32 && ch as u32 <= range.end),
+			None => true,
+		}
+	}
+}
+
+impl crate::css_writer::ToCss for FontFaceRule {
+	fn to_css<W>(&self, dest: &mut CssWriter<W>) -> core::fmt::Result
+	where
+		W: Write,
+	{
+		dest.write_str("@font-face {\n")?;
+		if let Some(family) = &self.family {
+			dest.write_fmt(format_args!("\tfont-family: {};\n", family.to_css_string()))?;
+		}
+		if let Some(sources) = &self.sources {
+			let sources: Vec<String> = sources.iter().map(|source| source.to_css_string()).collect();
+			dest.write_fmt(format_args!("\tsrc: {};\n", sources.join(", ")))?;
+		}
+		if let Some(weight) = &self.weight {
+			dest.write_fmt(format_args!("\tfont-weight: {};\n", weight.to_css_string()))?;
+		}
+		if let Some(style) = &self.style {
+			dest.write_fmt(format_args!("\tfont-style: {};\n", style.to_css_string()))?;
+		}
+		if let Some(ranges) = &self.unicode_range {
+			let ranges: Vec<String> = ranges.iter().map(|range| range.to_css_string()).collect();
+			dest.write_fmt(format_args!("\tunicode-range: {};\n", ranges.join(", ")))?;
+		}
+		if let Some(display) = &self.display {
+			dest.write_fmt(format_args!("\tfont-display: {};\n", display.to_css_string()))?;
+		}
+		dest.write_str("}")
+	}
+}
+
+/// A single entry of `src`.
+///
+/// https://drafts.csswg.org/css-fonts/#src-desc
+#[derive(Clone, Debug)]
+pub enum Source {
+	Url(UrlSource),
+	/// A font installed on the system, found by its full or postscript name.
+	Local(FamilyName),
+}
+
+impl Source {
+	/// Relative urls are resolved against the url of the stylesheet.
+	pub fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		if input.try_parse(|input| input.expect_function_matching("local")).is_ok() {
+			let name = input.parse_nested_block(FamilyName::parse)?;
+			return Ok(Source::Local(name));
+		}
+		let base_url = context.base_url(input);
+		let url = CssUrl::parse_with_base(input, base_url.as_ref())?;
+		let format_hints = input
+			.try_parse(|input| -> Result<Vec<String>, ParseError<'i>> {
+				input.expect_function_matching("format")?;
+				input.parse_nested_block(|input| {
+					input.parse_comma_separated(|input| {
+						let location = input.current_source_location();
+						match input.next()? {
+							Token::QuotedString(value) | Token::Ident(value) => Ok(value.to_string()),
+							token => Err(location.new_unexpected_token_error(token.clone())),
+						}
+					})
+				})
+			})
+			.unwrap_or_default();
+		Ok(Source::Url(UrlSource { url, format_hints }))
+	}
+}
+
+impl ToCss for Source {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: Write,
+	{
+		match self {
+			Source::Url(source) => {
+				source.url.to_css(dest)?;
+				if !source.format_hints.is_empty() {
+					let hints: Vec<String> = source.format_hints.iter().map(|hint| format!("\"{}\"", hint)).collect();
+					dest.write_fmt(format_args!(" format({})", hints.join(", ")))?;
+				}
+				Ok(())
+			},
+			Source::Local(name) => {
+				dest.write_str("local(")?;
+				name.to_css(dest)?;
+				dest.write_char(')')
+			},
+		}
+	}
+}
+
+/// `<url> [ format(<string>#) ]?`
+#[derive(Clone, Debug)]
+pub struct UrlSource {
+	pub url: CssUrl,
+	/// The formats the font may be in, a source with only unsupported formats is skipped without loading.
+	pub format_hints: Vec<String>,
+}
+
+/// `font-weight: <font-weight-absolute>{1,2}`, keywords are converted to numbers.
+///
+/// https://drafts.csswg.org/css-fonts-4/#font-prop-desc
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontWeightRange(pub CSSFloat, pub CSSFloat);
+
+impl FontWeightRange {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let start = Self::parse_absolute(input)?;
+		let end = input.try_parse(Self::parse_absolute).unwrap_or(start);
+		// https://drafts.csswg.org/css-fonts-4/#font-prop-desc, a reversed range is swapped
+		Ok(FontWeightRange(start.min(end), start.max(end)))
+	}
+
+	fn parse_absolute<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CSSFloat, ParseError<'i>> {
+		if let Ok(weight) = input.try_parse(|input| {
+			let location = input.current_source_location();
+			let ident = input.expect_ident()?;
+			Ok(match_ignore_ascii_case! { ident,
+				"normal" => 400.0,
+				"bold" => 700.0,
+				_ => return Err(location.new_custom_error(StyleParseErrorKind::UnexpectedValue(ident.clone()))),
+			})
+		}) {
+			return Ok(weight);
+		}
+		Ok(Number::parse_in_range(input, 1.0, 1000.0)?.get())
+	}
+
+	pub fn contains(&self, weight: CSSFloat) -> bool {
+		self.0 <= weight && weight <= self.1
+	}
+}
+
+impl ToCss for FontWeightRange {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: Write,
+	{
+		if self.0 == self.1 {
+			dest.write_fmt(format_args!("{}", self.0))
+		} else {
+			dest.write_fmt(format_args!("{} {}", self.0, self.1))
+		}
+	}
+}
+
+/// `font-style: normal | italic | oblique <angle>{0,2}`
+#[derive(Clone, Debug)]
+pub enum FontFaceStyle {
+	Normal,
+	Italic,
+	Oblique(Angle, Angle),
+}
+
+impl FontFaceStyle {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let location = input.current_source_location();
+		let ident = input.expect_ident()?.clone();
+		Ok(match_ignore_ascii_case! { &ident,
+			"normal" => FontFaceStyle::Normal,
+			"italic" => FontFaceStyle::Italic,
+			"oblique" => {
+				let start = input.try_parse(Angle::parse).unwrap_or_else(|_| "14deg".into());
+				let end = input.try_parse(Angle::parse).unwrap_or_else(|_| start.clone());
+				FontFaceStyle::Oblique(start, end)
+			},
+			_ => return Err(location.new_custom_error(StyleParseErrorKind::UnexpectedValue(ident.clone()))),
+		})
+	}
+}
+
+impl ToCss for FontFaceStyle {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: Write,
+	{
+		match self {
+			FontFaceStyle::Normal => dest.write_str("normal"),
+			FontFaceStyle::Italic => dest.write_str("italic"),
+			FontFaceStyle::Oblique(start, end) => {
+				dest.write_str("oblique ")?;
+				start.to_css(dest)?;
+				if start != end {
+					dest.write_char(' ')?;
+					end.to_css(dest)?;
+				}
+				Ok(())
+			},
+		}
+	}
+}
+
+/// https://drafts.csswg.org/css-fonts-4/#font-display-desc
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FontDisplay {
+	Auto,
+	Block,
+	Swap,
+	Fallback,
+	Optional,
+}
+
+impl FontDisplay {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let location = input.current_source_location();
+		let ident = input.expect_ident()?;
+		Ok(match_ignore_ascii_case! { ident,
+			"auto" => FontDisplay::Auto,
+			"block" => FontDisplay::Block,
+			"swap" => FontDisplay::Swap,
+			"fallback" => FontDisplay::Fallback,
+			"optional" => FontDisplay::Optional,
+			_ => return Err(location.new_custom_error(StyleParseErrorKind::UnexpectedValue(ident.clone()))),
+		})
+	}
+}
+
+impl ToCss for FontDisplay {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: Write,
+	{
+		dest.write_str(match self {
+			FontDisplay::Auto => "auto",
+			FontDisplay::Block => "block",
+			FontDisplay::Swap => "swap",
+			FontDisplay::Fallback => "fallback",
+			FontDisplay::Optional => "optional",
+		})
+	}
+}
+
+struct FontFaceRuleParser<'a, 'b: 'a> {
+	context: &'a ParserContext<'b>,
+	rule: &'a mut FontFaceRule,
+}
+
+/// Default methods reject all at rules.
+impl<'a, 'b, 'i> AtRuleParser<'i> for FontFaceRuleParser<'a, 'b> {
+	type AtRule = ();
+	type Error = StyleParseErrorKind<'i>;
+	type PreludeBlock = ();
+	type PreludeNoBlock = ();
+}
+
+impl<'a, 'b, 'i> DeclarationParser<'i> for FontFaceRuleParser<'a, 'b> {
+	type Declaration = ();
+	type Error = StyleParseErrorKind<'i>;
+
+	fn parse_value<'t>(&mut self, name: CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i>> {
+		match_ignore_ascii_case! { &*name,
+			"font-family" => self.rule.family = Some(FamilyName::parse(input)?),
+			"src" => {
+				let context = self.context;
+				self.rule.sources = Some(input.parse_comma_separated(|input| Source::parse(context, input))?)
+			},
+			"font-weight" => self.rule.weight = Some(FontWeightRange::parse(input)?),
+			"font-style" => self.rule.style = Some(FontFaceStyle::parse(input)?),
+			"unicode-range" => {
+				let ranges = input.parse_comma_separated(|input| -> Result<UnicodeRange, ParseError<'i>> {
+					Ok(UnicodeRange::parse(input)?)
+				})?;
+				self.rule.unicode_range = Some(ranges)
+			},
+			"font-display" => self.rule.display = Some(FontDisplay::parse(input)?),
+			_ => return Err(input.new_custom_error(StyleParseErrorKind::UnknownProperty(name.clone()))),
+		}
+		input.expect_exhausted()?;
+		Ok(())
+	}
+}
+
+/// Parse the block of a @font-face rule, invalid descriptors are reported and ignored.
+pub fn parse_font_face_block(context: &ParserContext, input: &mut Parser, location: SourceLocation) -> FontFaceRule {
+	let mut rule = FontFaceRule {
+		family: None,
+		sources: None,
+		weight: None,
+		style: None,
+		unicode_range: None,
+		display: None,
+		source_location: location,
+	};
+	{
+		let parser = FontFaceRuleParser {
+			context,
+			rule: &mut rule,
+		};
+		let mut iter = DeclarationListParser::new(input, parser);
+		while let Some(declaration) = iter.next() {
+			if let Err((error, slice)) = declaration {
+				let location = error.location;
+				let error = ContextualParseError::UnsupportedFontFaceDescriptor(slice, error);
+				iter.parser.context.log_css_error(location, error);
+			}
+		}
+	}
+	rule
+}
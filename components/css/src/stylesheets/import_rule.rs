@@ -0,0 +1,122 @@
+use std::fmt::Write;
+use std::rc::Rc;
+
+use cssparser::{Parser, SourceLocation};
+
+use super::css_rule::CssRuleType;
+use super::layer_rule::LayerName;
+use super::stylesheet::{Namespaces, ParserContext, Stylesheet};
+use super::support_rule::{Declaration, SupportsCondition};
+use crate::css_writer::{CssWriter, ToCss};
+use crate::media_queries::media_list::MediaList;
+use crate::parser::ParseError;
+use crate::values::url::CssUrl;
+
+/// An [`@import`][import] rule.
+///
+/// [import]: https://drafts.csswg.org/css-cascade-5/#at-import
+#[derive(Clone)]
+pub struct ImportRule {
+	/// The url of the imported stylesheet.
+	pub url: CssUrl,
+	/// The media list the imported stylesheet applies to.
+	pub media: Rc<MediaList>,
+	/// The cascade layer the imported stylesheet is put into.
+	pub layer: Option<ImportLayer>,
+	/// The `supports()` condition, the stylesheet isn't loaded when it doesn't hold.
+	pub supports: Option<ImportSupportsCondition>,
+	/// The imported stylesheet, `None` if there is no loader or it failed to load.
+	pub stylesheet: Option<Rc<Stylesheet>>,
+	/// The line and column of the rule's source code.
+	pub source_location: SourceLocation,
+}
+
+impl ToCss for ImportRule {
+	fn to_css<W>(&self, dest: &mut CssWriter<W>) -> core::fmt::Result
+	where
+		W: Write,
+	{
+		dest.write_str("@import ")?;
+		dest.write_str(&cssparser::ToCss::to_css_string(&self.url))?;
+		if let Some(layer) = &self.layer {
+			dest.write_char(' ')?;
+			layer.to_css(dest)?;
+		}
+		if let Some(supports) = &self.supports {
+			dest.write_char(' ')?;
+			supports.to_css(dest)?;
+		}
+		if !self.media.is_empty() {
+			dest.write_char(' ')?;
+			self.media.to_css(dest)?;
+		}
+		dest.write_char(';')
+	}
+}
+
+/// `layer` or `layer(<layer-name>)`, an anonymous layer is represented by `None`.
+#[derive(Clone, Debug)]
+pub struct ImportLayer(pub Option<LayerName>);
+
+impl ImportLayer {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		if input.try_parse(|input| input.expect_ident_matching("layer")).is_ok() {
+			return Ok(ImportLayer(None));
+		}
+		input.expect_function_matching("layer")?;
+		let name = input.parse_nested_block(LayerName::parse)?;
+		Ok(ImportLayer(Some(name)))
+	}
+}
+
+impl ToCss for ImportLayer {
+	fn to_css<W>(&self, dest: &mut CssWriter<W>) -> core::fmt::Result
+	where
+		W: Write,
+	{
+		match &self.0 {
+			Some(name) => dest.write_fmt(format_args!("layer({})", cssparser::ToCss::to_css_string(name))),
+			None => dest.write_str("layer"),
+		}
+	}
+}
+
+/// `supports( [ <supports-condition> | <declaration> ] )`
+#[derive(Clone, Debug)]
+pub struct ImportSupportsCondition {
+	pub condition: SupportsCondition,
+	/// The result of evaluating the condition
+	pub enabled: bool,
+}
+
+impl ImportSupportsCondition {
+	pub fn parse<'i, 't>(
+		context: &ParserContext,
+		namespaces: &Namespaces,
+		input: &mut Parser<'i, 't>,
+	) -> Result<Self, ParseError<'i>> {
+		input.expect_function_matching("supports")?;
+		let condition = input.parse_nested_block(|input| {
+			input
+				.try_parse(SupportsCondition::parse)
+				.or_else(|_err: ParseError<'i>| Declaration::parse(input).map(SupportsCondition::Declaration))
+		})?;
+		let eval_context = ParserContext::new_with_rule_type(context, CssRuleType::Style, namespaces);
+		let enabled = condition.eval(&eval_context, namespaces);
+		Ok(ImportSupportsCondition { condition, enabled })
+	}
+}
+
+impl ToCss for ImportSupportsCondition {
+	fn to_css<W>(&self, dest: &mut CssWriter<W>) -> core::fmt::Result
+	where
+		W: Write,
+	{
+		dest.write_str("supports(")?;
+		match &self.condition {
+			SupportsCondition::Declaration(declaration) => dest.write_str(&declaration.0)?,
+			condition => condition.to_css(dest)?,
+		};
+		dest.write_char(')')
+	}
+}
@@ -0,0 +1,147 @@
+use std::fmt::Write;
+
+use cssparser::{
+	_cssparser_internal_to_lowercase, match_ignore_ascii_case, CowRcStr, Parser, ParserInput, SourceLocation, ToCss,
+	Token,
+};
+
+use super::css_rule::CssRule;
+use crate::css_writer::CssWriter;
+use crate::parser::ParseError;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+use crate::values::Ident;
+
+/// A `@layer` rule with a block, the layer is anonymous when there is no name.
+///
+/// <https://drafts.csswg.org/css-cascade-5/#layer-block>
+#[derive(Clone)]
+pub struct LayerBlockRule {
+	pub name: Option<LayerName>,
+	/// The nested rules, they are put into the layer.
+	pub rules: Vec<CssRule>,
+	/// The line and column of the rule's source code.
+	pub source_location: SourceLocation,
+}
+
+impl crate::css_writer::ToCss for LayerBlockRule {
+	fn to_css<W>(&self, dest: &mut CssWriter<W>) -> core::fmt::Result
+	where
+		W: Write,
+	{
+		dest.write_str("@layer ")?;
+		if let Some(name) = &self.name {
+			name.to_css(dest)?;
+			dest.write_char(' ')?;
+		}
+		dest.write_str("{\n")?;
+		for rule in self.rules.iter() {
+			for line in crate::css_writer::ToCss::to_css_string(rule).lines() {
+				dest.write_fmt(format_args!("\t{}\n", line))?;
+			}
+		}
+		dest.write_str("}")
+	}
+}
+
+/// A `@layer <layer-name>#;` rule, which declares layers to establish their order.
+///
+/// <https://drafts.csswg.org/css-cascade-5/#layer-empty>
+#[derive(Clone)]
+pub struct LayerStatementRule {
+	pub names: Vec<LayerName>,
+	/// The line and column of the rule's source code.
+	pub source_location: SourceLocation,
+}
+
+impl LayerStatementRule {
+	/// Parse the source of a rule which has been rejected as a `@layer <layer-name>;` statement.
+	///
+	/// The prelude of an at-rule has to tell whether a block follows before the parser reaches
+	/// the `{` or the `;`, so `@layer <layer-name>` is parsed as a block rule, and cssparser
+	/// rejects the statement once it reaches its semicolon (or the end of the stylesheet). A
+	/// statement with several names is told apart by its prelude and isn't rejected.
+	pub fn parse_rejected(slice: &str, location: SourceLocation) -> Option<Self> {
+		let mut input = ParserInput::new(slice);
+		let mut input = Parser::new(&mut input);
+		match input.next() {
+			Ok(Token::AtKeyword(name)) if name.eq_ignore_ascii_case("layer") => {},
+			_ => return None,
+		}
+		let name = LayerName::parse(&mut input).ok()?;
+		let _ = input.try_parse(|input| input.expect_semicolon());
+		input.expect_exhausted().ok()?;
+		Some(LayerStatementRule {
+			names: vec![name],
+			source_location: location,
+		})
+	}
+}
+
+impl crate::css_writer::ToCss for LayerStatementRule {
+	fn to_css<W>(&self, dest: &mut CssWriter<W>) -> core::fmt::Result
+	where
+		W: Write,
+	{
+		let names: Vec<String> = self.names.iter().map(|name| name.to_css_string()).collect();
+		dest.write_fmt(format_args!("@layer {};", names.join(", ")))
+	}
+}
+
+/// A cascade layer name, dot-separated for nested layers.
+///
+/// <https://drafts.csswg.org/css-cascade-5/#typedef-layer-name>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LayerName(pub Vec<Ident>);
+
+impl LayerName {
+	/// <layer-name> = <ident> [ '.' <ident> ]*
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let location = input.current_source_location();
+		let ident = input.expect_ident_cloned()?;
+		let mut names = vec![LayerName::to_ident(ident, location)?];
+		loop {
+			// no whitespace is allowed around the dots
+			let result = input.try_parse(|input| -> Result<Ident, ParseError<'i>> {
+				let location = input.current_source_location();
+				match input.next_including_whitespace()? {
+					Token::Delim('.') => {},
+					token => return Err(location.new_unexpected_token_error(token.clone())),
+				}
+				let location = input.current_source_location();
+				match input.next_including_whitespace()? {
+					Token::Ident(ident) => LayerName::to_ident(ident.clone(), location),
+					token => Err(location.new_unexpected_token_error(token.clone())),
+				}
+			});
+			match result {
+				Ok(name) => names.push(name),
+				Err(_) => break,
+			}
+		}
+		Ok(LayerName(names))
+	}
+
+	fn to_ident<'i>(ident: CowRcStr<'i>, location: SourceLocation) -> Result<Ident, ParseError<'i>> {
+		match_ignore_ascii_case! { &ident,
+			"initial" | "inherit" | "unset" | "revert" | "revert-layer" | "default" => {
+				Err(location.new_custom_error(StyleParseErrorKind::UnexpectedValue(ident.clone())))
+			},
+			_ => Ok(Ident::from(ident.as_ref())),
+		}
+	}
+}
+
+impl ToCss for LayerName {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: Write,
+	{
+		for (index, name) in self.0.iter().enumerate() {
+			if index != 0 {
+				dest.write_char('.')?;
+			}
+			name.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
@@ -1,5 +1,8 @@
 pub mod css_rule;
+pub mod font_face_rule;
+pub mod import_rule;
 pub mod keyframe_rule;
+pub mod layer_rule;
 pub mod media_rule;
 pub mod namespace_rule;
 pub mod origin;
@@ -7,4 +10,5 @@ pub mod page_rule;
 pub mod rule_parser;
 pub mod style_rule;
 pub mod stylesheet;
+pub mod stylesheet_loader;
 pub mod support_rule;
@@ -7,6 +7,44 @@ pub enum Origin {
 	/// <https://drafts.csswg.org/css-cascade/#cascade-origin-user-agent>
 	UserAgent = 0x1,
 
+	/// <https://drafts.csswg.org/css-cascade/#cascade-origin-user>
+	User = 0x2,
+
 	/// <https://drafts.csswg.org/css-cascade/#cascade-origin-author>
-	Author = 0x2,
+	Author = 0x4,
+}
+
+/// The origin and importance of a declaration, in increasing order of precedence.
+/// Important declarations reverse the order of origins.
+///
+/// <https://drafts.csswg.org/css-cascade-4/#cascade-origin>
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CascadeLevel {
+	UserAgentNormal,
+	UserNormal,
+	AuthorNormal,
+	AuthorImportant,
+	UserImportant,
+	UserAgentImportant,
+}
+
+impl CascadeLevel {
+	pub fn new(origin: Origin, importance: bool) -> Self {
+		match (origin, importance) {
+			(Origin::UserAgent, false) => CascadeLevel::UserAgentNormal,
+			(Origin::User, false) => CascadeLevel::UserNormal,
+			(Origin::Author, false) => CascadeLevel::AuthorNormal,
+			(Origin::Author, true) => CascadeLevel::AuthorImportant,
+			(Origin::User, true) => CascadeLevel::UserImportant,
+			(Origin::UserAgent, true) => CascadeLevel::UserAgentImportant,
+		}
+	}
+
+	pub fn origin(&self) -> Origin {
+		match self {
+			CascadeLevel::UserAgentNormal | CascadeLevel::UserAgentImportant => Origin::UserAgent,
+			CascadeLevel::UserNormal | CascadeLevel::UserImportant => Origin::User,
+			CascadeLevel::AuthorNormal | CascadeLevel::AuthorImportant => Origin::Author,
+		}
+	}
 }
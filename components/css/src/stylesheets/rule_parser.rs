@@ -1,18 +1,22 @@
-use common::not_supported;
+use common::{not_reached, not_supported};
 use cssparser::{
-	match_ignore_ascii_case, AtRuleParser, AtRuleType, CowRcStr, Parser, ParserState, QualifiedRuleParser,
-	RuleListParser, SourcePosition, Token, _cssparser_internal_to_lowercase,
+	_cssparser_internal_to_lowercase, match_ignore_ascii_case, AtRuleParser, AtRuleType, CowRcStr, Parser, ParserState,
+	QualifiedRuleParser, RuleListParser, SourcePosition, Token,
 };
 use html5ever::Prefix;
 use selectors::parser::SelectorParseErrorKind;
 use selectors::SelectorList;
 
 use super::css_rule::{CssRule, CssRuleType};
+use super::font_face_rule::parse_font_face_block;
+use super::import_rule::{ImportLayer, ImportRule, ImportSupportsCondition};
 use super::keyframe_rule::KeyframesRule;
+use super::layer_rule::{LayerBlockRule, LayerName, LayerStatementRule};
 use super::media_rule::MediaRule;
 use super::namespace_rule::{NamespaceRule, NamespaceValue};
 use super::style_rule::StyleRule;
-use super::stylesheet::{Namespaces, ParserContext};
+use super::stylesheet::{Namespaces, ParserContext, Stylesheet};
+use super::stylesheet_loader::ImportContext;
 use super::support_rule::{SupportsCondition, SupportsRule};
 use crate::error_reporting::ContextualParseError;
 use crate::media_queries::media_list::MediaList;
@@ -42,20 +46,26 @@ pub enum AtRuleBlockPrelude {
 	Media(MediaList),
 	/// An @supports rule, with its conditional
 	Supports(SupportsCondition),
+	/// A @font-face rule prelude.
+	FontFace,
 	/// A @viewport rule prelude.
 	Viewport,
 	/// A @keyframes rule, with its animation name and vendor prefix if exists.
 	Keyframes(KeyframesName, Option<VendorPrefix>),
 	/// A @page rule prelude.
 	Page,
+	/// A @layer rule prelude, with at most one layer name.
+	Layer(Vec<LayerName>),
 }
 
 /// A rule prelude for at-rule without block.
 pub enum AtRuleNonBlockPrelude {
 	/// A @import rule prelude.
-	Import(CssUrl, MediaList),
+	Import(CssUrl, MediaList, Option<ImportLayer>, Option<ImportSupportsCondition>),
 	/// A @namespace rule prelude.
 	Namespace(Option<Prefix>, NamespaceValue),
+	/// A @layer statement prelude, with its layer names.
+	Layer(Vec<LayerName>),
 }
 
 /// The current state of the parser.
@@ -85,6 +95,8 @@ pub struct TopLevelRuleParser<'a> {
 	pub dom_error: Option<RulesMutateError>,
 	pub namespaces: &'a mut Namespaces,
 	pub insert_rule_context: Option<InsertRuleContext<'a>>,
+	/// How to load the stylesheets of @import rules, they are kept unloaded if there is none.
+	pub import_context: Option<&'a ImportContext<'a>>,
 }
 
 impl<'b> TopLevelRuleParser<'b> {
@@ -100,6 +112,45 @@ impl<'b> TopLevelRuleParser<'b> {
 		self.state
 	}
 
+	/// Load the stylesheet of an @import rule, failures and cycles are reported and leave the rule
+	/// without stylesheet.
+	fn load_import(&self, url: &CssUrl, media: Rc<MediaList>, location: SourceLocation) -> Option<Rc<Stylesheet>> {
+		let import_context = self.import_context?;
+		let resolved = match url.url() {
+			Some(resolved) => resolved,
+			None => {
+				let original = url.to_css_string();
+				let error = ContextualParseError::UnloadableImport(&original, "invalid url".to_string());
+				self.context.log_css_error(location, error);
+				return None;
+			},
+		};
+		if import_context.is_cyclic(resolved) {
+			self.context
+				.log_css_error(location, ContextualParseError::CyclicImport(resolved.as_str()));
+			return None;
+		}
+		match import_context.loader.load(resolved) {
+			Ok(css) => {
+				let nested_context = import_context.nested(resolved.clone());
+				Some(Rc::new(Stylesheet::from_str_with_loader(
+					&css,
+					self.context.stylesheet_origin,
+					media,
+					Some(&nested_context),
+					self.context.error_reporter,
+					self.context.quirks_mode,
+					0,
+				)))
+			},
+			Err(reason) => {
+				let error = ContextualParseError::UnloadableImport(resolved.as_str(), reason);
+				self.context.log_css_error(location, error);
+				None
+			},
+		}
+	}
+
 	/// Checks whether we can parse a rule that would transition us to
 	/// `new_state`.
 	///
@@ -154,6 +205,21 @@ impl<'a, 'i> AtRuleParser<'i> for TopLevelRuleParser<'a> {
 		input: &mut Parser<'i, 't>,
 	) -> Result<AtRuleType<AtRuleNonBlockPrelude, AtRuleBlockPrelude>, ParseError<'i>> {
 		match_ignore_ascii_case! { &*name,
+			"import" => {
+				if !self.check_state(State::Imports) {
+					return Err(input.new_custom_error(StyleParseErrorKind::UnexpectedImportRule))
+				}
+
+				let base_url = self.context.base_url(input);
+				let url = CssUrl::parse_import(input, base_url.as_ref())?;
+				let layer = input.try_parse(ImportLayer::parse).ok();
+				let supports = input
+					.try_parse(|input| ImportSupportsCondition::parse(&self.context, self.namespaces, input))
+					.ok();
+				let media = MediaList::parse(&self.context, input);
+				let prelude = AtRuleNonBlockPrelude::Import(url, media, layer, supports);
+				return Ok(AtRuleType::WithoutBlock(prelude));
+			},
 			"namespace" => {
 				if !self.check_state(State::Namespaces) {
 					return Err(input.new_custom_error(StyleParseErrorKind::UnexpectedNamespaceRule))
@@ -184,6 +250,22 @@ impl<'a, 'i> AtRuleParser<'i> for TopLevelRuleParser<'a> {
 	#[inline]
 	fn rule_without_block(&mut self, prelude: AtRuleNonBlockPrelude, start: &ParserState) -> Self::AtRule {
 		let rule = match prelude {
+			AtRuleNonBlockPrelude::Import(url, media, layer, supports) => {
+				self.state = State::Imports;
+				let media = Rc::new(media);
+				let stylesheet = match supports {
+					Some(ref supports) if !supports.enabled => None,
+					_ => self.load_import(&url, media.clone(), start.source_location()),
+				};
+				CssRule::Import(ImportRule {
+					url,
+					media,
+					layer,
+					supports,
+					stylesheet,
+					source_location: start.source_location(),
+				})
+			},
 			AtRuleNonBlockPrelude::Namespace(prefix, url) => {
 				self.state = State::Namespaces;
 				CssRule::Namespace(NamespaceRule {
@@ -192,7 +274,10 @@ impl<'a, 'i> AtRuleParser<'i> for TopLevelRuleParser<'a> {
 					source_location: start.source_location(),
 				})
 			},
-			_ => not_supported!(),
+			// layer statements are allowed before @import, so the state is kept
+			prelude @ AtRuleNonBlockPrelude::Layer(_) => {
+				AtRuleParser::rule_without_block(&mut self.nested(), prelude, start)
+			},
 		};
 
 		(start.position(), rule)
@@ -221,6 +306,17 @@ impl<'a, 'i> QualifiedRuleParser<'i> for TopLevelRuleParser<'a> {
 	}
 }
 
+/// A rule which failed to parse is a `@layer <layer-name>;` statement or is reported, see
+/// `LayerStatementRule::parse_rejected`.
+pub fn recover_invalid_rule<'i>(context: &ParserContext, error: ParseError<'i>, slice: &'i str) -> Option<CssRule> {
+	let location = error.location;
+	if let Some(rule) = LayerStatementRule::parse_rejected(slice, location) {
+		return Some(CssRule::LayerStatement(rule));
+	}
+	context.log_css_error(location, ContextualParseError::InvalidRule(slice, error));
+	None
+}
+
 #[derive(Clone)] // shallow, relatively cheap .clone
 struct NestedRuleParser<'a, 'b: 'a> {
 	context: &'a ParserContext<'b>,
@@ -241,11 +337,7 @@ impl<'a, 'b> NestedRuleParser<'a, 'b> {
 		while let Some(result) = iter.next() {
 			match result {
 				Ok(rule) => rules.push(rule),
-				Err((error, slice)) => {
-					let location = error.location;
-					let error = ContextualParseError::InvalidRule(slice, error);
-					self.context.log_css_error(location, error);
-				},
+				Err((error, slice)) => rules.extend(recover_invalid_rule(self.context, error, slice)),
 			}
 		}
 		rules
@@ -272,6 +364,9 @@ impl<'a, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'b> {
 				let cond = SupportsCondition::parse(input)?;
 				Ok(AtRuleType::WithBlock(AtRuleBlockPrelude::Supports(cond)))
 			},
+			"font-face" => {
+				Ok(AtRuleType::WithBlock(AtRuleBlockPrelude::FontFace))
+			},
 			"keyframes" | "-webkit-keyframes" | "-moz-keyframes" => {
 				let prefix = if starts_with_ignore_ascii_case(&*name, "-webkit-") {
 					Some(VendorPrefix::WebKit)
@@ -286,6 +381,16 @@ impl<'a, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'b> {
 			"page" => {
 				Ok(AtRuleType::WithBlock(AtRuleBlockPrelude::Page))
 			},
+			"layer" => {
+				let names = input
+					.try_parse(|input| input.parse_comma_separated(LayerName::parse))
+					.unwrap_or_default();
+				if names.len() > 1 {
+					Ok(AtRuleType::WithoutBlock(AtRuleNonBlockPrelude::Layer(names)))
+				} else {
+					Ok(AtRuleType::WithBlock(AtRuleBlockPrelude::Layer(names)))
+				}
+			},
 			_ => Err(input.new_custom_error(StyleParseErrorKind::UnsupportedAtRule(name.clone())))
 		}
 	}
@@ -302,11 +407,26 @@ impl<'a, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'b> {
 				rules: self.parse_nested_rules(input, CssRuleType::Media),
 				source_location: start.source_location(),
 			})),
-			AtRuleBlockPrelude::Supports(condition) => Ok(CssRule::Supports(SupportsRule {
-				condition,
-				rules: self.parse_nested_rules(input, CssRuleType::Supports),
-				source_location: start.source_location(),
-			})),
+			AtRuleBlockPrelude::Supports(condition) => {
+				let eval_context = ParserContext::new_with_rule_type(self.context, CssRuleType::Style, self.namespaces);
+				let enabled = condition.eval(&eval_context, self.namespaces);
+
+				Ok(CssRule::Supports(SupportsRule {
+					condition,
+					rules: self.parse_nested_rules(input, CssRuleType::Supports),
+					enabled,
+					source_location: start.source_location(),
+				}))
+			},
+			AtRuleBlockPrelude::FontFace => {
+				let context = ParserContext::new_with_rule_type(self.context, CssRuleType::FontFace, self.namespaces);
+
+				Ok(CssRule::FontFace(parse_font_face_block(
+					&context,
+					input,
+					start.source_location(),
+				)))
+			},
 			AtRuleBlockPrelude::Keyframes(name, vendor_prefix) => {
 				let context = ParserContext::new_with_rule_type(self.context, CssRuleType::Keyframes, self.namespaces);
 
@@ -326,9 +446,24 @@ impl<'a, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'b> {
 					source_location: start.source_location(),
 				}))
 			},
+			AtRuleBlockPrelude::Layer(names) => Ok(CssRule::LayerBlock(LayerBlockRule {
+				name: names.into_iter().next(),
+				rules: self.parse_nested_rules(input, CssRuleType::LayerBlock),
+				source_location: start.source_location(),
+			})),
 			_ => not_supported!(),
 		}
 	}
+
+	fn rule_without_block(&mut self, prelude: AtRuleNonBlockPrelude, start: &ParserState) -> CssRule {
+		match prelude {
+			AtRuleNonBlockPrelude::Layer(names) => CssRule::LayerStatement(LayerStatementRule {
+				names,
+				source_location: start.source_location(),
+			}),
+			_ => not_reached!(),
+		}
+	}
 }
 
 impl<'a, 'b, 'i> QualifiedRuleParser<'i> for NestedRuleParser<'a, 'b> {
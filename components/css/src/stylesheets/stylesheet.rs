@@ -3,12 +3,14 @@ use std::fmt::Display;
 use std::ops::Add;
 use std::rc::Rc;
 
+use common::url::BrowserUrl;
 use cssparser::{Parser, ParserInput, RuleListParser, SourceLocation};
 use selectors::context::QuirksMode;
 
 use super::css_rule::{CssRule, CssRuleType};
 use super::origin::Origin;
-use super::rule_parser::{State, TopLevelRuleParser};
+use super::rule_parser::{recover_invalid_rule, State, TopLevelRuleParser};
+use super::stylesheet_loader::ImportContext;
 use crate::css_writer::ToCss;
 use crate::error_reporting::{ContextualParseError, ParseErrorReporter};
 use crate::media_queries::media_list::MediaList;
@@ -40,6 +42,7 @@ impl Stylesheet {
 		origin: Origin,
 		quirks_mode: QuirksMode,
 		namespaces: &mut Namespaces,
+		import_context: Option<&ImportContext>,
 		error_reporter: Option<&dyn ParseErrorReporter>,
 		line_number_offset: u32,
 	) -> (Vec<CssRule>, Option<String>) {
@@ -47,7 +50,8 @@ impl Stylesheet {
 		let mut input = ParserInput::new_with_line_number_offset(css, line_number_offset);
 		let mut input = Parser::new(&mut input);
 
-		let context = ParserContext::new(origin, None, quirks_mode, error_reporter);
+		let mut context = ParserContext::new(origin, None, quirks_mode, error_reporter);
+		context.url_data = import_context.and_then(|import_context| import_context.base_url());
 
 		let rule_parser = TopLevelRuleParser {
 			context,
@@ -55,6 +59,7 @@ impl Stylesheet {
 			state: State::Start,
 			dom_error: None,
 			insert_rule_context: None,
+			import_context,
 		};
 
 		{
@@ -71,11 +76,7 @@ impl Stylesheet {
 						// shown incorrectly, but it's better than OOMing.
 						rules.push(rule)
 					},
-					Err((error, slice)) => {
-						let location = error.location;
-						let error = ContextualParseError::InvalidRule(slice, error);
-						iter.parser.context.log_css_error(location, error);
-					},
+					Err((error, slice)) => rules.extend(recover_invalid_rule(&iter.parser.context, error, slice)),
 				}
 			}
 		}
@@ -91,6 +92,28 @@ impl Stylesheet {
 		error_reporter: Option<&dyn ParseErrorReporter>,
 		quirks_mode: QuirksMode,
 		line_number_offset: u32,
+	) -> Self {
+		Stylesheet::from_str_with_loader(
+			css,
+			origin,
+			media,
+			None,
+			error_reporter,
+			quirks_mode,
+			line_number_offset,
+		)
+	}
+
+	/// Parse a stylesheet and load its @import rules through `import_context`, whose url
+	/// becomes the `source_url` of the stylesheet unless it has a `sourceURL` comment.
+	pub fn from_str_with_loader(
+		css: &str,
+		origin: Origin,
+		media: Rc<MediaList>,
+		import_context: Option<&ImportContext>,
+		error_reporter: Option<&dyn ParseErrorReporter>,
+		quirks_mode: QuirksMode,
+		line_number_offset: u32,
 	) -> Self {
 		let mut namespaces = Namespaces::default();
 		let (rules, source_url) = Stylesheet::parse_rules(
@@ -98,9 +121,15 @@ impl Stylesheet {
 			origin,
 			quirks_mode,
 			&mut namespaces,
+			import_context,
 			error_reporter,
 			line_number_offset,
 		);
+		let source_url = source_url.or_else(|| {
+			import_context
+				.and_then(|import_context| import_context.base_url())
+				.map(|url| url.as_str().to_string())
+		});
 
 		Self {
 			rules,
@@ -135,6 +164,8 @@ pub struct ParserContext<'a> {
 	pub error_reporter: Option<&'a dyn ParseErrorReporter>,
 	/// The currently active namespaces.
 	pub namespaces: Option<&'a Namespaces>,
+	/// The url of the stylesheet, relative urls are resolved against it.
+	pub url_data: Option<&'a BrowserUrl>,
 }
 
 impl<'a> ParserContext<'a> {
@@ -150,6 +181,7 @@ impl<'a> ParserContext<'a> {
 			quirks_mode,
 			error_reporter,
 			namespaces: None,
+			url_data: None,
 		}
 	}
 
@@ -167,6 +199,7 @@ impl<'a> ParserContext<'a> {
 			quirks_mode: context.quirks_mode,
 			namespaces: Some(namespaces),
 			error_reporter: context.error_reporter,
+			url_data: context.url_data,
 		}
 	}
 
@@ -175,6 +208,15 @@ impl<'a> ParserContext<'a> {
 		self.rule_type.expect("Rule type expected, but none was found.")
 	}
 
+	/// The url relative urls are resolved against, the `sourceURL` of the stylesheet once it has
+	/// been read, the url of the stylesheet otherwise.
+	pub fn base_url(&self, input: &Parser) -> Option<BrowserUrl> {
+		input
+			.current_source_url()
+			.and_then(|source_url| BrowserUrl::parse_with_base(self.url_data, source_url).ok())
+			.or_else(|| self.url_data.cloned())
+	}
+
 	/// Returns whether CSS error reporting is enabled.
 	#[inline]
 	pub fn error_reporting_enabled(&self) -> bool {
@@ -0,0 +1,57 @@
+use std::fs;
+
+use common::url::BrowserUrl;
+
+/// Supplied by the embedder to fetch the stylesheets referenced by `@import` rules.
+pub trait StylesheetLoader {
+	/// Return the content of the stylesheet at `url`, or the reason why it can't be loaded.
+	fn load(&self, url: &BrowserUrl) -> Result<String, String>;
+}
+
+/// Load `file:` urls from the local filesystem.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FilesystemStylesheetLoader;
+
+impl StylesheetLoader for FilesystemStylesheetLoader {
+	fn load(&self, url: &BrowserUrl) -> Result<String, String> {
+		let path = url.to_file_path().map_err(|()| format!("{} is not a file url", url))?;
+		fs::read_to_string(&path).map_err(|err| format!("{}: {}", path.display(), err))
+	}
+}
+
+/// The loader and the chain of stylesheets being imported, from the root stylesheet to the
+/// one being parsed. It is used to resolve relative urls and to detect import cycles.
+#[derive(Clone)]
+pub struct ImportContext<'a> {
+	pub loader: &'a dyn StylesheetLoader,
+	chain: Vec<BrowserUrl>,
+}
+
+impl<'a> ImportContext<'a> {
+	pub fn new(loader: &'a dyn StylesheetLoader, url: Option<BrowserUrl>) -> Self {
+		ImportContext {
+			loader,
+			chain: url.into_iter().collect(),
+		}
+	}
+
+	/// The url of the stylesheet being parsed.
+	pub fn base_url(&self) -> Option<&BrowserUrl> {
+		self.chain.last()
+	}
+
+	/// Whether `url` is already being imported, importing it again would never terminate.
+	pub fn is_cyclic(&self, url: &BrowserUrl) -> bool {
+		self.chain.contains(url)
+	}
+
+	/// The context of a stylesheet imported from the current one.
+	pub fn nested(&self, url: BrowserUrl) -> Self {
+		let mut chain = self.chain.clone();
+		chain.push(url);
+		ImportContext {
+			loader: self.loader,
+			chain,
+		}
+	}
+}
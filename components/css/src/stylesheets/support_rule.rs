@@ -1,11 +1,20 @@
 use std::fmt::Write;
 
-use cssparser::{Parser, SourceLocation, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
+use cssparser::{
+	_cssparser_internal_to_lowercase, match_ignore_ascii_case, parse_important, Delimiter, Parser, ParserInput,
+	SourceLocation,
+};
+use selectors::SelectorList;
 
-use super::css_rule::CssRule;
+use super::css_rule::{CssRule, CssRuleType};
+use super::stylesheet::{Namespaces, ParserContext};
 use crate::css_writer::{CssWriter, ToCss};
 use crate::media_queries::media_condition::{consume_any_value, parse_general_enclosed};
 use crate::parser::ParseError;
+use crate::properties::declaration::PropertyDeclaration;
+use crate::properties::declaration_block::SourcePropertyDeclaration;
+use crate::properties::property_id::PropertyId;
+use crate::selectors::selector_parser::SelectorParser;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 
 /// An [`@supports`][supports] rule.
@@ -17,6 +26,8 @@ pub struct SupportsRule {
 	pub condition: SupportsCondition,
 	/// Child rules
 	pub rules: Vec<CssRule>,
+	/// The result of evaluating the condition
+	pub enabled: bool,
 	/// The line and column of the rule's source code.
 	pub source_location: SourceLocation,
 }
@@ -49,6 +60,8 @@ pub enum SupportsCondition {
 	Or(Vec<SupportsCondition>),
 	/// `property-ident: value` (value can be any tokens)
 	Declaration(Declaration),
+	/// `selector(<complex selector>)`
+	Selector(RawSelector),
 	/// `(any tokens)` or `func(any tokens)`
 	GeneralEnclosed(String),
 }
@@ -134,12 +147,37 @@ impl SupportsCondition {
 					})
 				})
 			})
+			.or_else(|_err| input.try_parse(|input| SupportsCondition::parse_selector(input)))
 			.or_else(|_err| {
 				let value = parse_general_enclosed(input)?;
 				Ok(SupportsCondition::GeneralEnclosed(value))
 			})
 	}
 
+	fn parse_selector<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		input.expect_function_matching("selector")?;
+		input.parse_nested_block(|input| {
+			let selector = consume_any_value(input)?;
+			Ok(SupportsCondition::Selector(RawSelector(selector)))
+		})
+	}
+
+	/// Evaluate a supports condition, `context` is expected to be a style rule context
+	/// so every property is allowed.
+	///
+	/// <https://drafts.csswg.org/css-conditional-3/#evaluation-of-support-condition>
+	pub fn eval(&self, context: &ParserContext, namespaces: &Namespaces) -> bool {
+		match self {
+			SupportsCondition::Not(support) => !support.eval(context, namespaces),
+			SupportsCondition::Parenthesized(support) => support.eval(context, namespaces),
+			SupportsCondition::And(supports) => supports.iter().all(|support| support.eval(context, namespaces)),
+			SupportsCondition::Or(supports) => supports.iter().any(|support| support.eval(context, namespaces)),
+			SupportsCondition::Declaration(declaration) => declaration.eval(context),
+			SupportsCondition::Selector(selector) => selector.eval(context, namespaces),
+			SupportsCondition::GeneralEnclosed(_) => false,
+		}
+	}
+
 	fn parse_declaration<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
 		input.expect_parenthesis_block()?;
 		input.parse_nested_block(|input| {
@@ -187,6 +225,7 @@ impl ToCss for SupportsCondition {
 			SupportsCondition::And(supports) => write_for_vector(supports, "and", dest),
 			SupportsCondition::Or(supports) => write_for_vector(supports, "or", dest),
 			SupportsCondition::Declaration(declaration) => declaration.to_css(dest),
+			SupportsCondition::Selector(selector) => selector.to_css(dest),
 			SupportsCondition::GeneralEnclosed(value) => value.to_css(dest),
 		}
 	}
@@ -204,6 +243,32 @@ impl Declaration {
 		consume_any_value(input)?;
 		Ok(Declaration(input.slice_from(pos).to_owned()))
 	}
+
+	/// A declaration is supported when it would be kept in a style rule.
+	///
+	/// <https://drafts.csswg.org/css-conditional-3/#support-definition>
+	pub fn eval(&self, context: &ParserContext) -> bool {
+		debug_assert_eq!(context.rule_type(), CssRuleType::Style);
+
+		let mut input = ParserInput::new(&self.0);
+		let mut input = Parser::new(&mut input);
+		input
+			.parse_entirely(|input| -> Result<(), ParseError> {
+				let location = input.current_source_location();
+				let name = input.expect_ident_cloned()?;
+				input.expect_colon()?;
+				let id = PropertyId::parse(&name, context)
+					.map_err(|()| location.new_custom_error(StyleParseErrorKind::UnknownProperty(name.clone())))?;
+
+				let mut declarations = SourcePropertyDeclaration::new();
+				input.parse_until_before(Delimiter::Bang, |input| {
+					PropertyDeclaration::parse_into(&mut declarations, id, context, input)
+				})?;
+				let _ = input.try_parse(parse_important);
+				Ok(())
+			})
+			.is_ok()
+	}
 }
 
 impl ToCss for Declaration {
@@ -214,3 +279,33 @@ impl ToCss for Declaration {
 		dest.write_fmt(format_args!("({})", &self.0))
 	}
 }
+
+/// A possibly-invalid selector from `selector()`
+#[derive(Clone, Debug)]
+pub struct RawSelector(pub String);
+
+impl RawSelector {
+	/// A selector is supported when it parses as a single complex selector.
+	///
+	/// <https://drafts.csswg.org/css-conditional-4/#support-definition-ext>
+	pub fn eval(&self, context: &ParserContext, namespaces: &Namespaces) -> bool {
+		let mut input = ParserInput::new(&self.0);
+		let mut input = Parser::new(&mut input);
+		let selector_parser = SelectorParser {
+			stylesheet_origin: context.stylesheet_origin,
+			namespaces,
+		};
+		input
+			.parse_entirely(|input| SelectorList::parse(&selector_parser, input))
+			.map_or(false, |selectors| selectors.0.len() == 1)
+	}
+}
+
+impl ToCss for RawSelector {
+	fn to_css<W>(&self, dest: &mut crate::css_writer::CssWriter<W>) -> core::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		dest.write_fmt(format_args!("selector({})", &self.0))
+	}
+}
@@ -1,28 +1,43 @@
+use std::collections::HashMap;
+
 use selectors::context::QuirksMode;
 use selectors::parser::{AncestorHashes, Selector};
 
+use crate::media_queries::device::Device;
+use crate::selector_map::SelectorMap;
 use crate::selectors::select::Selectors;
 use crate::stylesheets::css_rule::CssRule;
+use crate::stylesheets::font_face_rule::FontFaceRule;
+use crate::stylesheets::layer_rule::LayerName;
 use crate::stylesheets::origin::Origin;
 use crate::stylesheets::style_rule::StyleRule;
 use crate::stylesheets::stylesheet::Stylesheet;
+use crate::values::Ident;
 
 #[derive(Debug)]
 pub struct Stylist {
 	user_agent: CascadeData,
+	user: CascadeData,
 	author: CascadeData,
+	device: Device,
 	quirks_mode: QuirksMode,
 }
 
 impl Stylist {
-	pub fn new(quirks_mode: QuirksMode) -> Self {
+	pub fn new(device: Device, quirks_mode: QuirksMode) -> Self {
 		Stylist {
 			user_agent: Default::default(),
+			user: Default::default(),
 			author: Default::default(),
+			device,
 			quirks_mode,
 		}
 	}
 
+	pub fn device(&self) -> &Device {
+		&self.device
+	}
+
 	pub fn quirks_mode(&self) -> QuirksMode {
 		self.quirks_mode
 	}
@@ -35,45 +50,187 @@ impl Stylist {
 		&self.user_agent
 	}
 
+	pub fn user_cascade_data(&self) -> &CascadeData {
+		&self.user
+	}
+
 	pub fn add_stylesheet(&mut self, stylesheet: &Stylesheet, origin: Origin) {
 		match origin {
-			Origin::UserAgent => self.user_agent.add_stylesheet(stylesheet),
-			Origin::Author => self.author.add_stylesheet(stylesheet),
+			Origin::UserAgent => self.user_agent.add_stylesheet(stylesheet, &self.device),
+			Origin::User => self.user.add_stylesheet(stylesheet, &self.device),
+			Origin::Author => self.author.add_stylesheet(stylesheet, &self.device),
 		}
 	}
+
+	/// The @font-face rules of all origins, author faces come last so they take precedence.
+	pub fn font_faces(&self) -> impl Iterator<Item = &FontFaceRule> {
+		self.user_agent
+			.font_faces
+			.iter()
+			.chain(self.user.font_faces.iter())
+			.chain(self.author.font_faces.iter())
+	}
+}
+
+/// The index of a cascade layer in `CascadeData::layers`, the root layer holds unlayered rules.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LayerId(pub usize);
+
+impl LayerId {
+	pub fn root() -> Self {
+		LayerId(0)
+	}
+}
+
+#[derive(Debug, Default)]
+struct CascadeLayer {
+	/// Sub-layers in the order they are declared.
+	children: Vec<LayerId>,
 }
 
 #[derive(Debug)]
 pub struct CascadeData {
-	rules: Vec<Rule>,
+	rules: SelectorMap,
 	rules_source_order: u32,
+	font_faces: Vec<FontFaceRule>,
+	layers: Vec<CascadeLayer>,
+	/// Named sub-layers by their parent, anonymous layers are never looked up.
+	layer_ids: HashMap<(LayerId, Ident), LayerId>,
+	/// The position of each layer in the cascade, indexed by `LayerId`.
+	layer_order: Vec<u32>,
 }
 
 impl CascadeData {
-	pub fn add_stylesheet(&mut self, stylesheet: &Stylesheet) {
-		for css_rule in &stylesheet.rules {
+	/// Rules inside a stylesheet or an @media block are only added when their media list matches the device,
+	/// rules inside an @supports block when its condition holds.
+	pub fn add_stylesheet(&mut self, stylesheet: &Stylesheet, device: &Device) {
+		self.add_stylesheet_in_layer(stylesheet, device, LayerId::root());
+		self.compute_layer_order();
+	}
+
+	fn add_stylesheet_in_layer(&mut self, stylesheet: &Stylesheet, device: &Device, layer: LayerId) {
+		if !stylesheet.media.evaluate(device) {
+			return;
+		}
+		self.add_rules(&stylesheet.rules, stylesheet.quirks_mode, device, layer);
+	}
+
+	fn add_rules(&mut self, css_rules: &[CssRule], quirks_mode: QuirksMode, device: &Device, layer: LayerId) {
+		for css_rule in css_rules {
 			match css_rule {
 				CssRule::Style(style) => {
 					for selector in &style.selectors.0 {
-						let hashes = AncestorHashes::new(&selector, stylesheet.quirks_mode);
+						let hashes = AncestorHashes::new(&selector, quirks_mode);
 						let rule = Rule {
 							selector: selector.clone(),
 							hashes,
 							source_order: self.rules_source_order,
+							layer_id: layer,
 							style_rule: style.clone(),
 						};
 						self.rules_source_order += 1;
-						self.rules.push(rule);
+						self.rules.insert(rule, quirks_mode);
+					}
+				},
+				CssRule::Import(import) => {
+					if let Some(stylesheet) = &import.stylesheet {
+						let layer = match &import.layer {
+							// the layer is only declared when the stylesheet applies
+							Some(import_layer) if stylesheet.media.evaluate(device) => {
+								self.declare_layer(layer, import_layer.0.as_ref())
+							},
+							_ => layer,
+						};
+						self.add_stylesheet_in_layer(stylesheet, device, layer);
+					}
+				},
+				CssRule::Media(media) => {
+					if media.media_queries.evaluate(device) {
+						self.add_rules(&media.rules, quirks_mode, device, layer);
 					}
 				},
-				_ => todo!(),
+				CssRule::Supports(supports) => {
+					if supports.enabled {
+						self.add_rules(&supports.rules, quirks_mode, device, layer);
+					}
+				},
+				CssRule::LayerBlock(layer_block) => {
+					let layer = self.declare_layer(layer, layer_block.name.as_ref());
+					self.add_rules(&layer_block.rules, quirks_mode, device, layer);
+				},
+				CssRule::LayerStatement(layer_statement) => {
+					for name in &layer_statement.names {
+						self.declare_layer(layer, Some(name));
+					}
+				},
+				CssRule::FontFace(font_face) => {
+					if font_face.is_valid() {
+						self.font_faces.push(font_face.clone());
+					}
+				},
+				CssRule::Namespace(_) | CssRule::Keyframes(_) | CssRule::Page(_) => {},
+			}
+		}
+	}
+
+	/// Find or create the layer `name` inside `parent`, each part of a dotted name is a nested layer.
+	/// An anonymous layer is always a new one.
+	fn declare_layer(&mut self, parent: LayerId, name: Option<&LayerName>) -> LayerId {
+		let name = match name {
+			Some(name) => name,
+			None => return self.push_layer(parent),
+		};
+		let mut layer = parent;
+		for ident in &name.0 {
+			layer = match self.layer_ids.get(&(layer, ident.clone())) {
+				Some(id) => *id,
+				None => {
+					let id = self.push_layer(layer);
+					self.layer_ids.insert((layer, ident.clone()), id);
+					id
+				},
+			};
+		}
+		layer
+	}
+
+	fn push_layer(&mut self, parent: LayerId) -> LayerId {
+		let id = LayerId(self.layers.len());
+		self.layers.push(Default::default());
+		self.layers[parent.0].children.push(id);
+		id
+	}
+
+	/// Layers are ordered by their first declaration, a layer comes after its sub-layers
+	/// so unlayered rules win over layered ones.
+	///
+	/// <https://drafts.csswg.org/css-cascade-5/#layer-ordering>
+	fn compute_layer_order(&mut self) {
+		fn visit(layers: &[CascadeLayer], layer: LayerId, order: &mut Vec<u32>, next: &mut u32) {
+			for child in &layers[layer.0].children {
+				visit(layers, *child, order, next);
 			}
+			order[layer.0] = *next;
+			*next += 1;
 		}
+
+		let mut order = vec![0; self.layers.len()];
+		visit(&self.layers, LayerId::root(), &mut order, &mut 0);
+		self.layer_order = order;
+	}
+
+	/// The position of the layer in the cascade, later layers win for normal declarations.
+	pub fn layer_order(&self, layer: LayerId) -> u32 {
+		self.layer_order[layer.0]
 	}
 
-	pub fn rules(&self) -> &Vec<Rule> {
+	pub fn rules(&self) -> &SelectorMap {
 		&self.rules
 	}
+
+	pub fn font_faces(&self) -> &Vec<FontFaceRule> {
+		&self.font_faces
+	}
 }
 
 impl Default for CascadeData {
@@ -81,6 +238,10 @@ impl Default for CascadeData {
 		Self {
 			rules: Default::default(),
 			rules_source_order: Default::default(),
+			font_faces: Default::default(),
+			layers: vec![Default::default()],
+			layer_ids: Default::default(),
+			layer_order: vec![0],
 		}
 	}
 }
@@ -90,5 +251,6 @@ pub struct Rule {
 	pub selector: Selector<Selectors>,
 	pub hashes: AncestorHashes,
 	pub source_order: u32,
+	pub layer_id: LayerId,
 	pub style_rule: StyleRule,
 }
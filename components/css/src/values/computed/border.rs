@@ -0,0 +1,14 @@
+use super::length::NonNegativeLengthPercentage;
+use crate::values::generics::border::GenericBorderCornerRadius;
+use crate::values::specified::length::Pair;
+
+pub type BorderCornerRadius = GenericBorderCornerRadius<NonNegativeLengthPercentage>;
+
+impl BorderCornerRadius {
+	pub fn zero() -> Self {
+		GenericBorderCornerRadius(Pair::new(
+			NonNegativeLengthPercentage::zero(),
+			NonNegativeLengthPercentage::zero(),
+		))
+	}
+}
@@ -0,0 +1,70 @@
+use cssparser::ToCss;
+
+use crate::values::generics::calc::GenericCalcNode;
+use crate::values::{AllowedNumericType, CSSFloat, Pixel};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CalcLeaf {
+	/// a length in px
+	Length(CSSFloat),
+	/// a percentage in its unit value (50% -> 0.5)
+	Percentage(CSSFloat),
+	Number(CSSFloat),
+}
+
+impl ToCss for CalcLeaf {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			CalcLeaf::Length(value) => dest.write_fmt(format_args!("{}px", value)),
+			CalcLeaf::Percentage(value) => dest.write_fmt(format_args!("{}%", value * 100.0)),
+			CalcLeaf::Number(value) => dest.write_fmt(format_args!("{}", value)),
+		}
+	}
+}
+
+pub type CalcNode = GenericCalcNode<CalcLeaf>;
+
+/// A computed math function, percentages are resolved against their basis at used value time
+/// https://drafts.csswg.org/css-values-4/#calc-computed-value
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalcLengthPercentage {
+	pub clamping_mode: AllowedNumericType,
+	pub node: CalcNode,
+}
+
+impl CalcLengthPercentage {
+	pub fn has_percentage(&self) -> bool {
+		self.node.any_leaf(&|leaf| matches!(leaf, CalcLeaf::Percentage(_)))
+	}
+
+	/// https://drafts.csswg.org/css-values-4/#calc-range
+	pub fn to_used_value(&self, base_value: Pixel) -> Pixel {
+		let value = self.node.resolve(&|leaf| match leaf {
+			CalcLeaf::Length(value) => *value,
+			CalcLeaf::Percentage(value) => base_value.get() * value,
+			CalcLeaf::Number(value) => *value,
+		});
+		Pixel::new(self.clamping_mode.clamp(value))
+	}
+
+	#[inline]
+	pub fn to_fixed_used_value(&self) -> Option<Pixel> {
+		if self.has_percentage() {
+			None
+		} else {
+			Some(self.to_used_value(Pixel::new(0.0)))
+		}
+	}
+}
+
+impl ToCss for CalcLengthPercentage {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		self.node.to_css_as_function(dest)
+	}
+}
@@ -0,0 +1,23 @@
+use super::length::NonNegativeLengthPercentage;
+use crate::values::generics::grid::{
+	GenericGridTemplateComponent, GenericImplicitGridTracks, GenericTrackBreadth, GenericTrackList,
+	GenericTrackListValue, GenericTrackRepeat, GenericTrackSize,
+};
+
+/// value = <length-percentage [0,∞]> | <flex [0,∞]> | min-content | max-content | auto
+pub type TrackBreadth = GenericTrackBreadth<NonNegativeLengthPercentage>;
+
+/// value = <track-breadth> | minmax( <inflexible-breadth> , <track-breadth> ) | fit-content( <length-percentage> )
+pub type TrackSize = GenericTrackSize<NonNegativeLengthPercentage>;
+
+pub type TrackRepeat = GenericTrackRepeat<NonNegativeLengthPercentage>;
+
+pub type TrackListValue = GenericTrackListValue<NonNegativeLengthPercentage>;
+
+pub type TrackList = GenericTrackList<NonNegativeLengthPercentage>;
+
+/// value = none | <track-list> | <auto-track-list>
+pub type GridTemplateComponent = GenericGridTemplateComponent<NonNegativeLengthPercentage>;
+
+/// value = <track-size>+
+pub type ImplicitGridTracks = GenericImplicitGridTracks<NonNegativeLengthPercentage>;
@@ -1,6 +1,8 @@
-use common::not_supported;
-
-use crate::values::generics::length::{GenericLengthPercentageOrAuto, GenericMaxSize, GenericSize};
+use super::calc::CalcLengthPercentage;
+use crate::values::generics::length::{
+	GenericLengthOrNone, GenericLengthPercentageNumberOrNormal, GenericLengthPercentageOrAuto,
+	GenericLengthPercentageOrNormal, GenericMaxSize, GenericSize,
+};
 use crate::values::generics::number::NonNegative;
 use crate::values::specified::percentage::Percentage;
 use crate::values::{CSSFloat, Pixel};
@@ -11,6 +13,28 @@ use crate::values::{CSSFloat, Pixel};
 pub enum LengthPercentage {
 	AbsoluteLength(CSSFloat),
 	Percentage(Percentage),
+	/// only math functions which contain percentages, the others are computed to absolute length
+	Calc(CalcLengthPercentage),
+}
+
+impl LengthPercentage {
+	#[inline]
+	pub fn to_used_value(&self, base_value: Pixel) -> Pixel {
+		match self {
+			LengthPercentage::AbsoluteLength(value) => Pixel::new(*value),
+			LengthPercentage::Percentage(value) => base_value * value.to_value(&(0.0..1.0)),
+			LengthPercentage::Calc(calc) => calc.to_used_value(base_value),
+		}
+	}
+
+	#[inline]
+	pub fn to_fixed_used_value(&self) -> Option<Pixel> {
+		match self {
+			LengthPercentage::AbsoluteLength(value) => Some(Pixel::new(*value)),
+			LengthPercentage::Percentage(_) => None,
+			LengthPercentage::Calc(calc) => calc.to_fixed_used_value(),
+		}
+	}
 }
 
 /// value = <length> | <percentage> | auto
@@ -24,10 +48,7 @@ impl LengthPercentageOrAuto {
 	#[inline]
 	pub fn to_used_value(&self, base_value: Pixel, fallback_value: Pixel) -> Pixel {
 		match self {
-			LengthPercentageOrAuto::LengthPercentage(length_percentage) => match length_percentage {
-				LengthPercentage::AbsoluteLength(value) => Pixel::new(*value),
-				LengthPercentage::Percentage(value) => base_value * value.to_value(&(0.0..1.0)),
-			},
+			LengthPercentageOrAuto::LengthPercentage(length_percentage) => length_percentage.to_used_value(base_value),
 			LengthPercentageOrAuto::Auto => fallback_value,
 		}
 	}
@@ -35,9 +56,8 @@ impl LengthPercentageOrAuto {
 	#[inline]
 	pub fn to_fixed_used_value(&self) -> Option<Pixel> {
 		match self {
-			GenericLengthPercentageOrAuto::LengthPercentage(length_percentage) => match length_percentage {
-				LengthPercentage::AbsoluteLength(length) => Some(Pixel::new(*length)),
-				_ => None,
+			GenericLengthPercentageOrAuto::LengthPercentage(length_percentage) => {
+				length_percentage.to_fixed_used_value()
 			},
 			_ => None,
 		}
@@ -46,6 +66,15 @@ impl LengthPercentageOrAuto {
 
 pub type NonNegativeLength = NonNegative<CSSFloat>;
 
+/// value = <length [0, ∞]> | auto
+pub type NonNegativeLengthOrAuto = GenericLengthPercentageOrAuto<NonNegativeLength>;
+
+/// value = <length [0, ∞]> | none
+pub type NonNegativeLengthOrNone = GenericLengthOrNone<NonNegativeLength>;
+
+/// value = <length> | <percentage> | normal
+pub type LengthPercentageOrNormal = GenericLengthPercentageOrNormal<LengthPercentage>;
+
 /// value = <length [0, ∞]> | <percentage>
 pub type NonNegativeLengthPercentage = NonNegative<LengthPercentage>;
 
@@ -56,21 +85,21 @@ impl NonNegativeLengthPercentage {
 
 	#[inline]
 	pub fn to_used_value(&self, base_value: Pixel) -> Pixel {
-		match &self.0 {
-			LengthPercentage::AbsoluteLength(value) => Pixel::new(*value),
-			LengthPercentage::Percentage(value) => base_value * value.to_value(&(0.0..1.0)),
-		}
+		self.0.to_used_value(base_value)
 	}
 
 	#[inline]
 	pub fn to_fixed_used_value(&self) -> Option<Pixel> {
-		match self.0 {
-			LengthPercentage::AbsoluteLength(length) => Some(Pixel::new(length)),
-			_ => None,
-		}
+		self.0.to_fixed_used_value()
 	}
 }
 
+/// value = <length [0, ∞]> | <percentage> | normal
+pub type NonNegativeLengthPercentageOrNormal = GenericLengthPercentageOrNormal<NonNegativeLengthPercentage>;
+
+/// value = <length [0, ∞]> | <number [0, ∞]> | normal, percentages are already resolved to a length
+pub type NonNegativeLengthNumberOrNormal = GenericLengthPercentageNumberOrNormal<CSSFloat, NonNegativeLength>;
+
 /// value = <length [0, ∞]> | <percentage>
 pub type NonNegativeLengthPercentageOrAuto = NonNegative<LengthPercentageOrAuto>;
 
@@ -80,28 +109,39 @@ impl Size {
 	#[inline]
 	pub fn to_fixed_used_value(&self) -> Option<Pixel> {
 		match self {
-			Self::Auto => None,
-			Self::LengthPercentage(length_percentage) => match length_percentage.0 {
-				LengthPercentage::AbsoluteLength(length) => Some(Pixel::new(length)),
-				_ => None,
-			},
-			Self::ExtremumLength(_) => not_supported!(),
+			Self::LengthPercentage(length_percentage) => length_percentage.to_fixed_used_value(),
+			// sizing keywords depend on the content
+			Self::Auto | Self::ExtremumLength(_) => None,
 		}
 	}
 }
 
 pub type MaxSize = GenericMaxSize<NonNegativeLengthPercentage>;
 
+impl From<Size> for MaxSize {
+	/// `max-block-size` and `max-inline-size` are parsed as a <'width'>, `auto` puts no limit on the size.
+	fn from(size: Size) -> Self {
+		match size {
+			Size::Auto => MaxSize::None,
+			Size::LengthPercentage(value) => MaxSize::LengthPercentage(value),
+			Size::ExtremumLength(value) => MaxSize::ExtremumLength(value),
+		}
+	}
+}
+
 impl MaxSize {
 	#[inline]
 	pub fn to_fixed_used_value(&self) -> Option<Pixel> {
 		match self {
-			Self::None => None,
-			Self::LengthPercentage(length_percentage) => match length_percentage.0 {
-				LengthPercentage::AbsoluteLength(length) => Some(Pixel::new(length)),
-				_ => None,
-			},
-			Self::ExtremumLength(_) => not_supported!(),
+			Self::LengthPercentage(length_percentage) => length_percentage.to_fixed_used_value(),
+			Self::None | Self::ExtremumLength(_) => None,
 		}
 	}
 }
+
+/// https://drafts.csswg.org/css-flexbox/#flex-basis-property
+#[derive(Clone, Debug, PartialEq)]
+pub enum FlexBasis {
+	Content,
+	Width(Size),
+}
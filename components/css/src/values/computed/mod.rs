@@ -1,5 +1,10 @@
+pub mod border;
+pub mod calc;
+pub mod grid;
 pub mod length;
 pub mod line;
+pub mod number;
+pub mod text;
 
 macro_rules! from_non_inherited_property {
 	($declaration: tt, $inherited_value: expr, $initial_value: expr, $longhand_id: expr, $pattern: pat => $then: expr) => {
@@ -0,0 +1,3 @@
+use crate::values::generics::number::GenericNumberOrAuto;
+
+pub type IntegerAuto = GenericNumberOrAuto<i32>;
@@ -0,0 +1,34 @@
+use super::length::LengthPercentage;
+use crate::properties::longhands::vertical_align::VerticalAlignKeyword;
+
+/// https://drafts.csswg.org/css-text/#text-indent-property
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextIndent {
+	pub indent: LengthPercentage,
+	pub hanging: bool,
+	pub each_line: bool,
+}
+
+impl Default for TextIndent {
+	fn default() -> Self {
+		TextIndent {
+			indent: LengthPercentage::AbsoluteLength(0.0),
+			hanging: false,
+			each_line: false,
+		}
+	}
+}
+
+/// https://drafts.csswg.org/css2/#propdef-vertical-align
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerticalAlign {
+	Keyword(VerticalAlignKeyword),
+	/// percentages refer to the `line-height` of the element itself
+	LengthPercentage(LengthPercentage),
+}
+
+impl Default for VerticalAlign {
+	fn default() -> Self {
+		VerticalAlign::Keyword(VerticalAlignKeyword::Baseline)
+	}
+}
@@ -2,7 +2,7 @@ use cssparser::ToCss;
 
 use crate::values::specified::length::Pair;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[repr(C)]
 pub struct GenericBorderCornerRadius<L>(pub Pair<L>);
 
@@ -0,0 +1,213 @@
+use cssparser::ToCss;
+
+use crate::values::CSSFloat;
+
+/// https://drafts.csswg.org/css-values-4/#comp-func
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MinMaxOp {
+	Min,
+	Max,
+}
+
+/// A node of a math function tree, with `L` being the leaf type (specified or computed).
+/// https://drafts.csswg.org/css-values-4/#calc-syntax
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenericCalcNode<L> {
+	Leaf(L),
+	/// `- <node>` inside a sum
+	Negate(Box<GenericCalcNode<L>>),
+	/// `/ <node>` inside a product
+	Invert(Box<GenericCalcNode<L>>),
+	Sum(Vec<GenericCalcNode<L>>),
+	Product(Vec<GenericCalcNode<L>>),
+	MinMax(Vec<GenericCalcNode<L>>, MinMaxOp),
+	Clamp {
+		min: Box<GenericCalcNode<L>>,
+		center: Box<GenericCalcNode<L>>,
+		max: Box<GenericCalcNode<L>>,
+	},
+}
+
+impl<L> GenericCalcNode<L> {
+	pub fn map_leaves<O, F>(&self, map: &mut F) -> GenericCalcNode<O>
+	where
+		F: FnMut(&L) -> O,
+	{
+		fn map_children<L, O, F>(children: &[GenericCalcNode<L>], map: &mut F) -> Vec<GenericCalcNode<O>>
+		where
+			F: FnMut(&L) -> O,
+		{
+			children.iter().map(|child| child.map_leaves(map)).collect()
+		}
+
+		match self {
+			GenericCalcNode::Leaf(leaf) => GenericCalcNode::Leaf(map(leaf)),
+			GenericCalcNode::Negate(child) => GenericCalcNode::Negate(Box::new(child.map_leaves(map))),
+			GenericCalcNode::Invert(child) => GenericCalcNode::Invert(Box::new(child.map_leaves(map))),
+			GenericCalcNode::Sum(children) => GenericCalcNode::Sum(map_children(children, map)),
+			GenericCalcNode::Product(children) => GenericCalcNode::Product(map_children(children, map)),
+			GenericCalcNode::MinMax(children, op) => GenericCalcNode::MinMax(map_children(children, map), *op),
+			GenericCalcNode::Clamp { min, center, max } => GenericCalcNode::Clamp {
+				min: Box::new(min.map_leaves(map)),
+				center: Box::new(center.map_leaves(map)),
+				max: Box::new(max.map_leaves(map)),
+			},
+		}
+	}
+
+	pub fn any_leaf<F>(&self, predicate: &F) -> bool
+	where
+		F: Fn(&L) -> bool,
+	{
+		match self {
+			GenericCalcNode::Leaf(leaf) => predicate(leaf),
+			GenericCalcNode::Negate(child) | GenericCalcNode::Invert(child) => child.any_leaf(predicate),
+			GenericCalcNode::Sum(children)
+			| GenericCalcNode::Product(children)
+			| GenericCalcNode::MinMax(children, _) => children.iter().any(|child| child.any_leaf(predicate)),
+			GenericCalcNode::Clamp { min, center, max } => {
+				min.any_leaf(predicate) || center.any_leaf(predicate) || max.any_leaf(predicate)
+			},
+		}
+	}
+
+	/// Evaluate the tree, the caller is in charge of turning every leaf into a float
+	/// (e.g. resolving percentages against their basis).
+	/// https://drafts.csswg.org/css-values-4/#calc-computed-value
+	pub fn resolve<F>(&self, leaf_to_value: &F) -> CSSFloat
+	where
+		F: Fn(&L) -> CSSFloat,
+	{
+		match self {
+			GenericCalcNode::Leaf(leaf) => leaf_to_value(leaf),
+			GenericCalcNode::Negate(child) => -child.resolve(leaf_to_value),
+			GenericCalcNode::Invert(child) => 1.0 / child.resolve(leaf_to_value),
+			GenericCalcNode::Sum(children) => children.iter().map(|child| child.resolve(leaf_to_value)).sum(),
+			GenericCalcNode::Product(children) => children.iter().map(|child| child.resolve(leaf_to_value)).product(),
+			GenericCalcNode::MinMax(children, op) => {
+				let values = children.iter().map(|child| child.resolve(leaf_to_value));
+				match op {
+					MinMaxOp::Min => values.fold(CSSFloat::INFINITY, CSSFloat::min),
+					MinMaxOp::Max => values.fold(CSSFloat::NEG_INFINITY, CSSFloat::max),
+				}
+			},
+			// https://drafts.csswg.org/css-values-4/#funcdef-clamp
+			// min wins over max when they conflict
+			GenericCalcNode::Clamp { min, center, max } => {
+				let min = min.resolve(leaf_to_value);
+				let center = center.resolve(leaf_to_value);
+				let max = max.resolve(leaf_to_value);
+				min.max(center.min(max))
+			},
+		}
+	}
+
+	fn is_sum(&self) -> bool {
+		matches!(self, GenericCalcNode::Sum(_))
+	}
+}
+
+impl<L: ToCss> GenericCalcNode<L> {
+	fn to_css_in_parens<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		if self.is_sum() {
+			dest.write_char('(')?;
+			self.to_css(dest)?;
+			dest.write_char(')')
+		} else {
+			self.to_css(dest)
+		}
+	}
+
+	/// Top level serialization, a bare expression is wrapped inside `calc()`.
+	pub fn to_css_as_function<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			GenericCalcNode::MinMax(..) | GenericCalcNode::Clamp { .. } => self.to_css(dest),
+			_ => {
+				dest.write_str("calc(")?;
+				self.to_css(dest)?;
+				dest.write_char(')')
+			},
+		}
+	}
+}
+
+impl<L: ToCss> ToCss for GenericCalcNode<L> {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			GenericCalcNode::Leaf(leaf) => leaf.to_css(dest),
+			GenericCalcNode::Negate(child) => {
+				dest.write_str("-1 * ")?;
+				child.to_css_in_parens(dest)
+			},
+			GenericCalcNode::Invert(child) => {
+				dest.write_str("1 / ")?;
+				child.to_css_in_parens(dest)
+			},
+			GenericCalcNode::Sum(children) => {
+				for (index, child) in children.iter().enumerate() {
+					match child {
+						GenericCalcNode::Negate(negated) if index > 0 => {
+							dest.write_str(" - ")?;
+							negated.to_css_in_parens(dest)?;
+						},
+						_ => {
+							if index > 0 {
+								dest.write_str(" + ")?;
+							}
+							child.to_css(dest)?;
+						},
+					}
+				}
+				Ok(())
+			},
+			GenericCalcNode::Product(children) => {
+				for (index, child) in children.iter().enumerate() {
+					match child {
+						GenericCalcNode::Invert(inverted) if index > 0 => {
+							dest.write_str(" / ")?;
+							inverted.to_css_in_parens(dest)?;
+						},
+						_ => {
+							if index > 0 {
+								dest.write_str(" * ")?;
+							}
+							child.to_css_in_parens(dest)?;
+						},
+					}
+				}
+				Ok(())
+			},
+			GenericCalcNode::MinMax(children, op) => {
+				dest.write_str(match op {
+					MinMaxOp::Min => "min(",
+					MinMaxOp::Max => "max(",
+				})?;
+				for (index, child) in children.iter().enumerate() {
+					if index > 0 {
+						dest.write_str(", ")?;
+					}
+					child.to_css(dest)?;
+				}
+				dest.write_char(')')
+			},
+			GenericCalcNode::Clamp { min, center, max } => {
+				dest.write_str("clamp(")?;
+				min.to_css(dest)?;
+				dest.write_str(", ")?;
+				center.to_css(dest)?;
+				dest.write_str(", ")?;
+				max.to_css(dest)?;
+				dest.write_char(')')
+			},
+		}
+	}
+}
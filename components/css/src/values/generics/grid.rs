@@ -0,0 +1,445 @@
+use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
+
+use crate::parser::ParseError;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+use crate::values::{CSSFloat, CustomIdent};
+
+/// https://drafts.csswg.org/css-grid/#typedef-track-breadth
+/// <track-breadth> = <length-percentage [0,∞]> | <flex [0,∞]> | min-content | max-content | auto
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenericTrackBreadth<LengthPercent> {
+	Breadth(LengthPercent),
+	Fr(CSSFloat),
+	Auto,
+	MinContent,
+	MaxContent,
+}
+
+impl<LP> GenericTrackBreadth<LP> {
+	/// <inflexible-breadth> is a <track-breadth> without <flex>
+	pub fn parse_with<'i, 't, F>(
+		input: &mut Parser<'i, 't>,
+		allow_flex: bool,
+		item_parser: &F,
+	) -> Result<Self, ParseError<'i>>
+	where
+		F: for<'ii, 'tt> Fn(&mut Parser<'ii, 'tt>) -> Result<LP, ParseError<'ii>>,
+	{
+		if let Ok(length_percentage) = input.try_parse(|input| item_parser(input)) {
+			return Ok(Self::Breadth(length_percentage));
+		}
+		let location = input.current_source_location();
+		let token = input.next()?.clone();
+		match &token {
+			Token::Dimension { value, unit, .. } if allow_flex && unit.eq_ignore_ascii_case("fr") && *value >= 0.0 => {
+				Ok(Self::Fr(*value))
+			},
+			Token::Ident(ident) => Ok(match_ignore_ascii_case! { ident,
+				"auto" => Self::Auto,
+				"min-content" => Self::MinContent,
+				"max-content" => Self::MaxContent,
+				_ => return Err(location.new_custom_error(StyleParseErrorKind::UnexpectedValue(ident.clone())))
+			}),
+			_ => Err(location.new_unexpected_token_error(token.clone())),
+		}
+	}
+
+	pub fn map<T, F: Fn(&LP) -> T>(&self, f: &F) -> GenericTrackBreadth<T> {
+		match self {
+			Self::Breadth(value) => GenericTrackBreadth::Breadth(f(value)),
+			Self::Fr(value) => GenericTrackBreadth::Fr(*value),
+			Self::Auto => GenericTrackBreadth::Auto,
+			Self::MinContent => GenericTrackBreadth::MinContent,
+			Self::MaxContent => GenericTrackBreadth::MaxContent,
+		}
+	}
+
+	pub fn is_fixed(&self) -> bool {
+		matches!(self, Self::Breadth(_))
+	}
+}
+
+impl<LP: ToCss> ToCss for GenericTrackBreadth<LP> {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			Self::Breadth(value) => value.to_css(dest),
+			Self::Fr(value) => dest.write_fmt(format_args!("{}fr", value)),
+			Self::Auto => dest.write_str("auto"),
+			Self::MinContent => dest.write_str("min-content"),
+			Self::MaxContent => dest.write_str("max-content"),
+		}
+	}
+}
+
+/// https://drafts.csswg.org/css-grid/#typedef-track-size
+/// <track-size> = <track-breadth> | minmax( <inflexible-breadth> , <track-breadth> ) | fit-content( <length-percentage> )
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenericTrackSize<LengthPercent> {
+	Breadth(GenericTrackBreadth<LengthPercent>),
+	Minmax(GenericTrackBreadth<LengthPercent>, GenericTrackBreadth<LengthPercent>),
+	FitContent(LengthPercent),
+}
+
+impl<LP> GenericTrackSize<LP> {
+	pub fn parse_with<'i, 't, F>(input: &mut Parser<'i, 't>, item_parser: &F) -> Result<Self, ParseError<'i>>
+	where
+		F: for<'ii, 'tt> Fn(&mut Parser<'ii, 'tt>) -> Result<LP, ParseError<'ii>>,
+	{
+		if let Ok(breadth) = input.try_parse(|input| GenericTrackBreadth::parse_with(input, true, item_parser)) {
+			return Ok(Self::Breadth(breadth));
+		}
+		let location = input.current_source_location();
+		let name = input.expect_function()?.clone();
+		match_ignore_ascii_case! { &name,
+			"minmax" => input.parse_nested_block(|input| {
+				let min = GenericTrackBreadth::parse_with(input, false, item_parser)?;
+				input.expect_comma()?;
+				let max = GenericTrackBreadth::parse_with(input, true, item_parser)?;
+				Ok(Self::Minmax(min, max))
+			}),
+			"fit-content" => input.parse_nested_block(|input| Ok(Self::FitContent(item_parser(input)?))),
+			_ => Err(location.new_custom_error(StyleParseErrorKind::UnexpectedFunction(name.clone()))),
+		}
+	}
+
+	pub fn map<T, F: Fn(&LP) -> T>(&self, f: &F) -> GenericTrackSize<T> {
+		match self {
+			Self::Breadth(value) => GenericTrackSize::Breadth(value.map(f)),
+			Self::Minmax(min, max) => GenericTrackSize::Minmax(min.map(f), max.map(f)),
+			Self::FitContent(value) => GenericTrackSize::FitContent(f(value)),
+		}
+	}
+
+	/// https://drafts.csswg.org/css-grid/#typedef-fixed-size
+	pub fn is_fixed(&self) -> bool {
+		match self {
+			Self::Breadth(breadth) => breadth.is_fixed(),
+			Self::Minmax(min, max) => min.is_fixed() || max.is_fixed(),
+			Self::FitContent(_) => false,
+		}
+	}
+
+}
+
+impl<LP> Default for GenericTrackSize<LP> {
+	fn default() -> Self {
+		Self::Breadth(GenericTrackBreadth::Auto)
+	}
+}
+
+impl<LP: ToCss> ToCss for GenericTrackSize<LP> {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			Self::Breadth(value) => value.to_css(dest),
+			Self::Minmax(min, max) => dest.write_fmt(format_args!(
+				"minmax({}, {})",
+				min.to_css_string(),
+				max.to_css_string()
+			)),
+			Self::FitContent(value) => dest.write_fmt(format_args!("fit-content({})", value.to_css_string())),
+		}
+	}
+}
+
+/// The implicit track sizes, https://drafts.csswg.org/css-grid/#auto-tracks
+/// <track-size>+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericImplicitGridTracks<LengthPercent>(pub Vec<GenericTrackSize<LengthPercent>>);
+
+impl<LP> GenericImplicitGridTracks<LP> {
+	pub fn parse_with<'i, 't, F>(input: &mut Parser<'i, 't>, item_parser: &F) -> Result<Self, ParseError<'i>>
+	where
+		F: for<'ii, 'tt> Fn(&mut Parser<'ii, 'tt>) -> Result<LP, ParseError<'ii>>,
+	{
+		let mut track_sizes = vec![GenericTrackSize::parse_with(input, item_parser)?];
+		while let Ok(track_size) = input.try_parse(|input| GenericTrackSize::parse_with(input, item_parser)) {
+			track_sizes.push(track_size);
+		}
+		Ok(Self(track_sizes))
+	}
+
+	pub fn map<T, F: Fn(&LP) -> T>(&self, f: &F) -> GenericImplicitGridTracks<T> {
+		GenericImplicitGridTracks(self.0.iter().map(|track_size| track_size.map(f)).collect())
+	}
+}
+
+impl<LP> Default for GenericImplicitGridTracks<LP> {
+	fn default() -> Self {
+		Self(vec![Default::default()])
+	}
+}
+
+impl<LP: ToCss> ToCss for GenericImplicitGridTracks<LP> {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		let values: Vec<String> = self.0.iter().map(|value| value.to_css_string()).collect();
+		dest.write_str(&values.join(" "))
+	}
+}
+
+/// https://drafts.csswg.org/css-grid/#typedef-line-names
+/// <line-names> = '[' <custom-ident>* ']'
+pub fn parse_line_names<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Vec<CustomIdent>, ParseError<'i>> {
+	input.expect_square_bracket_block()?;
+	input.parse_nested_block(|input| {
+		let mut names = vec![];
+		while let Ok(name) = input.try_parse(|input| CustomIdent::parse_excluding(input, &["span", "auto"])) {
+			names.push(name);
+		}
+		Ok(names)
+	})
+}
+
+fn line_names_to_css(names: &[CustomIdent]) -> String {
+	let names: Vec<String> = names.iter().map(|name| name.to_css_string()).collect();
+	std::format!("[{}]", names.join(" "))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RepeatCount {
+	Number(u32),
+	AutoFill,
+	AutoFit,
+}
+
+impl RepeatCount {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		if let Ok(value) = input.try_parse(|input| input.expect_integer()) {
+			if value > 0 {
+				return Ok(RepeatCount::Number(value as u32));
+			}
+			return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+		}
+		let location = input.current_source_location();
+		let ident = input.expect_ident()?;
+		Ok(match_ignore_ascii_case! { ident,
+			"auto-fill" => RepeatCount::AutoFill,
+			"auto-fit" => RepeatCount::AutoFit,
+			_ => return Err(location.new_custom_error(StyleParseErrorKind::UnexpectedValue(ident.clone())))
+		})
+	}
+}
+
+impl ToCss for RepeatCount {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			RepeatCount::Number(value) => dest.write_fmt(format_args!("{}", value)),
+			RepeatCount::AutoFill => dest.write_str("auto-fill"),
+			RepeatCount::AutoFit => dest.write_str("auto-fit"),
+		}
+	}
+}
+
+/// https://drafts.csswg.org/css-grid/#repeat-notation
+/// repeat( [ <integer [1,∞]> | auto-fill | auto-fit ] , [ <line-names>? <track-size> ]+ <line-names>? )
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericTrackRepeat<LengthPercent> {
+	pub count: RepeatCount,
+	/// one more entry than `track_sizes`, the names before each track and after the last one
+	pub line_names: Vec<Vec<CustomIdent>>,
+	pub track_sizes: Vec<GenericTrackSize<LengthPercent>>,
+}
+
+impl<LP> GenericTrackRepeat<LP> {
+	pub fn parse_with<'i, 't, F>(input: &mut Parser<'i, 't>, item_parser: &F) -> Result<Self, ParseError<'i>>
+	where
+		F: for<'ii, 'tt> Fn(&mut Parser<'ii, 'tt>) -> Result<LP, ParseError<'ii>>,
+	{
+		input.expect_function_matching("repeat")?;
+		input.parse_nested_block(|input| {
+			let count = RepeatCount::parse(input)?;
+			input.expect_comma()?;
+			let mut line_names = vec![];
+			let mut track_sizes = vec![];
+			loop {
+				line_names.push(input.try_parse(parse_line_names).unwrap_or_default());
+				match input.try_parse(|input| GenericTrackSize::parse_with(input, item_parser)) {
+					Ok(track_size) => track_sizes.push(track_size),
+					Err(_) => break,
+				}
+			}
+			// auto repetitions only accept fixed sizes, https://drafts.csswg.org/css-grid/#typedef-auto-repeat
+			if track_sizes.is_empty()
+				|| (!matches!(count, RepeatCount::Number(_))
+					&& !track_sizes.iter().all(|track_size| track_size.is_fixed()))
+			{
+				return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+			}
+			Ok(GenericTrackRepeat {
+				count,
+				line_names,
+				track_sizes,
+			})
+		})
+	}
+
+	pub fn map<T, F: Fn(&LP) -> T>(&self, f: &F) -> GenericTrackRepeat<T> {
+		GenericTrackRepeat {
+			count: self.count,
+			line_names: self.line_names.clone(),
+			track_sizes: self.track_sizes.iter().map(|track_size| track_size.map(f)).collect(),
+		}
+	}
+}
+
+impl<LP: ToCss> ToCss for GenericTrackRepeat<LP> {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		let mut values = vec![];
+		for (index, track_size) in self.track_sizes.iter().enumerate() {
+			if !self.line_names[index].is_empty() {
+				values.push(line_names_to_css(&self.line_names[index]));
+			}
+			values.push(track_size.to_css_string());
+		}
+		if let Some(names) = self.line_names.last().filter(|names| !names.is_empty()) {
+			values.push(line_names_to_css(names));
+		}
+		dest.write_fmt(format_args!("repeat({}, {})", self.count.to_css_string(), values.join(" ")))
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenericTrackListValue<LengthPercent> {
+	TrackSize(GenericTrackSize<LengthPercent>),
+	TrackRepeat(GenericTrackRepeat<LengthPercent>),
+}
+
+impl<LP: ToCss> ToCss for GenericTrackListValue<LP> {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			Self::TrackSize(value) => value.to_css(dest),
+			Self::TrackRepeat(value) => value.to_css(dest),
+		}
+	}
+}
+
+/// https://drafts.csswg.org/css-grid/#typedef-track-list
+/// [ <line-names>? [ <track-size> | <track-repeat> ] ]+ <line-names>?
+/// or with a single auto repetition https://drafts.csswg.org/css-grid/#typedef-auto-track-list
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericTrackList<LengthPercent> {
+	pub values: Vec<GenericTrackListValue<LengthPercent>>,
+	/// one more entry than `values`, the names before each value and after the last one
+	pub line_names: Vec<Vec<CustomIdent>>,
+}
+
+impl<LP> GenericTrackList<LP> {
+	pub fn parse_with<'i, 't, F>(input: &mut Parser<'i, 't>, item_parser: &F) -> Result<Self, ParseError<'i>>
+	where
+		F: for<'ii, 'tt> Fn(&mut Parser<'ii, 'tt>) -> Result<LP, ParseError<'ii>>,
+	{
+		let mut values = vec![];
+		let mut line_names = vec![];
+		let mut has_auto_repeat = false;
+		loop {
+			line_names.push(input.try_parse(parse_line_names).unwrap_or_default());
+			if let Ok(track_size) = input.try_parse(|input| GenericTrackSize::parse_with(input, item_parser)) {
+				values.push(GenericTrackListValue::TrackSize(track_size));
+			} else if let Ok(repeat) = input.try_parse(|input| GenericTrackRepeat::parse_with(input, item_parser)) {
+				if !matches!(repeat.count, RepeatCount::Number(_)) {
+					if has_auto_repeat {
+						return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+					}
+					has_auto_repeat = true;
+				}
+				values.push(GenericTrackListValue::TrackRepeat(repeat));
+			} else {
+				break;
+			}
+		}
+		if values.is_empty() {
+			return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+		}
+		Ok(GenericTrackList { values, line_names })
+	}
+
+	pub fn map<T, F: Fn(&LP) -> T>(&self, f: &F) -> GenericTrackList<T> {
+		GenericTrackList {
+			values: self
+				.values
+				.iter()
+				.map(|value| match value {
+					GenericTrackListValue::TrackSize(value) => GenericTrackListValue::TrackSize(value.map(f)),
+					GenericTrackListValue::TrackRepeat(value) => GenericTrackListValue::TrackRepeat(value.map(f)),
+				})
+				.collect(),
+			line_names: self.line_names.clone(),
+		}
+	}
+}
+
+impl<LP: ToCss> ToCss for GenericTrackList<LP> {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		let mut values = vec![];
+		for (index, value) in self.values.iter().enumerate() {
+			if !self.line_names[index].is_empty() {
+				values.push(line_names_to_css(&self.line_names[index]));
+			}
+			values.push(value.to_css_string());
+		}
+		if let Some(names) = self.line_names.last().filter(|names| !names.is_empty()) {
+			values.push(line_names_to_css(names));
+		}
+		dest.write_str(&values.join(" "))
+	}
+}
+
+/// https://drafts.csswg.org/css-grid/#track-sizing
+/// none | <track-list> | <auto-track-list>
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenericGridTemplateComponent<LengthPercent> {
+	None,
+	TrackList(Box<GenericTrackList<LengthPercent>>),
+}
+
+impl<LP> GenericGridTemplateComponent<LP> {
+	pub fn parse_with<'i, 't, F>(input: &mut Parser<'i, 't>, item_parser: &F) -> Result<Self, ParseError<'i>>
+	where
+		F: for<'ii, 'tt> Fn(&mut Parser<'ii, 'tt>) -> Result<LP, ParseError<'ii>>,
+	{
+		if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+			return Ok(Self::None);
+		}
+		let track_list = GenericTrackList::parse_with(input, item_parser)?;
+		Ok(Self::TrackList(Box::new(track_list)))
+	}
+
+	pub fn map<T, F: Fn(&LP) -> T>(&self, f: &F) -> GenericGridTemplateComponent<T> {
+		match self {
+			Self::None => GenericGridTemplateComponent::None,
+			Self::TrackList(track_list) => GenericGridTemplateComponent::TrackList(Box::new(track_list.map(f))),
+		}
+	}
+}
+
+impl<LP: ToCss> ToCss for GenericGridTemplateComponent<LP> {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			Self::None => dest.write_str("none"),
+			Self::TrackList(value) => value.to_css(dest),
+		}
+	}
+}
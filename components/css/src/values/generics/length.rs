@@ -40,7 +40,7 @@ impl<L: ToCss> ToCss for GenericLengthOrAuto<L> {
 }
 
 /// Generic for Length/None
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum GenericLengthOrNone<Length> {
 	Length(Length),
 	None,
@@ -294,7 +294,7 @@ impl<LP: ToCss> ToCss for GenericMaxSize<LP> {
 	}
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[repr(C, u8)]
 pub enum GenericLengthPercentageOrNormal<LengthPercent> {
 	LengthPercentage(LengthPercent),
@@ -330,7 +330,7 @@ impl<LP: ToCss> ToCss for GenericLengthPercentageOrNormal<LP> {
 	}
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[repr(C, u8)]
 pub enum GenericLengthPercentageNumberOrNormal<Number, LengthPercent> {
 	LengthPercentage(LengthPercent),
@@ -1,4 +1,6 @@
 pub mod border;
+pub mod calc;
 pub mod counter;
+pub mod grid;
 pub mod length;
 pub mod number;
@@ -63,7 +63,7 @@ impl<T: ToCss> ToCss for GreaterThanOrEqualToOne<T> {
 }
 
 /// Generic for Number/Auto
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum GenericNumberOrAuto<Number> {
 	Number(Number),
 	Auto,
@@ -35,6 +35,15 @@ impl AllowedNumericType {
 			AllowedNumericType::NonNegative => value >= 0.0,
 		}
 	}
+
+	/// Clamp the value, used for math functions which are not rejected at parse time
+	/// https://drafts.csswg.org/css-values-4/#calc-range
+	pub fn clamp(&self, value: f32) -> f32 {
+		match self {
+			AllowedNumericType::All => value,
+			AllowedNumericType::NonNegative => value.max(0.0),
+		}
+	}
 }
 
 /// A CSS float value.
@@ -47,7 +56,7 @@ pub type Pixel = Length<f32, CSSPixel>;
 
 pub const PIXEL_ZERO: Pixel = Pixel::new(0.0);
 
-#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
 pub struct Ident(pub String);
 
 impl Ident {
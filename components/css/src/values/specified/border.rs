@@ -1,7 +1,9 @@
 use cssparser::Parser;
 
 use super::length::{NonNegativeLengthPercentage, Pair};
+use crate::computed_values::StyleContext;
 use crate::parser::ParseError;
+use crate::values::computed;
 use crate::values::generics::border::GenericBorderCornerRadius;
 
 pub type BorderCornerRadius = GenericBorderCornerRadius<NonNegativeLengthPercentage>;
@@ -15,4 +17,11 @@ impl BorderCornerRadius {
 		let pair = Pair::parse_with(input, |input| NonNegativeLengthPercentage::parse(input))?;
 		Ok(BorderCornerRadius::new(pair.0, pair.1))
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::border::BorderCornerRadius {
+		GenericBorderCornerRadius(Pair::new(
+			self.0 .0.to_computed_value(context),
+			self.0 .1.to_computed_value(context),
+		))
+	}
 }
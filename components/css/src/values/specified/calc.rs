@@ -0,0 +1,304 @@
+use cssparser::{CowRcStr, Delimiter, Parser, ToCss, Token, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
+
+use super::length::NoCalcLength;
+use crate::computed_values::StyleContext;
+use crate::media_queries::device::Device;
+use crate::parser::ParseError;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+use crate::values::generics::calc::{GenericCalcNode, MinMaxOp};
+use crate::values::{computed, AllowedNumericType, CSSFloat};
+
+/// https://drafts.csswg.org/css-values-4/#math-function
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MathFunction {
+	Calc,
+	Min,
+	Max,
+	Clamp,
+}
+
+impl MathFunction {
+	pub fn from_name(name: &CowRcStr) -> Result<Self, ()> {
+		Ok(match_ignore_ascii_case! { name,
+			"calc" => MathFunction::Calc,
+			"min" => MathFunction::Min,
+			"max" => MathFunction::Max,
+			"clamp" => MathFunction::Clamp,
+			_ => return Err(()),
+		})
+	}
+}
+
+/// https://drafts.csswg.org/css-values-4/#calc-type-checking
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CalcCategory {
+	Number,
+	Length,
+	Percentage,
+	LengthPercentage,
+}
+
+impl CalcCategory {
+	fn add(self, other: CalcCategory) -> Result<CalcCategory, ()> {
+		use CalcCategory::*;
+		Ok(match (self, other) {
+			(Number, Number) => Number,
+			(Number, _) | (_, Number) => return Err(()),
+			(Length, Length) => Length,
+			(Percentage, Percentage) => Percentage,
+			_ => LengthPercentage,
+		})
+	}
+
+	fn multiply(self, other: CalcCategory) -> Result<CalcCategory, ()> {
+		match (self, other) {
+			(CalcCategory::Number, other) | (other, CalcCategory::Number) => Ok(other),
+			_ => Err(()),
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CalcLeaf {
+	Length(NoCalcLength),
+	/// a percentage in its unit value (50% -> 0.5)
+	Percentage(CSSFloat),
+	Number(CSSFloat),
+}
+
+impl CalcLeaf {
+	fn category(&self) -> CalcCategory {
+		match self {
+			CalcLeaf::Length(_) => CalcCategory::Length,
+			CalcLeaf::Percentage(_) => CalcCategory::Percentage,
+			CalcLeaf::Number(_) => CalcCategory::Number,
+		}
+	}
+}
+
+impl ToCss for CalcLeaf {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			CalcLeaf::Length(length) => length.to_css(dest),
+			CalcLeaf::Percentage(value) => dest.write_fmt(format_args!("{}%", value * 100.0)),
+			CalcLeaf::Number(value) => dest.write_fmt(format_args!("{}", value)),
+		}
+	}
+}
+
+pub type CalcNode = GenericCalcNode<CalcLeaf>;
+
+impl CalcNode {
+	/// Parse the arguments of a math function, the function token must already be consumed.
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>, function: MathFunction) -> Result<Self, ParseError<'i>> {
+		input.parse_nested_block(|input| match function {
+			MathFunction::Calc => Self::parse_sum(input),
+			MathFunction::Min | MathFunction::Max => {
+				let arguments = input.parse_comma_separated(|input| Self::parse_sum(input))?;
+				let op = if function == MathFunction::Min {
+					MinMaxOp::Min
+				} else {
+					MinMaxOp::Max
+				};
+				Ok(GenericCalcNode::MinMax(arguments, op))
+			},
+			MathFunction::Clamp => {
+				let min = input.parse_until_before(Delimiter::Comma, |input| Self::parse_sum(input))?;
+				input.expect_comma()?;
+				let center = input.parse_until_before(Delimiter::Comma, |input| Self::parse_sum(input))?;
+				input.expect_comma()?;
+				let max = Self::parse_sum(input)?;
+				Ok(GenericCalcNode::Clamp {
+					min: Box::new(min),
+					center: Box::new(center),
+					max: Box::new(max),
+				})
+			},
+		})
+	}
+
+	/// <calc-sum> = <calc-product> [ [ '+' | '-' ] <calc-product> ]*
+	fn parse_sum<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let mut terms = vec![Self::parse_product(input)?];
+		loop {
+			let state = input.state();
+			let token = match input.next() {
+				Ok(token) => token.clone(),
+				Err(_) => break,
+			};
+			match token {
+				Token::Delim('+') => terms.push(Self::parse_product(input)?),
+				Token::Delim('-') => terms.push(GenericCalcNode::Negate(Box::new(Self::parse_product(input)?))),
+				_ => {
+					input.reset(&state);
+					break;
+				},
+			}
+		}
+		if terms.len() == 1 {
+			Ok(terms.pop().unwrap())
+		} else {
+			Ok(GenericCalcNode::Sum(terms))
+		}
+	}
+
+	/// <calc-product> = <calc-value> [ '*' <calc-value> | '/' <calc-value> ]*
+	fn parse_product<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let mut factors = vec![Self::parse_value(input)?];
+		loop {
+			let state = input.state();
+			let token = match input.next() {
+				Ok(token) => token.clone(),
+				Err(_) => break,
+			};
+			match token {
+				Token::Delim('*') => factors.push(Self::parse_value(input)?),
+				Token::Delim('/') => factors.push(GenericCalcNode::Invert(Box::new(Self::parse_value(input)?))),
+				_ => {
+					input.reset(&state);
+					break;
+				},
+			}
+		}
+		if factors.len() == 1 {
+			Ok(factors.pop().unwrap())
+		} else {
+			Ok(GenericCalcNode::Product(factors))
+		}
+	}
+
+	/// <calc-value> = <number> | <dimension> | <percentage> | ( <calc-sum> ) | <math-function>
+	fn parse_value<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let location = input.current_source_location();
+		let token = input.next()?.clone();
+		match token {
+			Token::Number { value, .. } => Ok(GenericCalcNode::Leaf(CalcLeaf::Number(value))),
+			Token::Percentage { unit_value, .. } => Ok(GenericCalcNode::Leaf(CalcLeaf::Percentage(unit_value))),
+			Token::Dimension { value, ref unit, .. } => NoCalcLength::parse(unit, value)
+				.map(|length| GenericCalcNode::Leaf(CalcLeaf::Length(length)))
+				.map_err(|()| location.new_unexpected_token_error(token.clone())),
+			Token::ParenthesisBlock => input.parse_nested_block(|input| Self::parse_sum(input)),
+			Token::Function(ref name) => {
+				let function =
+					MathFunction::from_name(name).map_err(|()| location.new_unexpected_token_error(token.clone()))?;
+				Self::parse(input, function)
+			},
+			t => Err(location.new_unexpected_token_error(t)),
+		}
+	}
+
+	/// Resolve the type of the expression, fails if operands are mixed in an invalid way
+	/// like `10px + 2` or `10px * 10px`.
+	pub fn category(&self) -> Result<CalcCategory, ()> {
+		fn fold_sum(children: &[CalcNode]) -> Result<CalcCategory, ()> {
+			let mut category = children.first().ok_or(())?.category()?;
+			for child in children.iter().skip(1) {
+				category = category.add(child.category()?)?;
+			}
+			Ok(category)
+		}
+
+		match self {
+			GenericCalcNode::Leaf(leaf) => Ok(leaf.category()),
+			GenericCalcNode::Negate(child) => child.category(),
+			GenericCalcNode::Invert(child) => match child.category()? {
+				CalcCategory::Number => Ok(CalcCategory::Number),
+				_ => Err(()),
+			},
+			GenericCalcNode::Sum(children) | GenericCalcNode::MinMax(children, _) => fold_sum(children),
+			GenericCalcNode::Product(children) => {
+				let mut category = CalcCategory::Number;
+				for child in children.iter() {
+					category = category.multiply(child.category()?)?;
+				}
+				Ok(category)
+			},
+			GenericCalcNode::Clamp { min, center, max } => {
+				min.category()?.add(center.category()?)?.add(max.category()?)
+			},
+		}
+	}
+}
+
+/// A specified math function which resolves to a <length> or <length-percentage>.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalcLengthPercentage {
+	pub clamping_mode: AllowedNumericType,
+	pub node: CalcNode,
+}
+
+impl CalcLengthPercentage {
+	fn parse_with<'i, 't>(
+		input: &mut Parser<'i, 't>,
+		clamping_mode: AllowedNumericType,
+		allow_percentage: bool,
+	) -> Result<Self, ParseError<'i>> {
+		let location = input.current_source_location();
+		let name = input.expect_function()?.clone();
+		let function = MathFunction::from_name(&name)
+			.map_err(|()| location.new_custom_error(StyleParseErrorKind::UnexpectedFunction(name.clone())))?;
+		let node = CalcNode::parse(input, function)?;
+		match node.category() {
+			Ok(CalcCategory::Length) => {},
+			Ok(CalcCategory::Percentage) | Ok(CalcCategory::LengthPercentage) if allow_percentage => {},
+			_ => return Err(location.new_custom_error(StyleParseErrorKind::UnspecifiedError)),
+		};
+		Ok(CalcLengthPercentage { clamping_mode, node })
+	}
+
+	/// Parse a math function which resolves to a <length>.
+	pub fn parse_length<'i, 't>(
+		input: &mut Parser<'i, 't>,
+		clamping_mode: AllowedNumericType,
+	) -> Result<Self, ParseError<'i>> {
+		Self::parse_with(input, clamping_mode, false)
+	}
+
+	/// Parse a math function which resolves to a <length-percentage>.
+	pub fn parse_length_percentage<'i, 't>(
+		input: &mut Parser<'i, 't>,
+		clamping_mode: AllowedNumericType,
+	) -> Result<Self, ParseError<'i>> {
+		Self::parse_with(input, clamping_mode, true)
+	}
+
+	pub fn has_percentage(&self) -> bool {
+		self.node.any_leaf(&|leaf| matches!(leaf, CalcLeaf::Percentage(_)))
+	}
+
+	/// Absolute and relative lengths are turned into px, percentages are kept until used value time.
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::calc::CalcLengthPercentage {
+		let node = self.node.map_leaves(&mut |leaf| match leaf {
+			CalcLeaf::Length(length) => computed::calc::CalcLeaf::Length(length.to_computed_value(context)),
+			CalcLeaf::Percentage(value) => computed::calc::CalcLeaf::Percentage(*value),
+			CalcLeaf::Number(value) => computed::calc::CalcLeaf::Number(*value),
+		});
+		computed::calc::CalcLengthPercentage {
+			clamping_mode: self.clamping_mode,
+			node,
+		}
+	}
+
+	/// Resolve a <length> math function without an element, see `Length::to_computed_value_with_device`.
+	pub fn to_computed_value_with_device(&self, device: &Device) -> CSSFloat {
+		let value = self.node.resolve(&|leaf| match leaf {
+			CalcLeaf::Length(length) => length.to_computed_value_with_device(device),
+			// percentages are rejected while parsing a <length>
+			CalcLeaf::Percentage(_) => 0.0,
+			CalcLeaf::Number(value) => *value,
+		});
+		self.clamping_mode.clamp(value)
+	}
+}
+
+impl ToCss for CalcLengthPercentage {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		self.node.to_css_as_function(dest)
+	}
+}
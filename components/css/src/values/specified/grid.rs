@@ -0,0 +1,330 @@
+use std::ops::Range;
+
+use cssparser::{match_ignore_ascii_case, Parser, ToCss, Token, _cssparser_internal_to_lowercase};
+
+use super::length::NonNegativeLengthPercentage;
+use crate::computed_values::StyleContext;
+use crate::parser::ParseError;
+use crate::properties::declaration::property_keywords_impl;
+use crate::stylesheets::rule_parser::StyleParseErrorKind;
+use crate::values::generics::grid::{
+	GenericGridTemplateComponent, GenericImplicitGridTracks, GenericTrackList, GenericTrackSize,
+};
+use crate::values::{computed, CustomIdent};
+
+/// value = <track-size>
+pub type TrackSize = GenericTrackSize<NonNegativeLengthPercentage>;
+
+impl TrackSize {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		Self::parse_with(input, &NonNegativeLengthPercentage::parse)
+	}
+}
+
+/// value = <track-list> | <auto-track-list>
+pub type TrackList = GenericTrackList<NonNegativeLengthPercentage>;
+
+impl TrackList {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		Self::parse_with(input, &NonNegativeLengthPercentage::parse)
+	}
+}
+
+/// value = none | <track-list> | <auto-track-list>
+pub type GridTemplateComponent = GenericGridTemplateComponent<NonNegativeLengthPercentage>;
+
+impl GridTemplateComponent {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		Self::parse_with(input, &NonNegativeLengthPercentage::parse)
+	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::grid::GridTemplateComponent {
+		self.map(&|value| value.to_computed_value(context))
+	}
+}
+
+/// value = <track-size>+
+pub type ImplicitGridTracks = GenericImplicitGridTracks<NonNegativeLengthPercentage>;
+
+impl ImplicitGridTracks {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		Self::parse_with(input, &NonNegativeLengthPercentage::parse)
+	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::grid::ImplicitGridTracks {
+		self.map(&|value| value.to_computed_value(context))
+	}
+}
+
+/// https://drafts.csswg.org/css-grid/#typedef-grid-row-start-grid-line
+/// <grid-line> = auto | <custom-ident> |
+///               [ [ <integer [-∞,-1]> | <integer [1,∞]> ] && <custom-ident>? ] |
+///               [ span && [ <integer [1,∞]> || <custom-ident> ] ]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridLine {
+	pub ident: Option<CustomIdent>,
+	pub line_num: Option<i32>,
+	pub is_span: bool,
+}
+
+impl GridLine {
+	pub fn auto() -> Self {
+		Default::default()
+	}
+
+	pub fn is_auto(&self) -> bool {
+		self.ident.is_none() && self.line_num.is_none() && !self.is_span
+	}
+
+	/// The value of an omitted line in `grid-row`, `grid-column` and `grid-area`,
+	/// https://drafts.csswg.org/css-grid/#propdef-grid-area
+	pub fn omitted_counterpart(&self) -> Self {
+		if self.ident.is_some() && self.line_num.is_none() && !self.is_span {
+			self.clone()
+		} else {
+			GridLine::auto()
+		}
+	}
+
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+			return Ok(GridLine::auto());
+		}
+		let mut grid_line = GridLine::auto();
+		loop {
+			if !grid_line.is_span && input.try_parse(|input| input.expect_ident_matching("span")).is_ok() {
+				grid_line.is_span = true;
+				continue;
+			}
+			if grid_line.line_num.is_none() {
+				if let Ok(value) = input.try_parse(|input| input.expect_integer()) {
+					grid_line.line_num = Some(value);
+					continue;
+				}
+			}
+			if grid_line.ident.is_none() {
+				if let Ok(ident) = input.try_parse(|input| CustomIdent::parse_excluding(input, &["span", "auto"])) {
+					grid_line.ident = Some(ident);
+					continue;
+				}
+			}
+			break;
+		}
+		let valid = match grid_line.line_num {
+			Some(0) => false,
+			Some(value) if grid_line.is_span => value > 0,
+			Some(_) => true,
+			None => grid_line.ident.is_some(),
+		};
+		if !valid {
+			return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+		}
+		Ok(grid_line)
+	}
+}
+
+impl ToCss for GridLine {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		if self.is_auto() {
+			return dest.write_str("auto");
+		}
+		let mut values = vec![];
+		if self.is_span {
+			values.push("span".to_string());
+		}
+		if let Some(line_num) = self.line_num {
+			values.push(line_num.to_string());
+		}
+		if let Some(ident) = &self.ident {
+			values.push(ident.to_css_string());
+		}
+		dest.write_str(&values.join(" "))
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AutoFlow {
+	Row,
+	Column,
+}
+
+property_keywords_impl! { AutoFlow,
+	AutoFlow::Row, "row",
+	AutoFlow::Column, "column",
+}
+
+/// https://drafts.csswg.org/css-grid/#propdef-grid-auto-flow
+/// [ row | column ] || dense
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridAutoFlow {
+	pub auto_flow: AutoFlow,
+	pub dense: bool,
+}
+
+impl GridAutoFlow {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		let mut auto_flow = None;
+		let mut dense = false;
+		loop {
+			if auto_flow.is_none() {
+				if let Ok(value) = input.try_parse(AutoFlow::parse) {
+					auto_flow = Some(value);
+					continue;
+				}
+			}
+			if !dense && input.try_parse(|input| input.expect_ident_matching("dense")).is_ok() {
+				dense = true;
+				continue;
+			}
+			break;
+		}
+		if auto_flow.is_none() && !dense {
+			return Err(input.new_custom_error(StyleParseErrorKind::UnspecifiedError));
+		}
+		Ok(GridAutoFlow {
+			auto_flow: auto_flow.unwrap_or(AutoFlow::Row),
+			dense,
+		})
+	}
+}
+
+impl ToCss for GridAutoFlow {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		self.auto_flow.to_css(dest)?;
+		if self.dense {
+			dest.write_str(" dense")?;
+		}
+		Ok(())
+	}
+}
+
+/// A rectangle of cells named in `grid-template-areas`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedArea {
+	pub name: String,
+	pub rows: Range<u32>,
+	pub columns: Range<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateAreas {
+	pub strings: Vec<String>,
+	pub areas: Vec<NamedArea>,
+	pub width: u32,
+}
+
+impl TemplateAreas {
+	/// https://drafts.csswg.org/css-grid/#grid-template-areas-property
+	/// every string has the same number of cell tokens and each named area forms a filled-in rectangle
+	pub fn from_strings(strings: Vec<String>) -> Result<Self, ()> {
+		let mut areas: Vec<NamedArea> = vec![];
+		let mut width = None;
+		for (row, string) in strings.iter().enumerate() {
+			let row = row as u32;
+			let tokens = tokenize_area_string(string)?;
+			if *width.get_or_insert(tokens.len()) != tokens.len() || tokens.is_empty() {
+				return Err(());
+			}
+			let mut column = 0;
+			while column < tokens.len() {
+				let name = match &tokens[column] {
+					Some(name) => name,
+					None => {
+						column += 1;
+						continue;
+					},
+				};
+				let start = column;
+				while column < tokens.len() && tokens[column].as_ref() == Some(name) {
+					column += 1;
+				}
+				let columns = start as u32..column as u32;
+				match areas.iter_mut().find(|area| &area.name == name) {
+					Some(area) if area.columns == columns && area.rows.end == row => area.rows.end = row + 1,
+					Some(_) => return Err(()),
+					None => areas.push(NamedArea {
+						name: name.clone(),
+						rows: row..row + 1,
+						columns,
+					}),
+				}
+			}
+		}
+		Ok(TemplateAreas {
+			strings,
+			areas,
+			width: width.unwrap_or_default() as u32,
+		})
+	}
+}
+
+/// Split a row of `grid-template-areas` into named cell tokens and null cell tokens (`None`)
+fn tokenize_area_string(string: &str) -> Result<Vec<Option<String>>, ()> {
+	let is_name_code_point = |ch: char| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-' || !ch.is_ascii();
+	let mut tokens = vec![];
+	let mut chars = string.chars().peekable();
+	while let Some(&ch) = chars.peek() {
+		if ch.is_ascii_whitespace() {
+			chars.next();
+		} else if ch == '.' {
+			while chars.peek() == Some(&'.') {
+				chars.next();
+			}
+			tokens.push(None);
+		} else if is_name_code_point(ch) {
+			let mut name = String::new();
+			while let Some(&ch) = chars.peek().filter(|ch| is_name_code_point(**ch)) {
+				name.push(ch);
+				chars.next();
+			}
+			tokens.push(Some(name));
+		} else {
+			return Err(());
+		}
+	}
+	Ok(tokens)
+}
+
+/// https://drafts.csswg.org/css-grid/#propdef-grid-template-areas
+/// none | <string>+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridTemplateAreas {
+	None,
+	Areas(Box<TemplateAreas>),
+}
+
+impl GridTemplateAreas {
+	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+			return Ok(GridTemplateAreas::None);
+		}
+		let mut strings = vec![input.expect_string()?.as_ref().to_owned()];
+		while let Ok(string) = input.try_parse(|input| input.expect_string().map(|value| value.as_ref().to_owned())) {
+			strings.push(string);
+		}
+		TemplateAreas::from_strings(strings)
+			.map(|areas| GridTemplateAreas::Areas(Box::new(areas)))
+			.map_err(|_| input.new_custom_error(StyleParseErrorKind::UnspecifiedError))
+	}
+}
+
+impl ToCss for GridTemplateAreas {
+	fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+	where
+		W: std::fmt::Write,
+	{
+		match self {
+			GridTemplateAreas::None => dest.write_str("none"),
+			GridTemplateAreas::Areas(areas) => {
+				let strings: Vec<String> = areas.strings.iter().map(|string| std::format!("\"{}\"", string)).collect();
+				dest.write_str(&strings.join(" "))
+			},
+		}
+	}
+}
@@ -1,13 +1,16 @@
 use std::fmt::Write;
 
-use common::not_supported;
 use cssparser::{CowRcStr, Parser, ToCss, Token, _cssparser_internal_to_lowercase, match_ignore_ascii_case};
+use euclid::Size2D;
 use regex::Regex;
 
+use super::calc::CalcLengthPercentage;
 use super::number::NonNegativeNumber;
 use super::percentage::Percentage;
 use crate::computed_values::StyleContext;
+use crate::media_queries::device::Device;
 use crate::parser::ParseError;
+use crate::properties::longhands::font_size::DEFAULT_FONT_SIZE;
 use crate::stylesheets::rule_parser::StyleParseErrorKind;
 use crate::values::generics::length::{
 	GenericLengthOrAuto, GenericLengthOrNone, GenericLengthOrNumber, GenericLengthPercentageNumberOrAuto,
@@ -15,12 +18,13 @@ use crate::values::generics::length::{
 	GenericMaxSize, GenericRectOrAuto, GenericSize, Rect,
 };
 use crate::values::generics::number::NonNegative;
-use crate::values::{computed, generics, AllowedNumericType, CSSFloat};
+use crate::values::{computed, generics, AllowedNumericType, CSSFloat, CSSPixel, Pixel};
 
 /// <https://drafts.csswg.org/css-values/#lengths>
 #[derive(Clone, Debug, PartialEq)]
 pub enum Length {
 	NoCalc(NoCalcLength),
+	Calc(Box<CalcLengthPercentage>),
 }
 
 impl Length {
@@ -38,6 +42,7 @@ impl Length {
 		num_context: AllowedNumericType,
 	) -> Result<Self, ParseError<'i>> {
 		let location = input.current_source_location();
+		let state = input.state();
 		let token = input.next()?.clone();
 		match token {
 			Token::Dimension { value, ref unit, .. } if num_context.is_ok(value) => NoCalcLength::parse(unit, value)
@@ -49,6 +54,11 @@ impl Length {
 				}
 				Ok(Length::NoCalc(NoCalcLength::Absolute(AbsoluteLength::Px(value))))
 			},
+			Token::Function(_) => {
+				input.reset(&state);
+				let calc = CalcLengthPercentage::parse_length(input, num_context)?;
+				Ok(Length::Calc(Box::new(calc)))
+			},
 			ref t => return Err(location.new_unexpected_token_error(t.clone())),
 		}
 	}
@@ -57,13 +67,21 @@ impl Length {
 		Length::NoCalc(NoCalcLength::Absolute(AbsoluteLength::Px(0.0)))
 	}
 
-	pub fn to_computed_value(&self, _context: &StyleContext) -> CSSFloat {
+	pub fn to_computed_value(&self, context: &StyleContext) -> CSSFloat {
 		match self {
-			Length::NoCalc(value) => match value {
-				NoCalcLength::Absolute(absolute) => absolute.to_px(),
-				NoCalcLength::FontRelative(_) => not_supported!(),
-				NoCalcLength::ViewportPercentage(_) => not_supported!(),
-			},
+			Length::NoCalc(value) => value.to_computed_value(context),
+			// percentages are rejected while parsing, there is nothing to resolve against
+			Length::Calc(calc) => calc.to_computed_value(context).to_used_value(Pixel::new(0.0)).get(),
+		}
+	}
+
+	/// Lengths in media queries don't belong to any element, relative units are resolved
+	/// against the initial font size and the viewport.
+	/// https://drafts.csswg.org/mediaqueries/#units
+	pub fn to_computed_value_with_device(&self, device: &Device) -> CSSFloat {
+		match self {
+			Length::NoCalc(value) => value.to_computed_value_with_device(device),
+			Length::Calc(calc) => calc.to_computed_value_with_device(device),
 		}
 	}
 }
@@ -101,6 +119,7 @@ impl ToCss for Length {
 	{
 		match self {
 			Length::NoCalc(length) => length.to_css(dest),
+			Length::Calc(calc) => calc.to_css(dest),
 		}
 	}
 }
@@ -133,6 +152,29 @@ impl NoCalcLength {
 			_ => return Err(()),
 		})
 	}
+
+	/// Font-relative lengths are resolved against the font size of the element, which is already computed because
+	/// font properties cascade first, or the font size of the root element, and viewport-percentage lengths against
+	/// the viewport of the device.
+	pub fn to_computed_value(&self, context: &StyleContext) -> CSSFloat {
+		self.to_px(
+			context.computed_values.get_font_size(),
+			context.root_font_size,
+			context.device.viewport_size(),
+		)
+	}
+
+	pub fn to_computed_value_with_device(&self, device: &Device) -> CSSFloat {
+		self.to_px(DEFAULT_FONT_SIZE, DEFAULT_FONT_SIZE, device.viewport_size())
+	}
+
+	fn to_px(&self, font_size: CSSFloat, root_font_size: CSSFloat, viewport_size: Size2D<f32, CSSPixel>) -> CSSFloat {
+		match self {
+			NoCalcLength::Absolute(absolute) => absolute.to_px(),
+			NoCalcLength::FontRelative(relative) => relative.to_px(font_size, root_font_size),
+			NoCalcLength::ViewportPercentage(percentage) => percentage.to_px(viewport_size),
+		}
+	}
 }
 
 impl ToCss for NoCalcLength {
@@ -213,6 +255,18 @@ pub enum FontRelativeLength {
 	Rem(CSSFloat),
 }
 
+impl FontRelativeLength {
+	/// `ex` and `ch` fall back to 0.5em since font metrics aren't available.
+	/// https://drafts.csswg.org/css-values/#ex
+	pub fn to_px(&self, font_size: CSSFloat, root_font_size: CSSFloat) -> CSSFloat {
+		match self {
+			FontRelativeLength::Em(value) => value * font_size,
+			FontRelativeLength::Rem(value) => value * root_font_size,
+			FontRelativeLength::Ex(value) | FontRelativeLength::Ch(value) => value * font_size * 0.5,
+		}
+	}
+}
+
 impl ToCss for FontRelativeLength {
 	fn to_css<W>(&self, dest: &mut W) -> core::fmt::Result
 	where
@@ -241,6 +295,18 @@ pub enum ViewportPercentageLength {
 	Vmax(CSSFloat),
 }
 
+impl ViewportPercentageLength {
+	pub fn to_px(&self, viewport_size: Size2D<f32, CSSPixel>) -> CSSFloat {
+		let (value, basis) = match self {
+			ViewportPercentageLength::Vw(value) => (value, viewport_size.width),
+			ViewportPercentageLength::Vh(value) => (value, viewport_size.height),
+			ViewportPercentageLength::Vmin(value) => (value, viewport_size.width.min(viewport_size.height)),
+			ViewportPercentageLength::Vmax(value) => (value, viewport_size.width.max(viewport_size.height)),
+		};
+		value * basis / 100.0
+	}
+}
+
 impl ToCss for ViewportPercentageLength {
 	fn to_css<W>(&self, dest: &mut W) -> core::fmt::Result
 	where
@@ -262,6 +328,7 @@ impl ToCss for ViewportPercentageLength {
 pub enum LengthPercentage {
 	Length(Length),
 	Percentage(Percentage),
+	Calc(Box<CalcLengthPercentage>),
 }
 
 impl LengthPercentage {
@@ -279,8 +346,14 @@ impl LengthPercentage {
 	) -> Result<Self, ParseError<'i>> {
 		input
 			.try_parse(|input| {
-				let length = Length::parse_internal(input, num_context)?;
-				Ok(LengthPercentage::Length(length))
+				let calc = CalcLengthPercentage::parse_length_percentage(input, num_context)?;
+				Ok(LengthPercentage::Calc(Box::new(calc)))
+			})
+			.or_else(|_err: ParseError<'i>| {
+				input.try_parse(|input| {
+					let length = Length::parse_internal(input, num_context)?;
+					Ok(LengthPercentage::Length(length))
+				})
 			})
 			.or_else(|_err: ParseError<'i>| {
 				let percentage = Percentage::parse(input)?;
@@ -294,6 +367,13 @@ impl LengthPercentage {
 				computed::length::LengthPercentage::AbsoluteLength(value.to_computed_value(context))
 			},
 			LengthPercentage::Percentage(value) => computed::length::LengthPercentage::Percentage(value.clone()),
+			LengthPercentage::Calc(calc) => {
+				let calc = calc.to_computed_value(context);
+				match calc.to_fixed_used_value() {
+					Some(length) => computed::length::LengthPercentage::AbsoluteLength(length.get()),
+					None => computed::length::LengthPercentage::Calc(calc),
+				}
+			},
 		}
 	}
 
@@ -322,6 +402,7 @@ impl ToCss for LengthPercentage {
 		match self {
 			LengthPercentage::Length(value) => value.to_css(dest),
 			LengthPercentage::Percentage(value) => value.to_css(dest),
+			LengthPercentage::Calc(value) => value.to_css(dest),
 		}
 	}
 }
@@ -338,6 +419,10 @@ impl NonNegativeLength {
 		let length = Length::parse_non_negative(input)?;
 		Ok(Self(length))
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::length::NonNegativeLength {
+		NonNegative(self.0.to_computed_value(context))
+	}
 }
 
 impl From<&str> for NonNegativeLength {
@@ -380,6 +465,13 @@ impl NonNegativeLengthOrNone {
 	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
 		Self::parse_with(input, |input| NonNegativeLength::parse(input))
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::length::NonNegativeLengthOrNone {
+		match self {
+			GenericLengthOrNone::Length(value) => GenericLengthOrNone::Length(value.to_computed_value(context)),
+			GenericLengthOrNone::None => GenericLengthOrNone::None,
+		}
+	}
 }
 
 /// value = <length> | <percentage> | auto
@@ -414,6 +506,15 @@ impl NonNegativeLengthOrAuto {
 	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
 		Self::parse_with(input, |input| NonNegativeLength::parse(input))
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::length::NonNegativeLengthOrAuto {
+		match self {
+			GenericLengthPercentageOrAuto::LengthPercentage(value) => {
+				GenericLengthPercentageOrAuto::LengthPercentage(value.to_computed_value(context))
+			},
+			GenericLengthPercentageOrAuto::Auto => GenericLengthPercentageOrAuto::Auto,
+		}
+	}
 }
 
 /// value = <length [0, ∞]> | <percentage>
@@ -508,6 +609,15 @@ impl LengthPercentageOrNormal {
 	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
 		Self::parse_with(input, |input| LengthPercentage::parse(input))
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::length::LengthPercentageOrNormal {
+		match self {
+			GenericLengthPercentageOrNormal::LengthPercentage(value) => {
+				GenericLengthPercentageOrNormal::LengthPercentage(value.to_computed_value(context))
+			},
+			GenericLengthPercentageOrNormal::Normal => GenericLengthPercentageOrNormal::Normal,
+		}
+	}
 }
 
 pub type NonNegativeLengthPercentageOrNormal = GenericLengthPercentageOrNormal<NonNegativeLengthPercentage>;
@@ -516,6 +626,15 @@ impl NonNegativeLengthPercentageOrNormal {
 	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
 		Self::parse_with(input, |input| NonNegativeLengthPercentage::parse(input))
 	}
+
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::length::NonNegativeLengthPercentageOrNormal {
+		match self {
+			GenericLengthPercentageOrNormal::LengthPercentage(value) => {
+				GenericLengthPercentageOrNormal::LengthPercentage(value.to_computed_value(context))
+			},
+			GenericLengthPercentageOrNormal::Normal => GenericLengthPercentageOrNormal::Normal,
+		}
+	}
 }
 
 pub type NonNegativeLengthPercentageNumberOrNormal =
@@ -529,6 +648,21 @@ impl NonNegativeLengthPercentageNumberOrNormal {
 			|input| NonNegativeLengthPercentage::parse(input),
 		)
 	}
+
+	/// Percentages are relative to the font size of the element itself.
+	pub fn to_computed_value(&self, context: &StyleContext) -> computed::length::NonNegativeLengthNumberOrNormal {
+		match self {
+			GenericLengthPercentageNumberOrNormal::LengthPercentage(value) => {
+				let font_size = Pixel::new(context.computed_values.get_font_size());
+				let length = value.to_computed_value(context).to_used_value(font_size);
+				GenericLengthPercentageNumberOrNormal::LengthPercentage(NonNegative(length.get()))
+			},
+			GenericLengthPercentageNumberOrNormal::Number(value) => {
+				GenericLengthPercentageNumberOrNormal::Number(value.get())
+			},
+			GenericLengthPercentageNumberOrNormal::Normal => GenericLengthPercentageNumberOrNormal::Normal,
+		}
+	}
 }
 
 pub type NonNegativeLengthOrNumber = GenericLengthOrNumber<NonNegativeNumber, NonNegativeLength>;
@@ -561,7 +695,7 @@ impl LengthOrAutoRectAuto {
 	}
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Pair<T>(pub T, pub T);
 
 impl<T: Clone> Pair<T> {
@@ -1,8 +1,10 @@
 pub mod angle;
 pub mod border;
+pub mod calc;
 pub mod color;
 pub mod counter;
 pub mod easing;
+pub mod grid;
 pub mod image;
 pub mod layout;
 pub mod leader;
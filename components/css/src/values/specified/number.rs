@@ -11,7 +11,7 @@ use crate::stylesheets::stylesheet::ParserContext;
 use crate::values::generics::number::{
 	GenericNumberOrAuto, GenericNumberOrPercentage, GreaterThanOrEqualToOne, NonNegative,
 };
-use crate::values::CSSFloat;
+use crate::values::{computed, CSSFloat};
 
 /// https://www.w3.org/TR/css-values-4/#numbers
 #[derive(Clone, PartialEq, PartialOrd, Debug)]
@@ -225,6 +225,13 @@ impl IntegerAuto {
 	pub fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
 		Self::parse_with(input, |input| Integer::parse(context, input))
 	}
+
+	pub fn to_computed_value(&self) -> computed::number::IntegerAuto {
+		match self {
+			GenericNumberOrAuto::Number(value) => GenericNumberOrAuto::Number(value.get()),
+			GenericNumberOrAuto::Auto => GenericNumberOrAuto::Auto,
+		}
+	}
 }
 
 pub type NumberOrPercentage = GenericNumberOrPercentage<Number>;
@@ -234,6 +241,14 @@ impl NumberOrPercentage {
 		Self::parse_with(input, |input| Number::parse(input))
 	}
 
+	/// A percentage is turned into its number equivalent, 50% -> 0.5.
+	pub fn to_number(&self) -> CSSFloat {
+		match self {
+			GenericNumberOrPercentage::Number(value) => value.get(),
+			GenericNumberOrPercentage::Percentage(value) => value.to_value(&(0.0..1.0)),
+		}
+	}
+
 	pub fn parse_in_range<'i, 't>(
 		input: &mut Parser<'i, 't>,
 		number_range: &Range<f32>,
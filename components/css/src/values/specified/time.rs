@@ -30,6 +30,20 @@ pub struct Time {
 }
 
 impl Time {
+	pub fn zero() -> Self {
+		Time {
+			amount: 0.0,
+			unit: TimeUnit::Second,
+		}
+	}
+
+	pub fn seconds(&self) -> CSSFloat {
+		match self.unit {
+			TimeUnit::Second => self.amount,
+			TimeUnit::Millisecond => self.amount / 1000.0,
+		}
+	}
+
 	pub fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
 		macro_rules! return_unexpected_token {
 			($location:tt, $token:tt) => {
@@ -34,6 +34,14 @@ pub struct CssUrl {
 
 impl CssUrl {
 	pub fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i>> {
+		CssUrl::parse_with_base(input, None)
+	}
+
+	/// Parse a `<url>`, relative urls are resolved against `base`.
+	pub fn parse_with_base<'i, 't>(
+		input: &mut Parser<'i, 't>,
+		base: Option<&BrowserUrl>,
+	) -> Result<Self, ParseError<'i>> {
 		let location = input.current_source_location();
 		let token = input.next()?.clone();
 		let (name, value, modifiers) = match token {
@@ -49,8 +57,8 @@ impl CssUrl {
 			Token::UnquotedUrl(ref value) => ("url".to_string(), value.to_string(), vec![]),
 			_ => return Err(location.new_custom_error(StyleParseErrorKind::UnexpectedToken(token.clone()))),
 		};
-		let url =
-			BrowserUrl::parse(&value).map_err(|_err| input.new_custom_error(StyleParseErrorKind::UnspecifiedError))?;
+		let url = BrowserUrl::parse_with_base(base, &value)
+			.map_err(|_err| input.new_custom_error(StyleParseErrorKind::UnspecifiedError))?;
 		Ok(CssUrl {
 			original: std::format!(
 				"{}(\"{}\"{})",
@@ -83,6 +91,20 @@ impl CssUrl {
 		})
 	}
 
+	/// Parse the `<url> | <string>` of an @import, relative urls are resolved against `base`.
+	pub fn parse_import<'i, 't>(input: &mut Parser<'i, 't>, base: Option<&BrowserUrl>) -> Result<Self, ParseError<'i>> {
+		let value = input.expect_url_or_string()?.to_string();
+		Ok(CssUrl {
+			original: std::format!("url(\"{}\")", value),
+			resolved: BrowserUrl::parse_with_base(base, &value).ok(),
+			modifiers: vec![],
+		})
+	}
+
+	pub fn url(&self) -> Option<&BrowserUrl> {
+		self.resolved.as_ref()
+	}
+
 	pub fn parse_url_modifier<'i, 't>(input: &mut Parser<'i, 't>) -> Result<UrlModifier, ParseError<'i>> {
 		input
 			.try_parse(|input| {
@@ -0,0 +1,74 @@
+use setup::{assert_css, parse};
+
+mod setup;
+
+#[test]
+pub fn parse_custom_property() {
+	let css = r#"
+.name {
+	--primary: #fff;
+	--spacing: calc(4px * 2);
+	--font: "Helvetica Neue", sans-serif;
+	--empty: ;
+	--keyword: inherit;
+}
+    "#;
+	let output = r#"
+.name {
+	--primary: #fff;
+	--spacing: calc(4px * 2);
+	--font: "Helvetica Neue", sans-serif;
+	--empty: ;
+	--keyword: inherit;
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, output);
+}
+
+#[test]
+pub fn parse_longhand_with_var() {
+	let css = r#"
+.name {
+	width: var(--size);
+	color: var(--primary, rgb(0, 0, 0));
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, css);
+}
+
+#[test]
+pub fn parse_shorthand_with_var() {
+	let css = r#"
+.name {
+	margin: var(--vertical) 5px;
+}
+    "#;
+	let output = r#"
+.name {
+	margin-top: var(--vertical) 5px;
+	margin-right: var(--vertical) 5px;
+	margin-bottom: var(--vertical) 5px;
+	margin-left: var(--vertical) 5px;
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, output);
+}
+
+#[test]
+pub fn parse_invalid_var() {
+	let css = r#"
+.name {
+	width: var(size);
+	--unbalanced: 10px);
+}
+    "#;
+	let output = r#"
+.name {
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, output);
+}
@@ -0,0 +1,172 @@
+use std::rc::Rc;
+
+use common::url::BrowserUrl;
+use css::media_queries::media_list::MediaList;
+use css::stylesheets::css_rule::CssRule;
+use css::stylesheets::font_face_rule::{FontDisplay, FontWeightRange, Source};
+use css::stylesheets::origin::Origin;
+use css::stylesheets::stylesheet::Stylesheet;
+use css::stylesheets::stylesheet_loader::{FilesystemStylesheetLoader, ImportContext};
+use selectors::context::QuirksMode;
+use setup::{assert_css, parse, stylist};
+
+mod setup;
+
+#[test]
+pub fn parse_font_face() {
+	let css = r#"
+@font-face {
+	font-family: "Open Sans";
+	src: url("file:///fonts/OpenSans.woff2") format("woff2"), url("file:///fonts/OpenSans.ttf") format("truetype"), local(Arial);
+	font-weight: 100 900;
+	font-style: oblique 10deg 20deg;
+	unicode-range: U+0-FF, U+131;
+	font-display: swap;
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, css);
+}
+
+#[test]
+pub fn parse_font_weight_keywords() {
+	let css = r#"
+@font-face {
+	font-family: Lato;
+	font-weight: bold normal;
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	match &stylesheet.rules[0] {
+		CssRule::FontFace(font_face) => assert_eq!(font_face.weight, Some(FontWeightRange(400.0, 700.0))),
+		_ => unreachable!(),
+	}
+}
+
+#[test]
+pub fn parse_sources() {
+	let css = r#"
+@font-face {
+	font-family: Lato;
+	src: local("Lato Regular"), url("file:///fonts/Lato.otf") format(opentype, "collection");
+	font-display: optional;
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	let font_face = match &stylesheet.rules[0] {
+		CssRule::FontFace(font_face) => font_face,
+		_ => unreachable!(),
+	};
+	let sources = font_face.sources.as_ref().unwrap();
+	match &sources[0] {
+		Source::Local(name) => assert_eq!(name.name(), "Lato Regular"),
+		_ => unreachable!(),
+	}
+	match &sources[1] {
+		Source::Url(source) => assert_eq!(source.format_hints, vec!["opentype", "collection"]),
+		_ => unreachable!(),
+	}
+	assert_eq!(font_face.display, Some(FontDisplay::Optional));
+}
+
+#[test]
+pub fn relative_sources() {
+	let css = r#"
+@font-face {
+	font-family: Lato;
+	src: url(fonts/Lato.ttf), url("../Lato.otf");
+}
+    "#;
+	let import_context = ImportContext::new(
+		&FilesystemStylesheetLoader,
+		Some(BrowserUrl::parse("file:///styles/main.css").unwrap()),
+	);
+	let stylesheet = Stylesheet::from_str_with_loader(
+		css,
+		Origin::Author,
+		Rc::new(MediaList::empty()),
+		Some(&import_context),
+		None,
+		QuirksMode::NoQuirks,
+		0,
+	);
+	let font_face = match &stylesheet.rules[0] {
+		CssRule::FontFace(font_face) => font_face,
+		_ => unreachable!(),
+	};
+	let urls: Vec<&str> = font_face
+		.sources
+		.as_ref()
+		.unwrap()
+		.iter()
+		.map(|source| match source {
+			Source::Url(source) => source.url.url().unwrap().as_str(),
+			_ => unreachable!(),
+		})
+		.collect();
+	assert_eq!(urls, vec!["file:///styles/fonts/Lato.ttf", "file:///Lato.otf"]);
+}
+
+#[test]
+pub fn unicode_range() {
+	let css = r#"
+@font-face {
+	font-family: Lato;
+	unicode-range: U+4??, U+600-6FF;
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	let font_face = match &stylesheet.rules[0] {
+		CssRule::FontFace(font_face) => font_face,
+		_ => unreachable!(),
+	};
+	assert!(font_face.contains_char('\u{410}'));
+	assert!(font_face.contains_char('\u{627}'));
+	assert!(!font_face.contains_char('a'));
+}
+
+#[test]
+pub fn invalid_descriptors() {
+	let css = r#"
+@font-face {
+	font-family: Lato;
+	font-weight: 1200;
+	font-display: later;
+	font-size: 12px;
+}
+    "#;
+	let output = r#"
+@font-face {
+	font-family: Lato;
+}
+    "#;
+	let (stylesheet, error_reporter) = parse(css);
+	assert_css(&stylesheet, output);
+	assert_eq!(error_reporter.messages().len(), 3);
+}
+
+#[test]
+pub fn stylist_font_faces() {
+	let css = r#"
+@font-face {
+	font-family: Lato;
+	src: url("file:///fonts/Lato.ttf");
+}
+@font-face {
+	font-family: Missing;
+}
+@media (max-width: 600px) {
+	@font-face {
+		font-family: Small;
+		src: url("file:///fonts/Small.ttf");
+	}
+}
+"#;
+	let (stylesheet, _) = parse(css);
+	let stylist = stylist(&stylesheet);
+	let families: Vec<String> = stylist
+		.font_faces()
+		.map(|font_face| font_face.family.as_ref().unwrap().name())
+		.collect();
+	assert_eq!(families, vec!["Lato"]);
+}
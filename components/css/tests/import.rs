@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use common::url::BrowserUrl;
+use css::media_queries::media_list::MediaList;
+use css::stylesheets::css_rule::CssRule;
+use css::stylesheets::origin::Origin;
+use css::stylesheets::stylesheet::Stylesheet;
+use css::stylesheets::stylesheet_loader::{FilesystemStylesheetLoader, ImportContext, StylesheetLoader};
+use selectors::context::QuirksMode;
+use setup::{assert_css, parse, stylist, TestingErrorReporter};
+
+mod setup;
+
+struct TestingLoader(HashMap<&'static str, &'static str>);
+
+impl StylesheetLoader for TestingLoader {
+	fn load(&self, url: &BrowserUrl) -> Result<String, String> {
+		self.0
+			.get(url.as_str())
+			.map(|css| css.to_string())
+			.ok_or_else(|| "not found".to_string())
+	}
+}
+
+fn load(css: &str, files: &[(&'static str, &'static str)], error_reporter: &TestingErrorReporter) -> Stylesheet {
+	let loader = TestingLoader(files.iter().cloned().collect());
+	let import_context = ImportContext::new(&loader, Some(BrowserUrl::parse("file:///styles/main.css").unwrap()));
+	Stylesheet::from_str_with_loader(
+		css,
+		Origin::Author,
+		Rc::new(MediaList::empty()),
+		Some(&import_context),
+		Some(error_reporter),
+		QuirksMode::NoQuirks,
+		0,
+	)
+}
+
+fn count_author_rules(stylesheet: &Stylesheet) -> usize {
+	let stylist = stylist(stylesheet);
+	stylist.author_cascade_data().rules().len()
+}
+
+fn imported_stylesheet(stylesheet: &Stylesheet, index: usize) -> Option<Rc<Stylesheet>> {
+	match &stylesheet.rules[index] {
+		CssRule::Import(import_rule) => import_rule.stylesheet.clone(),
+		_ => unreachable!(),
+	}
+}
+
+#[test]
+pub fn parse_import() {
+	let css = r#"
+@import url("reset.css");
+@import url("theme.css") layer;
+@import url("base.css") layer(base.elements) supports(display: flex) screen and (min-width: 600px);
+@import url("print.css") supports((display: grid) and (not (display: inline-grid))) print;
+.name {
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, css);
+}
+
+#[test]
+pub fn parse_import_after_rules() {
+	let css = r#"
+.name {
+}
+@import url("reset.css");
+    "#;
+	let output = r#"
+.name {
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, output);
+}
+
+#[test]
+pub fn load_relative_import() {
+	let error_reporter = TestingErrorReporter::new();
+	let stylesheet = load(
+		r#"@import "parts/reset.css"; .main {}"#,
+		&[
+			(
+				"file:///styles/parts/reset.css",
+				r#"@import url("../base.css"); .reset {}"#,
+			),
+			("file:///styles/base.css", ".base {}"),
+		],
+		&error_reporter,
+	);
+	let reset = imported_stylesheet(&stylesheet, 0).unwrap();
+	assert_eq!(reset.source_url.as_deref(), Some("file:///styles/parts/reset.css"));
+	assert!(imported_stylesheet(&reset, 0).is_some());
+	assert!(error_reporter.messages().is_empty());
+	assert_eq!(count_author_rules(&stylesheet), 3);
+}
+
+#[test]
+pub fn import_relative_to_source_url() {
+	let error_reporter = TestingErrorReporter::new();
+	let stylesheet = load(
+		r#"/*# sourceURL=file:///themes/dark.css */ @import "colors.css"; .main {}"#,
+		&[("file:///themes/colors.css", ".colors {}")],
+		&error_reporter,
+	);
+	assert_eq!(stylesheet.source_url.as_deref(), Some("file:///themes/dark.css"));
+	assert!(imported_stylesheet(&stylesheet, 0).is_some());
+	assert!(error_reporter.messages().is_empty());
+}
+
+#[test]
+pub fn import_conditions() {
+	let error_reporter = TestingErrorReporter::new();
+	let stylesheet = load(
+		r#"
+@import "screen.css" screen;
+@import "print.css" print;
+@import "flex.css" supports(display: flex);
+@import "unknown.css" supports(display: unknown);
+"#,
+		&[
+			("file:///styles/screen.css", ".screen {}"),
+			("file:///styles/print.css", ".print {}"),
+			("file:///styles/flex.css", ".flex {}"),
+			("file:///styles/unknown.css", ".unknown {}"),
+		],
+		&error_reporter,
+	);
+	assert!(imported_stylesheet(&stylesheet, 1).is_some());
+	assert!(imported_stylesheet(&stylesheet, 3).is_none());
+	assert_eq!(count_author_rules(&stylesheet), 2);
+}
+
+#[test]
+pub fn import_cycle() {
+	let error_reporter = TestingErrorReporter::new();
+	let stylesheet = load(
+		r#"@import "a.css";"#,
+		&[
+			("file:///styles/a.css", r#"@import "b.css"; .a {}"#),
+			("file:///styles/b.css", r#"@import "a.css"; .b {}"#),
+		],
+		&error_reporter,
+	);
+	let a = imported_stylesheet(&stylesheet, 0).unwrap();
+	let b = imported_stylesheet(&a, 0).unwrap();
+	assert!(imported_stylesheet(&b, 0).is_none());
+	assert_eq!(
+		error_reporter.messages(),
+		vec!["Cyclic @import of 'file:///styles/a.css'".to_string()]
+	);
+	assert_eq!(count_author_rules(&stylesheet), 2);
+}
+
+#[test]
+pub fn import_load_failure() {
+	let error_reporter = TestingErrorReporter::new();
+	let stylesheet = load(r#"@import "missing.css"; .main {}"#, &[], &error_reporter);
+	assert!(imported_stylesheet(&stylesheet, 0).is_none());
+	assert_eq!(
+		error_reporter.messages(),
+		vec!["Failed to load @import 'file:///styles/missing.css': not found".to_string()]
+	);
+	assert_eq!(count_author_rules(&stylesheet), 1);
+}
+
+#[test]
+pub fn filesystem_loader() {
+	let directory = std::env::temp_dir().join("css-filesystem-loader");
+	std::fs::create_dir_all(&directory).unwrap();
+	std::fs::write(directory.join("main.css"), r#"@import "child.css"; .main {}"#).unwrap();
+	std::fs::write(directory.join("child.css"), ".child {}").unwrap();
+
+	let loader = FilesystemStylesheetLoader;
+	let url = BrowserUrl::from_file_path(directory.join("main.css")).unwrap();
+	let css = loader.load(&url).unwrap();
+	let import_context = ImportContext::new(&loader, Some(url));
+	let stylesheet = Stylesheet::from_str_with_loader(
+		&css,
+		Origin::Author,
+		Rc::new(MediaList::empty()),
+		Some(&import_context),
+		None,
+		QuirksMode::NoQuirks,
+		0,
+	);
+	assert_eq!(count_author_rules(&stylesheet), 2);
+}
+
+#[test]
+pub fn import_into_layer() {
+	let error_reporter = TestingErrorReporter::new();
+	let stylesheet = load(
+		r#"
+@layer theme, base;
+@import "base.css" layer(base);
+@import "print.css" layer(print) print;
+.main {}
+"#,
+		&[
+			("file:///styles/base.css", ".base {}"),
+			("file:///styles/print.css", ".print {}"),
+		],
+		&error_reporter,
+	);
+	let stylist = stylist(&stylesheet);
+	let cascade_data = stylist.author_cascade_data();
+	let layer_orders: Vec<u32> = cascade_data
+		.rules()
+		.iter()
+		.map(|rule| cascade_data.layer_order(rule.layer_id))
+		.collect();
+	assert_eq!(layer_orders, vec![1, 2]);
+	assert!(error_reporter.messages().is_empty());
+}
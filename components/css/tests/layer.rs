@@ -0,0 +1,154 @@
+use css::stylesheets::css_rule::CssRule;
+use setup::{assert_css, parse, stylist};
+
+mod setup;
+
+fn layer_orders(css: &str) -> Vec<u32> {
+	let (stylesheet, _) = parse(css);
+	let stylist = stylist(&stylesheet);
+	let cascade_data = stylist.author_cascade_data();
+	cascade_data
+		.rules()
+		.iter()
+		.map(|rule| cascade_data.layer_order(rule.layer_id))
+		.collect()
+}
+
+#[test]
+pub fn parse_layer_block() {
+	let css = r#"
+@layer base {
+}
+@layer framework.theme {
+}
+@layer {
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, css);
+}
+
+#[test]
+pub fn parse_layer_block_with_rules() {
+	let css = r#"
+@layer base {
+	p {
+		margin-top: 1px;
+	}
+	@layer reset {
+		div {
+			margin-top: 2px;
+		}
+	}
+}
+    "#;
+	let (stylesheet, error_reporter) = parse(css);
+	assert_css(&stylesheet, css);
+	assert!(error_reporter.messages().is_empty());
+}
+
+#[test]
+pub fn parse_layer_statement() {
+	let css = r#"
+@layer reset, base.typography;
+@layer theme;
+    "#;
+	let (stylesheet, error_reporter) = parse(css);
+	assert_css(&stylesheet, css);
+	assert!(error_reporter.messages().is_empty());
+	match &stylesheet.rules[1] {
+		CssRule::LayerStatement(statement) => assert_eq!(statement.names.len(), 1),
+		_ => unreachable!(),
+	}
+}
+
+#[test]
+pub fn parse_nested_layer_statement() {
+	let css = r#"
+@layer base {
+	@layer reset;
+	@media screen {
+		@layer print;
+	}
+}
+@layer theme"#;
+	let (stylesheet, error_reporter) = parse(css);
+	assert!(error_reporter.messages().is_empty());
+	let rules = match &stylesheet.rules[0] {
+		CssRule::LayerBlock(block) => &block.rules,
+		_ => unreachable!(),
+	};
+	assert!(matches!(rules[0], CssRule::LayerStatement(_)));
+	match &rules[1] {
+		CssRule::Media(media) => assert!(matches!(media.rules[0], CssRule::LayerStatement(_))),
+		_ => unreachable!(),
+	}
+	assert!(matches!(stylesheet.rules[1], CssRule::LayerStatement(_)));
+}
+
+#[test]
+pub fn layer_statement_before_import() {
+	let css = r#"
+@layer base;
+@import url("base.css") layer(base);
+    "#;
+	let (stylesheet, error_reporter) = parse(css);
+	assert_css(&stylesheet, css);
+	assert!(error_reporter.messages().is_empty());
+}
+
+#[test]
+pub fn invalid_layer_name() {
+	let css = r#"
+@layer initial {
+}
+@layer base. theme;
+    "#;
+	let (stylesheet, error_reporter) = parse(css);
+	assert!(stylesheet.rules.is_empty());
+	assert_eq!(error_reporter.messages().len(), 2);
+}
+
+#[test]
+pub fn layer_order_by_first_declaration() {
+	let css = r#"
+@layer theme, base;
+@layer base {
+	p { color: red; }
+}
+@layer theme {
+	p { color: blue; }
+}
+p { color: green; }
+"#;
+	assert_eq!(layer_orders(css), vec![1, 0, 2]);
+}
+
+#[test]
+pub fn nested_layers_before_parent() {
+	let css = r#"
+@layer framework {
+	p { color: red; }
+	@layer base {
+		p { color: blue; }
+	}
+}
+@layer framework.theme {
+	p { color: green; }
+}
+"#;
+	assert_eq!(layer_orders(css), vec![2, 0, 1]);
+}
+
+#[test]
+pub fn anonymous_layers_are_distinct() {
+	let css = r#"
+@layer {
+	p { color: red; }
+}
+@layer {
+	p { color: blue; }
+}
+"#;
+	assert_eq!(layer_orders(css), vec![0, 1]);
+}
@@ -1,7 +1,23 @@
-use setup::{assert_css, parse};
+use std::rc::Rc;
+
+use css::media_queries::media_list::MediaList;
+use css::stylesheets::css_rule::CssRule;
+use css::stylesheets::origin::Origin;
+use css::stylesheets::stylesheet::{ParserContext, Stylesheet};
+use cssparser::{Parser, ParserInput};
+use selectors::context::QuirksMode;
+use setup::{assert_css, device, parse, stylist};
 
 mod setup;
 
+fn evaluate(media: &str) -> bool {
+	let (stylesheet, _) = parse(&format!("@media {} {{}}", media));
+	match &stylesheet.rules[0] {
+		CssRule::Media(media_rule) => media_rule.media_queries.evaluate(&device()),
+		_ => unreachable!(),
+	}
+}
+
 #[test]
 pub fn parse_media_empty_all() {
 	let css = r#"
@@ -79,3 +95,81 @@ pub fn parse_media_multiple_queries() {
 "#,
 	);
 }
+
+#[test]
+pub fn evaluate_media_type() {
+	assert!(evaluate("all"));
+	assert!(evaluate("screen"));
+	assert!(evaluate("only screen"));
+	assert!(!evaluate("print"));
+	assert!(evaluate("not print"));
+	assert!(!evaluate("not all"));
+}
+
+#[test]
+pub fn evaluate_media_dimensions() {
+	assert!(evaluate("(min-width: 1000px)"));
+	assert!(!evaluate("(max-width: 600px)"));
+	assert!(evaluate("(width: 1200px)"));
+	assert!(evaluate("(width)"));
+	assert!(evaluate("(600px < width <= 1200px)"));
+	assert!(!evaluate("(height > 800px)"));
+	assert!(evaluate("(max-height: 50em)"));
+	assert!(evaluate("(min-width: calc(50vw + 400px))"));
+	assert!(evaluate("(min-device-width: 1440px)"));
+	assert!(evaluate("(aspect-ratio: 3/2)"));
+	assert!(evaluate("(orientation: landscape)"));
+	assert!(!evaluate("(orientation: portrait)"));
+}
+
+#[test]
+pub fn evaluate_media_device_features() {
+	assert!(evaluate("(min-resolution: 2dppx)"));
+	assert!(!evaluate("(min-resolution: 200dpi)"));
+	assert!(evaluate("(color)"));
+	assert!(!evaluate("(monochrome)"));
+	assert!(evaluate("(prefers-color-scheme: light)"));
+	assert!(!evaluate("(prefers-color-scheme: dark)"));
+	assert!(evaluate("(pointer: fine) and (hover: hover)"));
+	assert!(!evaluate("(any-pointer: coarse)"));
+	assert!(evaluate("(prefers-reduced-motion: no-preference)"));
+}
+
+#[test]
+pub fn evaluate_media_conditions() {
+	assert!(evaluate("screen and (min-width: 900px) and (hover)"));
+	assert!(evaluate("(max-width: 600px) or (orientation: landscape)"));
+	assert!(evaluate("(not (color: 1))"));
+	assert!(evaluate("(max-width: 600px), (min-height: 700px)"));
+	assert!(!evaluate("(unknown-feature)"));
+	assert!(!evaluate("(max-width: 600px), print"));
+}
+
+#[test]
+pub fn stylist_with_media() {
+	let css = r#"
+.a {}
+@media (min-width: 1000px) {
+	.b {}
+	@media (hover: none) {
+		.c {}
+	}
+}
+@media print {
+	.d {}
+}
+"#;
+	let (stylesheet, _) = parse(css);
+	let stylist = stylist(&stylesheet);
+	assert_eq!(stylist.author_cascade_data().rules().len(), 2);
+}
+
+#[test]
+pub fn stylist_with_stylesheet_media() {
+	let context = ParserContext::new(Origin::Author, None, QuirksMode::NoQuirks, None);
+	let mut input = ParserInput::new("print");
+	let media = MediaList::parse(&context, &mut Parser::new(&mut input));
+	let stylesheet = Stylesheet::from_str(".a {}", Origin::Author, Rc::new(media), None, QuirksMode::NoQuirks, 0);
+	let stylist = stylist(&stylesheet);
+	assert!(stylist.author_cascade_data().rules().is_empty());
+}
@@ -70,6 +70,9 @@ mod properties {
 	mod color;
 	mod column_count;
 	mod column_gap;
+	mod column_rule_color;
+	mod column_rule_style;
+	mod column_rule_width;
 	mod column_width;
 	mod content;
 	mod counter_increment;
@@ -92,6 +95,16 @@ mod properties {
 	mod font_style;
 	mod font_variant_caps;
 	mod font_weight;
+	mod grid_auto_columns;
+	mod grid_auto_flow;
+	mod grid_auto_rows;
+	mod grid_column_end;
+	mod grid_column_start;
+	mod grid_row_end;
+	mod grid_row_start;
+	mod grid_template_areas;
+	mod grid_template_columns;
+	mod grid_template_rows;
 	mod height;
 	mod image_rendering;
 	mod inline_size;
@@ -100,6 +113,8 @@ mod properties {
 	mod inset_inline_end;
 	mod inset_inline_start;
 	mod justify_content;
+	mod justify_items;
+	mod justify_self;
 	mod left;
 	mod letter_spacing;
 	mod line_height;
@@ -149,6 +164,7 @@ mod properties {
 	mod quotes;
 	mod right;
 	mod rotate;
+	mod row_gap;
 	mod scale;
 	mod table_layout;
 	mod text_align;
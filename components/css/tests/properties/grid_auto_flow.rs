@@ -0,0 +1,32 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, assert_property, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const TEMPLATE: &str = r#"
+.name {{
+	grid-auto-flow: {};
+}}"#;
+
+#[test]
+pub fn keyword() {
+	for value in ["row", "column", "row dense", "column dense"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn any_order() {
+	for (input, output) in [
+		("dense", "row dense"),
+		("dense column", "column dense"),
+	]
+	.iter()
+	{
+		assert_property(TEMPLATE, input, output);
+	}
+}
@@ -0,0 +1,28 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const TEMPLATE: &str = r#"
+.name {{
+	grid-auto-rows: {};
+}}"#;
+
+#[test]
+pub fn track_size() {
+	for value in ["auto", "100px", "min-content 1fr", "minmax(100px, auto) 25%"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn invalid() {
+	for value in ["none", "repeat(2, 1fr)"].iter() {
+		let (stylesheet, _) = parse(&TEMPLATE.format(&[&value]));
+		assert_css(&stylesheet, ".name {\n}");
+	}
+}
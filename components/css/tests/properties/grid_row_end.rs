@@ -0,0 +1,50 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, assert_property, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const TEMPLATE: &str = r#"
+.name {{
+	grid-row-end: {};
+}}"#;
+
+#[test]
+pub fn keyword() {
+	for value in ["auto"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn line() {
+	for value in ["1", "-1", "a", "2 a", "span 2", "span a", "span 2 a"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn any_order() {
+	for (input, output) in [
+		("a 2", "2 a"),
+		("a span", "span a"),
+		("2 span", "span 2"),
+	]
+	.iter()
+	{
+		assert_property(TEMPLATE, input, output);
+	}
+}
+
+#[test]
+pub fn invalid() {
+	for value in ["0", "span -1", "span", "a b"].iter() {
+		let (stylesheet, _) = parse(&TEMPLATE.format(&[&value]));
+		assert_css(&stylesheet, ".name {\n}");
+	}
+}
@@ -0,0 +1,46 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const TEMPLATE: &str = r#"
+.name {{
+	grid-template-areas: {};
+}}"#;
+
+#[test]
+pub fn keyword() {
+	for value in ["none"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn strings() {
+	for value in [r#""a a b" "c c b""#, r#""header header" "nav main" ". footer""#].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn null_cell_tokens() {
+	for value in [r#""a ... b""#, r#""a...b" "c . d""#].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn invalid() {
+	for value in [r#""a b" "c""#, r#""a b a""#, r#""a a" "a b""#, r#""a $""#, r#""""#].iter() {
+		let (stylesheet, _) = parse(&TEMPLATE.format(&[&value]));
+		assert_css(&stylesheet, ".name {\n}");
+	}
+}
@@ -0,0 +1,55 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const TEMPLATE: &str = r#"
+.name {{
+	grid-template-columns: {};
+}}"#;
+
+#[test]
+pub fn keyword() {
+	for value in ["none"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn track_size() {
+	for value in ["100px", "100px 1fr auto", "25% min-content max-content", "minmax(100px, 1fr) fit-content(50%)"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn line_names() {
+	for value in ["[a] 100px [b c] 1fr [d]", "[a] 1fr"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn repeat() {
+	for value in ["repeat(3, 1fr)", "repeat(2, [a] 100px [b])", "repeat(auto-fill, 100px) 1fr", "repeat(auto-fit, minmax(100px, 1fr))"].iter() {
+		let css = &TEMPLATE.format(&[&value]);
+		let (stylesheet, _) = parse(css);
+		assert_css(&stylesheet, css);
+	}
+}
+
+#[test]
+pub fn invalid() {
+	for value in ["-1fr", "repeat(0, 1fr)", "repeat(auto-fill, 1fr)", "repeat(auto-fill, 10px) repeat(auto-fit, 10px)", "minmax(1fr, 100px)"].iter() {
+		let (stylesheet, _) = parse(&TEMPLATE.format(&[&value]));
+		assert_css(&stylesheet, ".name {\n}");
+	}
+}
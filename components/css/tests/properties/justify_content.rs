@@ -13,12 +13,16 @@ const TEMPLATE: &str = r#"
 #[test]
 pub fn keyword() {
 	for value in [
+		"normal",
+		"start",
+		"end",
 		"flex-start",
 		"stretch",
 		"flex-end",
 		"center",
 		"space-between",
 		"space-around",
+		"space-evenly",
 	]
 	.iter()
 	{
@@ -0,0 +1,53 @@
+use html5ever::local_name;
+use selectors::context::QuirksMode;
+use setup::{parse, stylist};
+
+mod setup;
+
+fn candidates(css: &str, id: Option<&str>, classes: &[&str], local_name: html5ever::LocalName) -> Vec<u32> {
+	let (stylesheet, _) = parse(css);
+	let stylist = stylist(&stylesheet);
+	let classes: Vec<String> = classes.iter().map(|class| class.to_string()).collect();
+	let mut source_orders = Vec::new();
+	stylist
+		.author_cascade_data()
+		.rules()
+		.for_each_candidate(id, &classes, &local_name, QuirksMode::NoQuirks, |rule| {
+			source_orders.push(rule.source_order)
+		});
+	source_orders.sort_unstable();
+	source_orders
+}
+
+const CSS: &str = r#"
+#main { color: red; }
+div.card { color: red; }
+.card.active { color: red; }
+div p { color: red; }
+* { color: red; }
+:hover { color: red; }
+span { color: red; }
+"#;
+
+#[test]
+pub fn bucket_by_rightmost_compound() {
+	assert_eq!(candidates(CSS, None, &[], local_name!("p")), vec![3, 4, 5]);
+	assert_eq!(
+		candidates(CSS, Some("main"), &[], local_name!("span")),
+		vec![0, 4, 5, 6]
+	);
+	assert_eq!(
+		candidates(CSS, None, &["card", "active", "card"], local_name!("div")),
+		vec![1, 2, 4, 5]
+	);
+}
+
+#[test]
+pub fn rules_in_source_order() {
+	let (stylesheet, _) = parse(CSS);
+	let stylist = stylist(&stylesheet);
+	let rules = stylist.author_cascade_data().rules();
+	assert_eq!(rules.len(), 7);
+	let source_orders: Vec<u32> = rules.iter().map(|rule| rule.source_order).collect();
+	assert_eq!(source_orders, vec![0, 1, 2, 3, 4, 5, 6]);
+}
@@ -2,12 +2,16 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use css::error_reporting::{ContextualParseError, ParseErrorReporter};
+use css::media_queries::device::Device;
+use css::media_queries::media_features::{Hover, Pointer, PrefersColorScheme};
 use css::media_queries::media_list::MediaList;
 use css::parser::ParseError;
 use css::stylesheets::origin::Origin;
 use css::stylesheets::stylesheet::{ParserContext, Stylesheet};
+use css::stylist::Stylist;
 use cssparser::{Parser, ParserInput, SourceLocation, ToCss};
 use dyn_fmt::AsStrFormatExt;
+use euclid::Size2D;
 use selectors::context::QuirksMode;
 
 #[derive(Debug)]
@@ -27,6 +31,11 @@ impl TestingErrorReporter {
 			errors: RefCell::new(Vec::new()),
 		}
 	}
+
+	#[allow(dead_code)]
+	pub fn messages(&self) -> Vec<String> {
+		self.errors.borrow().iter().map(|error| error.message.clone()).collect()
+	}
 }
 
 impl ParseErrorReporter for TestingErrorReporter {
@@ -55,6 +64,27 @@ pub fn parse(css: &str) -> (Stylesheet, TestingErrorReporter) {
 	)
 }
 
+/// A 1200x800 viewport on a 1440x900 screen with a fine pointer which can hover.
+#[allow(dead_code)]
+pub fn device() -> Device {
+	Device::new(
+		Size2D::new(1200.0, 800.0),
+		Size2D::new(1440.0, 900.0),
+		2.0,
+		PrefersColorScheme::Light,
+		Pointer::Fine,
+		Hover::Hover,
+	)
+}
+
+/// A stylist for `device` with `stylesheet` as the only author stylesheet.
+#[allow(dead_code)]
+pub fn stylist(stylesheet: &Stylesheet) -> Stylist {
+	let mut stylist = Stylist::new(device(), QuirksMode::NoQuirks);
+	stylist.add_stylesheet(stylesheet, Origin::Author);
+	stylist
+}
+
 #[allow(dead_code)]
 pub fn parse_value<'i, F, T: ToCss>(text: &'i str, func: F) -> Result<String, ParseError<'i>>
 where
@@ -2,6 +2,16 @@ mod shorthands {
 	pub mod border_color;
 	pub mod border_style;
 	pub mod border_width;
+	pub mod column_rule;
+	pub mod columns;
+	pub mod flex;
+	pub mod flex_flow;
+	pub mod gap;
+	pub mod grid;
+	pub mod grid_area;
+	pub mod grid_column;
+	pub mod grid_row;
+	pub mod grid_template;
 	pub mod margin;
 	pub mod margin_block;
 	pub mod margin_inline;
@@ -0,0 +1,34 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	column-rule: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	column-rule-width: {};
+	column-rule-style: {};
+	column-rule-color: {};
+}}"#;
+
+#[test]
+pub fn any_order() {
+	for (input, output) in [
+		("1px solid red", ("1px", "solid", "rgb(255 0 0 / 1)")),
+		("dashed thick", ("thick", "dashed", "currentcolor")),
+		("blue", ("medium", "none", "rgb(0 0 255 / 1)")),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[&output.0, &output.1, &output.2]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
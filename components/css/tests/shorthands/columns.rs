@@ -0,0 +1,50 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	columns: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	column-width: {};
+	column-count: {};
+}}"#;
+
+#[test]
+pub fn one_value() {
+	for (input, output) in [
+		("10px", ["10px", "auto"]),
+		("3", ["auto", "3"]),
+		("auto", ["auto", "auto"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn two_values() {
+	for (input, output) in [
+		("12em 2", ["12em", "2"]),
+		("2 12em", ["12em", "2"]),
+		("auto 4", ["auto", "4"]),
+		("auto auto", ["auto", "auto"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
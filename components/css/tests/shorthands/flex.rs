@@ -0,0 +1,55 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	flex: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	flex-grow: {};
+	flex-shrink: {};
+	flex-basis: {};
+}}"#;
+
+#[test]
+pub fn keyword() {
+	for (input, output) in [("none", ("0", "0", "auto")), ("auto", ("1", "1", "auto"))].iter() {
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[&output.0, output.1, output.2]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn flex_factors() {
+	for (input, output) in [
+		("2", ("2", "1", "0px")),
+		("2 3", ("2", "3", "0px")),
+		("2 3 10px", ("2", "3", "10px")),
+		("1 30%", ("1", "1", "30%")),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[&output.0, output.1, output.2]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn flex_basis_first() {
+	for (input, output) in [("10px", ("1", "1", "10px")), ("content 2", ("2", "1", "content"))].iter() {
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[&output.0, output.1, output.2]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
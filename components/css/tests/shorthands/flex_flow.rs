@@ -0,0 +1,33 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	flex-flow: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	flex-direction: {};
+	flex-wrap: {};
+}}"#;
+
+#[test]
+pub fn any_order() {
+	for (input, output) in [
+		("column wrap", ("column", "wrap")),
+		("wrap-reverse row-reverse", ("row-reverse", "wrap-reverse")),
+		("wrap", ("row", "wrap")),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[&output.0, output.1]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
@@ -0,0 +1,47 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	gap: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	row-gap: {};
+	column-gap: {};
+}}"#;
+
+#[test]
+pub fn one_value() {
+	for (input, output) in [
+		("10px", ["10px", "10px"]),
+		("normal", ["normal", "normal"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn two_values() {
+	for (input, output) in [
+		("10px 20%", ["10px", "20%"]),
+		("normal 5px", ["normal", "5px"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
@@ -0,0 +1,67 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	grid: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	grid-template-rows: {};
+	grid-template-columns: {};
+	grid-template-areas: {};
+	grid-auto-rows: {};
+	grid-auto-columns: {};
+	grid-auto-flow: {};
+}}"#;
+
+#[test]
+pub fn template() {
+	for (input, output) in [
+		("none", ["none", "none", "none", "auto", "auto", "row"]),
+		("100px / 1fr 1fr", ["100px", "1fr 1fr", "none", "auto", "auto", "row"]),
+		(r#""a b" / 100px 1fr"#, ["auto", "100px 1fr", r#""a b""#, "auto", "auto", "row"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1], output[2], output[3], output[4], output[5]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn auto_flow_column() {
+	for (input, output) in [
+		("100px / auto-flow 50px", ["100px", "none", "none", "auto", "50px", "column"]),
+		("100px 1fr / dense auto-flow", ["100px 1fr", "none", "none", "auto", "auto", "column dense"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1], output[2], output[3], output[4], output[5]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn auto_flow_row() {
+	for (input, output) in [
+		("auto-flow / 1fr 1fr", ["none", "1fr 1fr", "none", "auto", "auto", "row"]),
+		("auto-flow dense 50px 100px / 1fr", ["none", "1fr", "none", "50px 100px", "auto", "row dense"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1], output[2], output[3], output[4], output[5]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
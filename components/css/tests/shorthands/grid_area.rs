@@ -0,0 +1,50 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	grid-area: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	grid-row-start: {};
+	grid-column-start: {};
+	grid-row-end: {};
+	grid-column-end: {};
+}}"#;
+
+#[test]
+pub fn ident() {
+	for (input, output) in [
+		("a", ["a", "a", "a", "a"]),
+		("a / b", ["a", "b", "a", "b"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1], output[2], output[3]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn lines() {
+	for (input, output) in [
+		("1 / 2", ["1", "2", "auto", "auto"]),
+		("1 / 2 / 3", ["1", "2", "3", "auto"]),
+		("1 / 2 / span 2 / a", ["1", "2", "span 2", "a"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1], output[2], output[3]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
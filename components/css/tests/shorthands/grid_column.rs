@@ -0,0 +1,49 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	grid-column: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	grid-column-start: {};
+	grid-column-end: {};
+}}"#;
+
+#[test]
+pub fn start_only() {
+	for (input, output) in [
+		("2", ["2", "auto"]),
+		("a", ["a", "a"]),
+		("span 2", ["span 2", "auto"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn start_and_end() {
+	for (input, output) in [
+		("1 / 3", ["1", "3"]),
+		("a / span 2", ["a", "span 2"]),
+		("auto / -1", ["auto", "-1"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
@@ -0,0 +1,63 @@
+use dyn_fmt::AsStrFormatExt;
+use setup::{assert_css, parse};
+
+#[macro_use]
+#[path = "../setup/mod.rs"]
+mod setup;
+
+const SHORTHAND: &str = r#"
+.name {{
+	grid-template: {};
+}}"#;
+
+const LONGHAND: &str = r#"
+.name {{
+	grid-template-rows: {};
+	grid-template-columns: {};
+	grid-template-areas: {};
+}}"#;
+
+#[test]
+pub fn keyword() {
+	for (input, output) in [
+		("none", ["none", "none", "none"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1], output[2]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn rows_and_columns() {
+	for (input, output) in [
+		("100px 1fr / repeat(3, 50px)", ["100px 1fr", "repeat(3, 50px)", "none"]),
+		("none / [a] 1fr", ["none", "[a] 1fr", "none"]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1], output[2]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
+
+#[test]
+pub fn areas() {
+	for (input, output) in [
+		(r#""a a" "b c""#, ["auto auto", "none", r#""a a" "b c""#]),
+		(r#""a a" 100px "b c" 1fr / 50px 1fr"#, ["100px 1fr", "50px 1fr", r#""a a" "b c""#]),
+		(r#"[top] "a" 100px [middle] [center] "b" [bottom]"#, ["[top] 100px [middle center] auto [bottom]", "none", r#""a" "b""#]),
+	]
+	.iter()
+	{
+		let cinput = &SHORTHAND.format(&[&input]);
+		let coutput = &LONGHAND.format(&[output[0], output[1], output[2]]);
+		let (stylesheet, _) = parse(cinput);
+		assert_css(&stylesheet, coutput);
+	}
+}
@@ -1,7 +1,16 @@
-use setup::{assert_css, parse};
+use css::stylesheets::css_rule::CssRule;
+use setup::{assert_css, parse, stylist};
 
 mod setup;
 
+fn evaluate(condition: &str) -> bool {
+	let (stylesheet, _) = parse(&format!("@supports {} {{}}", condition));
+	match &stylesheet.rules[0] {
+		CssRule::Supports(supports_rule) => supports_rule.enabled,
+		_ => unreachable!(),
+	}
+}
+
 #[test]
 pub fn parse_supports_not() {
 	let css = r#"
@@ -51,3 +60,53 @@ pub fn parse_supports_custom_property() {
 	let (stylesheet, _) = parse(css);
 	assert_css(&stylesheet, css);
 }
+
+#[test]
+pub fn parse_supports_selector() {
+	let css = r#"
+@supports selector(a > b) or (not selector(:unknown)) {
+}
+    "#;
+	let (stylesheet, _) = parse(css);
+	assert_css(&stylesheet, css);
+}
+
+#[test]
+pub fn evaluate_supports() {
+	assert!(evaluate("(display: flex)"));
+	assert!(evaluate("(width: calc(100% - 10px)) and (color: red !important)"));
+	assert!(evaluate("(--foo: green)"));
+	assert!(evaluate("(color: var(--primary))"));
+	assert!(!evaluate("(display: unknown)"));
+	assert!(!evaluate("(unknown-property: 10px)"));
+	assert!(!evaluate("(width: 10px 10px)"));
+	assert!(evaluate("not (display: unknown)"));
+	assert!(evaluate("(display: unknown) or (display: block)"));
+	assert!(!evaluate("(display: block) and (display: unknown)"));
+	assert!(!evaluate("unknown(display: block)"));
+}
+
+#[test]
+pub fn evaluate_supports_selector() {
+	assert!(evaluate("selector(div > .name:hover)"));
+	assert!(!evaluate("selector(:unknown-pseudo)"));
+	assert!(!evaluate("selector(a, b)"));
+}
+
+#[test]
+pub fn stylist_with_supports() {
+	let css = r#"
+@supports (display: block) {
+	.a {}
+	@supports not (display: block) {
+		.b {}
+	}
+}
+@supports (display: unknown) {
+	.c {}
+}
+"#;
+	let (stylesheet, _) = parse(css);
+	let stylist = stylist(&stylesheet);
+	assert_eq!(stylist.author_cascade_data().rules().len(), 1);
+}
@@ -84,6 +84,26 @@ pub fn non_negative_length_percentage_number_or_normal_data() -> Vec<(String, St
 	length
 }
 
+pub fn calc_length_percentage_data() -> Vec<(String, String)> {
+	let mut data = Vec::with_capacity(1);
+	for value in [
+		"calc(100% - 20px)",
+		"calc(2 * 10px)",
+		"calc((100% - 10px) / 2)",
+		"calc(1em + 2px - 5%)",
+		"min(10px, 5%)",
+		"max(10px, 5%, 2em)",
+		"clamp(10px, 50% - 2em, 400px)",
+	]
+	.iter()
+	{
+		data.push((value.to_string(), value.to_string()));
+	}
+	data.push(("calc( 100%   -  20px )".to_string(), "calc(100% - 20px)".to_string()));
+	data.push(("CALC(10px + calc(5%))".to_string(), "calc(10px + 5%)".to_string()));
+	data
+}
+
 pub fn size_data() -> Vec<(String, String)> {
 	let mut data = length_percentage_data();
 	for value in [
@@ -97,6 +117,7 @@ pub fn size_data() -> Vec<(String, String)> {
 	{
 		data.push((value.to_string(), value.to_string()));
 	}
+	data.append(&mut calc_length_percentage_data());
 	data
 }
 
@@ -113,6 +134,7 @@ pub fn max_size_data() -> Vec<(String, String)> {
 	{
 		data.push((value.to_string(), value.to_string()));
 	}
+	data.append(&mut calc_length_percentage_data());
 	data
 }
 
@@ -27,6 +27,12 @@ use crate::htmlheadingelement::{HTMLHeadingElement, HeadingLevel};
 use crate::htmlhtmlelement::HTMLHtmlElement;
 use crate::htmlparagraphelement::HTMLParagraphElement;
 use crate::htmlspanelement::HTMLSpanElement;
+use crate::htmltablecaptionelement::HTMLTableCaptionElement;
+use crate::htmltablecellelement::HTMLTableCellElement;
+use crate::htmltablecolelement::HTMLTableColElement;
+use crate::htmltableelement::HTMLTableElement;
+use crate::htmltablerowelement::HTMLTableRowElement;
+use crate::htmltablesectionelement::HTMLTableSectionElement;
 use crate::htmlunknownelement::HTMLUnknownElement;
 use crate::inheritance::{downcast, upcast, Castable};
 use crate::node::Node;
@@ -283,6 +289,9 @@ fn create_native_html_element(name: QualName, prefix: Option<Prefix>, document:
 	match name.local {
 		local_name!("b") => make_element!(HTMLElement, name.local, prefix, document),
 		local_name!("body") => make_element!(HTMLBodyElement, name.local, prefix, document),
+		local_name!("caption") => make_element!(HTMLTableCaptionElement, name.local, prefix, document),
+		local_name!("col") => make_element!(HTMLTableColElement, name.local, prefix, document),
+		local_name!("colgroup") => make_element!(HTMLTableColElement, name.local, prefix, document),
 		local_name!("div") => make_element!(HTMLDivElement, name.local, prefix, document),
 		local_name!("footer") => make_element!(HTMLElement, name.local, prefix, document),
 		local_name!("h1") => make_element!(HTMLHeadingElement, name.local, prefix, document, HeadingLevel::Heading1),
@@ -296,6 +305,13 @@ fn create_native_html_element(name: QualName, prefix: Option<Prefix>, document:
 		local_name!("html") => make_element!(HTMLHtmlElement, name.local, prefix, document),
 		local_name!("span") => make_element!(HTMLSpanElement, name.local, prefix, document),
 		local_name!("strong") => make_element!(HTMLElement, name.local, prefix, document),
+		local_name!("table") => make_element!(HTMLTableElement, name.local, prefix, document),
+		local_name!("tbody") => make_element!(HTMLTableSectionElement, name.local, prefix, document),
+		local_name!("td") => make_element!(HTMLTableCellElement, name.local, prefix, document),
+		local_name!("tfoot") => make_element!(HTMLTableSectionElement, name.local, prefix, document),
+		local_name!("th") => make_element!(HTMLTableCellElement, name.local, prefix, document),
+		local_name!("thead") => make_element!(HTMLTableSectionElement, name.local, prefix, document),
+		local_name!("tr") => make_element!(HTMLTableRowElement, name.local, prefix, document),
 		local_name!("p") => make_element!(HTMLParagraphElement, name.local, prefix, document),
 		_ if is_valid_custom_element_name(&*name.local) => {
 			make_element!(HTMLElement, name.local, prefix, document)
@@ -0,0 +1,78 @@
+use std::rc::Rc;
+
+use html5ever::{local_name, namespace_url, ns, LocalName, Prefix};
+
+use crate::attr::AttrValue;
+use crate::document::Document;
+use crate::element::Element;
+use crate::htmlelement::HTMLElement;
+use crate::inheritance::{Castable, DerivedFrom};
+use crate::node::Node;
+use crate::nodetype::{ElementTypeId, HTMLElementTypeId, NodeTypeId};
+use crate::virtualmethods::VirtualMethods;
+
+const MAX_COLSPAN: u32 = 1_000;
+const MAX_ROWSPAN: u32 = 65_534;
+
+#[derive(Clone)]
+#[repr(C)]
+pub struct HTMLTableCellElement {
+	htmlelement: HTMLElement,
+}
+
+impl Castable for HTMLTableCellElement {}
+impl DerivedFrom<Node> for HTMLTableCellElement {}
+impl DerivedFrom<Element> for HTMLTableCellElement {}
+impl DerivedFrom<HTMLElement> for HTMLTableCellElement {}
+
+impl HTMLTableCellElement {
+	pub fn new(local_name: LocalName, prefix: Option<Prefix>, document: Rc<Document>) -> Self {
+		Self {
+			htmlelement: HTMLElement::new_inherited(
+				NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLTableCellElement)),
+				local_name,
+				prefix,
+				document,
+			),
+		}
+	}
+
+	/// <https://html.spec.whatwg.org/multipage/#dom-tdth-colspan>
+	pub fn col_span(&self) -> u32 {
+		match self.get_uint_attribute(&local_name!("colspan")) {
+			Some(0) | None => 1,
+			Some(value) => value.min(MAX_COLSPAN),
+		}
+	}
+
+	/// Zero means the cell spans the remaining rows of its row group.
+	/// <https://html.spec.whatwg.org/multipage/#dom-tdth-rowspan>
+	pub fn row_span(&self) -> u32 {
+		match self.get_uint_attribute(&local_name!("rowspan")) {
+			Some(value) => value.min(MAX_ROWSPAN),
+			None => 1,
+		}
+	}
+
+	fn get_uint_attribute(&self, local_name: &LocalName) -> Option<u32> {
+		let attr = self.upcast::<Element>().get_attribute(&ns!(), local_name)?;
+		let value = attr.value();
+		match *value {
+			AttrValue::UInt(_, value) => Some(value),
+			_ => None,
+		}
+	}
+}
+
+impl VirtualMethods for HTMLTableCellElement {
+	fn super_type(&self) -> Option<&dyn VirtualMethods> {
+		Some(self.upcast::<HTMLElement>() as &dyn VirtualMethods)
+	}
+
+	fn parse_plain_attribute(&self, name: &LocalName, value: String) -> AttrValue {
+		match *name {
+			local_name!("colspan") | local_name!("rowspan") => AttrValue::from_u32(value.into(), 1),
+			_ => self.super_type().unwrap().parse_plain_attribute(name, value),
+		}
+	}
+}
@@ -0,0 +1,66 @@
+use std::rc::Rc;
+
+use html5ever::{local_name, namespace_url, ns, LocalName, Prefix};
+
+use crate::attr::AttrValue;
+use crate::document::Document;
+use crate::element::Element;
+use crate::htmlelement::HTMLElement;
+use crate::inheritance::{Castable, DerivedFrom};
+use crate::node::Node;
+use crate::nodetype::{ElementTypeId, HTMLElementTypeId, NodeTypeId};
+use crate::virtualmethods::VirtualMethods;
+
+const MAX_SPAN: u32 = 1_000;
+
+/// `col` and `colgroup` elements.
+#[derive(Clone)]
+#[repr(C)]
+pub struct HTMLTableColElement {
+	htmlelement: HTMLElement,
+}
+
+impl Castable for HTMLTableColElement {}
+impl DerivedFrom<Node> for HTMLTableColElement {}
+impl DerivedFrom<Element> for HTMLTableColElement {}
+impl DerivedFrom<HTMLElement> for HTMLTableColElement {}
+
+impl HTMLTableColElement {
+	pub fn new(local_name: LocalName, prefix: Option<Prefix>, document: Rc<Document>) -> Self {
+		Self {
+			htmlelement: HTMLElement::new_inherited(
+				NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLTableColElement)),
+				local_name,
+				prefix,
+				document,
+			),
+		}
+	}
+
+	/// <https://html.spec.whatwg.org/multipage/#dom-colgroup-span>
+	pub fn span(&self) -> u32 {
+		let attr = match self.upcast::<Element>().get_attribute(&ns!(), &local_name!("span")) {
+			Some(attr) => attr,
+			None => return 1,
+		};
+		let value = attr.value();
+		match *value {
+			AttrValue::UInt(_, 0) => 1,
+			AttrValue::UInt(_, value) => value.min(MAX_SPAN),
+			_ => 1,
+		}
+	}
+}
+
+impl VirtualMethods for HTMLTableColElement {
+	fn super_type(&self) -> Option<&dyn VirtualMethods> {
+		Some(self.upcast::<HTMLElement>() as &dyn VirtualMethods)
+	}
+
+	fn parse_plain_attribute(&self, name: &LocalName, value: String) -> AttrValue {
+		match *name {
+			local_name!("span") => AttrValue::from_u32(value.into(), 1),
+			_ => self.super_type().unwrap().parse_plain_attribute(name, value),
+		}
+	}
+}
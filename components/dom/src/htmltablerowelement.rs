@@ -0,0 +1,34 @@
+use std::rc::Rc;
+
+use html5ever::{LocalName, Prefix};
+
+use crate::document::Document;
+use crate::element::Element;
+use crate::htmlelement::HTMLElement;
+use crate::inheritance::{Castable, DerivedFrom};
+use crate::node::Node;
+use crate::nodetype::{ElementTypeId, HTMLElementTypeId, NodeTypeId};
+
+#[derive(Clone)]
+#[repr(C)]
+pub struct HTMLTableRowElement {
+	htmlelement: HTMLElement,
+}
+
+impl HTMLTableRowElement {
+	pub fn new(local_name: LocalName, prefix: Option<Prefix>, document: Rc<Document>) -> Self {
+		Self {
+			htmlelement: HTMLElement::new_inherited(
+				NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLTableRowElement)),
+				local_name,
+				prefix,
+				document,
+			),
+		}
+	}
+}
+
+impl Castable for HTMLTableRowElement {}
+impl DerivedFrom<Node> for HTMLTableRowElement {}
+impl DerivedFrom<Element> for HTMLTableRowElement {}
+impl DerivedFrom<HTMLElement> for HTMLTableRowElement {}
@@ -18,6 +18,12 @@ pub mod htmlheadingelement;
 pub mod htmlhtmlelement;
 pub mod htmlparagraphelement;
 pub mod htmlspanelement;
+pub mod htmltablecaptionelement;
+pub mod htmltablecellelement;
+pub mod htmltablecolelement;
+pub mod htmltableelement;
+pub mod htmltablerowelement;
+pub mod htmltablesectionelement;
 pub mod htmlunknownelement;
 pub mod inheritance;
 pub mod node;
@@ -201,16 +201,8 @@ fn insert(parent: &Rc<Node>, reference_child: Option<Rc<Node>>, child: NodeOrTex
 			parent.insert_before(n, reference_child).unwrap();
 		},
 		NodeOrText::AppendText(t) => {
-			let content = t.trim_matches(|c| c == ' ' || c == '\t' || c == '\n');
-			// skip segment which only contains space, tab and newline
-			// since we don't want something like below (newline and spaces before span and newline after span)
-			// is created as text node
-			// <div>
-			//   <span>hello world</span>
-			// <div>
-			if content.len() == 0 {
-				return;
-			}
+			// the text is kept as it is, white space is processed in layout
+			// https://drafts.csswg.org/css-text/#white-space-processing
 			// https://html.spec.whatwg.org/multipage/#insert-a-character
 			let node = reference_child
 				.clone()
@@ -219,10 +211,10 @@ fn insert(parent: &Rc<Node>, reference_child: Option<Rc<Node>>, child: NodeOrTex
 
 			match node {
 				Some(node) if node.node_type_id().is_character_data_text() => {
-					node.downcast::<CharacterData>().append_data(&content)
+					node.downcast::<CharacterData>().append_data(&t)
 				},
 				_ => {
-					let text = Text::create(content.to_string(), parent.owner_doc().unwrap());
+					let text = Text::create(t.to_string(), parent.owner_doc().unwrap());
 					parent.insert_before(upcast(text), reference_child).unwrap();
 				},
 			}
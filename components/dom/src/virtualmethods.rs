@@ -7,6 +7,8 @@ use crate::document::Document;
 use crate::element::{AttributeMutation, Element};
 use crate::htmlbodyelement::HTMLBodyElement;
 use crate::htmlelement::HTMLElement;
+use crate::htmltablecellelement::HTMLTableCellElement;
+use crate::htmltablecolelement::HTMLTableColElement;
 use crate::inheritance::Castable;
 use crate::node::Node;
 use crate::nodetype::{ElementTypeId, HTMLElementTypeId, NodeTypeId, SVGElementTypeId, SVGGraphicsElementTypeId};
@@ -73,6 +75,12 @@ pub fn vtable_for(node: &Node) -> &dyn VirtualMethods {
 		NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLBodyElement)) => {
 			node.downcast::<HTMLBodyElement>() as &dyn VirtualMethods
 		},
+		NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLTableCellElement)) => {
+			node.downcast::<HTMLTableCellElement>() as &dyn VirtualMethods
+		},
+		NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLTableColElement)) => {
+			node.downcast::<HTMLTableColElement>() as &dyn VirtualMethods
+		},
 		NodeTypeId::Element(ElementTypeId::SVGElement(SVGElementTypeId::SVGGraphicsElement(
 			SVGGraphicsElementTypeId::SVGSVGElement,
 		))) => node.downcast::<SVGSVGElement>() as &dyn VirtualMethods,
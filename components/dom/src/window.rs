@@ -1,7 +1,9 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::{Rc, Weak};
 
 use css::error_reporting::{ContextualParseError, ParseErrorReporter};
+use css::media_queries::device::Device;
+use css::media_queries::media_features::{Hover, Pointer, PrefersColorScheme};
 use css::values::CSSPixel;
 use cssparser::SourceLocation;
 use euclid::{Length, Point2D, Rect, Scale, Size2D};
@@ -19,7 +21,10 @@ pub struct Window {
 	error_reporter: CSSErrorReporter,
 	document: Weak<Document>,
 	window_size: WindowSize,
-	viewport: Rect<f32, Pixel>,
+	viewport: Cell<Rect<f32, Pixel>>,
+	color_scheme: PrefersColorScheme,
+	pointer: Pointer,
+	hover: Hover,
 }
 
 impl Window {
@@ -28,7 +33,13 @@ impl Window {
 			error_reporter,
 			document: Rc::downgrade(&document),
 			window_size: WindowSize::new(DEFAULT_WIDTH, DEFAULT_HEIGHT, DEFAULT_RATIO),
-			viewport: Rect::new(Point2D::new(0.0, 0.0), Size2D::new(DEFAULT_WIDTH, DEFAULT_HEIGHT)),
+			viewport: Cell::new(Rect::new(
+				Point2D::new(0.0, 0.0),
+				Size2D::new(DEFAULT_WIDTH, DEFAULT_HEIGHT),
+			)),
+			color_scheme: PrefersColorScheme::Light,
+			pointer: Pointer::Fine,
+			hover: Hover::Hover,
 		}
 	}
 
@@ -36,8 +47,28 @@ impl Window {
 		&self.error_reporter
 	}
 
-	pub fn viewport(&self) -> &Rect<f32, Pixel> {
-		&self.viewport
+	/// The origin is the scroll position of the document.
+	pub fn viewport(&self) -> Rect<f32, Pixel> {
+		self.viewport.get()
+	}
+
+	/// https://drafts.csswg.org/cssom-view/#dom-window-scrollto
+	pub fn scroll_to(&self, x: f32, y: f32) {
+		let mut viewport = self.viewport.get();
+		viewport.origin = Point2D::new(x.max(0.0), y.max(0.0));
+		self.viewport.set(viewport);
+	}
+
+	/// The device media queries are evaluated against.
+	pub fn device(&self) -> Device {
+		Device::new(
+			self.viewport.get().size.cast_unit(),
+			self.window_size.initial_viewport,
+			self.window_size.device_pixel_ratio.get(),
+			self.color_scheme.clone(),
+			self.pointer.clone(),
+			self.hover.clone(),
+		)
 	}
 }
 
@@ -1,6 +1,7 @@
+use css::computed_values::CascadePriority;
 use css::properties::declaration_block::PropertyDeclarationBlock;
 use css::properties::SelectorSpecificity;
-use css::stylesheets::origin::Origin;
+use css::stylesheets::origin::{CascadeLevel, Origin};
 use css::stylesheets::style_rule::StyleRule;
 use css::stylist::Rule;
 
@@ -9,14 +10,20 @@ pub struct ApplicableDeclarationBlock {
 	pub source: StyleSource,
 	pub specificity: u32,
 	pub origin: Origin,
+	/// The order of appearance of the rule in its origin.
+	pub source_order: u32,
+	/// The order of the rule's cascade layer in its origin, see `CascadeData::layer_order`.
+	pub layer_order: u32,
 }
 
 impl ApplicableDeclarationBlock {
-	pub fn from_rule(rule: &Rule, origin: Origin) -> Self {
+	pub fn from_rule(rule: &Rule, origin: Origin, layer_order: u32) -> Self {
 		Self {
 			origin,
 			specificity: rule.selector.specificity(),
 			source: StyleSource::StyleRule(rule.style_rule.clone()),
+			source_order: rule.source_order,
+			layer_order,
 		}
 	}
 
@@ -25,6 +32,30 @@ impl ApplicableDeclarationBlock {
 			origin: Origin::Author,
 			specificity: SelectorSpecificity::STYLE.bits(),
 			source: StyleSource::DeclarationBlock(declaration.clone()),
+			source_order: 0,
+			layer_order: 0,
+		}
+	}
+
+	pub fn block(&self) -> &PropertyDeclarationBlock {
+		match &self.source {
+			StyleSource::StyleRule(style) => &style.block,
+			StyleSource::DeclarationBlock(block) => block,
+		}
+	}
+
+	pub fn priority(&self, importance: bool) -> CascadePriority {
+		CascadePriority {
+			level: CascadeLevel::new(self.origin, importance),
+			style_attribute: matches!(self.source, StyleSource::DeclarationBlock(_)),
+			// important declarations in earlier layers win
+			layer_order: if importance {
+				u32::MAX - self.layer_order
+			} else {
+				self.layer_order
+			},
+			specificity: self.specificity,
+			source_order: self.source_order,
 		}
 	}
 }
@@ -7,6 +7,7 @@ use css::values::{CSSFloat, Pixel, PIXEL_ZERO};
 use super::display_item::{DisplayItem, LayoutRect, RectangleDisplayItem, TextDisplayItem};
 use crate::flow::fragment::{Fragment, FragmentClass, Line};
 use crate::flow::tree::BoxTree;
+use crate::text::ShapedText;
 
 #[derive(Debug)]
 pub struct BuilderContext {
@@ -32,10 +33,20 @@ impl DisplayListBuilder {
 			y: PIXEL_ZERO,
 		};
 		builder.construct_fragment(box_tree.root.as_block_level_box().fragment(), &mut context);
+		// fixed positioned boxes and absolutely positioned boxes without a positioned ancestor
+		let initial_containing_block = box_tree.initial_containing_block.as_block_level_box().fragment();
+		for fragment in initial_containing_block.out_of_flow_children.iter() {
+			builder.construct_fragment(fragment.borrow(), &mut context);
+		}
 		builder
 	}
 
 	fn construct_fragment(&mut self, fragment: Ref<dyn Fragment>, context: &mut BuilderContext) {
+		let offset = fragment.offset();
+		let context = &mut BuilderContext {
+			x: context.x + offset.x,
+			y: context.y + offset.y,
+		};
 		fragment.build_display_list(self, context);
 		let mut child_context = BuilderContext {
 			x: context.x + fragment.rect_x(),
@@ -45,6 +56,9 @@ impl DisplayListBuilder {
 			FragmentClass::BoxFragment => {
 				let box_fragment = fragment.as_box_fragment();
 				self.construct_children(&mut child_context, box_fragment.lines.borrow(), &box_fragment.children);
+				for out_of_flow_fragment in box_fragment.out_of_flow_children.iter() {
+					self.construct_fragment(out_of_flow_fragment.borrow(), &mut child_context);
+				}
 			},
 			FragmentClass::TextFragment => {
 				fragment.as_text_fragment().build_display_list(self, context);
@@ -69,7 +83,7 @@ impl DisplayListBuilder {
 		if lines.len() > 0 {
 			for line in lines.iter() {
 				let mut line_context = BuilderContext {
-					x: context.x,
+					x: context.x + line.x(),
 					y: context.y + line.y(),
 				};
 				for fragment in line.fragments().iter() {
@@ -92,6 +106,7 @@ impl DisplayListBuilder {
 		&mut self,
 		bounds: LayoutRect,
 		content: &str,
+		shaped_text: &ShapedText,
 		color: RGBA,
 		font_families: &Vec<String>,
 		font_size: CSSFloat,
@@ -99,6 +114,7 @@ impl DisplayListBuilder {
 		self.items.push(DisplayItem::Text(TextDisplayItem {
 			bounds,
 			content: content.to_string(),
+			shaped_text: shaped_text.clone(),
 			color,
 			font_size,
 			font_families: font_families.clone(),
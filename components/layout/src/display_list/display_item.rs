@@ -2,6 +2,8 @@ use css::values::specified::color::RGBA;
 use css::values::{CSSFloat, CSSPixel, Pixel};
 use euclid::Rect;
 
+use crate::text::ShapedText;
+
 pub type LayoutRect = Rect<Pixel, CSSPixel>;
 
 #[derive(Debug)]
@@ -10,10 +12,13 @@ pub struct RectangleDisplayItem {
 	pub color: RGBA,
 }
 
+/// `shaped_text` has the glyphs which the text is measured with in layout, they are positioned from the top left of
+/// `bounds`.
 #[derive(Debug)]
 pub struct TextDisplayItem {
 	pub bounds: LayoutRect,
 	pub content: String,
+	pub shaped_text: ShapedText,
 	pub color: RGBA,
 	pub font_families: Vec<String>,
 	pub font_size: CSSFloat,
@@ -0,0 +1,316 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use css::computed_values::ComputedValues;
+use css::properties::longhands::direction::Direction;
+use css::properties::longhands::unicode_bidi::UnicodeBidi;
+use css::values::{Pixel, PIXEL_ZERO};
+use dom::global_scope::GlobalScope;
+use unicode_bidi::format_chars::{FSI, LRE, LRI, LRO, PDF, PDI, RLE, RLI, RLO};
+use unicode_bidi::{BidiClass, BidiInfo, Level};
+
+use super::boxes::{Box, BoxClass};
+use super::formatting_context::FormattingContextType;
+use super::fragment::{Fragment, FragmentClass, TextFragment};
+
+/// An atomic inline is a neutral character in the text of its paragraph.
+/// https://drafts.csswg.org/css-writing-modes/#bidi-atomic-inline
+const OBJECT_REPLACEMENT_CHARACTER: char = '\u{FFFC}';
+
+type FragmentPieces = HashMap<*const (), Vec<Rc<RefCell<TextFragment>>>>;
+
+/// The content of an inline formatting context in logical order with the bidi formatting characters which its inline
+/// boxes are equivalent to, `ranges` are the bytes of every text fragment and atomic inline in `text`.
+/// https://drafts.csswg.org/css-writing-modes/#bidi-paragraph
+struct Paragraph {
+	text: String,
+	ranges: HashMap<*const (), Range<usize>>,
+	text_runs: Vec<Rc<dyn Box>>,
+}
+
+impl Paragraph {
+	fn new(establisher: &dyn Box) -> Self {
+		let mut paragraph = Paragraph {
+			text: String::new(),
+			ranges: HashMap::new(),
+			text_runs: vec![],
+		};
+		paragraph.collect(establisher);
+		paragraph
+	}
+
+	fn collect(&mut self, source: &dyn Box) {
+		for child in source.children() {
+			match child.class() {
+				BoxClass::TextRun => {
+					for fragment in child.as_text_run().fragments().iter() {
+						let start = self.text.len();
+						self.text.push_str(&fragment.borrow().content);
+						self.ranges.insert(fragment_key(fragment), start..self.text.len());
+					}
+					self.text_runs.push(child.clone());
+				},
+				BoxClass::Inline
+					if child.formatting_context_type() == FormattingContextType::InlineFormattingContext =>
+				{
+					let computed_values =
+						GlobalScope::get_or_init_computed_values(child.as_inline_level_box().dom_node().id());
+					let (open, close) = control_codes(computed_values);
+					self.text.extend(open);
+					self.collect(child.as_ref());
+					self.text.extend(close);
+				},
+				BoxClass::Inline => {
+					for fragment in child.as_inline_level_box().fragments().iter() {
+						let start = self.text.len();
+						self.text.push(OBJECT_REPLACEMENT_CHARACTER);
+						self.ranges.insert(fragment_key(fragment), start..self.text.len());
+					}
+				},
+				// floats and absolutely positioned boxes aren't in the line boxes
+				BoxClass::Block | BoxClass::Anonymous => {},
+			}
+		}
+	}
+
+	/// The bytes which the fragments of a line come from.
+	fn line_range(&self, fragments: &[Rc<RefCell<dyn Fragment>>]) -> Option<Range<usize>> {
+		let mut line_range: Option<Range<usize>> = None;
+		for fragment in fragments {
+			let range = match self.ranges.get(&fragment_key(fragment)) {
+				Some(range) => Some(range.clone()),
+				None if matches!(fragment.borrow().class(), FragmentClass::BoxFragment) => {
+					self.line_range(&fragment.borrow().as_box_fragment().children)
+				},
+				None => None,
+			};
+			if let Some(range) = range {
+				line_range = Some(match line_range {
+					Some(line_range) => line_range.start.min(range.start)..line_range.end.max(range.end),
+					None => range,
+				});
+			}
+		}
+		line_range
+	}
+}
+
+/// Fragments are shared by their box, their parent and their line, so they are looked up by their address.
+fn fragment_key<T: ?Sized>(fragment: &Rc<RefCell<T>>) -> *const () {
+	Rc::as_ptr(fragment) as *const ()
+}
+
+/// The paragraph embedding level comes from `direction`, `unicode-bidi: plaintext` takes it from the first strong
+/// character of the paragraph instead.
+/// https://drafts.csswg.org/css-writing-modes/#unicode-bidi
+fn paragraph_level(computed_values: &ComputedValues) -> Option<Level> {
+	match (computed_values.get_unicode_bidi(), computed_values.get_direction()) {
+		(UnicodeBidi::Plaintext, _) => None,
+		(_, Direction::Ltr) => Some(Level::ltr()),
+		(_, Direction::Rtl) => Some(Level::rtl()),
+	}
+}
+
+/// The bidi formatting characters at the start and the end of an inline box.
+/// https://drafts.csswg.org/css-writing-modes/#bidi-control-codes-injection-table
+fn control_codes(computed_values: &ComputedValues) -> (Vec<char>, Vec<char>) {
+	let is_rtl = matches!(computed_values.get_direction(), Direction::Rtl);
+	match computed_values.get_unicode_bidi() {
+		UnicodeBidi::Normal => (vec![], vec![]),
+		UnicodeBidi::Embed => (vec![if is_rtl { RLE } else { LRE }], vec![PDF]),
+		UnicodeBidi::Isolate => (vec![if is_rtl { RLI } else { LRI }], vec![PDI]),
+		UnicodeBidi::BidiOverride => (vec![if is_rtl { RLO } else { LRO }], vec![PDF]),
+		UnicodeBidi::IsolateOverride => (if is_rtl { vec![RLI, RLO] } else { vec![LRI, LRO] }, vec![PDF, PDI]),
+		UnicodeBidi::Plaintext => (vec![FSI], vec![PDI]),
+	}
+}
+
+/// Resolves the embedding levels of the inline formatting context which `establisher` establishes and places the
+/// fragments of every line in visual order. Text fragments are split where their level changes, the fragments stay in
+/// logical order in their line and their parent, only their positions are changed.
+/// https://drafts.csswg.org/css-writing-modes/#text-direction
+/// https://www.unicode.org/reports/tr9/#Reordering_Resolved_Levels
+pub fn reorder_lines(establisher: &dyn Box) {
+	if establisher.formatting_context_type() != FormattingContextType::InlineFormattingContext {
+		return;
+	}
+	let paragraph = Paragraph::new(establisher);
+	let bidi_info = BidiInfo::new(
+		&paragraph.text,
+		paragraph_level(BoxClass::get_computed_values(establisher)),
+	);
+	// left-to-right content without right-to-left characters is already in visual order
+	if !bidi_info.has_rtl()
+		&& !bidi_info
+			.original_classes
+			.iter()
+			.any(|class| matches!(class, BidiClass::R | BidiClass::AL))
+	{
+		return;
+	}
+
+	let mut pieces = FragmentPieces::new();
+	for line in establisher.lines().iter() {
+		let mut fragments = line.fragments.borrow_mut();
+		// a line which is left empty by a forced break has nothing to reorder
+		let range = match paragraph.line_range(&fragments) {
+			Some(range) if !range.is_empty() => range,
+			_ => continue,
+		};
+		let para = bidi_info
+			.paragraphs
+			.iter()
+			.find(|para| para.range.contains(&range.start))
+			.unwrap();
+		// the spaces at the end of the line are reset to the paragraph level
+		let levels = bidi_info.reordered_levels(para, range.start..range.end.min(para.range.end));
+		place_fragments(&mut fragments, &levels, &paragraph, para.level.number(), &mut pieces);
+	}
+
+	for text_run in paragraph.text_runs.iter() {
+		let mut fragments = text_run.as_text_run().fragments_mut();
+		let split: Vec<Rc<RefCell<TextFragment>>> = fragments
+			.iter()
+			.flat_map(|fragment| {
+				pieces
+					.remove(&fragment_key(fragment))
+					.unwrap_or_else(|| vec![fragment.clone()])
+			})
+			.collect();
+		*fragments = split;
+	}
+}
+
+/// Splits the text fragments at the changes of their levels and moves every fragment to its visual position. An inline
+/// box is reordered as a whole at the lowest level of its content, which is reordered inside it. Returns that level.
+fn place_fragments(
+	fragments: &mut Vec<Rc<RefCell<dyn Fragment>>>,
+	levels: &[Level],
+	paragraph: &Paragraph,
+	paragraph_level: u8,
+	pieces: &mut FragmentPieces,
+) -> Option<u8> {
+	let mut placed: Vec<(Rc<RefCell<dyn Fragment>>, u8)> = Vec::with_capacity(fragments.len());
+	for fragment in fragments.drain(..) {
+		let key = fragment_key(&fragment);
+		let class = fragment.borrow().class();
+		let level = match (class, paragraph.ranges.get(&key)) {
+			(FragmentClass::TextFragment, Some(range)) => {
+				let text_pieces = split_text_fragment(
+					fragment.borrow().as_text_fragment(),
+					range.clone(),
+					levels,
+					paragraph_level,
+				);
+				for piece in text_pieces.iter() {
+					let level = piece.borrow().bidi_level;
+					placed.push((piece.clone(), level));
+				}
+				pieces.insert(key, text_pieces);
+				continue;
+			},
+			(FragmentClass::BoxFragment, Some(range)) => Some(levels[range.start].number()),
+			(FragmentClass::BoxFragment, None) => place_fragments(
+				&mut fragment.borrow_mut().as_box_fragment_mut().children,
+				levels,
+				paragraph,
+				paragraph_level,
+				pieces,
+			),
+			_ => None,
+		};
+		// an empty inline box goes with the content before it
+		let level = level
+			.or_else(|| placed.last().map(|(_, level)| *level))
+			.unwrap_or(paragraph_level);
+		placed.push((fragment, level));
+	}
+
+	let placed_levels: Vec<u8> = placed.iter().map(|(_, level)| *level).collect();
+	let mut x = PIXEL_ZERO;
+	for index in visual_order(&placed_levels) {
+		let mut fragment = placed[index].0.borrow_mut();
+		fragment.set_x(x);
+		x += fragment.total_width();
+	}
+	fragments.extend(placed.into_iter().map(|(fragment, _)| fragment));
+	placed_levels.into_iter().min()
+}
+
+/// The pieces of a text fragment between the changes of its levels, each one has its glyphs in the visual order of its
+/// level. The spaces which hang at the end of a line are left out of the width of the last piece, like the fragment's.
+fn split_text_fragment(
+	fragment: &TextFragment,
+	range: Range<usize>,
+	levels: &[Level],
+	paragraph_level: u8,
+) -> Vec<Rc<RefCell<TextFragment>>> {
+	let mut runs: Vec<(Range<usize>, u8)> = vec![];
+	for (index, ch) in fragment.content.char_indices() {
+		let level = levels[range.start + index].number();
+		let end = index + ch.len_utf8();
+		match runs.last_mut() {
+			Some((run, run_level)) if *run_level == level => run.end = end,
+			_ => runs.push((index..end, level)),
+		}
+	}
+	// an empty line keeps the height of its text
+	if runs.is_empty() {
+		let mut piece = TextFragment::new(fragment.dom_node.clone(), String::new(), fragment.shaped_text.clone());
+		piece.rect = fragment.rect;
+		piece.bidi_level = paragraph_level;
+		return vec![Rc::new(RefCell::new(piece))];
+	}
+
+	let mut pieces = Vec::with_capacity(runs.len());
+	let mut remaining_width = fragment.width();
+	let last_index = runs.len() - 1;
+	for (index, (run, level)) in runs.into_iter().enumerate() {
+		let shaped_text = fragment.shaped_text.slice(run.clone()).to_visual_order(level % 2 == 1);
+		let width = if index == last_index {
+			remaining_width.max(PIXEL_ZERO)
+		} else {
+			Pixel::new(shaped_text.width)
+		};
+		remaining_width = remaining_width - width;
+
+		let mut piece = TextFragment::new(
+			fragment.dom_node.clone(),
+			fragment.content[run].to_string(),
+			shaped_text,
+		);
+		piece.rect = fragment.rect;
+		piece.set_width(width);
+		piece.bidi_level = level;
+		pieces.push(Rc::new(RefCell::new(piece)));
+	}
+	pieces
+}
+
+/// The indices of items with `levels` in visual order, from the highest level to the lowest odd level every sequence of
+/// items at that level or higher is reversed.
+/// https://www.unicode.org/reports/tr9/#L2
+pub fn visual_order(levels: &[u8]) -> Vec<usize> {
+	let mut order: Vec<usize> = (0..levels.len()).collect();
+	let (lowest_odd, highest) = match (levels.iter().min(), levels.iter().max()) {
+		(Some(lowest), Some(highest)) => (*lowest | 1, *highest),
+		_ => return order,
+	};
+	for level in (lowest_odd..=highest).rev() {
+		let mut index = 0;
+		while index < order.len() {
+			if levels[order[index]] < level {
+				index += 1;
+				continue;
+			}
+			let start = index;
+			while index < order.len() && levels[order[index]] >= level {
+				index += 1;
+			}
+			order[start..index].reverse();
+		}
+	}
+	order
+}
@@ -1,14 +1,20 @@
 use std::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
 
-use common::not_supported;
-use css::values::computed::length::{LengthPercentage, LengthPercentageOrAuto, Size};
+use css::computed_values::ComputedValues;
+use css::values::computed::length::{LengthPercentageOrAuto, Size};
 use css::values::{Pixel, PIXEL_ZERO};
 use dom::global_scope::{GlobalScope, NodeRef};
 
 use super::boxes::{BaseBox, Box, BoxClass, SimpleBoxIterator};
+use super::flex::FlexContainer;
+use super::float::FloatSide;
 use super::formatting_context::{FormattingContext, FormattingContextType};
-use super::fragment::{BoxFragment, Fragment, LayoutInfo, Line};
+use super::fragment::{BoxFragment, Fragment, LayoutInfo, Line, Sides};
+use super::grid::GridContainer;
+use super::multicol::MulticolContainer;
+use super::position::{AbsoluteAxis, Insets};
+use super::table::TableContainer;
 use super::tree::VisitingContext;
 
 /// https://www.w3.org/TR/CSS22/visuren.html#block-boxes
@@ -50,6 +56,375 @@ impl BlockLevelBox {
 		self.fragment.replace(value);
 	}
 
+	fn layout_container_items(&self) {
+		match self.formatting_context_type() {
+			FormattingContextType::FlexFormattingContext => FlexContainer::new(self).layout_items(),
+			FormattingContextType::GridFormattingContext => GridContainer::new(self).layout_items(),
+			FormattingContextType::TableFormattingContext if BoxClass::is_table(self) => {
+				TableContainer::new(self).layout_items()
+			},
+			FormattingContextType::BlockFormattingContext if BoxClass::is_multicol_container(self) => {
+				MulticolContainer::new(self).layout_items()
+			},
+			_ => {},
+		}
+	}
+
+	/// The height of the content box limited by `min-height` and `max-height`, `None` when it depends on the content.
+	/// A percentage of a containing block whose height depends on the content computes to auto.
+	/// https://www.w3.org/TR/CSS22/visudet.html#the-height-property
+	fn get_used_height(
+		layout_info: &LayoutInfo,
+		computed_values: &ComputedValues,
+		containing_height: Option<Pixel>,
+	) -> Option<Pixel> {
+		let height = match computed_values.get_height() {
+			Size::LengthPercentage(length_percentage) => match containing_height {
+				Some(containing_height) => Some(length_percentage.to_used_value(containing_height)),
+				None => length_percentage.to_fixed_used_value(),
+			},
+			_ => None,
+		};
+		height.map(|height| {
+			let height = BoxClass::get_content_size(computed_values, height, layout_info.vertical_padding());
+			layout_info.clamp_height(computed_values, height, containing_height)
+		})
+	}
+
+	/// The width and the height of the content box once the preferred aspect ratio is applied, `width` is the used
+	/// width from the style. The height is `None` when it depends on the content.
+	pub fn get_used_size(
+		layout_info: &LayoutInfo,
+		computed_values: &ComputedValues,
+		width: Pixel,
+		containing_width: Pixel,
+		containing_height: Option<Pixel>,
+	) -> (Pixel, Option<Pixel>) {
+		let height = BlockLevelBox::get_used_height(layout_info, computed_values, containing_height);
+		layout_info.get_ratio_size(computed_values, width, height, containing_width, containing_height)
+	}
+
+	/// Auto margins share the space which is left in the containing block, or become zero when there is none.
+	/// https://www.w3.org/TR/CSS22/visudet.html#blockwidth
+	fn resolve_auto_margins(
+		computed_values: &ComputedValues,
+		margin: &mut Sides,
+		padding: &Sides,
+		containing_width: Pixel,
+		width: Pixel,
+	) {
+		let margin_value = containing_width - width - padding.left - padding.right;
+		if margin_value <= PIXEL_ZERO {
+			if *computed_values.get_margin_left() == LengthPercentageOrAuto::Auto {
+				margin.left = PIXEL_ZERO;
+			}
+			if *computed_values.get_margin_right() == LengthPercentageOrAuto::Auto {
+				margin.right = PIXEL_ZERO;
+			}
+		} else if *computed_values.get_margin_left() == LengthPercentageOrAuto::Auto
+			&& *computed_values.get_margin_right() == LengthPercentageOrAuto::Auto
+		{
+			margin.left = margin_value / 2.0;
+			margin.right = margin_value / 2.0;
+		} else if *computed_values.get_margin_left() == LengthPercentageOrAuto::Auto {
+			margin.right = computed_values
+				.get_margin_right()
+				.to_used_value(containing_width, PIXEL_ZERO);
+			margin.left = margin_value - margin.right;
+		} else if *computed_values.get_margin_right() == LengthPercentageOrAuto::Auto {
+			margin.left = computed_values
+				.get_margin_left()
+				.to_used_value(containing_width, PIXEL_ZERO);
+			margin.right = margin_value - margin.left;
+		}
+	}
+
+	/// https://www.w3.org/TR/CSS22/visuren.html#flow-control
+	fn get_flow_y_with_clearance(&self) -> Pixel {
+		let y = BoxClass::get_flow_y(self);
+		let parent = match self.parent() {
+			Some(parent) => parent,
+			None => return y,
+		};
+		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
+		let (formatting_context, _, parent_y) = BoxClass::float_root(parent.as_ref());
+		let clearance = formatting_context
+			.floats
+			.borrow()
+			.clearance(computed_values.get_clear());
+		match clearance {
+			// the top border edge is placed right below the floats
+			Some(bottom) => y.max(bottom - parent_y - self.layout_info().margin.top),
+			None => y,
+		}
+	}
+
+	/// https://www.w3.org/TR/CSS22/visudet.html#float-width
+	fn visit_float(&self) {
+		let containing_block = self.containing_block().unwrap();
+		let containing_layout = containing_block.layout_info();
+		let containing_width = containing_layout.width;
+		let containing_height = containing_layout.definite_height();
+
+		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
+		let padding = BoxClass::get_padding_for_non_replaced_elements(computed_values, containing_width);
+		let margin = BoxClass::get_margin_for_non_replaced_elements(computed_values, containing_width);
+		let mut layout_info = self.layout_info_mut();
+		layout_info.margin = margin;
+		layout_info.padding = padding;
+		let auto_width = layout_info
+			.intrinsic_size
+			.fit_content(containing_width - layout_info.horizontal_sides());
+		let width = layout_info.get_used_width(computed_values, containing_width, auto_width);
+		let (width, height) = BlockLevelBox::get_used_size(
+			&layout_info,
+			computed_values,
+			width,
+			containing_width,
+			containing_height,
+		);
+		layout_info.width = width;
+		layout_info.set_height(height);
+		drop(layout_info);
+
+		self.fragment.replace(self.create_fragment());
+		self.layout_container_items();
+	}
+
+	/// https://www.w3.org/TR/CSS22/visuren.html#float-position
+	fn place_float(&self) {
+		BoxClass::fit_height_to_content(self);
+		let layout_info = self.layout_info();
+		let (width, height) = (layout_info.total_width(), layout_info.total_height());
+		drop(layout_info);
+		BoxClass::calculate_lines(self);
+
+		// a float is not placed higher than the line box it appears in or the block box before it
+		let parent = self.parent().unwrap();
+		let establisher = match parent.formatting_context_type() {
+			FormattingContextType::InlineFormattingContext => Some(parent.formatting_context().established_by()),
+			_ => None,
+		};
+		let (formatting_context, flow_y) = match &establisher {
+			Some(establisher) => {
+				let (formatting_context, _, y) = BoxClass::float_root(establisher.as_ref());
+				let line_y = establisher
+					.lines()
+					.iter()
+					.fold(PIXEL_ZERO, |height, line| height + line.height());
+				(formatting_context, y + line_y)
+			},
+			None => {
+				let (formatting_context, _, y) = BoxClass::float_root(parent.as_ref());
+				(formatting_context, y + BoxClass::get_flow_y(self))
+			},
+		};
+
+		let containing_block = self.containing_block().unwrap();
+		let (_, containing_x, containing_y) = BoxClass::float_root(containing_block.as_ref());
+		let containing_width = containing_block.layout_info().width;
+		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
+		let side = FloatSide::from_float(computed_values.get_float()).unwrap();
+
+		let mut floats = formatting_context.floats.borrow_mut();
+		let min_y = floats
+			.clearance(computed_values.get_clear())
+			.map_or(flow_y, |bottom| flow_y.max(bottom));
+		let (x, y) = floats.place(
+			side,
+			width,
+			height,
+			min_y,
+			containing_x,
+			containing_x + containing_width,
+		);
+		drop(floats);
+
+		let mut fragment = self.fragment_mut();
+		fragment.set_x(x - containing_x);
+		fragment.set_y(y - containing_y);
+		drop(fragment);
+		BoxClass::add_out_of_flow_fragment(containing_block.as_ref(), self.fragment.clone());
+
+		// the line which is still empty is shortened by the float
+		if let Some(establisher) = establisher {
+			let lines = establisher.lines();
+			if let Some((latest_line, previous_lines)) = lines.split_last() {
+				if latest_line.width() == PIXEL_ZERO {
+					let (x, width) = BoxClass::get_line_band(establisher.as_ref(), previous_lines);
+					latest_line.set_band(x, width);
+				}
+			}
+		}
+	}
+
+	/// The box whose content box the static position of an absolutely positioned box is relative to, which is the
+	/// box establishing the inline formatting context when the box stays with inline content.
+	fn get_static_parent(&self) -> Rc<dyn Box> {
+		let parent = self.parent().unwrap();
+		match parent.formatting_context_type() {
+			FormattingContextType::InlineFormattingContext => parent.formatting_context().established_by(),
+			_ => parent,
+		}
+	}
+
+	/// The position which the box would have in normal flow, relative to the content box of `get_static_parent`.
+	/// https://www.w3.org/TR/CSS22/visudet.html#static-position
+	fn get_static_position(&self) -> (Pixel, Pixel) {
+		let parent = self.parent().unwrap();
+		match parent.formatting_context_type() {
+			FormattingContextType::InlineFormattingContext => {
+				let establisher = parent.formatting_context().established_by();
+				let lines = establisher.lines();
+				match lines.split_last() {
+					Some((latest_line, previous_lines)) => (
+						latest_line.x() + latest_line.width(),
+						previous_lines
+							.iter()
+							.fold(PIXEL_ZERO, |height, line| height + line.height()),
+					),
+					None => (PIXEL_ZERO, PIXEL_ZERO),
+				}
+			},
+			FormattingContextType::BlockFormattingContext => (PIXEL_ZERO, BoxClass::get_flow_y(self)),
+			_ => (PIXEL_ZERO, PIXEL_ZERO),
+		}
+	}
+
+	/// The width is solved once the box is visited, its children need it. The vertical position waits until the
+	/// height of the containing block is known, see `place_absolute`.
+	/// https://www.w3.org/TR/CSS22/visudet.html#abs-non-replaced-width
+	fn visit_absolute(&self) {
+		let containing_block = self.containing_block().unwrap();
+		let containing_layout = containing_block.layout_info();
+		let containing_padding = containing_layout.padding;
+		let containing_width = containing_layout.width + containing_padding.left + containing_padding.right;
+		let containing_height = containing_layout.height + containing_padding.top + containing_padding.bottom;
+		// a percentage height waits for `place_absolute` when the containing block depends on its content
+		let definite_height = containing_layout
+			.definite_height()
+			.map(|height| height + containing_padding.top + containing_padding.bottom);
+		drop(containing_layout);
+
+		let (static_x, static_y) = self.get_static_position();
+		let (offset_x, _) = BoxClass::get_offset_from(self.get_static_parent(), containing_block.as_ref());
+		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
+		let padding = BoxClass::get_padding_for_non_replaced_elements(computed_values, containing_width);
+		let insets = Insets::new(computed_values, containing_width, containing_height);
+		let mut layout_info = self.layout_info_mut();
+		layout_info.padding = padding;
+		let mut horizontal = AbsoluteAxis::horizontal(
+			computed_values,
+			&insets,
+			&padding,
+			&layout_info.intrinsic_size,
+			containing_width,
+			offset_x + static_x + containing_padding.left,
+		);
+		let (_, width, _, _) =
+			horizontal.solve(|available_width| layout_info.intrinsic_size.fit_content(available_width));
+		// the tentative width is limited by `min-width` and `max-width`, then it is solved as a specified width
+		// https://www.w3.org/TR/CSS22/visudet.html#min-max-widths
+		let width = layout_info.clamp_width(computed_values, width, Some(containing_width));
+		let (width, height) =
+			BlockLevelBox::get_used_size(&layout_info, computed_values, width, containing_width, definite_height);
+		horizontal.set_size(width);
+		let (_, width, margin_left, margin_right) = horizontal.solve(|width| width);
+		let mut margin = BoxClass::get_margin_for_non_replaced_elements(computed_values, containing_width);
+		margin.left = margin_left;
+		margin.right = margin_right;
+		layout_info.margin = margin;
+		layout_info.width = width;
+		layout_info.set_height(height);
+		drop(layout_info);
+
+		self.fragment.replace(self.create_fragment());
+		// the static position is kept until the box is placed
+		let mut fragment = self.fragment_mut();
+		fragment.set_x(static_x);
+		fragment.set_y(static_y);
+		drop(fragment);
+		self.layout_container_items();
+	}
+
+	/// Places an absolutely positioned box inside the padding box of its containing block, this happens after the
+	/// layout of the whole tree because the static position and the height of the containing block are needed.
+	/// https://www.w3.org/TR/CSS22/visudet.html#abs-non-replaced-height
+	pub fn place_absolute(&self) {
+		let containing_block = self.containing_block().unwrap();
+		let containing_layout = containing_block.layout_info();
+		let containing_padding = containing_layout.padding;
+		let containing_width = containing_layout.width + containing_padding.left + containing_padding.right;
+		let containing_height = containing_layout.height + containing_padding.top + containing_padding.bottom;
+		drop(containing_layout);
+
+		let fragment = self.fragment();
+		let (static_x, static_y) = (fragment.x(), fragment.y());
+		drop(fragment);
+		let (offset_x, offset_y) = BoxClass::get_offset_from(self.get_static_parent(), containing_block.as_ref());
+		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
+		let layout_info = self.layout_info();
+		let (width, height, padding) = (layout_info.width, layout_info.height, layout_info.padding);
+		let insets = Insets::new(computed_values, containing_width, containing_height);
+		let mut horizontal = AbsoluteAxis::horizontal(
+			computed_values,
+			&insets,
+			&padding,
+			&layout_info.intrinsic_size,
+			containing_width,
+			offset_x + static_x + containing_padding.left,
+		);
+		drop(layout_info);
+		// the width is already solved by `visit_absolute`
+		horizontal.set_size(width);
+		let (x, width, margin_left, margin_right) = horizontal.solve(|_| width);
+		let mut vertical = AbsoluteAxis::vertical(
+			computed_values,
+			&insets,
+			&padding,
+			containing_width,
+			containing_height,
+			offset_y + static_y + containing_padding.top,
+		);
+		// a height from the preferred aspect ratio isn't stretched between the insets
+		if BoxClass::get_aspect_ratio(computed_values).is_some()
+			&& !matches!(computed_values.get_height(), Size::LengthPercentage(_))
+		{
+			vertical.set_size(height);
+		}
+		// the tentative height is limited by `min-height` and `max-height`, then it is solved as a specified height
+		// https://www.w3.org/TR/CSS22/visudet.html#min-max-heights
+		let (_, tentative_height, _, _) = vertical.solve(|_| height);
+		let height = self
+			.layout_info()
+			.clamp_height(computed_values, tentative_height, Some(containing_height));
+		if height != tentative_height {
+			vertical.set_size(height);
+		}
+		let (y, height, margin_top, margin_bottom) = vertical.solve(|_| height);
+
+		let mut layout_info = self.layout_info_mut();
+		layout_info.margin.left = margin_left;
+		layout_info.margin.right = margin_right;
+		layout_info.margin.top = margin_top;
+		layout_info.margin.bottom = margin_bottom;
+		layout_info.width = width;
+		layout_info.height = height;
+		let margin = layout_info.margin;
+		drop(layout_info);
+
+		let mut fragment = self.fragment_mut();
+		fragment.margin = margin;
+		fragment.set_width(width);
+		fragment.set_bounded_width(width);
+		fragment.set_height(height);
+		fragment.set_bounded_height(height);
+		fragment.set_x(x - containing_padding.left);
+		fragment.set_y(y - containing_padding.top);
+		drop(fragment);
+		BoxClass::add_out_of_flow_fragment(containing_block.as_ref(), self.fragment.clone());
+	}
+
 	pub fn create_fragment(&self) -> BoxFragment {
 		let layout_info = self.layout_info();
 		let mut fragment = BoxFragment::new(self.dom_node.clone(), self.lines.clone());
@@ -165,94 +540,125 @@ impl Box for BlockLevelBox {
 		layout_info.compute_fixed_padding(computed_values);
 		layout_info.compute_width_and_height(computed_values);
 		layout_info.compute_intrinsic(self);
+		layout_info.compute_contributions(computed_values);
+		// a table is never narrower than its content
+		if BoxClass::is_table(self) {
+			let intrinsic_size = &mut layout_info.intrinsic_size;
+			intrinsic_size.preferred_minimum_width = intrinsic_size
+				.preferred_minimum_width
+				.max(intrinsic_size.min_content_width);
+			intrinsic_size.preferred_width = intrinsic_size
+				.preferred_width
+				.max(intrinsic_size.preferred_minimum_width);
+		}
 	}
 
 	/// https://www.w3.org/TR/CSS22/visudet.html#blockwidth
 	fn visit_layout(&self) {
+		// a flex or grid item, or a part of a table, is already sized by its container
+		if BoxClass::is_flex_item(self) || BoxClass::is_grid_item(self) || BoxClass::is_table_part(self) {
+			self.fragment.replace(self.create_fragment());
+			self.parent().unwrap().add_child_fragment(self.fragment.clone());
+			self.layout_container_items();
+			return;
+		}
+		if self.parent().is_some() && BoxClass::is_absolutely_positioned(self) {
+			self.visit_absolute();
+			return;
+		}
+		if self.parent().is_some() && BoxClass::is_float(self) {
+			self.visit_float();
+			return;
+		}
+
 		let containing_block = self.containing_block().unwrap();
 		let containing_layout = containing_block.layout_info();
 		let containing_width = containing_layout.width;
-		let containing_height = containing_layout.height;
+		let containing_height = containing_layout.definite_height();
 
 		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
 		let padding = BoxClass::get_padding_for_non_replaced_elements(computed_values, containing_width);
 		let mut margin = BoxClass::get_margin_for_non_replaced_elements(computed_values, containing_width);
 		let mut layout_info = self.layout_info_mut();
+		layout_info.padding = padding;
 
-		let width = match computed_values.get_width() {
-			Size::Auto => layout_info
-				.intrinsic_size
-				.preferred_minimum_width
-				.max(containing_width - margin.left - padding.left - padding.right - margin.right),
-			Size::LengthPercentage(length_percentage) => {
-				let width = length_percentage.to_used_value(containing_width);
-				let margin_value = containing_width - width - padding.left - padding.right;
-				if margin_value <= PIXEL_ZERO {
-					if *computed_values.get_margin_left() == LengthPercentageOrAuto::Auto {
-						margin.left = PIXEL_ZERO;
-					}
-					if *computed_values.get_margin_right() == LengthPercentageOrAuto::Auto {
-						margin.right = PIXEL_ZERO;
-					}
-				} else {
-					if *computed_values.get_margin_left() == LengthPercentageOrAuto::Auto
-						&& *computed_values.get_margin_right() == LengthPercentageOrAuto::Auto
-					{
-						margin.left = margin_value / 2.0;
-						margin.right = margin_value / 2.0;
-					} else if *computed_values.get_margin_left() == LengthPercentageOrAuto::Auto {
-						margin.right = computed_values
-							.get_margin_right()
-							.to_used_value(containing_width, PIXEL_ZERO);
-						margin.left = margin_value - margin.right;
-					} else if *computed_values.get_margin_right() == LengthPercentageOrAuto::Auto {
-						margin.left = computed_values
-							.get_margin_left()
-							.to_used_value(containing_width, PIXEL_ZERO);
-						margin.right = margin_value - margin.left;
-					}
-				};
-				width
-			},
-			Size::ExtremumLength(_) => {
-				not_supported!()
-			},
-		};
-		let height = match computed_values.get_height() {
-			Size::LengthPercentage(length_percentage) => match &length_percentage.0 {
-				LengthPercentage::AbsoluteLength(value) => Pixel::new(*value),
-				LengthPercentage::Percentage(percentage) if containing_height != PIXEL_ZERO => {
-					containing_height * percentage.to_value(&(0.0..1.0))
-				},
-				_ => PIXEL_ZERO,
-			},
-			_ => PIXEL_ZERO,
+		let available_width = containing_width - margin.left - padding.left - padding.right - margin.right;
+		let is_table = BoxClass::is_table(self);
+		let auto_width = if is_table {
+			// a table with an auto width shrinks to fit its content like a float
+			// https://www.w3.org/TR/CSS22/tables.html#auto-table-layout
+			layout_info.intrinsic_size.fit_content(available_width)
+		} else {
+			layout_info.intrinsic_size.min_content_width.max(available_width)
 		};
+		let width = layout_info.get_used_width(computed_values, containing_width, auto_width);
+		let (mut width, height) = BlockLevelBox::get_used_size(
+			&layout_info,
+			computed_values,
+			width,
+			containing_width,
+			containing_height,
+		);
+		if is_table {
+			// a table is never narrower than its content
+			width = width.max(layout_info.intrinsic_size.min_content_width);
+		}
+		// an auto width which is limited by `min-width` or `max-width`, or which comes from the preferred aspect ratio,
+		// is used like a specified one
+		if !matches!(computed_values.get_width(), Size::Auto) || is_table || width != auto_width {
+			BlockLevelBox::resolve_auto_margins(computed_values, &mut margin, &padding, containing_width, width);
+		}
 		layout_info.margin = margin;
-		layout_info.padding = padding;
 		layout_info.width = width;
-		layout_info.height = height;
+		layout_info.set_height(height);
 		drop(layout_info);
 
 		self.fragment.replace(self.create_fragment());
+		self.fragment_mut().set_y(self.get_flow_y_with_clearance());
 		if let Some(parent) = self.parent() {
 			parent.add_child_fragment(self.fragment.clone());
 		}
+		self.layout_container_items();
 	}
 
 	/// https://www.w3.org/TR/CSS22/visudet.html#normal-block
 	fn revisit_layout(&self, context: &mut VisitingContext) {
-		let mut fragment = self.fragment_mut();
-		fragment.set_y(context.height);
-
-		let mut layout_info = self.layout_info_mut();
-		if layout_info.height == PIXEL_ZERO {
-			let height = BoxClass::get_block_height(self);
-			fragment.set_height(height);
-			layout_info.height = height;
+		// the table places its parts once they are all laid out
+		if BoxClass::is_table_part(self) {
+			BoxClass::fit_height_to_content(self);
+			BoxClass::calculate_lines(self);
+			return;
 		}
+		match self.formatting_context_type() {
+			FormattingContextType::FlexFormattingContext => FlexContainer::new(self).arrange_items(),
+			FormattingContextType::GridFormattingContext => GridContainer::new(self).arrange_items(),
+			FormattingContextType::TableFormattingContext if BoxClass::is_table(self) => {
+				TableContainer::new(self).arrange_items()
+			},
+			FormattingContextType::BlockFormattingContext if BoxClass::is_multicol_container(self) => {
+				MulticolContainer::new(self).arrange_items()
+			},
+			_ => {},
+		}
+		if self.parent().is_some() && BoxClass::is_absolutely_positioned(self) {
+			// an absolutely positioned box doesn't take space in the flow, it is placed by `place_absolute`
+			BoxClass::fit_height_to_content(self);
+			BoxClass::calculate_lines(self);
+			return;
+		}
+		if self.parent().is_some() && BoxClass::is_float(self) {
+			self.place_float();
+			return;
+		}
+		BoxClass::fit_height_to_content(self);
 		BoxClass::calculate_lines(self);
-		context.height += fragment.total_height();
+		BoxClass::collapse_margins(self);
+
+		// margins of its children which collapse with its own are only known now
+		let y = self.get_flow_y_with_clearance();
+		let mut fragment = self.fragment_mut();
+		fragment.set_y(y);
+		context.height = y + fragment.total_height();
 	}
 
 	fn class(&self) -> BoxClass {
@@ -3,13 +3,26 @@ use std::rc::{Rc, Weak};
 
 use common::not_reached;
 use css::computed_values::ComputedValues;
+use css::properties::longhands::box_sizing::BoxSizing;
+use css::properties::longhands::float::Float;
+use css::properties::longhands::position::Position;
+use css::values::computed::length::Size;
+use css::values::computed::line::LineWidth;
+use css::values::specified::layout::{LineStyle, Overflow};
 use css::values::{Pixel, PIXEL_ZERO};
+use dom::global_scope::GlobalScope;
 use uuid::Uuid;
 
+use super::bidi;
 use super::block::BlockLevelBox;
 use super::formatting_context::{FormattingContext, FormattingContextType};
 use super::fragment::{AnonymousFragment, Fragment, LayoutInfo, Line, Sides};
 use super::inline::InlineLevelBox;
+use super::line_height::Strut;
+use super::margin::CollapsedMargin;
+use super::multicol::MulticolContainer;
+use super::table::TablePart;
+use super::text_align;
 use super::text_run::TextRun;
 use super::tree::VisitingContext;
 
@@ -301,28 +314,35 @@ impl Box for AnonymousBox {
 	}
 
 	fn visit_layout(&self) {
-		let containing_width = self.containing_block().unwrap().layout_info().width;
-		let mut layout_info = self.layout_info_mut();
-		layout_info.width = containing_width;
-		drop(layout_info);
+		// a flex or grid item is already sized by its container
+		if !BoxClass::is_flex_item(self) && !BoxClass::is_grid_item(self) {
+			let containing_width = self.containing_block().unwrap().layout_info().width;
+			let mut layout_info = self.layout_info_mut();
+			layout_info.width = containing_width;
+		}
 
 		self.fragment.replace(self.create_fragment());
+		self.fragment_mut().set_y(BoxClass::get_flow_y(self));
 		let parent = self.parent().unwrap();
 		parent.add_child_fragment(self.fragment.clone());
 	}
 
 	fn revisit_layout(&self, context: &mut VisitingContext) {
-		let mut fragment = self.fragment_mut();
-		fragment.set_y(context.height);
-
 		let height = BoxClass::get_block_height(self);
-		fragment.set_height(height);
 		let mut layout_info = self.layout_info_mut();
-		if layout_info.height == PIXEL_ZERO {
+		if !layout_info.has_definite_height {
 			layout_info.height = height;
 		}
+		// empty line boxes don't keep the margins around them from collapsing
+		layout_info.collapses_through = height == PIXEL_ZERO;
+		drop(layout_info);
 		BoxClass::calculate_lines(self);
-		context.height += fragment.total_height();
+
+		let y = BoxClass::get_flow_y(self);
+		let mut fragment = self.fragment_mut();
+		fragment.set_height(height);
+		fragment.set_y(y);
+		context.height = y + fragment.total_height();
 	}
 
 	fn class(&self) -> BoxClass {
@@ -356,6 +376,8 @@ impl BoxClass {
 	pub fn append_child(source: Rc<dyn Box>, child: Rc<dyn Box>) {
 		let child = match source.formatting_context_type() {
 			FormattingContextType::BlockFormattingContext
+			| FormattingContextType::FlexFormattingContext
+			| FormattingContextType::GridFormattingContext
 				if child.class() == BoxClass::Inline || child.class() == BoxClass::TextRun =>
 			{
 				let last_child = source.get_last_child();
@@ -373,8 +395,20 @@ impl BoxClass {
 				child.set_formatting_context(anonymous_box.formatting_context());
 				BoxClass::add_child(anonymous_box.clone(), child);
 				anonymous_box
-			}
-			FormattingContextType::InlineFormattingContext if child.class() == BoxClass::Block => {
+			},
+			// an out-of-flow box next to inline content stays with it in the same anonymous box
+			FormattingContextType::BlockFormattingContext if BoxClass::is_out_of_flow(child.as_ref()) => {
+				if let Some(last_child) = source.get_last_child() {
+					if last_child.class() == BoxClass::Anonymous {
+						BoxClass::add_child(last_child, child);
+						return;
+					}
+				}
+				child
+			},
+			FormattingContextType::InlineFormattingContext
+				if child.class() == BoxClass::Block && !BoxClass::is_out_of_flow(child.as_ref()) =>
+			{
 				not_reached!()
 			},
 			_ => child,
@@ -387,6 +421,501 @@ impl BoxClass {
 		child.set_parent(Some(source));
 	}
 
+	pub fn is_flex_item(source: &dyn Box) -> bool {
+		source.parent().map_or(false, |parent| {
+			parent.formatting_context_type() == FormattingContextType::FlexFormattingContext
+		}) && !BoxClass::is_absolutely_positioned(source)
+	}
+
+	pub fn is_grid_item(source: &dyn Box) -> bool {
+		source.parent().map_or(false, |parent| {
+			parent.formatting_context_type() == FormattingContextType::GridFormattingContext
+		}) && !BoxClass::is_absolutely_positioned(source)
+	}
+
+	/// https://www.w3.org/TR/CSS22/tables.html#table-display
+	pub fn is_table(source: &dyn Box) -> bool {
+		source.formatting_context_type() == FormattingContextType::TableFormattingContext
+			&& source.formatting_context().established_by().id() == source.id()
+	}
+
+	/// Captions, cells, rows, columns and their groups are laid out by their table.
+	pub fn is_table_part(source: &dyn Box) -> bool {
+		TablePart::of(source).map_or(false, |part| part != TablePart::Table)
+	}
+
+	/// A block container which lays its content out in columns establishes a new block formatting context, the parts
+	/// of a table are left to their table.
+	/// https://drafts.csswg.org/css-multicol/#the-multi-column-model
+	pub fn is_multicol_container(source: &dyn Box) -> bool {
+		source.class() == BoxClass::Block
+			&& source.formatting_context_type() == FormattingContextType::BlockFormattingContext
+			&& source.formatting_context().established_by().id() == source.id()
+			&& !BoxClass::is_table_part(source)
+			&& MulticolContainer::is_multicol(GlobalScope::get_or_init_computed_values(
+				source.as_block_level_box().dom_node().id(),
+			))
+	}
+
+	/// Anonymous boxes and text runs are never positioned.
+	pub fn get_position(source: &dyn Box) -> Position {
+		let dom_node = match source.class() {
+			BoxClass::Block => source.as_block_level_box().dom_node(),
+			BoxClass::Inline => source.as_inline_level_box().dom_node(),
+			_ => return Position::Static,
+		};
+		GlobalScope::get_or_init_computed_values(dom_node.id())
+			.get_position()
+			.clone()
+	}
+
+	/// https://www.w3.org/TR/CSS22/visuren.html#absolute-positioning
+	pub fn is_absolutely_positioned(source: &dyn Box) -> bool {
+		matches!(BoxClass::get_position(source), Position::Absolute | Position::Fixed)
+	}
+
+	/// https://www.w3.org/TR/CSS22/visuren.html#positioning-scheme
+	pub fn is_out_of_flow(source: &dyn Box) -> bool {
+		BoxClass::is_float(source) || BoxClass::is_absolutely_positioned(source)
+	}
+
+	/// Floats inside flex and grid containers are ignored, the items are blockified instead.
+	/// https://www.w3.org/TR/CSS22/visuren.html#dis-pos-flo
+	pub fn is_float(source: &dyn Box) -> bool {
+		source.class() == BoxClass::Block
+			&& !BoxClass::is_absolutely_positioned(source)
+			&& !BoxClass::is_flex_item(source)
+			&& !BoxClass::is_grid_item(source)
+			&& !BoxClass::is_table_part(source)
+			&& !matches!(
+				GlobalScope::get_or_init_computed_values(source.as_block_level_box().dom_node().id()).get_float(),
+				Float::None
+			)
+	}
+
+	/// https://www.w3.org/TR/CSS22/visuren.html#block-formatting
+	pub fn establishes_block_formatting_context(source: &dyn Box) -> bool {
+		let formatting_context = source.formatting_context();
+		source.parent().is_none()
+			|| BoxClass::is_out_of_flow(source)
+			|| BoxClass::is_flex_item(source)
+			|| BoxClass::is_grid_item(source)
+			|| (formatting_context.formatting_context_type != FormattingContextType::InlineFormattingContext
+				&& formatting_context.established_by().id() == source.id())
+	}
+
+	/// The formatting context holding the floats which `source` flows around, and the position of `source`'s content box
+	/// relative to the content box of the box establishing it.
+	pub fn float_root(source: &dyn Box) -> (Rc<FormattingContext>, Pixel, Pixel) {
+		if BoxClass::establishes_block_formatting_context(source) {
+			return (source.formatting_context(), PIXEL_ZERO, PIXEL_ZERO);
+		}
+		let (mut x, mut y) = BoxClass::get_content_position(source);
+		let mut node = source.parent().unwrap();
+		while !BoxClass::establishes_block_formatting_context(node.as_ref()) {
+			let (node_x, node_y) = BoxClass::get_content_position(node.as_ref());
+			x += node_x;
+			y += node_y;
+			node = node.parent().unwrap();
+		}
+		(node.formatting_context(), x, y)
+	}
+
+	/// The position of the content box of `source` relative to the content box of its parent, or of its containing
+	/// block when it is out of flow. An inline-level box is positioned by its first fragment.
+	fn get_content_position(source: &dyn Box) -> (Pixel, Pixel) {
+		match source.class() {
+			BoxClass::Block => {
+				let fragment = source.as_block_level_box().fragment();
+				(fragment.rect_x(), fragment.rect_y())
+			},
+			BoxClass::Anonymous => {
+				let fragment = source.as_anonymous_box().fragment();
+				(fragment.rect_x(), fragment.rect_y())
+			},
+			BoxClass::Inline => {
+				let fragments = source.as_inline_level_box().fragments();
+				let fragment = match fragments.first() {
+					Some(fragment) => fragment,
+					None => return (PIXEL_ZERO, PIXEL_ZERO),
+				};
+				let (x, y) = (fragment.borrow().rect_x(), fragment.borrow().rect_y());
+				let parent = source.parent().unwrap();
+				if parent.class() == BoxClass::Inline {
+					return (x, y);
+				}
+				// fragments which are direct children of a block container are placed in its lines
+				let fragment_ptr = Rc::as_ptr(fragment) as *const ();
+				for line in parent.lines().iter() {
+					let fragments = line.fragments();
+					if fragments
+						.iter()
+						.any(|line_fragment| Rc::as_ptr(line_fragment) as *const () == fragment_ptr)
+					{
+						return (line.x() + x, line.y() + y);
+					}
+				}
+				(x, y)
+			},
+			BoxClass::TextRun => (PIXEL_ZERO, PIXEL_ZERO),
+		}
+	}
+
+	/// The position of the content box of `source` relative to the content box of `ancestor` once the layout is done,
+	/// or relative to the initial containing block when `ancestor` is not one of its ancestors.
+	pub fn get_offset_from(source: Rc<dyn Box>, ancestor: &dyn Box) -> (Pixel, Pixel) {
+		let (mut x, mut y) = (PIXEL_ZERO, PIXEL_ZERO);
+		let mut node = source;
+		while node.id() != ancestor.id() {
+			let (node_x, node_y) = BoxClass::get_content_position(node.as_ref());
+			x += node_x;
+			y += node_y;
+			let layout_parent = if BoxClass::is_out_of_flow(node.as_ref()) {
+				node.containing_block()
+			} else {
+				node.parent()
+			};
+			node = match layout_parent {
+				Some(layout_parent) => layout_parent,
+				None => break,
+			};
+		}
+		(x, y)
+	}
+
+	/// The top of the margin box of a block-level box in normal flow, its top margin collapses with the margins before
+	/// it, which belong to its parent when there is no in-flow content in between.
+	pub fn get_flow_y(source: &dyn Box) -> Pixel {
+		let parent = match source.parent() {
+			Some(parent) => parent,
+			None => return PIXEL_ZERO,
+		};
+		let (bottom, margin, is_first) = BoxClass::get_adjoining_margins(parent.as_ref(), Some(source));
+		let layout_info = source.layout_info();
+		let margin_top = layout_info.margin.top;
+		let border_top = if is_first && BoxClass::is_top_margin_adjoining_children(parent.as_ref()) {
+			bottom
+		} else {
+			bottom
+				+ margin
+					.adjoin(&CollapsedMargin::new(margin_top))
+					.adjoin(&layout_info.collapsed_margin_top)
+					.solve()
+		};
+		border_top - margin_top
+	}
+
+	/// Walks the in-flow children of `source` before `child` (or all of them), returns the bottom border edge of the last
+	/// one which doesn't collapse through, the margins which are adjoining after it, and whether there is no such child.
+	fn get_adjoining_margins(source: &dyn Box, child: Option<&dyn Box>) -> (Pixel, CollapsedMargin, bool) {
+		let mut bottom = PIXEL_ZERO;
+		let mut margin = CollapsedMargin::default();
+		let mut is_first = true;
+		for sibling in source.children() {
+			if child.map_or(false, |child| child.id() == sibling.id()) {
+				break;
+			}
+			let border_bottom = match BoxClass::get_flow_bottom(sibling.as_ref()) {
+				Some(border_bottom) => border_bottom,
+				None => continue,
+			};
+			let layout_info = sibling.layout_info();
+			if layout_info.collapses_through {
+				margin = margin
+					.adjoin(&layout_info.collapsed_margin_top)
+					.adjoin(&layout_info.collapsed_margin_bottom);
+			} else {
+				bottom = border_bottom;
+				margin = layout_info.collapsed_margin_bottom;
+				is_first = false;
+			}
+		}
+		(bottom, margin, is_first)
+	}
+
+	/// The margins at the top edge of the in-flow children of `source`, up to the first child which doesn't collapse
+	/// through.
+	fn get_leading_margin(source: &dyn Box) -> CollapsedMargin {
+		let mut margin = CollapsedMargin::default();
+		for child in source.children() {
+			if BoxClass::get_flow_bottom(child.as_ref()).is_none() {
+				continue;
+			}
+			let layout_info = child.layout_info();
+			margin = margin.adjoin(&layout_info.collapsed_margin_top);
+			if !layout_info.collapses_through {
+				break;
+			}
+			margin = margin.adjoin(&layout_info.collapsed_margin_bottom);
+		}
+		margin
+	}
+
+	/// The bottom border edge of an in-flow block-level box.
+	fn get_flow_bottom(source: &dyn Box) -> Option<Pixel> {
+		match source.class() {
+			BoxClass::Block if !BoxClass::is_out_of_flow(source) => {
+				let fragment = source.as_block_level_box().fragment();
+				Some(fragment.y() + fragment.total_height() - fragment.margin.bottom)
+			},
+			BoxClass::Anonymous => {
+				let fragment = source.as_anonymous_box().fragment();
+				Some(fragment.y() + fragment.total_height())
+			},
+			_ => None,
+		}
+	}
+
+	/// Borders are not laid out yet, they only keep margins from collapsing.
+	fn has_border(style: &LineStyle, width: &LineWidth) -> bool {
+		!matches!(style, LineStyle::None | LineStyle::Hidden)
+			&& !matches!(width, LineWidth::Length(length) if length.0 == 0.0)
+	}
+
+	/// Whether padding or border separates the top or the bottom margin of `source` from its content, a box which
+	/// establishes a block formatting context keeps its margins apart from the ones of its children as well.
+	fn is_margin_separated(source: &dyn Box, is_top: bool) -> bool {
+		if source.class() != BoxClass::Block || BoxClass::establishes_block_formatting_context(source) {
+			return true;
+		}
+		let padding = source.layout_info().padding;
+		let computed_values = GlobalScope::get_or_init_computed_values(source.as_block_level_box().dom_node().id());
+		if is_top {
+			padding.top != PIXEL_ZERO
+				|| BoxClass::has_border(
+					computed_values.get_border_top_style(),
+					computed_values.get_border_top_width(),
+				)
+		} else {
+			padding.bottom != PIXEL_ZERO
+				|| BoxClass::has_border(
+					computed_values.get_border_bottom_style(),
+					computed_values.get_border_bottom_width(),
+				)
+		}
+	}
+
+	/// Whether the top margin of `source` collapses with the top margin of its first in-flow child.
+	/// https://www.w3.org/TR/CSS22/box.html#collapsing-margins
+	pub fn is_top_margin_adjoining_children(source: &dyn Box) -> bool {
+		source.formatting_context_type() == FormattingContextType::BlockFormattingContext
+			&& !BoxClass::is_margin_separated(source, true)
+	}
+
+	/// Whether the bottom margin of `source` collapses with the bottom margin of its last in-flow child, which needs an
+	/// auto height.
+	pub fn is_bottom_margin_adjoining_children(source: &dyn Box) -> bool {
+		source.formatting_context_type() == FormattingContextType::BlockFormattingContext
+			&& !BoxClass::is_margin_separated(source, false)
+			&& matches!(
+				GlobalScope::get_or_init_computed_values(source.as_block_level_box().dom_node().id()).get_height(),
+				Size::Auto
+			)
+	}
+
+	/// Collects the margins of `source` with the adjoining margins of its children, once its height is known.
+	pub fn collapse_margins(source: &dyn Box) {
+		let (margin_top, margin_bottom, height) = {
+			let layout_info = source.layout_info();
+			(layout_info.margin.top, layout_info.margin.bottom, layout_info.height)
+		};
+		let mut top = CollapsedMargin::new(margin_top);
+		let mut bottom = CollapsedMargin::new(margin_bottom);
+		if BoxClass::is_top_margin_adjoining_children(source) {
+			top = top.adjoin(&BoxClass::get_leading_margin(source));
+		}
+		let (_, trailing_margin, is_empty) = BoxClass::get_adjoining_margins(source, None);
+		if BoxClass::is_bottom_margin_adjoining_children(source) {
+			bottom = bottom.adjoin(&trailing_margin);
+		}
+		// there is no in-flow content, line box, padding or border between the top and bottom margins
+		let collapses_through = is_empty
+			&& height == PIXEL_ZERO
+			&& !BoxClass::is_margin_separated(source, true)
+			&& !BoxClass::is_margin_separated(source, false);
+		if collapses_through {
+			top = top.adjoin(&bottom);
+			bottom = top;
+		}
+		let mut layout_info = source.layout_info_mut();
+		layout_info.collapsed_margin_top = top;
+		layout_info.collapsed_margin_bottom = bottom;
+		layout_info.collapses_through = collapses_through;
+	}
+
+	/// The left offset and the width of the next line in `establisher`, shortened by floats at its top and by the
+	/// indentation on its start side.
+	pub fn get_line_band(establisher: &dyn Box, lines: &[Line]) -> (Pixel, Pixel) {
+		let width = establisher.layout_info().width;
+		let line_y = lines.iter().fold(PIXEL_ZERO, |height, line| height + line.height());
+		let (formatting_context, x, y) = BoxClass::float_root(establisher);
+		let floats = formatting_context.floats.borrow();
+		let (left, right) = floats.available_band(y + line_y, PIXEL_ZERO, x, x + width);
+		let indent = text_align::get_indent(establisher, lines);
+		let left = if text_align::has_rtl_direction(establisher) {
+			left
+		} else {
+			left + indent
+		};
+		(left - x, (right - left - indent).max(PIXEL_ZERO))
+	}
+
+	pub fn new_line(establisher: &dyn Box, lines: &[Line]) -> Line {
+		// the previous line doesn't get more fragments, its height is kept for the bands of the next lines
+		if let Some(previous_line) = lines.last() {
+			previous_line.align();
+		}
+		let line = Line::new(Strut::new(BoxClass::get_computed_values(establisher)));
+		let (x, width) = BoxClass::get_line_band(establisher, lines);
+		line.set_band(x, width);
+		line
+	}
+
+	/// The width of the line after the latest one, which is where a text continues after wrapping.
+	pub fn get_next_line_width(parent: Rc<dyn Box>) -> Pixel {
+		match parent.class() {
+			BoxClass::Inline => parent.as_inline_level_box().max_width(),
+			BoxClass::Block | BoxClass::Anonymous => {
+				let lines = parent.lines();
+				BoxClass::get_line_band(parent.as_ref(), &lines).1
+			},
+			BoxClass::TextRun => not_reached!(),
+		}
+	}
+
+	pub fn add_out_of_flow_fragment(containing_block: &dyn Box, fragment: Rc<RefCell<dyn Fragment>>) {
+		match containing_block.class() {
+			BoxClass::Block => containing_block
+				.as_block_level_box()
+				.fragment_mut()
+				.out_of_flow_children
+				.push(fragment),
+			BoxClass::Inline => {
+				if let Some(inline_fragment) = containing_block.as_inline_level_box().fragments().last() {
+					inline_fragment.borrow_mut().out_of_flow_children.push(fragment);
+				}
+			},
+			_ => not_reached!(),
+		}
+	}
+
+	/// Flex, grid, table and multi-column containers with an auto height get it once their items are arranged. The
+	/// height is limited by `min-height` and `max-height`, an absolutely positioned box is limited again once the
+	/// height of its containing block is known, see `BlockLevelBox::place_absolute`.
+	pub fn set_content_height(source: &dyn Box, height: Pixel) {
+		let containing_height = if BoxClass::is_absolutely_positioned(source) {
+			None
+		} else {
+			source
+				.containing_block()
+				.and_then(|containing_block| containing_block.layout_info().definite_height())
+		};
+		let height =
+			source
+				.layout_info()
+				.clamp_height(BoxClass::get_computed_values(source), height, containing_height);
+		source.layout_info_mut().height = height;
+		match source.class() {
+			BoxClass::Block => source.as_block_level_box().fragment_mut().set_height(height),
+			BoxClass::Inline => {
+				if let Some(fragment) = source.as_inline_level_box().fragments().last() {
+					fragment.borrow_mut().set_height(height);
+				}
+			},
+			_ => not_reached!(),
+		}
+	}
+
+	/// Whether `source` lays out its items itself, flex, grid, table and multi-column containers get the height of
+	/// their content in `arrange_items`.
+	pub fn arranges_items(source: &dyn Box) -> bool {
+		match source.formatting_context_type() {
+			FormattingContextType::FlexFormattingContext | FormattingContextType::GridFormattingContext => true,
+			FormattingContextType::TableFormattingContext => BoxClass::is_table(source),
+			FormattingContextType::BlockFormattingContext => BoxClass::is_multicol_container(source),
+			FormattingContextType::InlineFormattingContext => false,
+		}
+	}
+
+	/// A box with an auto height gets the height of its content. A height from the preferred aspect ratio grows with
+	/// the content too, unless the content is allowed to overflow it.
+	/// https://drafts.csswg.org/css-sizing-4/#aspect-ratio-minimum
+	pub fn fit_height_to_content(source: &dyn Box) {
+		let (height, has_definite_height) = {
+			let layout_info = source.layout_info();
+			(layout_info.height, layout_info.has_definite_height)
+		};
+		if !has_definite_height {
+			if !BoxClass::arranges_items(source) {
+				BoxClass::set_content_height(source, BoxClass::get_block_height(source));
+			}
+			return;
+		}
+		let computed_values = BoxClass::get_computed_values(source);
+		let has_ratio_height = BoxClass::get_aspect_ratio(computed_values).is_some()
+			&& !matches!(computed_values.get_height(), Size::LengthPercentage(_))
+			&& matches!(computed_values.get_min_height(), Size::Auto)
+			&& matches!(computed_values.get_overflow_x(), Overflow::Visible)
+			&& matches!(computed_values.get_overflow_y(), Overflow::Visible);
+		if has_ratio_height {
+			let content_height = BoxClass::get_block_height(source);
+			if content_height > height {
+				BoxClass::set_content_height(source, content_height);
+			}
+		}
+	}
+
+	/// Anonymous boxes have the style of the element they are generated in.
+	pub fn get_computed_values<'a>(source: &dyn Box) -> &'a mut ComputedValues {
+		match source.class() {
+			BoxClass::Block => GlobalScope::get_or_init_computed_values(source.as_block_level_box().dom_node().id()),
+			BoxClass::Inline => GlobalScope::get_or_init_computed_values(source.as_inline_level_box().dom_node().id()),
+			_ => BoxClass::get_computed_values(source.parent().unwrap().as_ref()),
+		}
+	}
+
+	/// Flex and grid items are blockified, so they are either block-level boxes or anonymous boxes wrapping text.
+	pub fn item_computed_values<'a>(item: &Rc<dyn Box>) -> Option<&'a mut ComputedValues> {
+		match item.class() {
+			BoxClass::Block => Some(GlobalScope::get_or_init_computed_values(
+				item.as_block_level_box().dom_node().id(),
+			)),
+			_ => None,
+		}
+	}
+
+	/// `x` and `y` are the position of the item's margin box inside the container's content box.
+	pub fn place_item(item: &Rc<dyn Box>, x: Pixel, y: Pixel, width: Pixel, height: Pixel, margin: Sides) {
+		match item.class() {
+			BoxClass::Block => {
+				let block = item.as_block_level_box();
+				let mut fragment = block.fragment_mut();
+				fragment.margin = margin;
+				fragment.set_x(x);
+				fragment.set_y(y);
+				fragment.set_width(width);
+				fragment.set_bounded_width(width);
+				fragment.set_height(height);
+				fragment.set_bounded_height(height);
+			},
+			BoxClass::Anonymous => {
+				let anonymous = item.as_anonymous_box();
+				let mut fragment = anonymous.fragment_mut();
+				fragment.set_x(x);
+				fragment.set_y(y);
+				fragment.set_width(width);
+				fragment.set_bounded_width(width);
+				fragment.set_height(height);
+				fragment.set_bounded_height(height);
+			},
+			_ => not_reached!(),
+		}
+		let mut layout_info = item.layout_info_mut();
+		layout_info.margin = margin;
+		layout_info.width = width;
+		layout_info.height = height;
+	}
+
 	pub fn set_containing_box(source: Rc<dyn Box>) {
 		let mut containing_block = None;
 		for ancestor in source.ancestors() {
@@ -398,7 +927,21 @@ impl BoxClass {
 			}
 		}
 		if let Some(containing_block) = containing_block {
-			source.set_containing_block(Some(containing_block.clone()));
+			let initial_containing_block = || source.ancestors().last().and_then(|root| root.containing_block());
+			let positioned_containing_block = match BoxClass::get_position(source.as_ref()) {
+				Position::Absolute => source
+					.ancestors()
+					.find(|ancestor| {
+						ancestor.is_block_container()
+							&& !matches!(BoxClass::get_position(ancestor.as_ref()), Position::Static)
+					})
+					.or_else(initial_containing_block),
+				Position::Fixed => initial_containing_block(),
+				_ => None,
+			};
+			source.set_containing_block(Some(
+				positioned_containing_block.unwrap_or_else(|| containing_block.clone()),
+			));
 			// during constructing box tree, there might be anonymous boxes which are added (as its parent) to ensure https://www.w3.org/TR/CSS22/visuren.html#anonymous-block-level
 			for ancestor in source.ancestors() {
 				match ancestor.class() {
@@ -417,6 +960,9 @@ impl BoxClass {
 		computed_values: &mut ComputedValues,
 		containing_width: Pixel,
 	) -> Sides {
+		if !TablePart::has_padding(computed_values) {
+			return Sides::default();
+		}
 		let padding_top = computed_values.get_padding_top().to_used_value(containing_width);
 		let padding_right = computed_values.get_padding_right().to_used_value(containing_width);
 		let padding_bottom = computed_values.get_padding_bottom().to_used_value(containing_width);
@@ -429,6 +975,29 @@ impl BoxClass {
 		}
 	}
 
+	/// A size of a `box-sizing: border-box` box includes the padding, which is `padding` on both sides of its axis.
+	/// Borders take no space in the layout.
+	/// https://drafts.csswg.org/css-sizing-3/#box-sizing
+	pub fn get_content_size(computed_values: &ComputedValues, size: Pixel, padding: Pixel) -> Pixel {
+		match computed_values.get_box_sizing() {
+			BoxSizing::ContentBox => size,
+			BoxSizing::BorderBox => (size - padding).max(PIXEL_ZERO),
+		}
+	}
+
+	/// The preferred aspect ratio as the width divided by the height, `auto` alone gives none to a non-replaced box and
+	/// so does a degenerate ratio.
+	/// https://drafts.csswg.org/css-sizing-4/#aspect-ratio
+	pub fn get_aspect_ratio(computed_values: &ComputedValues) -> Option<f32> {
+		let ratio = computed_values.get_aspect_ratio().ratio.as_ref()?;
+		let (width, height) = (ratio.0.get(), ratio.1.get());
+		if width > 0.0 && height > 0.0 {
+			Some(width / height)
+		} else {
+			None
+		}
+	}
+
 	pub fn get_margin_for_non_replaced_elements(
 		computed_values: &mut ComputedValues,
 		containing_width: Pixel,
@@ -465,9 +1034,12 @@ impl BoxClass {
 			},
 			BoxClass::Block | BoxClass::Anonymous => {
 				let lines = parent.lines();
-				let line_width = lines.last().map_or(PIXEL_ZERO, |latest_line| latest_line.width());
+				let (line_width, available_width) = match lines.last() {
+					Some(latest_line) => (latest_line.width(), latest_line.available_width()),
+					None => (PIXEL_ZERO, BoxClass::get_line_band(parent.as_ref(), &lines).1),
+				};
 				let layout_info = parent.layout_info();
-				(line_width, layout_info.width - line_width, layout_info.width)
+				(line_width, available_width - line_width, layout_info.width)
 			},
 			BoxClass::TextRun => not_reached!(),
 		}
@@ -501,7 +1073,7 @@ impl BoxClass {
 		lines: &mut RefMut<Vec<Line>>,
 		ancestors: SimpleBoxIterator,
 	) {
-		let latest_line = Line::new();
+		let latest_line = BoxClass::new_line(establisher.as_ref(), &lines[..]);
 		let mut child_fragment = fragment;
 		for ancestor in ancestors {
 			if ancestor.id() == establisher.id() {
@@ -525,22 +1097,32 @@ impl BoxClass {
 		lines.push(latest_line);
 	}
 
+	/// The fragments of every line are placed in visual order, aligned by `text-align` and aligned vertically before
+	/// the lines are stacked.
 	pub fn calculate_lines(source: &dyn Box) {
+		bidi::reorder_lines(source);
+		text_align::align_lines(source);
 		let mut height = PIXEL_ZERO;
 		for line in source.lines().iter() {
+			let line_box = line.align();
+			line_box.place_fragments();
 			line.set_y(height);
-			height += line.height();
+			height += line_box.height;
 		}
 	}
 
 	pub fn get_block_height(source: &dyn Box) -> Pixel {
-		match source.formatting_context_type() {
+		let height = match source.formatting_context_type() {
 			FormattingContextType::BlockFormattingContext => {
-				let mut height = PIXEL_ZERO;
-				for child in source.children() {
-					height += child.layout_info().total_height();
+				let (bottom, margin, is_empty) = BoxClass::get_adjoining_margins(source, None);
+				// the margins after the last child belong to `source` when they are adjoining its edges
+				if BoxClass::is_bottom_margin_adjoining_children(source)
+					|| (is_empty && BoxClass::is_top_margin_adjoining_children(source))
+				{
+					bottom
+				} else {
+					bottom + margin.solve()
 				}
-				height
 			},
 			FormattingContextType::InlineFormattingContext => {
 				let mut height = PIXEL_ZERO;
@@ -549,6 +1131,16 @@ impl BoxClass {
 				}
 				height
 			},
+			// flex, grid and table containers resolve their auto height in `arrange_items`
+			FormattingContextType::FlexFormattingContext
+			| FormattingContextType::GridFormattingContext
+			| FormattingContextType::TableFormattingContext => PIXEL_ZERO,
+		};
+		// https://www.w3.org/TR/CSS22/visudet.html#root-height
+		if BoxClass::establishes_block_formatting_context(source) {
+			height.max(source.formatting_context().floats.borrow().bottom())
+		} else {
+			height
 		}
 	}
 }
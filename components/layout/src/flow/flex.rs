@@ -0,0 +1,747 @@
+use std::rc::Rc;
+
+use common::not_reached;
+use css::computed_values::ComputedValues;
+use css::properties::longhands::align_content::AlignContent;
+use css::properties::longhands::align_items::AlignItems;
+use css::properties::longhands::align_self::AlignSelf;
+use css::properties::longhands::flex_direction::FlexDirection;
+use css::properties::longhands::flex_wrap::FlexWrap;
+use css::properties::longhands::justify_content::JustifyContent;
+use css::values::computed::length::{FlexBasis, LengthPercentageOrAuto, MaxSize, NonNegativeLengthPercentage, Size};
+use css::values::specified::layout::Overflow;
+use css::values::specified::position::{ContentDistribution, ContentPosition, OverflowPosition, SelfPosition};
+use css::values::{CSSFloat, Pixel, PIXEL_ZERO};
+use dom::global_scope::GlobalScope;
+
+use super::boxes::{Box, BoxClass};
+use super::fragment::Sides;
+
+/// Where an item sits inside its flex line, start and end are already flipped for `wrap-reverse`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CrossAlignment {
+	Start,
+	End,
+	Center,
+	Stretch,
+}
+
+/// https://drafts.csswg.org/css-flexbox-1/#flex-items
+struct FlexItem {
+	item: Rc<dyn Box>,
+	order: i32,
+	grow: CSSFloat,
+	shrink: CSSFloat,
+	margin: Sides,
+	padding: Sides,
+	/// margins and paddings along the main axis, they are not part of the flexed size
+	main_sides: Pixel,
+	cross_sides: Pixel,
+	/// auto margins at the main-start and main-end sides
+	main_auto_margins: (bool, bool),
+	/// auto margins at the cross-start and cross-end sides
+	cross_auto_margins: (bool, bool),
+	base_size: Pixel,
+	hypothetical_size: Pixel,
+	min_size: Pixel,
+	max_size: Option<Pixel>,
+	target_size: Pixel,
+	frozen: bool,
+	has_definite_cross_size: bool,
+	alignment: CrossAlignment,
+	/// `safe` alignment falls back to start when the item overflows its line
+	is_safe: bool,
+}
+
+impl FlexItem {
+	fn clamp(&self, size: Pixel) -> Pixel {
+		let size = match self.max_size {
+			Some(max_size) => size.min(max_size),
+			None => size,
+		};
+		size.max(self.min_size)
+	}
+
+	fn outer_hypothetical_size(&self) -> Pixel {
+		self.hypothetical_size + self.main_sides
+	}
+
+	fn outer_target_size(&self) -> Pixel {
+		self.target_size + self.main_sides
+	}
+}
+
+struct FlexLine {
+	items: Vec<FlexItem>,
+	cross_size: Pixel,
+}
+
+impl FlexLine {
+	fn new() -> Self {
+		FlexLine {
+			items: vec![],
+			cross_size: PIXEL_ZERO,
+		}
+	}
+
+	fn remaining_free_space(&self, main_size: Pixel) -> Pixel {
+		let mut used_space = PIXEL_ZERO;
+		for item in self.items.iter() {
+			used_space += item.main_sides + if item.frozen { item.target_size } else { item.base_size };
+		}
+		main_size - used_space
+	}
+
+	/// https://drafts.csswg.org/css-flexbox-1/#resolve-flexible-lengths
+	fn resolve_flexible_lengths(&mut self, main_size: Pixel) {
+		let mut hypothetical_size = PIXEL_ZERO;
+		for item in self.items.iter() {
+			hypothetical_size += item.outer_hypothetical_size();
+		}
+		let is_growing = hypothetical_size < main_size;
+		for item in self.items.iter_mut() {
+			let factor = if is_growing { item.grow } else { item.shrink };
+			item.target_size = item.hypothetical_size;
+			item.frozen = factor == 0.0
+				|| (is_growing && item.base_size > item.hypothetical_size)
+				|| (!is_growing && item.base_size < item.hypothetical_size);
+		}
+
+		let initial_free_space = self.remaining_free_space(main_size);
+		while self.items.iter().any(|item| !item.frozen) {
+			let mut free_space = self.remaining_free_space(main_size);
+			let mut factors = 0.0;
+			let mut scaled_shrink_factors = 0.0;
+			for item in self.items.iter().filter(|item| !item.frozen) {
+				factors += if is_growing { item.grow } else { item.shrink };
+				scaled_shrink_factors += item.shrink * item.base_size.get();
+			}
+			if factors < 1.0 && (initial_free_space * factors).get().abs() < free_space.get().abs() {
+				free_space = initial_free_space * factors;
+			}
+
+			let mut total_violation = PIXEL_ZERO;
+			let mut violations = Vec::with_capacity(self.items.len());
+			for item in self.items.iter_mut() {
+				if item.frozen {
+					violations.push(PIXEL_ZERO);
+					continue;
+				}
+				if is_growing {
+					item.target_size = item.base_size + free_space * (item.grow / factors);
+				} else if scaled_shrink_factors > 0.0 {
+					let ratio = item.shrink * item.base_size.get() / scaled_shrink_factors;
+					item.target_size = item.base_size + free_space * ratio;
+				}
+				let clamped_size = item.clamp(item.target_size);
+				violations.push(clamped_size - item.target_size);
+				total_violation += clamped_size - item.target_size;
+				item.target_size = clamped_size;
+			}
+			for (item, violation) in self.items.iter_mut().zip(violations) {
+				if item.frozen {
+					continue;
+				}
+				if total_violation == PIXEL_ZERO
+					|| (total_violation > PIXEL_ZERO && violation > PIXEL_ZERO)
+					|| (total_violation < PIXEL_ZERO && violation < PIXEL_ZERO)
+				{
+					item.frozen = true;
+				}
+			}
+		}
+	}
+}
+
+/// A block or inline-level box which establishes a flex formatting context.
+///
+/// The layout runs in two passes which follow the box tree traversal:
+/// - `layout_items`, before the items are laid out, sizes them along the inline axis. In a row container that is
+///   the main axis so line breaking and flexible lengths are resolved here.
+/// - `arrange_items`, after the items are laid out, resolves the flexible lengths of a column container, sizes
+///   the flex lines and aligns the items in both axes.
+///
+/// https://drafts.csswg.org/css-flexbox-1/#layout-algorithm
+pub struct FlexContainer<'a> {
+	source: &'a dyn Box,
+	computed_values: &'a ComputedValues,
+}
+
+impl<'a> FlexContainer<'a> {
+	pub fn new(source: &'a dyn Box) -> Self {
+		let dom_node = match source.class() {
+			BoxClass::Block => source.as_block_level_box().dom_node(),
+			BoxClass::Inline => source.as_inline_level_box().dom_node(),
+			_ => not_reached!(),
+		};
+		FlexContainer {
+			source,
+			computed_values: GlobalScope::get_or_init_computed_values(dom_node.id()),
+		}
+	}
+
+	fn is_row(&self) -> bool {
+		matches!(
+			self.computed_values.get_flex_direction(),
+			FlexDirection::Row | FlexDirection::RowReverse
+		)
+	}
+
+	fn is_main_reverse(&self) -> bool {
+		matches!(
+			self.computed_values.get_flex_direction(),
+			FlexDirection::RowReverse | FlexDirection::ColumnReverse
+		)
+	}
+
+	fn is_single_line(&self) -> bool {
+		matches!(self.computed_values.get_flex_wrap(), FlexWrap::Nowrap)
+	}
+
+	fn is_wrap_reverse(&self) -> bool {
+		matches!(self.computed_values.get_flex_wrap(), FlexWrap::WrapReverse)
+	}
+
+	/// https://drafts.csswg.org/css-flexbox-1/#intrinsic-sizes
+	pub fn intrinsic_widths(&self) -> (Pixel, Pixel) {
+		let mut minimum_width = PIXEL_ZERO;
+		let mut width = PIXEL_ZERO;
+		for child in self
+			.source
+			.children()
+			.into_iter()
+			.filter(|child| BoxClass::is_flex_item(child.as_ref()))
+		{
+			let layout_info = child.layout_info();
+			let child_minimum_width =
+				layout_info.intrinsic_size.preferred_minimum_width + layout_info.horizontal_sides();
+			let child_width = layout_info.intrinsic_size.preferred_width + layout_info.horizontal_sides();
+			if !self.is_row() {
+				minimum_width = minimum_width.max(child_minimum_width);
+				width = width.max(child_width);
+			} else if self.is_single_line() {
+				minimum_width += child_minimum_width;
+				width += child_width;
+			} else {
+				minimum_width = minimum_width.max(child_minimum_width);
+				width += child_width;
+			}
+		}
+		(minimum_width, width)
+	}
+
+	/// Sizes the items along the inline axis, it runs once the container knows its own width.
+	pub fn layout_items(&self) {
+		if self.is_row() {
+			let width = self.source.layout_info().width;
+			for line in self.collect_lines(Some(width)) {
+				for item in line.items {
+					let mut layout_info = item.item.layout_info_mut();
+					layout_info.margin = item.margin;
+					layout_info.padding = item.padding;
+					layout_info.width = item.target_size;
+				}
+			}
+		} else {
+			let container_width = self.source.layout_info().width;
+			for item in self.collect_items(None) {
+				let computed_values = BoxClass::item_computed_values(&item.item);
+				let size = computed_values.as_ref().map(|values| values.get_width().clone());
+				let mut layout_info = item.item.layout_info_mut();
+				let available_width = container_width - item.cross_sides;
+				let width = match size {
+					Some(Size::LengthPercentage(length_percentage)) => BoxClass::get_content_size(
+						computed_values.unwrap(),
+						length_percentage.to_used_value(container_width),
+						item.padding.left + item.padding.right,
+					),
+					// a multi-line container only knows its line widths after laying out the items
+					_ if item.alignment == CrossAlignment::Stretch
+						&& self.is_single_line()
+						&& item.cross_auto_margins == (false, false) =>
+					{
+						available_width
+					},
+					_ => layout_info
+						.intrinsic_size
+						.preferred_width
+						.min(layout_info.intrinsic_size.preferred_minimum_width.max(available_width)),
+				};
+				layout_info.margin = item.margin;
+				layout_info.padding = item.padding;
+				layout_info.width = width.max(PIXEL_ZERO);
+			}
+		}
+	}
+
+	/// Sizes the flex lines, aligns and positions the items, it runs once the items are laid out.
+	/// A container with an auto height gets the height of its lines.
+	pub fn arrange_items(&self) {
+		let (width, mut height, has_definite_height) = {
+			let layout_info = self.source.layout_info();
+			(layout_info.width, layout_info.height, layout_info.has_definite_height)
+		};
+		let mut lines = if self.is_row() {
+			self.collect_lines(Some(width))
+		} else {
+			if !has_definite_height {
+				for line in self.collect_lines(None) {
+					let mut line_height = PIXEL_ZERO;
+					for item in line.items.iter() {
+						line_height += item.outer_target_size();
+					}
+					height = height.max(line_height);
+				}
+				BoxClass::set_content_height(self.source, height);
+				height = self.source.layout_info().height;
+			}
+			self.collect_lines(Some(height))
+		};
+
+		for line in lines.iter_mut() {
+			for item in line.items.iter() {
+				line.cross_size = line.cross_size.max(self.outer_cross_size(item));
+			}
+		}
+		if self.is_row() && !has_definite_height {
+			for line in lines.iter() {
+				height += line.cross_size;
+			}
+			BoxClass::set_content_height(self.source, height);
+			height = self.source.layout_info().height;
+		}
+
+		let (main_size, cross_size) = if self.is_row() {
+			(width, height)
+		} else {
+			(height, width)
+		};
+		if self.is_single_line() {
+			lines[0].cross_size = cross_size;
+		}
+		let (leading_space, between_space, stretch_space) = self.distribute_lines(&lines, cross_size);
+
+		let mut line_offset = leading_space;
+		for line in lines.iter_mut() {
+			line.cross_size += stretch_space;
+			self.arrange_line(line, main_size, cross_size, line_offset);
+			line_offset += line.cross_size + between_space;
+		}
+	}
+
+	fn outer_cross_size(&self, item: &FlexItem) -> Pixel {
+		let layout_info = item.item.layout_info();
+		if self.is_row() {
+			layout_info.height + item.cross_sides
+		} else {
+			layout_info.width + item.cross_sides
+		}
+	}
+
+	/// The space before the first line, between lines and added to each line.
+	/// https://drafts.csswg.org/css-align-3/#align-justify-content
+	fn distribute_lines(&self, lines: &[FlexLine], cross_size: Pixel) -> (Pixel, Pixel, Pixel) {
+		if self.is_single_line() {
+			return (PIXEL_ZERO, PIXEL_ZERO, PIXEL_ZERO);
+		}
+		let mut free_space = cross_size;
+		for line in lines.iter() {
+			free_space -= line.cross_size;
+		}
+		let count = lines.len() as CSSFloat;
+		match self.computed_values.get_align_content() {
+			AlignContent::Normal | AlignContent::Distribution(ContentDistribution::Stretch)
+				if free_space > PIXEL_ZERO =>
+			{
+				(PIXEL_ZERO, PIXEL_ZERO, free_space / count)
+			},
+			AlignContent::Distribution(ContentDistribution::SpaceBetween) if free_space > PIXEL_ZERO && count > 1.0 => {
+				(PIXEL_ZERO, free_space / (count - 1.0), PIXEL_ZERO)
+			},
+			AlignContent::Distribution(ContentDistribution::SpaceAround) => {
+				if free_space > PIXEL_ZERO {
+					(free_space / count / 2.0, free_space / count, PIXEL_ZERO)
+				} else {
+					(free_space / 2.0, PIXEL_ZERO, PIXEL_ZERO)
+				}
+			},
+			AlignContent::Distribution(ContentDistribution::SpaceEvenly) => {
+				if free_space > PIXEL_ZERO {
+					(free_space / (count + 1.0), free_space / (count + 1.0), PIXEL_ZERO)
+				} else {
+					(free_space / 2.0, PIXEL_ZERO, PIXEL_ZERO)
+				}
+			},
+			AlignContent::Overflow(_, ContentPosition::Center) => (free_space / 2.0, PIXEL_ZERO, PIXEL_ZERO),
+			AlignContent::Overflow(_, ContentPosition::FlexEnd) => (free_space, PIXEL_ZERO, PIXEL_ZERO),
+			AlignContent::Overflow(_, ContentPosition::End) if !self.is_wrap_reverse() => {
+				(free_space, PIXEL_ZERO, PIXEL_ZERO)
+			},
+			AlignContent::Overflow(_, ContentPosition::Start) if self.is_wrap_reverse() => {
+				(free_space, PIXEL_ZERO, PIXEL_ZERO)
+			},
+			_ => (PIXEL_ZERO, PIXEL_ZERO, PIXEL_ZERO),
+		}
+	}
+
+	/// https://drafts.csswg.org/css-flexbox-1/#main-alignment
+	/// https://drafts.csswg.org/css-flexbox-1/#cross-alignment
+	fn arrange_line(&self, line: &mut FlexLine, main_size: Pixel, cross_size: Pixel, line_offset: Pixel) {
+		let mut free_space = main_size;
+		let mut auto_margins = 0;
+		for item in line.items.iter() {
+			free_space -= item.outer_target_size();
+			auto_margins += item.main_auto_margins.0 as usize + item.main_auto_margins.1 as usize;
+		}
+		let count = line.items.len() as CSSFloat;
+		let auto_margin_space = if free_space > PIXEL_ZERO && auto_margins > 0 {
+			free_space / auto_margins as CSSFloat
+		} else {
+			PIXEL_ZERO
+		};
+		let (leading_space, between_space) = if auto_margins > 0 && free_space > PIXEL_ZERO {
+			(PIXEL_ZERO, PIXEL_ZERO)
+		} else {
+			match self.computed_values.get_justify_content() {
+				JustifyContent::Normal | JustifyContent::FlexStart | JustifyContent::Stretch => {
+					(PIXEL_ZERO, PIXEL_ZERO)
+				},
+				// start and end follow the writing mode, so they flip with a reversed main axis
+				JustifyContent::Start if self.is_main_reverse() => (free_space, PIXEL_ZERO),
+				JustifyContent::Start => (PIXEL_ZERO, PIXEL_ZERO),
+				JustifyContent::End if self.is_main_reverse() => (PIXEL_ZERO, PIXEL_ZERO),
+				JustifyContent::FlexEnd | JustifyContent::End => (free_space, PIXEL_ZERO),
+				JustifyContent::Center => (free_space / 2.0, PIXEL_ZERO),
+				JustifyContent::SpaceBetween if free_space > PIXEL_ZERO && count > 1.0 => {
+					(PIXEL_ZERO, free_space / (count - 1.0))
+				},
+				JustifyContent::SpaceBetween => (PIXEL_ZERO, PIXEL_ZERO),
+				JustifyContent::SpaceAround if free_space > PIXEL_ZERO => {
+					(free_space / count / 2.0, free_space / count)
+				},
+				JustifyContent::SpaceAround => (free_space / 2.0, PIXEL_ZERO),
+				JustifyContent::SpaceEvenly if free_space > PIXEL_ZERO => {
+					(free_space / (count + 1.0), free_space / (count + 1.0))
+				},
+				JustifyContent::SpaceEvenly => (free_space / 2.0, PIXEL_ZERO),
+			}
+		};
+
+		let mut main_offset = leading_space;
+		for item in line.items.iter() {
+			let main_start_margin = if item.main_auto_margins.0 {
+				auto_margin_space
+			} else {
+				PIXEL_ZERO
+			};
+			let main_end_margin = if item.main_auto_margins.1 {
+				auto_margin_space
+			} else {
+				PIXEL_ZERO
+			};
+			let outer_main_size = item.outer_target_size() + main_start_margin + main_end_margin;
+
+			let stretched = item.alignment == CrossAlignment::Stretch
+				&& !item.has_definite_cross_size
+				&& item.cross_auto_margins == (false, false);
+			let inner_cross_size = if stretched {
+				(line.cross_size - item.cross_sides).max(PIXEL_ZERO)
+			} else {
+				self.outer_cross_size(item) - item.cross_sides
+			};
+			let cross_free_space = line.cross_size - inner_cross_size - item.cross_sides;
+			let (cross_start_margin, cross_end_margin) = match item.cross_auto_margins {
+				(true, true) if cross_free_space > PIXEL_ZERO => (cross_free_space / 2.0, cross_free_space / 2.0),
+				(true, false) if cross_free_space > PIXEL_ZERO => (cross_free_space, PIXEL_ZERO),
+				(false, true) if cross_free_space > PIXEL_ZERO => (PIXEL_ZERO, cross_free_space),
+				_ => (PIXEL_ZERO, PIXEL_ZERO),
+			};
+			let has_auto_margins = cross_start_margin != PIXEL_ZERO || cross_end_margin != PIXEL_ZERO;
+			let cross_offset = if has_auto_margins || (item.is_safe && cross_free_space < PIXEL_ZERO) {
+				PIXEL_ZERO
+			} else {
+				match item.alignment {
+					CrossAlignment::Start | CrossAlignment::Stretch => PIXEL_ZERO,
+					CrossAlignment::End => cross_free_space,
+					CrossAlignment::Center => cross_free_space / 2.0,
+				}
+			};
+			let outer_cross_size = inner_cross_size + item.cross_sides + cross_start_margin + cross_end_margin;
+
+			let main_position = if self.is_main_reverse() {
+				main_size - main_offset - outer_main_size
+			} else {
+				main_offset
+			};
+			let cross_position = if self.is_wrap_reverse() {
+				cross_size - line_offset - cross_offset - outer_cross_size
+			} else {
+				line_offset + cross_offset
+			};
+
+			let mut margin = item.margin;
+			self.add_auto_margins(
+				&mut margin,
+				(main_start_margin, main_end_margin),
+				(cross_start_margin, cross_end_margin),
+			);
+			if self.is_row() {
+				BoxClass::place_item(
+					&item.item,
+					main_position,
+					cross_position,
+					item.target_size,
+					inner_cross_size,
+					margin,
+				);
+			} else {
+				BoxClass::place_item(
+					&item.item,
+					cross_position,
+					main_position,
+					inner_cross_size,
+					item.target_size,
+					margin,
+				);
+			}
+			main_offset += outer_main_size + between_space;
+		}
+	}
+
+	/// `main` and `cross` are the space given to the auto margins in (start, end) order.
+	fn add_auto_margins(&self, margin: &mut Sides, main: (Pixel, Pixel), cross: (Pixel, Pixel)) {
+		let (main_before, main_after) = if self.is_main_reverse() { (main.1, main.0) } else { main };
+		let (cross_before, cross_after) = if self.is_wrap_reverse() {
+			(cross.1, cross.0)
+		} else {
+			cross
+		};
+		if self.is_row() {
+			margin.left += main_before;
+			margin.right += main_after;
+			margin.top += cross_before;
+			margin.bottom += cross_after;
+		} else {
+			margin.top += main_before;
+			margin.bottom += main_after;
+			margin.left += cross_before;
+			margin.right += cross_after;
+		}
+	}
+
+	/// https://drafts.csswg.org/css-flexbox-1/#algo-line-break
+	/// Without a main size, the container is laid out in a single line and nothing is flexed.
+	fn collect_lines(&self, main_size: Option<Pixel>) -> Vec<FlexLine> {
+		let mut lines = vec![];
+		let mut line = FlexLine::new();
+		let mut line_size = PIXEL_ZERO;
+		for item in self.collect_items(main_size) {
+			let outer_size = item.outer_hypothetical_size();
+			if let Some(main_size) = main_size {
+				if !self.is_single_line() && !line.items.is_empty() && line_size + outer_size > main_size {
+					lines.push(std::mem::replace(&mut line, FlexLine::new()));
+					line_size = PIXEL_ZERO;
+				}
+			}
+			line_size += outer_size;
+			line.items.push(item);
+		}
+		if !line.items.is_empty() || lines.is_empty() {
+			lines.push(line);
+		}
+		for line in lines.iter_mut() {
+			match main_size {
+				Some(main_size) => line.resolve_flexible_lengths(main_size),
+				None => {
+					for item in line.items.iter_mut() {
+						item.target_size = item.hypothetical_size;
+					}
+				},
+			}
+		}
+		lines
+	}
+
+	/// Items in order-modified document order.
+	/// https://drafts.csswg.org/css-display/#order-modified-document-order
+	fn collect_items(&self, main_size: Option<Pixel>) -> Vec<FlexItem> {
+		let mut items: Vec<FlexItem> = self
+			.source
+			.children()
+			.into_iter()
+			.filter(|child| BoxClass::is_flex_item(child.as_ref()))
+			.map(|child| self.create_item(child, main_size))
+			.collect();
+		items.sort_by_key(|item| item.order);
+		items
+	}
+
+	fn create_item(&self, item: Rc<dyn Box>, main_size: Option<Pixel>) -> FlexItem {
+		// anonymous items hold the initial values of the flex properties
+		let mut initial_values = ComputedValues::default();
+		let computed_values = match BoxClass::item_computed_values(&item) {
+			Some(computed_values) => computed_values,
+			None => &mut initial_values,
+		};
+		let containing_width = self.source.layout_info().width;
+		let margin = BoxClass::get_margin_for_non_replaced_elements(computed_values, containing_width);
+		let padding = BoxClass::get_padding_for_non_replaced_elements(computed_values, containing_width);
+		let is_auto = |value: &LengthPercentageOrAuto| *value == LengthPercentageOrAuto::Auto;
+		let (horizontal_auto_margins, vertical_auto_margins) = (
+			(
+				is_auto(computed_values.get_margin_left()),
+				is_auto(computed_values.get_margin_right()),
+			),
+			(
+				is_auto(computed_values.get_margin_top()),
+				is_auto(computed_values.get_margin_bottom()),
+			),
+		);
+		let horizontal_sides = margin.left + padding.left + padding.right + margin.right;
+		let vertical_sides = margin.top + padding.top + padding.bottom + margin.bottom;
+
+		let main_padding = if self.is_row() {
+			padding.left + padding.right
+		} else {
+			padding.top + padding.bottom
+		};
+		let resolve = |value: &NonNegativeLengthPercentage| -> Option<Pixel> {
+			match main_size {
+				Some(main_size) => Some(value.to_used_value(main_size)),
+				None => value.to_fixed_used_value(),
+			}
+			.map(|size| BoxClass::get_content_size(computed_values, size, main_padding))
+		};
+		let layout_info = item.layout_info();
+		let (main_property, min_property, max_property, content_size, minimum_content_size, overflow) = if self.is_row()
+		{
+			(
+				computed_values.get_width(),
+				computed_values.get_min_width(),
+				computed_values.get_max_width(),
+				layout_info.intrinsic_size.preferred_width,
+				layout_info.intrinsic_size.preferred_minimum_width,
+				computed_values.get_overflow_x(),
+			)
+		} else {
+			(
+				computed_values.get_height(),
+				computed_values.get_min_height(),
+				computed_values.get_max_height(),
+				layout_info.height,
+				layout_info.height,
+				computed_values.get_overflow_y(),
+			)
+		};
+		let main_property_size = match main_property {
+			Size::LengthPercentage(length_percentage) => resolve(length_percentage),
+			_ => None,
+		};
+
+		// https://drafts.csswg.org/css-flexbox-1/#algo-main-item
+		let base_size = match computed_values.get_flex_basis() {
+			FlexBasis::Width(Size::LengthPercentage(length_percentage)) => {
+				resolve(length_percentage).unwrap_or(content_size)
+			},
+			FlexBasis::Width(Size::Auto) => main_property_size.unwrap_or(content_size),
+			_ => content_size,
+		};
+		// https://drafts.csswg.org/css-flexbox-1/#min-size-auto
+		let min_size = match min_property {
+			Size::LengthPercentage(length_percentage) => resolve(length_percentage).unwrap_or(PIXEL_ZERO),
+			_ if matches!(overflow, Overflow::Visible) => match main_property_size {
+				Some(size) => minimum_content_size.min(size),
+				None => minimum_content_size,
+			},
+			_ => PIXEL_ZERO,
+		};
+		let max_size = match max_property {
+			MaxSize::LengthPercentage(length_percentage) => resolve(length_percentage),
+			_ => None,
+		};
+		let has_definite_cross_size = if self.is_row() {
+			computed_values.get_height().to_fixed_used_value().is_some()
+		} else {
+			matches!(computed_values.get_width(), Size::LengthPercentage(_))
+		};
+		let (alignment, is_safe) = self.resolve_alignment(computed_values);
+		drop(layout_info);
+
+		let (main_sides, cross_sides, main_auto_margins, cross_auto_margins) = if self.is_row() {
+			(
+				horizontal_sides,
+				vertical_sides,
+				horizontal_auto_margins,
+				vertical_auto_margins,
+			)
+		} else {
+			(
+				vertical_sides,
+				horizontal_sides,
+				vertical_auto_margins,
+				horizontal_auto_margins,
+			)
+		};
+		let main_auto_margins = if self.is_main_reverse() {
+			(main_auto_margins.1, main_auto_margins.0)
+		} else {
+			main_auto_margins
+		};
+		let cross_auto_margins = if self.is_wrap_reverse() {
+			(cross_auto_margins.1, cross_auto_margins.0)
+		} else {
+			cross_auto_margins
+		};
+
+		let mut flex_item = FlexItem {
+			order: computed_values.get_order(),
+			grow: computed_values.get_flex_grow(),
+			shrink: computed_values.get_flex_shrink(),
+			item,
+			margin,
+			padding,
+			main_sides,
+			cross_sides,
+			main_auto_margins,
+			cross_auto_margins,
+			base_size,
+			hypothetical_size: base_size,
+			min_size: min_size.max(PIXEL_ZERO),
+			max_size,
+			target_size: base_size,
+			frozen: false,
+			has_definite_cross_size,
+			alignment,
+			is_safe,
+		};
+		flex_item.hypothetical_size = flex_item.clamp(base_size);
+		flex_item.target_size = flex_item.hypothetical_size;
+		flex_item
+	}
+
+	/// https://drafts.csswg.org/css-align-3/#align-flex
+	fn resolve_alignment(&self, computed_values: &ComputedValues) -> (CrossAlignment, bool) {
+		let (overflow, position) = match computed_values.get_align_self() {
+			AlignSelf::Auto => match self.computed_values.get_align_items() {
+				AlignItems::Normal | AlignItems::Stretch => return (CrossAlignment::Stretch, false),
+				AlignItems::Baseline(_) => return (CrossAlignment::Start, false),
+				AlignItems::Overflow(overflow, position) => (overflow, position),
+			},
+			AlignSelf::Normal | AlignSelf::Stretch => return (CrossAlignment::Stretch, false),
+			AlignSelf::Baseline(_) => return (CrossAlignment::Start, false),
+			AlignSelf::Overflow(overflow, position) => (overflow, position),
+		};
+		let is_safe = matches!(overflow, Some(OverflowPosition::Safe));
+		// start and end follow the writing mode, so they do not flip with wrap-reverse
+		let alignment = match position {
+			SelfPosition::Center => CrossAlignment::Center,
+			SelfPosition::FlexStart => CrossAlignment::Start,
+			SelfPosition::FlexEnd => CrossAlignment::End,
+			SelfPosition::Start | SelfPosition::SelfStart if self.is_wrap_reverse() => CrossAlignment::End,
+			SelfPosition::Start | SelfPosition::SelfStart => CrossAlignment::Start,
+			SelfPosition::End | SelfPosition::SelfEnd if self.is_wrap_reverse() => CrossAlignment::Start,
+			SelfPosition::End | SelfPosition::SelfEnd => CrossAlignment::End,
+		};
+		(alignment, is_safe)
+	}
+}
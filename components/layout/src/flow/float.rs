@@ -0,0 +1,113 @@
+use css::properties::longhands::clear::Clear;
+use css::properties::longhands::float::Float;
+use css::values::{CSSPixel, Pixel, PIXEL_ZERO};
+use euclid::{Point2D, Rect, Size2D};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FloatSide {
+	Left,
+	Right,
+}
+
+impl FloatSide {
+	/// Inline-start and inline-end are resolved for a left-to-right direction.
+	pub fn from_float(float: &Float) -> Option<FloatSide> {
+		match float {
+			Float::Left | Float::InlineStart => Some(FloatSide::Left),
+			Float::Right | Float::InlineEnd => Some(FloatSide::Right),
+			Float::None => None,
+		}
+	}
+}
+
+/// Floats which are placed in a block formatting context, their margin boxes are relative to the content box of the
+/// box establishing it.
+/// https://www.w3.org/TR/CSS22/visuren.html#floats
+#[derive(Debug, Default)]
+pub struct FloatContext {
+	floats: Vec<(FloatSide, Rect<Pixel, CSSPixel>)>,
+}
+
+impl FloatContext {
+	/// The left and right edges of the space between `left` and `right` which is not taken by floats
+	/// within the band from `y` to `y + height`, an empty band is the line at `y`.
+	pub fn available_band(&self, y: Pixel, height: Pixel, left: Pixel, right: Pixel) -> (Pixel, Pixel) {
+		let mut band = (left, right);
+		for (side, rect) in self.overlapping_floats(y, height) {
+			match side {
+				FloatSide::Left => band.0 = band.0.max(rect.max_x()),
+				FloatSide::Right => band.1 = band.1.min(rect.min_x()),
+			}
+		}
+		band
+	}
+
+	/// https://www.w3.org/TR/CSS22/visuren.html#float-rules
+	pub fn place(
+		&mut self,
+		side: FloatSide,
+		width: Pixel,
+		height: Pixel,
+		min_y: Pixel,
+		left: Pixel,
+		right: Pixel,
+	) -> (Pixel, Pixel) {
+		// the outer top of a float may not be higher than the outer top of any earlier float
+		let mut y = self.floats.iter().fold(min_y, |y, (_, rect)| y.max(rect.min_y()));
+		loop {
+			let (band_left, band_right) = self.available_band(y, height, left, right);
+			let next_y = self
+				.overlapping_floats(y, height)
+				.map(|(_, rect)| rect.max_y())
+				.fold(None, |next_y: Option<Pixel>, bottom| {
+					Some(next_y.map_or(bottom, |next_y| next_y.min(bottom)))
+				});
+			match next_y {
+				Some(next_y) if band_right - band_left < width => y = next_y,
+				_ => {
+					let x = match side {
+						FloatSide::Left => band_left,
+						FloatSide::Right => band_right - width,
+					};
+					self.floats
+						.push((side, Rect::new(Point2D::new(x, y), Size2D::new(width, height))));
+					return (x, y);
+				},
+			}
+		}
+	}
+
+	/// The outer bottom of the lowest float which a box with `clear` has to be placed below.
+	/// https://www.w3.org/TR/CSS22/visuren.html#flow-control
+	pub fn clearance(&self, clear: &Clear) -> Option<Pixel> {
+		let (left, right) = match clear {
+			Clear::None => return None,
+			Clear::Left | Clear::InlineStart => (true, false),
+			Clear::Right | Clear::InlineEnd => (false, true),
+			Clear::Both => (true, true),
+		};
+		self.floats
+			.iter()
+			.filter(|(side, _)| match side {
+				FloatSide::Left => left,
+				FloatSide::Right => right,
+			})
+			.map(|(_, rect)| rect.max_y())
+			.fold(None, |bottom: Option<Pixel>, value| {
+				Some(bottom.map_or(value, |bottom| bottom.max(value)))
+			})
+	}
+
+	/// The outer bottom of the lowest float, a block formatting context root grows to contain it.
+	pub fn bottom(&self) -> Pixel {
+		self.floats
+			.iter()
+			.fold(PIXEL_ZERO, |bottom, (_, rect)| bottom.max(rect.max_y()))
+	}
+
+	fn overlapping_floats(&self, y: Pixel, height: Pixel) -> impl Iterator<Item = &(FloatSide, Rect<Pixel, CSSPixel>)> {
+		self.floats
+			.iter()
+			.filter(move |(_, rect)| y < rect.max_y() && (rect.min_y() < y + height || rect.min_y() <= y))
+	}
+}
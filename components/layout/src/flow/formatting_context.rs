@@ -1,18 +1,27 @@
 use core::panic;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::{Rc, Weak};
 
 use super::boxes::Box;
+use super::float::FloatContext;
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum FormattingContextType {
 	BlockFormattingContext,
 	InlineFormattingContext,
+	FlexFormattingContext,
+	GridFormattingContext,
+	TableFormattingContext,
 }
 
 pub struct FormattingContext {
 	pub formatting_context_type: FormattingContextType,
 	pub established_by: RefCell<Option<Weak<dyn Box>>>,
+	pub floats: RefCell<FloatContext>,
+	/// Whether the inline content laid out so far ends with a collapsible space or a line break, the collapsible
+	/// spaces at the start of the next text are removed then.
+	/// https://drafts.csswg.org/css-text/#white-space-phase-1
+	pub after_collapsible_space: Cell<bool>,
 }
 
 impl FormattingContext {
@@ -20,6 +29,8 @@ impl FormattingContext {
 		FormattingContext {
 			established_by: RefCell::new(None),
 			formatting_context_type: context_type,
+			floats: Default::default(),
+			after_collapsible_space: Cell::new(true),
 		}
 	}
 
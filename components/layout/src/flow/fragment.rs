@@ -1,25 +1,44 @@
-use std::cell::{Ref, RefCell};
+use std::cell::{Cell, Ref, RefCell};
 use std::rc::Rc;
 
 use css::computed_values::ComputedValues;
+use css::properties::longhands::box_sizing::BoxSizing;
+use css::values::computed::length::{MaxSize, NonNegativeLengthPercentage, Size};
+use css::values::generics::length::ExtremumLength;
 use css::values::{CSSPixel, Pixel, PIXEL_ZERO};
 use dom::global_scope::{GlobalScope, NodeRef};
-use euclid::{Point2D, Rect, Size2D};
+use euclid::{Point2D, Rect, Size2D, Vector2D};
 
-use super::boxes::Box;
+use super::boxes::{Box, BoxClass};
+use super::flex::FlexContainer;
 use super::formatting_context::FormattingContextType;
+use super::grid::GridContainer;
+use super::line_height::{align_line, LineBox, Strut};
+use super::margin::CollapsedMargin;
+use super::multicol::MulticolContainer;
+use super::table::{TableContainer, TablePart};
 use crate::display_list::builder::{BuilderContext, DisplayListBuilder};
+use crate::text::ShapedText;
 
 pub struct Line {
 	pub fragments: RefCell<Vec<Rc<RefCell<dyn Fragment>>>>, // BoxFragment or TextFragment
 	pub bounds: RefCell<Rect<Pixel, CSSPixel>>,
+	// the strut of the box establishing the line, which every line box starts with
+	pub strut: Strut,
+	// a line ended by a forced break is aligned like the last line, `text-indent: each-line` indents the next one
+	pub ends_with_forced_break: Cell<bool>,
+	// the height and the baseline of the line box once it is aligned, see `align`
+	aligned: Cell<Option<(Pixel, Pixel)>>,
 }
 
 impl Line {
-	pub fn new() -> Self {
+	pub fn new(strut: Strut) -> Self {
 		Line {
 			fragments: Default::default(),
 			bounds: Default::default(),
+			strut,
+			ends_with_forced_break: Cell::new(false),
+			aligned: Cell::new(None),
 		}
 	}
 
@@ -32,11 +51,27 @@ impl Line {
 	}
 
 	pub fn height(&self) -> Pixel {
-		let mut height = PIXEL_ZERO;
-		for fragment in self.fragments.borrow().iter() {
-			height = height.max(fragment.borrow().total_height());
-		}
-		height
+		self.height_and_baseline().0
+	}
+
+	/// The distance from the top of the line to the baseline of its strut.
+	pub fn baseline(&self) -> Pixel {
+		self.height_and_baseline().1
+	}
+
+	fn height_and_baseline(&self) -> (Pixel, Pixel) {
+		self.aligned.get().unwrap_or_else(|| {
+			let line_box = align_line(self);
+			(line_box.height, line_box.baseline)
+		})
+	}
+
+	/// Aligns the fragments vertically and keeps the height and the baseline of the line box, which is done once the
+	/// next line starts and again once the fragments are reordered.
+	pub fn align(&self) -> LineBox {
+		let line_box = align_line(self);
+		self.aligned.set(Some((line_box.height, line_box.baseline)));
+		line_box
 	}
 
 	pub fn y(&self) -> Pixel {
@@ -47,8 +82,24 @@ impl Line {
 		self.bounds.borrow_mut().origin.y = value;
 	}
 
+	pub fn x(&self) -> Pixel {
+		self.bounds.borrow().origin.x
+	}
+
+	/// The width between floats which is left for the line's fragments
+	pub fn available_width(&self) -> Pixel {
+		self.bounds.borrow().size.width
+	}
+
+	pub fn set_band(&self, x: Pixel, width: Pixel) {
+		let mut bounds = self.bounds.borrow_mut();
+		bounds.origin.x = x;
+		bounds.size.width = width;
+	}
+
 	pub fn add_fragment(&self, fragment: Rc<RefCell<dyn Fragment>>) {
 		self.fragments.borrow_mut().push(fragment.clone());
+		self.aligned.set(None);
 	}
 
 	pub fn fragments(&self) -> Ref<Vec<Rc<RefCell<dyn Fragment>>>> {
@@ -75,11 +126,17 @@ impl Default for Sides {
 	}
 }
 
+/// The widths of a box which are measured from its content before the layout.
+/// https://drafts.csswg.org/css-sizing-3/#intrinsic-sizes
 #[derive(Debug)]
 pub struct IntrinsicSize {
+	// the max-content and min-content contributions of the content box, which its parent is measured with
 	pub preferred_width: Pixel,
 	pub preferred_height: Pixel,
 	pub preferred_minimum_width: Pixel,
+	// the min-content and max-content widths of the content, whatever the sizing properties of the box are
+	pub min_content_width: Pixel,
+	pub max_content_width: Pixel,
 }
 
 impl Default for IntrinsicSize {
@@ -88,6 +145,32 @@ impl Default for IntrinsicSize {
 			preferred_width: PIXEL_ZERO,
 			preferred_minimum_width: PIXEL_ZERO,
 			preferred_height: PIXEL_ZERO,
+			min_content_width: PIXEL_ZERO,
+			max_content_width: PIXEL_ZERO,
+		}
+	}
+}
+
+impl IntrinsicSize {
+	/// The shrink-to-fit width, the content is as wide as `available_width` allows but not narrower than its
+	/// min-content width.
+	/// https://drafts.csswg.org/css-sizing-3/#fit-content-size
+	pub fn fit_content(&self, available_width: Pixel) -> Pixel {
+		self.max_content_width.min(self.min_content_width.max(available_width))
+	}
+
+	/// https://drafts.csswg.org/css-sizing-3/#sizing-values
+	pub fn resolve_extremum(
+		&self,
+		extremum: &ExtremumLength<NonNegativeLengthPercentage>,
+		containing_width: Pixel,
+	) -> Pixel {
+		match extremum {
+			ExtremumLength::MinContent => self.min_content_width,
+			ExtremumLength::MaxContent => self.max_content_width,
+			ExtremumLength::FitContent(length_percentage) => {
+				self.fit_content(length_percentage.to_used_value(containing_width))
+			},
 		}
 	}
 }
@@ -99,6 +182,13 @@ pub struct LayoutInfo {
 	pub margin: Sides,
 	pub padding: Sides,
 	pub intrinsic_size: IntrinsicSize,
+	// margins at the top and bottom edges, including the margins of children which collapse with them
+	pub collapsed_margin_top: CollapsedMargin,
+	pub collapsed_margin_bottom: CollapsedMargin,
+	// the top and bottom margins collapse together when there is nothing in between
+	pub collapses_through: bool,
+	// the height doesn't depend on the content, percentage heights of the children are resolved against it
+	pub has_definite_height: bool,
 }
 
 impl Default for LayoutInfo {
@@ -109,6 +199,10 @@ impl Default for LayoutInfo {
 			margin: Default::default(),
 			padding: Default::default(),
 			intrinsic_size: Default::default(),
+			collapsed_margin_top: Default::default(),
+			collapsed_margin_bottom: Default::default(),
+			collapses_through: false,
+			has_definite_height: false,
 		}
 	}
 }
@@ -164,6 +258,9 @@ impl LayoutInfo {
 	}
 
 	pub fn compute_fixed_padding(&mut self, computed_values: &mut ComputedValues) {
+		if !TablePart::has_padding(computed_values) {
+			return;
+		}
 		if let Some(padding_top) = computed_values.get_padding_top().to_fixed_used_value() {
 			self.padding.top = padding_top;
 		}
@@ -178,33 +275,72 @@ impl LayoutInfo {
 		}
 	}
 
+	pub fn horizontal_padding(&self) -> Pixel {
+		self.padding.left + self.padding.right
+	}
+
+	pub fn vertical_padding(&self) -> Pixel {
+		self.padding.top + self.padding.bottom
+	}
+
 	pub fn compute_width_and_height(&mut self, computed_values: &mut ComputedValues) {
 		if let Some(width) = computed_values.get_width().to_fixed_used_value() {
-			self.width = width;
-			self.intrinsic_size.preferred_minimum_width = width;
-			self.intrinsic_size.preferred_width = width;
+			self.width = BoxClass::get_content_size(computed_values, width, self.horizontal_padding());
 		}
 		if let Some(height) = computed_values.get_height().to_fixed_used_value() {
-			self.height = height;
+			let height = BoxClass::get_content_size(computed_values, height, self.vertical_padding());
+			self.set_height(Some(self.clamp_height(computed_values, height, None)));
 		}
 	}
 
+	/// The height when it doesn't depend on the content.
+	pub fn definite_height(&self) -> Option<Pixel> {
+		if self.has_definite_height {
+			Some(self.height)
+		} else {
+			None
+		}
+	}
+
+	/// `None` is a height which depends on the content, it stays zero until the content is laid out, see
+	/// `BoxClass::fit_height_to_content`.
+	pub fn set_height(&mut self, height: Option<Pixel>) {
+		self.height = height.unwrap_or(PIXEL_ZERO);
+		self.has_definite_height = height.is_some();
+	}
+
+	/// The min-content and max-content widths of the content box from the contributions of the children, they are
+	/// the contributions of the box too until `compute_contributions` applies its sizing properties.
 	pub fn compute_intrinsic(&mut self, node: &dyn Box) {
-		let mut preferred_minimum_width = self.intrinsic_size.preferred_minimum_width;
-		let mut preferred_width = self.intrinsic_size.preferred_width;
+		let mut preferred_minimum_width = PIXEL_ZERO;
+		let mut preferred_width = PIXEL_ZERO;
 		match node.formatting_context_type() {
+			FormattingContextType::BlockFormattingContext if BoxClass::is_multicol_container(node) => {
+				let (minimum_width, width) = MulticolContainer::new(node).intrinsic_widths();
+				preferred_minimum_width = preferred_minimum_width.max(minimum_width);
+				preferred_width = preferred_width.max(width);
+			},
 			FormattingContextType::BlockFormattingContext => {
-				for child in node.children() {
+				// absolutely positioned children don't take space in their parent
+				for child in node
+					.children()
+					.into_iter()
+					.filter(|child| !BoxClass::is_absolutely_positioned(child.as_ref()))
+				{
 					let child_layout_info = child.layout_info();
-					preferred_minimum_width = self.width.max(
+					preferred_minimum_width = preferred_minimum_width.max(
 						child_layout_info.intrinsic_size.preferred_minimum_width + child_layout_info.horizontal_sides(),
 					);
-					preferred_width = preferred_width.max(child_layout_info.intrinsic_size.preferred_width)
-						+ child_layout_info.horizontal_sides();
+					preferred_width = preferred_width
+						.max(child_layout_info.intrinsic_size.preferred_width + child_layout_info.horizontal_sides());
 				}
 			},
 			FormattingContextType::InlineFormattingContext => {
-				for child in node.children() {
+				for child in node
+					.children()
+					.into_iter()
+					.filter(|child| !BoxClass::is_absolutely_positioned(child.as_ref()))
+				{
 					let child_layout_info = child.layout_info();
 					preferred_minimum_width = preferred_minimum_width.max(
 						child_layout_info.intrinsic_size.preferred_minimum_width + child_layout_info.horizontal_sides(),
@@ -213,10 +349,211 @@ impl LayoutInfo {
 						child_layout_info.intrinsic_size.preferred_width + child_layout_info.horizontal_sides();
 				}
 			},
+			FormattingContextType::FlexFormattingContext => {
+				let (minimum_width, width) = FlexContainer::new(node).intrinsic_widths();
+				preferred_minimum_width = preferred_minimum_width.max(minimum_width);
+				preferred_width = preferred_width.max(width);
+			},
+			FormattingContextType::GridFormattingContext => {
+				let (minimum_width, width) = GridContainer::new(node).intrinsic_widths();
+				preferred_minimum_width = preferred_minimum_width.max(minimum_width);
+				preferred_width = preferred_width.max(width);
+			},
+			FormattingContextType::TableFormattingContext if BoxClass::is_table(node) => {
+				let (minimum_width, width) = TableContainer::new(node).intrinsic_widths();
+				preferred_minimum_width = preferred_minimum_width.max(minimum_width);
+				preferred_width = preferred_width.max(width);
+			},
+			// rows, columns and their groups are measured by their table
+			FormattingContextType::TableFormattingContext => {},
 		};
+		preferred_width = preferred_width.max(preferred_minimum_width);
+		self.intrinsic_size.min_content_width = preferred_minimum_width;
+		self.intrinsic_size.max_content_width = preferred_width;
 		self.intrinsic_size.preferred_minimum_width = preferred_minimum_width;
 		self.intrinsic_size.preferred_width = preferred_width;
 	}
+
+	/// A fixed `width` or a sizing keyword replaces the measured widths, which are then clamped by `min-width` and
+	/// `max-width`. A percentage can't be resolved before the layout, the box is measured as if it were `auto`.
+	/// https://drafts.csswg.org/css-sizing-3/#contributions
+	pub fn compute_contributions(&mut self, computed_values: &ComputedValues) {
+		let intrinsic_size = &self.intrinsic_size;
+		let fixed_height = computed_values.get_height().to_fixed_used_value();
+		let (minimum_width, width) = match computed_values.get_width() {
+			Size::LengthPercentage(length_percentage) => match length_percentage.to_fixed_used_value() {
+				Some(width) => {
+					let width = BoxClass::get_content_size(computed_values, width, self.horizontal_padding());
+					(width, width)
+				},
+				None => (intrinsic_size.min_content_width, intrinsic_size.max_content_width),
+			},
+			Size::ExtremumLength(extremum) => {
+				let width = self.resolve_sizing_keyword(extremum, None);
+				(width, width)
+			},
+			// a fixed height gives the width through the preferred aspect ratio
+			Size::Auto => match (BoxClass::get_aspect_ratio(computed_values), fixed_height) {
+				(Some(ratio), Some(_)) => {
+					let height = self.clamp_height(computed_values, self.height, None);
+					let width = self.transfer_height(computed_values, height, ratio);
+					(width, width)
+				},
+				_ => (intrinsic_size.min_content_width, intrinsic_size.max_content_width),
+			},
+		};
+		self.intrinsic_size.preferred_minimum_width = self.clamp_width(computed_values, minimum_width, None);
+		self.intrinsic_size.preferred_width = self.clamp_width(computed_values, width, None);
+	}
+
+	/// The used width of the content box, `auto` takes `auto_width`.
+	/// https://www.w3.org/TR/CSS22/visudet.html#min-max-widths
+	pub fn get_used_width(
+		&self,
+		computed_values: &ComputedValues,
+		containing_width: Pixel,
+		auto_width: Pixel,
+	) -> Pixel {
+		let width = match computed_values.get_width() {
+			Size::Auto => auto_width,
+			Size::LengthPercentage(length_percentage) => BoxClass::get_content_size(
+				computed_values,
+				length_percentage.to_used_value(containing_width),
+				self.horizontal_padding(),
+			),
+			Size::ExtremumLength(extremum) => self.intrinsic_size.resolve_extremum(extremum, containing_width),
+		};
+		self.clamp_width(computed_values, width, Some(containing_width))
+	}
+
+	/// `max-width` limits `width` and `min-width` wins over it. Percentages are ignored without `containing_width`.
+	pub fn clamp_width(
+		&self,
+		computed_values: &ComputedValues,
+		width: Pixel,
+		containing_width: Option<Pixel>,
+	) -> Pixel {
+		let resolve = |length_percentage: &NonNegativeLengthPercentage| {
+			match containing_width {
+				Some(containing_width) => Some(length_percentage.to_used_value(containing_width)),
+				None => length_percentage.to_fixed_used_value(),
+			}
+			.map(|width| BoxClass::get_content_size(computed_values, width, self.horizontal_padding()))
+		};
+		let max_width = match computed_values.get_max_width() {
+			MaxSize::None => None,
+			MaxSize::LengthPercentage(length_percentage) => resolve(length_percentage),
+			MaxSize::ExtremumLength(extremum) => Some(self.resolve_sizing_keyword(extremum, containing_width)),
+		};
+		let min_width = match computed_values.get_min_width() {
+			Size::Auto => None,
+			Size::LengthPercentage(length_percentage) => resolve(length_percentage),
+			Size::ExtremumLength(extremum) => Some(self.resolve_sizing_keyword(extremum, containing_width)),
+		};
+		let width = max_width.map_or(width, |max_width| width.min(max_width));
+		min_width.map_or(width, |min_width| width.max(min_width))
+	}
+
+	/// `max-height` limits `height` and `min-height` wins over it. Percentages are ignored without the height of the
+	/// containing block, and so are the sizing keywords which depend on the height of the content.
+	/// https://www.w3.org/TR/CSS22/visudet.html#min-max-heights
+	pub fn clamp_height(
+		&self,
+		computed_values: &ComputedValues,
+		height: Pixel,
+		containing_height: Option<Pixel>,
+	) -> Pixel {
+		let resolve = |length_percentage: &NonNegativeLengthPercentage| {
+			match containing_height {
+				Some(containing_height) => Some(length_percentage.to_used_value(containing_height)),
+				None => length_percentage.to_fixed_used_value(),
+			}
+			.map(|height| BoxClass::get_content_size(computed_values, height, self.vertical_padding()))
+		};
+		let max_height = match computed_values.get_max_height() {
+			MaxSize::LengthPercentage(length_percentage) => resolve(length_percentage),
+			MaxSize::None | MaxSize::ExtremumLength(_) => None,
+		};
+		let min_height = match computed_values.get_min_height() {
+			Size::LengthPercentage(length_percentage) => resolve(length_percentage),
+			Size::Auto | Size::ExtremumLength(_) => None,
+		};
+		let height = max_height.map_or(height, |max_height| height.min(max_height));
+		min_height.map_or(height, |min_height| height.max(min_height))
+	}
+
+	/// The size of a box in the axis which depends on the other one through the preferred aspect ratio, `height` is
+	/// `None` when it depends on the content. A definite height gives an auto width, otherwise the width gives the
+	/// height, and the transferred size is clamped by its min and max sizes.
+	/// https://drafts.csswg.org/css-sizing-4/#aspect-ratio-automatic
+	pub fn get_ratio_size(
+		&self,
+		computed_values: &ComputedValues,
+		width: Pixel,
+		height: Option<Pixel>,
+		containing_width: Pixel,
+		containing_height: Option<Pixel>,
+	) -> (Pixel, Option<Pixel>) {
+		let ratio = match BoxClass::get_aspect_ratio(computed_values) {
+			Some(ratio) => ratio,
+			None => return (width, height),
+		};
+		match height {
+			Some(height) if matches!(computed_values.get_width(), Size::Auto) => {
+				let width = self.transfer_height(computed_values, height, ratio);
+				(
+					self.clamp_width(computed_values, width, Some(containing_width)),
+					Some(height),
+				)
+			},
+			Some(height) => (width, Some(height)),
+			None => {
+				let height = self.transfer_width(computed_values, width, ratio);
+				(
+					width,
+					Some(self.clamp_height(computed_values, height, containing_height)),
+				)
+			},
+		}
+	}
+
+	/// The content width from the content height through the preferred aspect ratio, the ratio is the one of the box
+	/// which `box-sizing` sizes.
+	/// https://drafts.csswg.org/css-sizing-4/#aspect-ratio-size-transfers
+	pub fn transfer_height(&self, computed_values: &ComputedValues, height: Pixel, ratio: f32) -> Pixel {
+		match computed_values.get_box_sizing() {
+			BoxSizing::ContentBox => height * ratio,
+			BoxSizing::BorderBox => {
+				((height + self.vertical_padding()) * ratio - self.horizontal_padding()).max(PIXEL_ZERO)
+			},
+		}
+	}
+
+	/// The content height from the content width through the preferred aspect ratio.
+	pub fn transfer_width(&self, computed_values: &ComputedValues, width: Pixel, ratio: f32) -> Pixel {
+		match computed_values.get_box_sizing() {
+			BoxSizing::ContentBox => width / ratio,
+			BoxSizing::BorderBox => {
+				((width + self.horizontal_padding()) / ratio - self.vertical_padding()).max(PIXEL_ZERO)
+			},
+		}
+	}
+
+	/// `fit-content` with a percentage is the max-content width when the containing block isn't known.
+	fn resolve_sizing_keyword(
+		&self,
+		extremum: &ExtremumLength<NonNegativeLengthPercentage>,
+		containing_width: Option<Pixel>,
+	) -> Pixel {
+		match (extremum, containing_width) {
+			(_, Some(containing_width)) => self.intrinsic_size.resolve_extremum(extremum, containing_width),
+			(ExtremumLength::FitContent(length_percentage), None) => match length_percentage.to_fixed_used_value() {
+				Some(width) => self.intrinsic_size.fit_content(width),
+				None => self.intrinsic_size.max_content_width,
+			},
+			(_, None) => self.intrinsic_size.resolve_extremum(extremum, PIXEL_ZERO),
+		}
+	}
 }
 
 pub trait Fragment {
@@ -238,16 +575,35 @@ pub trait Fragment {
 
 	fn rect_y(&self) -> Pixel;
 
+	/// Moves the fragment along the line, the bidi reordering places fragments after they are laid out.
+	fn set_x(&mut self, value: Pixel);
+
+	/// Moves the top of the margin box, fragments in a line are aligned once the line is complete.
+	fn set_y(&mut self, value: Pixel);
+
 	fn class(&self) -> FragmentClass;
 
+	/// How far the fragment is moved from where it is laid out when it is painted.
+	fn offset(&self) -> Vector2D<Pixel, CSSPixel> {
+		Default::default()
+	}
+
 	fn as_box_fragment(&self) -> &BoxFragment {
 		panic!("called as_box_fragment on a non box fragment");
 	}
 
+	fn as_box_fragment_mut(&mut self) -> &mut BoxFragment {
+		panic!("called as_box_fragment_mut on a non box fragment");
+	}
+
 	fn as_text_fragment(&self) -> &TextFragment {
 		panic!("called as_text_fragment on a non text fragment");
 	}
 
+	fn as_text_fragment_mut(&mut self) -> &mut TextFragment {
+		panic!("called as_text_fragment_mut on a non text fragment");
+	}
+
 	fn as_anonymous_fragment(&self) -> &AnonymousFragment {
 		panic!("called as_anonymous_fragment on a non anonymous fragment");
 	}
@@ -268,7 +624,15 @@ pub struct BoxFragment {
 	pub rect: Rect<Pixel, CSSPixel>,
 	pub bounds: Size2D<Pixel, CSSPixel>,
 	pub children: Vec<Rc<RefCell<dyn Fragment>>>,
+	// floats and absolutely positioned boxes whose containing block is this box
+	pub out_of_flow_children: Vec<Rc<RefCell<dyn Fragment>>>,
+	// relative, sticky and fixed positioning move the painted box without affecting the layout
+	pub offset: Vector2D<Pixel, CSSPixel>,
 	pub lines: Rc<RefCell<Vec<Line>>>,
+	// the rules between the columns of a multi-column container, relative to its content box
+	pub column_rules: Vec<Rect<Pixel, CSSPixel>>,
+	// the font metrics and the line height of an inline box which isn't atomic, its content is aligned to its baseline
+	pub strut: Option<Strut>,
 }
 
 impl Fragment for BoxFragment {
@@ -300,6 +664,14 @@ impl Fragment for BoxFragment {
 		self.rect.origin.y
 	}
 
+	fn set_x(&mut self, value: Pixel) {
+		self.rect.origin.x = value;
+	}
+
+	fn set_y(&mut self, value: Pixel) {
+		self.rect.origin.y = value;
+	}
+
 	fn rect_x(&self) -> Pixel {
 		self.x() + self.margin.left + self.padding.left
 	}
@@ -312,22 +684,34 @@ impl Fragment for BoxFragment {
 		FragmentClass::BoxFragment
 	}
 
+	fn offset(&self) -> Vector2D<Pixel, CSSPixel> {
+		self.offset
+	}
+
 	fn as_box_fragment(&self) -> &BoxFragment {
 		self
 	}
 
+	fn as_box_fragment_mut(&mut self) -> &mut BoxFragment {
+		self
+	}
+
 	fn build_display_list(&self, builder: &mut DisplayListBuilder, context: &mut BuilderContext) {
 		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
+		let content_origin = Point2D::new(
+			context.x + self.rect.origin.x + self.margin.left + self.padding.left,
+			context.y + self.rect.origin.y + self.margin.top + self.padding.top,
+		);
 		builder.push_rect(
-			Rect::new(
-				Point2D::new(
-					context.x + self.rect.origin.x + self.margin.left + self.padding.left,
-					context.y + self.rect.origin.y + self.margin.top + self.padding.top,
-				),
-				self.rect.size,
-			),
+			Rect::new(content_origin, self.rect.size),
 			computed_values.get_background_color().clone(),
 		);
+		for column_rule in self.column_rules.iter() {
+			builder.push_rect(
+				column_rule.translate(content_origin.to_vector()),
+				computed_values.get_column_rule_color().clone(),
+			);
+		}
 	}
 }
 
@@ -341,6 +725,10 @@ impl BoxFragment {
 			margin: Default::default(),
 			bounds: Default::default(),
 			children: Default::default(),
+			out_of_flow_children: Default::default(),
+			offset: Default::default(),
+			column_rules: Default::default(),
+			strut: None,
 		}
 	}
 
@@ -368,16 +756,6 @@ impl BoxFragment {
 		self.bounds.height = value;
 	}
 
-	#[inline]
-	pub fn set_x(&mut self, value: Pixel) {
-		self.rect.origin.x = value;
-	}
-
-	#[inline]
-	pub fn set_y(&mut self, value: Pixel) {
-		self.rect.origin.y = value;
-	}
-
 	#[inline]
 	pub fn reset_right_sides(&mut self) {
 		self.margin.right = PIXEL_ZERO;
@@ -390,6 +768,9 @@ pub struct TextFragment {
 	pub dom_node: NodeRef,
 	pub rect: Rect<Pixel, CSSPixel>,
 	pub content: String,
+	pub shaped_text: ShapedText,
+	// the resolved bidi embedding level, its glyphs go right to left when it is odd
+	pub bidi_level: u8,
 }
 
 impl Fragment for TextFragment {
@@ -421,6 +802,14 @@ impl Fragment for TextFragment {
 		self.rect.origin.y
 	}
 
+	fn set_x(&mut self, value: Pixel) {
+		self.rect.origin.x = value;
+	}
+
+	fn set_y(&mut self, value: Pixel) {
+		self.rect.origin.y = value;
+	}
+
 	fn rect_x(&self) -> Pixel {
 		self.x()
 	}
@@ -437,11 +826,16 @@ impl Fragment for TextFragment {
 		self
 	}
 
+	fn as_text_fragment_mut(&mut self) -> &mut TextFragment {
+		self
+	}
+
 	fn build_display_list(&self, builder: &mut DisplayListBuilder, context: &mut BuilderContext) {
 		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.parent().unwrap().id());
 		builder.push_text(
 			Rect::new(Point2D::new(context.x + self.x(), context.y + self.y()), self.rect.size),
 			&self.content,
+			&self.shaped_text,
 			computed_values.get_color().clone(),
 			computed_values.get_font_families(),
 			computed_values.get_font_size(),
@@ -450,11 +844,13 @@ impl Fragment for TextFragment {
 }
 
 impl TextFragment {
-	pub fn new(dom_node: NodeRef, content: String) -> Self {
+	pub fn new(dom_node: NodeRef, content: String, shaped_text: ShapedText) -> Self {
 		Self {
 			dom_node,
 			content,
+			shaped_text,
 			rect: Default::default(),
+			bidi_level: 0,
 		}
 	}
 
@@ -467,16 +863,6 @@ impl TextFragment {
 	pub fn set_height(&mut self, value: Pixel) {
 		self.rect.size.height = value;
 	}
-
-	#[inline]
-	pub fn set_x(&mut self, value: Pixel) {
-		self.rect.origin.x = value;
-	}
-
-	#[inline]
-	pub fn set_y(&mut self, value: Pixel) {
-		self.rect.origin.y = value;
-	}
 }
 
 pub struct AnonymousFragment {
@@ -508,13 +894,21 @@ impl Fragment for AnonymousFragment {
 	}
 
 	fn x(&self) -> Pixel {
-		PIXEL_ZERO
+		self.rect.origin.x
 	}
 
 	fn y(&self) -> Pixel {
 		self.rect.origin.y
 	}
 
+	fn set_x(&mut self, value: Pixel) {
+		self.rect.origin.x = value;
+	}
+
+	fn set_y(&mut self, value: Pixel) {
+		self.rect.origin.y = value;
+	}
+
 	fn rect_x(&self) -> Pixel {
 		self.x()
 	}
@@ -567,8 +961,4 @@ impl AnonymousFragment {
 	pub fn set_bounded_height(&mut self, value: Pixel) {
 		self.bounds.height = value;
 	}
-
-	pub fn set_y(&mut self, value: Pixel) {
-		self.rect.origin.y = value;
-	}
 }
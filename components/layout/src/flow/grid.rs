@@ -0,0 +1,1278 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use common::not_reached;
+use css::computed_values::ComputedValues;
+use css::properties::longhands::align_content::AlignContent;
+use css::properties::longhands::align_items::AlignItems;
+use css::properties::longhands::align_self::AlignSelf;
+use css::properties::longhands::justify_content::JustifyContent;
+use css::properties::longhands::justify_items::JustifyItems;
+use css::properties::longhands::justify_self::JustifySelf;
+use css::values::computed::grid::{GridTemplateComponent, ImplicitGridTracks, TrackBreadth, TrackListValue, TrackSize};
+use css::values::computed::length::{LengthPercentageOrAuto, NonNegativeLengthPercentage, Size};
+use css::values::generics::grid::RepeatCount;
+use css::values::generics::length::GenericLengthPercentageOrNormal;
+use css::values::specified::grid::{AutoFlow, GridLine, GridTemplateAreas};
+use css::values::specified::position::{ContentDistribution, ContentPosition, OverflowPosition, SelfPosition};
+use css::values::{CSSFloat, CustomIdent, Pixel, PIXEL_ZERO};
+use dom::global_scope::GlobalScope;
+
+use super::boxes::{Box, BoxClass};
+use super::fragment::Sides;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Axis {
+	Column,
+	Row,
+}
+
+/// Where an item sits inside its grid area along one axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SelfAlignment {
+	Start,
+	End,
+	Center,
+	Stretch,
+}
+
+/// How the free space of the grid container is distributed around its tracks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ContentAlignment {
+	Start,
+	End,
+	Center,
+	SpaceBetween,
+	SpaceAround,
+	SpaceEvenly,
+}
+
+/// The lines an item is placed between along one axis, they are counted from the first line of the explicit grid.
+/// An auto placement only knows how many tracks the item spans.
+#[derive(Clone, Debug, PartialEq)]
+enum LinePlacement {
+	Definite(Range<i32>),
+	Auto(i32),
+}
+
+/// https://drafts.csswg.org/css-grid/#grid-items
+struct GridItem {
+	item: Rc<dyn Box>,
+	order: i32,
+	column_placement: LinePlacement,
+	row_placement: LinePlacement,
+	/// the tracks of the implicit grid the item spans, known once the items are placed
+	columns: Range<usize>,
+	rows: Range<usize>,
+	margin: Sides,
+	padding: Sides,
+	horizontal_auto_margins: (bool, bool),
+	vertical_auto_margins: (bool, bool),
+	width: Size,
+	height: Size,
+	justify: SelfAlignment,
+	align: SelfAlignment,
+	/// `safe` alignment falls back to start when the item overflows its grid area
+	is_justify_safe: bool,
+	is_align_safe: bool,
+}
+
+impl GridItem {
+	fn horizontal_sides(&self) -> Pixel {
+		self.margin.left + self.padding.left + self.padding.right + self.margin.right
+	}
+
+	fn vertical_sides(&self) -> Pixel {
+		self.margin.top + self.padding.top + self.padding.bottom + self.margin.bottom
+	}
+}
+
+/// The tracks of the explicit grid along one axis once `repeat()` is expanded.
+struct ExplicitTracks {
+	sizes: Vec<TrackSize>,
+	/// one more entry than `sizes`, the names of every grid line
+	line_names: Vec<Vec<String>>,
+	/// the tracks repeated by `repeat(auto-fit, ..)`, they collapse when no item is placed in them
+	auto_fit: Option<Range<usize>>,
+}
+
+impl ExplicitTracks {
+	fn new() -> Self {
+		ExplicitTracks {
+			sizes: vec![],
+			line_names: vec![vec![]],
+			auto_fit: None,
+		}
+	}
+
+	fn track_count(&self) -> i32 {
+		self.sizes.len() as i32
+	}
+
+	fn push(&mut self, size: TrackSize) {
+		self.sizes.push(size);
+		self.line_names.push(vec![]);
+	}
+
+	fn add_names(&mut self, names: &[CustomIdent]) {
+		let line_names = self.line_names.last_mut().unwrap();
+		line_names.extend(names.iter().map(|name| name.0.clone()));
+	}
+
+	fn lines_named(&self, name: &str) -> Vec<i32> {
+		self.line_names
+			.iter()
+			.enumerate()
+			.filter(|(_, names)| names.iter().any(|value| value == name))
+			.map(|(index, _)| index as i32)
+			.collect()
+	}
+
+	/// https://drafts.csswg.org/css-grid/#grid-placement-int
+	/// when there are not enough lines with the name, all implicit lines are assumed to have it
+	fn nth_line_named(&self, name: &str, nth: i32) -> i32 {
+		let lines = self.lines_named(name);
+		let count = lines.len() as i32;
+		if nth > 0 {
+			match lines.get(nth as usize - 1) {
+				Some(&line) => line,
+				None => self.track_count() + nth - count,
+			}
+		} else if -nth <= count {
+			lines[(count + nth) as usize]
+		} else {
+			nth + count
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+struct Track {
+	size: TrackSize,
+	is_auto_fit: bool,
+	collapsed: bool,
+	base_size: Pixel,
+	/// `None` is an infinite growth limit
+	growth_limit: Option<Pixel>,
+}
+
+impl Track {
+	fn new(size: TrackSize, is_auto_fit: bool) -> Self {
+		Track {
+			size,
+			is_auto_fit,
+			collapsed: false,
+			base_size: PIXEL_ZERO,
+			growth_limit: None,
+		}
+	}
+
+	/// https://drafts.csswg.org/css-grid/#min-track-sizing-function
+	fn min_sizing_function(&self) -> TrackBreadth {
+		match &self.size {
+			TrackSize::Breadth(TrackBreadth::Fr(_)) => TrackBreadth::Auto,
+			TrackSize::Breadth(breadth) => breadth.clone(),
+			TrackSize::Minmax(min, _) => min.clone(),
+			TrackSize::FitContent(_) => TrackBreadth::Auto,
+		}
+	}
+
+	/// https://drafts.csswg.org/css-grid/#max-track-sizing-function
+	fn max_sizing_function(&self) -> TrackBreadth {
+		match &self.size {
+			TrackSize::Breadth(breadth) => breadth.clone(),
+			TrackSize::Minmax(_, max) => max.clone(),
+			TrackSize::FitContent(_) => TrackBreadth::MaxContent,
+		}
+	}
+
+	fn flex_factor(&self) -> Option<CSSFloat> {
+		match self.max_sizing_function() {
+			TrackBreadth::Fr(factor) if !self.collapsed => Some(factor),
+			_ => None,
+		}
+	}
+}
+
+/// A block or inline-level box which establishes a grid formatting context.
+///
+/// Like flex containers, the layout follows the box tree traversal:
+/// - `layout_items`, before the items are laid out, places them and sizes the columns, so every item knows the
+///   width of its grid area.
+/// - `arrange_items`, after the items are laid out, sizes the rows and aligns the items inside their grid areas.
+///
+/// https://drafts.csswg.org/css-grid/#layout-algorithm
+pub struct GridContainer<'a> {
+	source: &'a dyn Box,
+	computed_values: &'a ComputedValues,
+}
+
+impl<'a> GridContainer<'a> {
+	pub fn new(source: &'a dyn Box) -> Self {
+		let dom_node = match source.class() {
+			BoxClass::Block => source.as_block_level_box().dom_node(),
+			BoxClass::Inline => source.as_inline_level_box().dom_node(),
+			_ => not_reached!(),
+		};
+		GridContainer {
+			source,
+			computed_values: GlobalScope::get_or_init_computed_values(dom_node.id()),
+		}
+	}
+
+	/// https://drafts.csswg.org/css-align-3/#column-row-gap
+	/// `normal` is 0px in grid containers
+	fn gap(&self, axis: Axis, available_size: Option<Pixel>) -> Pixel {
+		let gap = match axis {
+			Axis::Column => self.computed_values.get_column_gap(),
+			Axis::Row => self.computed_values.get_row_gap(),
+		};
+		match gap {
+			GenericLengthPercentageOrNormal::LengthPercentage(value) => {
+				resolve(value, available_size).unwrap_or(PIXEL_ZERO)
+			},
+			GenericLengthPercentageOrNormal::Normal => PIXEL_ZERO,
+		}
+	}
+
+	/// https://drafts.csswg.org/css-grid/#intrinsic-sizes
+	pub fn intrinsic_widths(&self) -> (Pixel, Pixel) {
+		let (items, columns, _) = self.place_items(None, None);
+		let gap = self.gap(Axis::Column, None);
+		let mut widths = vec![];
+		for is_max_content in [false, true].iter() {
+			let mut columns = columns.clone();
+			let contributions: Vec<(Range<usize>, Pixel, Pixel)> = items
+				.iter()
+				.map(|item| {
+					let (min_content, max_content) = self.width_contributions(item);
+					let contribution = if *is_max_content { max_content } else { min_content };
+					(item.columns.clone(), min_content, contribution)
+				})
+				.collect();
+			size_tracks(&mut columns, &contributions, None, gap, false);
+			widths.push(total_size(&columns, gap));
+		}
+		(widths[0], widths[1])
+	}
+
+	/// Sizes the columns and the items along the inline axis, it runs once the container knows its own width.
+	pub fn layout_items(&self) {
+		let (width, height) = self.container_size();
+		let (items, mut columns, _) = self.place_items(Some(width), height);
+		self.size_columns(&items, &mut columns, width);
+		let column_starts = self.track_starts(&columns, Axis::Column, width);
+		for item in items {
+			let (_, area_width) = area(&columns, &column_starts, &item.columns);
+			let available_width = area_width - item.horizontal_sides();
+			let mut layout_info = item.item.layout_info_mut();
+			let width = match &item.width {
+				Size::LengthPercentage(length_percentage) => {
+					let width = length_percentage.to_used_value(area_width);
+					BoxClass::item_computed_values(&item.item).map_or(width, |computed_values| {
+						BoxClass::get_content_size(computed_values, width, item.padding.left + item.padding.right)
+					})
+				},
+				_ if item.justify == SelfAlignment::Stretch && item.horizontal_auto_margins == (false, false) => {
+					available_width
+				},
+				_ => layout_info
+					.intrinsic_size
+					.preferred_width
+					.min(layout_info.intrinsic_size.preferred_minimum_width.max(available_width)),
+			};
+			layout_info.margin = item.margin;
+			layout_info.padding = item.padding;
+			layout_info.width = width.max(PIXEL_ZERO);
+		}
+	}
+
+	/// Sizes the rows, aligns and positions the items, it runs once the items are laid out.
+	/// A container with an auto height gets the height of its rows.
+	pub fn arrange_items(&self) {
+		let (width, definite_height) = self.container_size();
+		let (items, mut columns, mut rows) = self.place_items(Some(width), definite_height);
+		self.size_columns(&items, &mut columns, width);
+
+		let row_gap = self.gap(Axis::Row, definite_height);
+		let contributions: Vec<(Range<usize>, Pixel, Pixel)> = items
+			.iter()
+			.map(|item| {
+				let height = item.item.layout_info().height + item.vertical_sides();
+				(item.rows.clone(), height, height)
+			})
+			.collect();
+		let stretch_rows = matches!(
+			self.computed_values.get_align_content(),
+			AlignContent::Normal | AlignContent::Distribution(ContentDistribution::Stretch)
+		);
+		size_tracks(&mut rows, &contributions, definite_height, row_gap, stretch_rows);
+		let height = match definite_height {
+			Some(height) => height,
+			None => {
+				BoxClass::set_content_height(self.source, total_size(&rows, row_gap));
+				self.source.layout_info().height
+			},
+		};
+
+		let column_starts = self.track_starts(&columns, Axis::Column, width);
+		let row_starts = self.track_starts(&rows, Axis::Row, height);
+		for item in items.iter() {
+			let (x, area_width) = area(&columns, &column_starts, &item.columns);
+			let (y, area_height) = area(&rows, &row_starts, &item.rows);
+			let (item_width, item_height) = {
+				let layout_info = item.item.layout_info();
+				(layout_info.width, layout_info.height)
+			};
+			let stretched = item.align == SelfAlignment::Stretch
+				&& !matches!(item.height, Size::LengthPercentage(_))
+				&& item.vertical_auto_margins == (false, false);
+			let item_height = if stretched {
+				(area_height - item.vertical_sides()).max(PIXEL_ZERO)
+			} else {
+				item_height
+			};
+
+			let mut margin = item.margin;
+			let x_offset = align_in_area(
+				area_width - item_width - item.horizontal_sides(),
+				item.justify,
+				item.is_justify_safe,
+				item.horizontal_auto_margins,
+				(&mut margin.left, &mut margin.right),
+			);
+			let y_offset = align_in_area(
+				area_height - item_height - item.vertical_sides(),
+				item.align,
+				item.is_align_safe,
+				item.vertical_auto_margins,
+				(&mut margin.top, &mut margin.bottom),
+			);
+			BoxClass::place_item(&item.item, x + x_offset, y + y_offset, item_width, item_height, margin);
+		}
+	}
+
+	/// The width and, unless it is auto, the height of the container's content box.
+	fn container_size(&self) -> (Pixel, Option<Pixel>) {
+		let layout_info = self.source.layout_info();
+		(layout_info.width, layout_info.definite_height())
+	}
+
+	fn size_columns(&self, items: &[GridItem], columns: &mut [Track], width: Pixel) {
+		let gap = self.gap(Axis::Column, Some(width));
+		let contributions: Vec<(Range<usize>, Pixel, Pixel)> = items
+			.iter()
+			.map(|item| {
+				let (min_content, max_content) = self.width_contributions(item);
+				(item.columns.clone(), min_content, max_content)
+			})
+			.collect();
+		let stretch_columns = matches!(
+			self.computed_values.get_justify_content(),
+			JustifyContent::Normal | JustifyContent::Stretch
+		);
+		size_tracks(columns, &contributions, Some(width), gap, stretch_columns);
+	}
+
+	/// The min-content and max-content contributions of an item, including its margins and paddings.
+	fn width_contributions(&self, item: &GridItem) -> (Pixel, Pixel) {
+		let sides = item.horizontal_sides();
+		let layout_info = item.item.layout_info();
+		(
+			layout_info.intrinsic_size.preferred_minimum_width + sides,
+			layout_info.intrinsic_size.preferred_width + sides,
+		)
+	}
+
+	/// The position of every track from the content edge, after distributing the free space.
+	/// https://drafts.csswg.org/css-align-3/#distribution-grid
+	fn track_starts(&self, tracks: &[Track], axis: Axis, size: Pixel) -> Vec<Pixel> {
+		let gap = self.gap(axis, Some(size));
+		let free_space = size - total_size(tracks, gap);
+		let alignment = match axis {
+			Axis::Column => self.justify_content_alignment(),
+			Axis::Row => self.align_content_alignment(free_space),
+		};
+		let count = tracks.iter().filter(|track| !track.collapsed).count() as CSSFloat;
+		let (leading_space, between_space) = match alignment {
+			ContentAlignment::Start => (PIXEL_ZERO, PIXEL_ZERO),
+			ContentAlignment::End => (free_space, PIXEL_ZERO),
+			ContentAlignment::Center => (free_space / 2.0, PIXEL_ZERO),
+			ContentAlignment::SpaceBetween if free_space > PIXEL_ZERO && count > 1.0 => {
+				(PIXEL_ZERO, free_space / (count - 1.0))
+			},
+			ContentAlignment::SpaceBetween => (PIXEL_ZERO, PIXEL_ZERO),
+			ContentAlignment::SpaceAround if free_space > PIXEL_ZERO && count > 0.0 => {
+				(free_space / count / 2.0, free_space / count)
+			},
+			ContentAlignment::SpaceEvenly if free_space > PIXEL_ZERO => {
+				(free_space / (count + 1.0), free_space / (count + 1.0))
+			},
+			ContentAlignment::SpaceAround | ContentAlignment::SpaceEvenly => (free_space / 2.0, PIXEL_ZERO),
+		};
+
+		let last_visible = tracks.iter().rposition(|track| !track.collapsed);
+		let mut position = leading_space;
+		let mut starts = Vec::with_capacity(tracks.len());
+		for (index, track) in tracks.iter().enumerate() {
+			starts.push(position);
+			position += track.base_size;
+			if !track.collapsed && Some(index) < last_visible {
+				position += gap + between_space;
+			}
+		}
+		starts
+	}
+
+	fn justify_content_alignment(&self) -> ContentAlignment {
+		match self.computed_values.get_justify_content() {
+			JustifyContent::Normal | JustifyContent::Stretch | JustifyContent::Start | JustifyContent::FlexStart => {
+				ContentAlignment::Start
+			},
+			JustifyContent::End | JustifyContent::FlexEnd => ContentAlignment::End,
+			JustifyContent::Center => ContentAlignment::Center,
+			JustifyContent::SpaceBetween => ContentAlignment::SpaceBetween,
+			JustifyContent::SpaceAround => ContentAlignment::SpaceAround,
+			JustifyContent::SpaceEvenly => ContentAlignment::SpaceEvenly,
+		}
+	}
+
+	fn align_content_alignment(&self, free_space: Pixel) -> ContentAlignment {
+		match self.computed_values.get_align_content() {
+			AlignContent::Normal | AlignContent::Baseline(_) => ContentAlignment::Start,
+			AlignContent::Distribution(ContentDistribution::Stretch) => ContentAlignment::Start,
+			AlignContent::Distribution(ContentDistribution::SpaceBetween) => ContentAlignment::SpaceBetween,
+			AlignContent::Distribution(ContentDistribution::SpaceAround) => ContentAlignment::SpaceAround,
+			AlignContent::Distribution(ContentDistribution::SpaceEvenly) => ContentAlignment::SpaceEvenly,
+			AlignContent::Overflow(Some(OverflowPosition::Safe), _) if free_space < PIXEL_ZERO => {
+				ContentAlignment::Start
+			},
+			AlignContent::Overflow(_, ContentPosition::Center) => ContentAlignment::Center,
+			AlignContent::Overflow(_, ContentPosition::End) | AlignContent::Overflow(_, ContentPosition::FlexEnd) => {
+				ContentAlignment::End
+			},
+			AlignContent::Overflow(_, _) => ContentAlignment::Start,
+		}
+	}
+
+	/// Places the items and creates the tracks of the implicit grid.
+	/// https://drafts.csswg.org/css-grid/#placement
+	fn place_items(
+		&self,
+		available_width: Option<Pixel>,
+		available_height: Option<Pixel>,
+	) -> (Vec<GridItem>, Vec<Track>, Vec<Track>) {
+		let areas = self.computed_values.get_grid_template_areas();
+		let explicit_columns = self.explicit_tracks(Axis::Column, areas, available_width);
+		let explicit_rows = self.explicit_tracks(Axis::Row, areas, available_height);
+		let mut items: Vec<GridItem> = self
+			.source
+			.children()
+			.into_iter()
+			.filter(|child| BoxClass::is_grid_item(child.as_ref()))
+			.map(|child| self.create_item(child, &explicit_columns, &explicit_rows))
+			.collect();
+		items.sort_by_key(|item| item.order);
+
+		let ((column_offset, column_count), (row_offset, row_count)) =
+			self.auto_place(&mut items, &explicit_columns, &explicit_rows);
+		let mut columns = implicit_tracks(
+			&explicit_columns,
+			self.computed_values.get_grid_auto_columns(),
+			column_offset,
+			column_count,
+		);
+		let mut rows = implicit_tracks(
+			&explicit_rows,
+			self.computed_values.get_grid_auto_rows(),
+			row_offset,
+			row_count,
+		);
+		// https://drafts.csswg.org/css-grid/#collapsed-track
+		for (index, column) in columns.iter_mut().enumerate() {
+			column.collapsed = column.is_auto_fit && !items.iter().any(|item| item.columns.contains(&index));
+		}
+		for (index, row) in rows.iter_mut().enumerate() {
+			row.collapsed = row.is_auto_fit && !items.iter().any(|item| item.rows.contains(&index));
+		}
+		(items, columns, rows)
+	}
+
+	/// https://drafts.csswg.org/css-grid/#explicit-grids
+	fn explicit_tracks(&self, axis: Axis, areas: &GridTemplateAreas, available_size: Option<Pixel>) -> ExplicitTracks {
+		let (template, implicit) = match axis {
+			Axis::Column => (
+				self.computed_values.get_grid_template_columns(),
+				self.computed_values.get_grid_auto_columns(),
+			),
+			Axis::Row => (
+				self.computed_values.get_grid_template_rows(),
+				self.computed_values.get_grid_auto_rows(),
+			),
+		};
+		let mut tracks = ExplicitTracks::new();
+		if let GridTemplateComponent::TrackList(track_list) = template {
+			let auto_repeat_count = self.auto_repeat_count(&track_list.values, axis, available_size);
+			for (index, value) in track_list.values.iter().enumerate() {
+				tracks.add_names(&track_list.line_names[index]);
+				match value {
+					TrackListValue::TrackSize(size) => tracks.push(size.clone()),
+					TrackListValue::TrackRepeat(repeat) => {
+						let count = match repeat.count {
+							RepeatCount::Number(count) => count as usize,
+							RepeatCount::AutoFill | RepeatCount::AutoFit => auto_repeat_count,
+						};
+						let start = tracks.sizes.len();
+						for repetition in 0..count {
+							if repetition > 0 {
+								tracks.add_names(repeat.line_names.last().unwrap());
+							}
+							for (index, size) in repeat.track_sizes.iter().enumerate() {
+								tracks.add_names(&repeat.line_names[index]);
+								tracks.push(size.clone());
+							}
+						}
+						tracks.add_names(repeat.line_names.last().unwrap());
+						if repeat.count == RepeatCount::AutoFit {
+							tracks.auto_fit = Some(start..tracks.sizes.len());
+						}
+					},
+				}
+			}
+			tracks.add_names(track_list.line_names.last().unwrap());
+		}
+
+		// named areas add implicit `<name>-start` and `<name>-end` lines and may need more explicit tracks
+		// https://drafts.csswg.org/css-grid/#implicit-named-lines
+		if let GridTemplateAreas::Areas(areas) = areas {
+			for area in areas.areas.iter() {
+				let lines = match axis {
+					Axis::Column => area.columns.clone(),
+					Axis::Row => area.rows.clone(),
+				};
+				while tracks.sizes.len() < lines.end as usize {
+					let size = implicit.0[tracks.sizes.len() % implicit.0.len()].clone();
+					tracks.push(size);
+				}
+				tracks.line_names[lines.start as usize].push(format!("{}-start", area.name));
+				tracks.line_names[lines.end as usize].push(format!("{}-end", area.name));
+			}
+		}
+		tracks
+	}
+
+	/// The number of repetitions of `repeat(auto-fill, ..)` or `repeat(auto-fit, ..)` which fit the container,
+	/// a container without a definite size repeats them once.
+	/// https://drafts.csswg.org/css-grid/#auto-repeat
+	fn auto_repeat_count(&self, values: &[TrackListValue], axis: Axis, available_size: Option<Pixel>) -> usize {
+		let available_size = match available_size {
+			Some(available_size) => available_size,
+			None => return 1,
+		};
+		let gap = self.gap(axis, Some(available_size));
+		let fixed_size = |size: &TrackSize| -> Pixel {
+			let fixed_breadth = |breadth: &TrackBreadth| match breadth {
+				TrackBreadth::Breadth(value) => Some(value.to_used_value(available_size)),
+				_ => None,
+			};
+			match size {
+				TrackSize::Breadth(breadth) => fixed_breadth(breadth).unwrap_or(PIXEL_ZERO),
+				TrackSize::Minmax(min, max) => fixed_breadth(max).or_else(|| fixed_breadth(min)).unwrap_or(PIXEL_ZERO),
+				TrackSize::FitContent(_) => PIXEL_ZERO,
+			}
+		};
+
+		let mut other_size = PIXEL_ZERO;
+		let mut other_count = 0;
+		let mut repeat_size = PIXEL_ZERO;
+		let mut repeat_count = 0;
+		for value in values.iter() {
+			match value {
+				TrackListValue::TrackSize(size) => {
+					other_size += fixed_size(size);
+					other_count += 1;
+				},
+				TrackListValue::TrackRepeat(repeat) => {
+					let size = repeat
+						.track_sizes
+						.iter()
+						.fold(PIXEL_ZERO, |sum, size| sum + fixed_size(size));
+					match repeat.count {
+						RepeatCount::Number(count) => {
+							other_size += size * count as CSSFloat;
+							other_count += count as usize * repeat.track_sizes.len();
+						},
+						RepeatCount::AutoFill | RepeatCount::AutoFit => {
+							repeat_size = size;
+							repeat_count = repeat.track_sizes.len();
+						},
+					}
+				},
+			}
+		}
+		let repetition_size = repeat_size + gap * repeat_count as CSSFloat;
+		if repetition_size <= PIXEL_ZERO {
+			return 1;
+		}
+		// every repetition also adds a gap, the last track of the grid does not have one
+		let free_space = available_size - other_size - gap * (other_count as CSSFloat - 1.0);
+		(free_space.get() / repetition_size.get()).floor().max(1.0) as usize
+	}
+
+	fn create_item(&self, item: Rc<dyn Box>, columns: &ExplicitTracks, rows: &ExplicitTracks) -> GridItem {
+		// anonymous items hold the initial values of the grid properties
+		let mut initial_values = ComputedValues::default();
+		let computed_values = match BoxClass::item_computed_values(&item) {
+			Some(computed_values) => computed_values,
+			None => &mut initial_values,
+		};
+		let containing_width = self.source.layout_info().width;
+		let margin = BoxClass::get_margin_for_non_replaced_elements(computed_values, containing_width);
+		let padding = BoxClass::get_padding_for_non_replaced_elements(computed_values, containing_width);
+		let is_auto = |value: &LengthPercentageOrAuto| *value == LengthPercentageOrAuto::Auto;
+		let ((justify, is_justify_safe), (align, is_align_safe)) = self.resolve_alignment(computed_values);
+		GridItem {
+			order: computed_values.get_order(),
+			column_placement: resolve_placement(
+				computed_values.get_grid_column_start(),
+				computed_values.get_grid_column_end(),
+				columns,
+			),
+			row_placement: resolve_placement(
+				computed_values.get_grid_row_start(),
+				computed_values.get_grid_row_end(),
+				rows,
+			),
+			columns: 0..1,
+			rows: 0..1,
+			margin,
+			padding,
+			horizontal_auto_margins: (
+				is_auto(computed_values.get_margin_left()),
+				is_auto(computed_values.get_margin_right()),
+			),
+			vertical_auto_margins: (
+				is_auto(computed_values.get_margin_top()),
+				is_auto(computed_values.get_margin_bottom()),
+			),
+			width: computed_values.get_width().clone(),
+			height: computed_values.get_height().clone(),
+			justify,
+			align,
+			is_justify_safe,
+			is_align_safe,
+			item,
+		}
+	}
+
+	/// https://drafts.csswg.org/css-align-3/#justify-grid
+	/// https://drafts.csswg.org/css-align-3/#align-grid
+	fn resolve_alignment(&self, computed_values: &ComputedValues) -> ((SelfAlignment, bool), (SelfAlignment, bool)) {
+		let justify = match computed_values.get_justify_self() {
+			JustifySelf::Auto => match self.computed_values.get_justify_items() {
+				JustifyItems::Legacy | JustifyItems::Normal | JustifyItems::Stretch => (SelfAlignment::Stretch, false),
+				JustifyItems::Baseline(_) => (SelfAlignment::Start, false),
+				JustifyItems::Overflow(overflow, position) => self_alignment(overflow, position),
+			},
+			JustifySelf::Normal | JustifySelf::Stretch => (SelfAlignment::Stretch, false),
+			JustifySelf::Baseline(_) => (SelfAlignment::Start, false),
+			JustifySelf::Overflow(overflow, position) => self_alignment(overflow, position),
+		};
+		let align = match computed_values.get_align_self() {
+			AlignSelf::Auto => match self.computed_values.get_align_items() {
+				AlignItems::Normal | AlignItems::Stretch => (SelfAlignment::Stretch, false),
+				AlignItems::Baseline(_) => (SelfAlignment::Start, false),
+				AlignItems::Overflow(overflow, position) => self_alignment(overflow, position),
+			},
+			AlignSelf::Normal | AlignSelf::Stretch => (SelfAlignment::Stretch, false),
+			AlignSelf::Baseline(_) => (SelfAlignment::Start, false),
+			AlignSelf::Overflow(overflow, position) => self_alignment(overflow, position),
+		};
+		(justify, align)
+	}
+
+	/// Places the items without a definite area and returns the offset of the explicit grid
+	/// and the number of tracks in the implicit grid, for columns and rows.
+	/// https://drafts.csswg.org/css-grid/#auto-placement-algo
+	fn auto_place(
+		&self,
+		items: &mut [GridItem],
+		columns: &ExplicitTracks,
+		rows: &ExplicitTracks,
+	) -> ((i32, usize), (i32, usize)) {
+		// implicit tracks before the explicit grid shift every line, so the implicit grid starts at 0
+		let offset = |placement: &LinePlacement| match placement {
+			LinePlacement::Definite(lines) => -lines.start,
+			LinePlacement::Auto(_) => 0,
+		};
+		let column_offset = items
+			.iter()
+			.map(|item| offset(&item.column_placement))
+			.fold(0, i32::max);
+		let row_offset = items.iter().map(|item| offset(&item.row_placement)).fold(0, i32::max);
+
+		// the items are placed along the flow axis first, e.g. every row is filled one after another for `row`
+		let grid_auto_flow = self.computed_values.get_grid_auto_flow();
+		let is_row_flow = grid_auto_flow.auto_flow == AutoFlow::Row;
+		let dense = grid_auto_flow.dense;
+		let (outer_offset, inner_offset, explicit_inner_count) = if is_row_flow {
+			(row_offset, column_offset, columns.track_count())
+		} else {
+			(column_offset, row_offset, rows.track_count())
+		};
+		let shift = |placement: &LinePlacement, offset: i32| match placement {
+			LinePlacement::Definite(lines) => LinePlacement::Definite(lines.start + offset..lines.end + offset),
+			LinePlacement::Auto(span) => LinePlacement::Auto(*span),
+		};
+		let slots: Vec<(LinePlacement, LinePlacement)> = items
+			.iter()
+			.map(|item| {
+				let (outer, inner) = if is_row_flow {
+					(&item.row_placement, &item.column_placement)
+				} else {
+					(&item.column_placement, &item.row_placement)
+				};
+				(shift(outer, outer_offset), shift(inner, inner_offset))
+			})
+			.collect();
+		let mut placed: Vec<Option<(Range<i32>, Range<i32>)>> = slots
+			.iter()
+			.map(|slot| match slot {
+				(LinePlacement::Definite(outer), LinePlacement::Definite(inner)) => {
+					Some((outer.clone(), inner.clone()))
+				},
+				_ => None,
+			})
+			.collect();
+		let overlaps = |placed: &[Option<(Range<i32>, Range<i32>)>], outer: &Range<i32>, inner: &Range<i32>| {
+			placed.iter().flatten().any(|(placed_outer, placed_inner)| {
+				placed_outer.start < outer.end
+					&& outer.start < placed_outer.end
+					&& placed_inner.start < inner.end
+					&& inner.start < placed_inner.end
+			})
+		};
+
+		// items locked to a row (or a column for `column`)
+		let mut cursors: HashMap<i32, i32> = HashMap::new();
+		for (index, slot) in slots.iter().enumerate() {
+			if let (LinePlacement::Definite(outer), LinePlacement::Auto(span)) = slot {
+				let mut start = if dense {
+					0
+				} else {
+					*cursors.get(&outer.start).unwrap_or(&0)
+				};
+				while overlaps(&placed, outer, &(start..start + span)) {
+					start += 1;
+				}
+				cursors.insert(outer.start, start + span);
+				placed[index] = Some((outer.clone(), start..start + span));
+			}
+		}
+
+		// the number of columns (or rows for `column`) of the implicit grid
+		let mut inner_count = explicit_inner_count + inner_offset;
+		for (slot, place) in slots.iter().zip(placed.iter()) {
+			match (slot, place) {
+				(_, Some((_, inner))) => inner_count = inner_count.max(inner.end),
+				((_, LinePlacement::Auto(span)), None) => inner_count = inner_count.max(*span),
+				((_, LinePlacement::Definite(inner)), None) => inner_count = inner_count.max(inner.end),
+			}
+		}
+
+		// the remaining items
+		let (mut cursor_outer, mut cursor_inner) = (0, 0);
+		for (index, slot) in slots.iter().enumerate() {
+			if placed[index].is_some() {
+				continue;
+			}
+			let outer_span = match &slot.0 {
+				LinePlacement::Auto(span) => *span,
+				LinePlacement::Definite(_) => not_reached!(),
+			};
+			if dense {
+				cursor_outer = 0;
+				cursor_inner = 0;
+			}
+			match &slot.1 {
+				LinePlacement::Definite(inner) => {
+					if inner.start < cursor_inner {
+						cursor_outer += 1;
+					}
+					while overlaps(&placed, &(cursor_outer..cursor_outer + outer_span), inner) {
+						cursor_outer += 1;
+					}
+					cursor_inner = inner.start;
+					placed[index] = Some((cursor_outer..cursor_outer + outer_span, inner.clone()));
+				},
+				LinePlacement::Auto(inner_span) => loop {
+					if cursor_inner + inner_span > inner_count {
+						cursor_outer += 1;
+						cursor_inner = 0;
+						continue;
+					}
+					let outer = cursor_outer..cursor_outer + outer_span;
+					let inner = cursor_inner..cursor_inner + inner_span;
+					if !overlaps(&placed, &outer, &inner) {
+						placed[index] = Some((outer, inner));
+						cursor_inner += inner_span;
+						break;
+					}
+					cursor_inner += 1;
+				},
+			}
+		}
+
+		let explicit_outer_count = if is_row_flow {
+			rows.track_count()
+		} else {
+			columns.track_count()
+		};
+		let mut outer_count = explicit_outer_count + outer_offset;
+		for (item, place) in items.iter_mut().zip(placed.into_iter()) {
+			let (outer, inner) = place.unwrap();
+			outer_count = outer_count.max(outer.end);
+			let (outer, inner) = (
+				outer.start as usize..outer.end as usize,
+				inner.start as usize..inner.end as usize,
+			);
+			if is_row_flow {
+				item.rows = outer;
+				item.columns = inner;
+			} else {
+				item.columns = outer;
+				item.rows = inner;
+			}
+		}
+		if is_row_flow {
+			(
+				(column_offset, inner_count as usize),
+				(row_offset, outer_count as usize),
+			)
+		} else {
+			(
+				(column_offset, outer_count as usize),
+				(row_offset, inner_count as usize),
+			)
+		}
+	}
+}
+
+fn resolve(value: &NonNegativeLengthPercentage, available_size: Option<Pixel>) -> Option<Pixel> {
+	match available_size {
+		Some(available_size) => Some(value.to_used_value(available_size)),
+		None => value.to_fixed_used_value(),
+	}
+}
+
+fn self_alignment(overflow: &Option<OverflowPosition>, position: &SelfPosition) -> (SelfAlignment, bool) {
+	let is_safe = matches!(overflow, Some(OverflowPosition::Safe));
+	let alignment = match position {
+		SelfPosition::Center => SelfAlignment::Center,
+		SelfPosition::Start | SelfPosition::SelfStart | SelfPosition::FlexStart => SelfAlignment::Start,
+		SelfPosition::End | SelfPosition::SelfEnd | SelfPosition::FlexEnd => SelfAlignment::End,
+	};
+	(alignment, is_safe)
+}
+
+/// The offset of an item's margin box inside its grid area, auto margins absorb the free space first.
+/// https://drafts.csswg.org/css-grid/#auto-margins
+fn align_in_area(
+	free_space: Pixel,
+	alignment: SelfAlignment,
+	is_safe: bool,
+	auto_margins: (bool, bool),
+	margins: (&mut Pixel, &mut Pixel),
+) -> Pixel {
+	let (start_margin, end_margin) = margins;
+	if free_space > PIXEL_ZERO && auto_margins != (false, false) {
+		match auto_margins {
+			(true, true) => {
+				*start_margin += free_space / 2.0;
+				*end_margin += free_space / 2.0;
+			},
+			(true, false) => *start_margin += free_space,
+			_ => *end_margin += free_space,
+		}
+		return PIXEL_ZERO;
+	}
+	if is_safe && free_space < PIXEL_ZERO {
+		return PIXEL_ZERO;
+	}
+	match alignment {
+		SelfAlignment::Start | SelfAlignment::Stretch => PIXEL_ZERO,
+		SelfAlignment::End => free_space,
+		SelfAlignment::Center => free_space / 2.0,
+	}
+}
+
+/// https://drafts.csswg.org/css-grid/#line-placement
+fn resolve_placement(start: &GridLine, end: &GridLine, tracks: &ExplicitTracks) -> LinePlacement {
+	match (resolve_line(start, tracks, "start"), resolve_line(end, tracks, "end")) {
+		(Some(start), Some(end)) if start == end => LinePlacement::Definite(start..start + 1),
+		(Some(start), Some(end)) => LinePlacement::Definite(start.min(end)..start.max(end)),
+		(Some(start), None) => LinePlacement::Definite(start..start + span_from(end, tracks, start, true)),
+		(None, Some(end)) => LinePlacement::Definite(end - span_from(start, tracks, end, false)..end),
+		// a named span is treated as `span 1` for auto-placed items
+		(None, None) if start.is_span && start.ident.is_none() => LinePlacement::Auto(start.line_num.unwrap_or(1)),
+		(None, None) if end.is_span && end.ident.is_none() => LinePlacement::Auto(end.line_num.unwrap_or(1)),
+		(None, None) => LinePlacement::Auto(1),
+	}
+}
+
+/// The index of a definite grid line, `side` picks the implicit line of a named area.
+fn resolve_line(line: &GridLine, tracks: &ExplicitTracks, side: &str) -> Option<i32> {
+	if line.is_span {
+		return None;
+	}
+	match (&line.ident, line.line_num) {
+		(None, Some(line_num)) if line_num > 0 => Some(line_num - 1),
+		(None, Some(line_num)) => Some(tracks.track_count() + 1 + line_num),
+		(Some(name), None) => {
+			let area_lines = tracks.lines_named(&format!("{}-{}", name.0, side));
+			match area_lines.first() {
+				Some(&line) => Some(line),
+				None => Some(tracks.nth_line_named(&name.0, 1)),
+			}
+		},
+		(Some(name), Some(line_num)) => Some(tracks.nth_line_named(&name.0, line_num)),
+		(None, None) => None,
+	}
+}
+
+/// The number of tracks a span covers from the line `from`, searching forward or backward for named spans.
+fn span_from(line: &GridLine, tracks: &ExplicitTracks, from: i32, forward: bool) -> i32 {
+	if !line.is_span {
+		return 1;
+	}
+	let count = line.line_num.unwrap_or(1);
+	let name = match &line.ident {
+		Some(name) => name,
+		None => return count,
+	};
+	let lines = tracks.lines_named(&name.0);
+	let mut found = 0;
+	if forward {
+		for &index in lines.iter().filter(|&&index| index > from) {
+			found += 1;
+			if found == count {
+				return index - from;
+			}
+		}
+		(tracks.track_count() + count - found - from).max(1)
+	} else {
+		for &index in lines.iter().rev().filter(|&&index| index < from) {
+			found += 1;
+			if found == count {
+				return from - index;
+			}
+		}
+		(from + count - found).max(1)
+	}
+}
+
+/// The tracks of the implicit grid, the ones outside the explicit grid are sized by `grid-auto-columns`
+/// and `grid-auto-rows`.
+/// https://drafts.csswg.org/css-grid/#implicit-grids
+fn implicit_tracks(explicit: &ExplicitTracks, implicit: &ImplicitGridTracks, offset: i32, count: usize) -> Vec<Track> {
+	let pattern = &implicit.0;
+	(0..count)
+		.map(|index| {
+			let explicit_index = index as i32 - offset;
+			if explicit_index >= 0 && explicit_index < explicit.track_count() {
+				let explicit_index = explicit_index as usize;
+				let is_auto_fit = explicit
+					.auto_fit
+					.as_ref()
+					.map_or(false, |auto_fit| auto_fit.contains(&explicit_index));
+				Track::new(explicit.sizes[explicit_index].clone(), is_auto_fit)
+			} else if explicit_index >= 0 {
+				let index = (explicit_index - explicit.track_count()) as usize % pattern.len();
+				Track::new(pattern[index].clone(), false)
+			} else {
+				// tracks before the explicit grid repeat the pattern backward
+				let index = pattern.len() - 1 - (-explicit_index - 1) as usize % pattern.len();
+				Track::new(pattern[index].clone(), false)
+			}
+		})
+		.collect()
+}
+
+/// The position and the size of the grid area covering `span`.
+fn area(tracks: &[Track], starts: &[Pixel], span: &Range<usize>) -> (Pixel, Pixel) {
+	let last = span.end - 1;
+	(
+		starts[span.start],
+		starts[last] + tracks[last].base_size - starts[span.start],
+	)
+}
+
+fn total_size(tracks: &[Track], gap: Pixel) -> Pixel {
+	let visible = tracks.iter().filter(|track| !track.collapsed).count();
+	let size = tracks.iter().fold(PIXEL_ZERO, |sum, track| sum + track.base_size);
+	if visible > 1 {
+		size + gap * (visible - 1) as CSSFloat
+	} else {
+		size
+	}
+}
+
+fn is_intrinsic(breadth: &TrackBreadth, available_size: Option<Pixel>) -> bool {
+	match breadth {
+		TrackBreadth::Breadth(value) => resolve(value, available_size).is_none(),
+		TrackBreadth::Fr(_) => false,
+		TrackBreadth::Auto | TrackBreadth::MinContent | TrackBreadth::MaxContent => true,
+	}
+}
+
+/// The track sizing algorithm, the final size of a track is its base size.
+/// `contributions` hold the tracks each item spans with its min-content and max-content contributions.
+/// https://drafts.csswg.org/css-grid/#algo-track-sizing
+fn size_tracks(
+	tracks: &mut [Track],
+	contributions: &[(Range<usize>, Pixel, Pixel)],
+	available_size: Option<Pixel>,
+	gap: Pixel,
+	stretch: bool,
+) {
+	// https://drafts.csswg.org/css-grid/#algo-init
+	for track in tracks.iter_mut() {
+		if track.collapsed {
+			track.base_size = PIXEL_ZERO;
+			track.growth_limit = Some(PIXEL_ZERO);
+			continue;
+		}
+		track.base_size = match track.min_sizing_function() {
+			TrackBreadth::Breadth(value) => resolve(&value, available_size).unwrap_or(PIXEL_ZERO),
+			_ => PIXEL_ZERO,
+		};
+		track.growth_limit = match track.max_sizing_function() {
+			TrackBreadth::Breadth(value) => resolve(&value, available_size).map(|limit| limit.max(track.base_size)),
+			_ => None,
+		};
+	}
+
+	// https://drafts.csswg.org/css-grid/#algo-content
+	let gaps = |span: &Range<usize>| gap * (span.len() as CSSFloat - 1.0);
+	let spans_flexible_track =
+		|tracks: &[Track], span: &Range<usize>| tracks[span.clone()].iter().any(|track| track.flex_factor().is_some());
+	let mut sorted_contributions: Vec<&(Range<usize>, Pixel, Pixel)> = contributions.iter().collect();
+	sorted_contributions.sort_by_key(|(span, _, _)| span.len());
+	for (span, min_content, max_content) in sorted_contributions.iter().cloned() {
+		if spans_flexible_track(&tracks[..], span) {
+			continue;
+		}
+		let intrinsic_minimums: Vec<usize> = span
+			.clone()
+			.filter(|&index| is_intrinsic(&tracks[index].min_sizing_function(), available_size))
+			.collect();
+		if !intrinsic_minimums.is_empty() {
+			let contribution = if intrinsic_minimums
+				.iter()
+				.all(|&index| tracks[index].min_sizing_function() == TrackBreadth::MaxContent)
+			{
+				*max_content
+			} else {
+				*min_content
+			};
+			let current_size = span
+				.clone()
+				.fold(PIXEL_ZERO, |sum, index| sum + tracks[index].base_size);
+			let extra_space = contribution - current_size - gaps(span);
+			if extra_space > PIXEL_ZERO {
+				let share = extra_space / intrinsic_minimums.len() as CSSFloat;
+				for index in intrinsic_minimums {
+					tracks[index].base_size += share;
+				}
+			}
+		}
+
+		let intrinsic_maximums: Vec<usize> = span
+			.clone()
+			.filter(|&index| is_intrinsic(&tracks[index].max_sizing_function(), available_size))
+			.collect();
+		if !intrinsic_maximums.is_empty() {
+			let contribution = if intrinsic_maximums
+				.iter()
+				.all(|&index| tracks[index].max_sizing_function() == TrackBreadth::MinContent)
+			{
+				*min_content
+			} else {
+				*max_content
+			};
+			let current_size = span.clone().fold(PIXEL_ZERO, |sum, index| {
+				sum + tracks[index].growth_limit.unwrap_or(tracks[index].base_size)
+			});
+			let extra_space = contribution - current_size - gaps(span);
+			let share = if extra_space > PIXEL_ZERO {
+				extra_space / intrinsic_maximums.len() as CSSFloat
+			} else {
+				PIXEL_ZERO
+			};
+			for index in intrinsic_maximums {
+				let track = &mut tracks[index];
+				let growth_limit = track.growth_limit.unwrap_or(track.base_size) + share;
+				// https://drafts.csswg.org/css-grid/#valdef-grid-template-columns-fit-content
+				let growth_limit = match &track.size {
+					TrackSize::FitContent(value) => match resolve(value, available_size) {
+						Some(limit) => growth_limit.min(limit.max(track.base_size)),
+						None => growth_limit,
+					},
+					_ => growth_limit,
+				};
+				track.growth_limit = Some(growth_limit);
+			}
+		}
+	}
+	// items spanning flexible tracks only increase their base sizes
+	for (span, min_content, _) in contributions.iter() {
+		if !spans_flexible_track(&tracks[..], span) {
+			continue;
+		}
+		let flexible: Vec<usize> = span
+			.clone()
+			.filter(|&index| tracks[index].flex_factor().is_some())
+			.collect();
+		let current_size = span
+			.clone()
+			.fold(PIXEL_ZERO, |sum, index| sum + tracks[index].base_size);
+		let extra_space = *min_content - current_size - gaps(span);
+		if extra_space > PIXEL_ZERO {
+			let share = extra_space / flexible.len() as CSSFloat;
+			for index in flexible {
+				tracks[index].base_size += share;
+			}
+		}
+	}
+	for track in tracks.iter_mut() {
+		if track.flex_factor().is_none() {
+			track.growth_limit = Some(track.growth_limit.unwrap_or(track.base_size).max(track.base_size));
+		}
+	}
+
+	// https://drafts.csswg.org/css-grid/#algo-grow-tracks
+	match available_size {
+		Some(available_size) => {
+			let mut free_space = available_size - total_size(tracks, gap);
+			while free_space > PIXEL_ZERO {
+				let growable: Vec<usize> = (0..tracks.len())
+					.filter(|&index| {
+						let track = &tracks[index];
+						track.flex_factor().is_none()
+							&& track.growth_limit.map_or(false, |limit| limit > track.base_size)
+					})
+					.collect();
+				if growable.is_empty() {
+					break;
+				}
+				let share = free_space / growable.len() as CSSFloat;
+				let mut is_capped = false;
+				for index in growable {
+					let track = &mut tracks[index];
+					let room = track.growth_limit.unwrap() - track.base_size;
+					let growth = if room <= share {
+						is_capped = true;
+						room
+					} else {
+						share
+					};
+					track.base_size += growth;
+					free_space -= growth;
+				}
+				if !is_capped {
+					break;
+				}
+			}
+		},
+		None => {
+			for track in tracks.iter_mut() {
+				if let Some(growth_limit) = track.growth_limit {
+					track.base_size = track.base_size.max(growth_limit);
+				}
+			}
+		},
+	}
+
+	// https://drafts.csswg.org/css-grid/#algo-flex-tracks
+	let flexible: Vec<usize> = (0..tracks.len())
+		.filter(|&index| tracks[index].flex_factor().is_some())
+		.collect();
+	if !flexible.is_empty() {
+		let fr_size = match available_size {
+			Some(available_size) => {
+				let all_tracks = 0..tracks.len();
+				let space = available_size - (total_size(tracks, gap) - sum_base_sizes(tracks, &all_tracks));
+				find_fr_size(tracks, &all_tracks, space)
+			},
+			None => {
+				let mut fr_size = PIXEL_ZERO;
+				for &index in flexible.iter() {
+					let factor = tracks[index].flex_factor().unwrap();
+					let base_size = tracks[index].base_size;
+					fr_size = fr_size.max(if factor > 1.0 { base_size / factor } else { base_size });
+				}
+				for (span, _, max_content) in contributions.iter() {
+					if spans_flexible_track(&tracks[..], span) {
+						fr_size = fr_size.max(find_fr_size(tracks, span, *max_content - gaps(span)));
+					}
+				}
+				fr_size
+			},
+		};
+		for index in flexible {
+			let track = &mut tracks[index];
+			track.base_size = track.base_size.max(fr_size * track.flex_factor().unwrap());
+		}
+	}
+
+	// https://drafts.csswg.org/css-grid/#algo-stretch
+	if let (true, Some(available_size)) = (stretch, available_size) {
+		let free_space = available_size - total_size(tracks, gap);
+		let auto_tracks: Vec<usize> = (0..tracks.len())
+			.filter(|&index| !tracks[index].collapsed && tracks[index].max_sizing_function() == TrackBreadth::Auto)
+			.collect();
+		if free_space > PIXEL_ZERO && !auto_tracks.is_empty() {
+			let share = free_space / auto_tracks.len() as CSSFloat;
+			for index in auto_tracks {
+				tracks[index].base_size += share;
+			}
+		}
+	}
+}
+
+fn sum_base_sizes(tracks: &[Track], span: &Range<usize>) -> Pixel {
+	span.clone()
+		.fold(PIXEL_ZERO, |sum, index| sum + tracks[index].base_size)
+}
+
+/// https://drafts.csswg.org/css-grid/#algo-find-fr-size
+fn find_fr_size(tracks: &[Track], span: &Range<usize>, space: Pixel) -> Pixel {
+	let mut inflexible = vec![false; tracks.len()];
+	loop {
+		let mut leftover_space = space;
+		let mut flex_factors = 0.0;
+		for index in span.clone() {
+			match tracks[index].flex_factor() {
+				Some(factor) if !inflexible[index] => flex_factors += factor,
+				_ => leftover_space -= tracks[index].base_size,
+			}
+		}
+		if flex_factors == 0.0 {
+			return PIXEL_ZERO;
+		}
+		let fr_size = leftover_space.max(PIXEL_ZERO) / flex_factors.max(1.0);
+		let mut has_inflexible = false;
+		for index in span.clone() {
+			if let Some(factor) = tracks[index].flex_factor() {
+				if !inflexible[index] && fr_size * factor < tracks[index].base_size {
+					inflexible[index] = true;
+					has_inflexible = true;
+				}
+			}
+		}
+		if !has_inflexible {
+			return fr_size;
+		}
+	}
+}
@@ -1,14 +1,17 @@
 use std::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
 
-use common::not_supported;
-use css::values::computed::length::{LengthPercentage, Size};
 use css::values::{Pixel, PIXEL_ZERO};
 use dom::global_scope::{GlobalScope, NodeRef};
 
+use super::block::BlockLevelBox;
 use super::boxes::{BaseBox, Box, BoxClass, SimpleBoxIterator};
+use super::flex::FlexContainer;
 use super::formatting_context::{FormattingContext, FormattingContextType};
-use super::fragment::{BoxFragment, Fragment, LayoutInfo, Line};
+use super::fragment::{BoxFragment, Fragment, LayoutInfo};
+use super::grid::GridContainer;
+use super::line_height::Strut;
+use super::table::TableContainer;
 use super::tree::VisitingContext;
 
 /// https://www.w3.org/TR/CSS22/visuren.html#inline-boxes
@@ -55,6 +58,12 @@ impl InlineLevelBox {
 			fragment.margin.left = layout_info.margin.left;
 		}
 		fragment.margin.right = layout_info.margin.right;
+		// the fragments of an inline box are as tall as its content area, the line height only counts in the line box
+		if self.formatting_context_type() == FormattingContextType::InlineFormattingContext {
+			let strut = Strut::new(GlobalScope::get_or_init_computed_values(self.dom_node.id()));
+			fragment.set_height(strut.content_height());
+			fragment.strut = Some(strut);
+		}
 		fragment
 	}
 
@@ -165,7 +174,7 @@ impl Box for InlineLevelBox {
 	}
 
 	fn is_block_container(&self) -> bool {
-		self.formatting_context_type() == FormattingContextType::BlockFormattingContext
+		self.formatting_context_type() != FormattingContextType::InlineFormattingContext
 	}
 
 	fn prepare_layout(&self) {
@@ -173,10 +182,12 @@ impl Box for InlineLevelBox {
 		let mut layout_info = self.layout_info_mut();
 		layout_info.compute_fixed_margin(computed_values);
 		layout_info.compute_fixed_padding(computed_values);
-		if self.formatting_context_type() == FormattingContextType::BlockFormattingContext {
+		layout_info.compute_intrinsic(self);
+		// the sizing properties don't apply to a non-atomic inline box
+		if self.formatting_context_type() != FormattingContextType::InlineFormattingContext {
 			layout_info.compute_width_and_height(computed_values);
+			layout_info.compute_contributions(computed_values);
 		}
-		layout_info.compute_intrinsic(self);
 	}
 
 	fn visit_layout(&self) {
@@ -186,7 +197,11 @@ impl Box for InlineLevelBox {
 		self.max_width.replace(parent_current_width + parent_leftover_width);
 
 		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
-		let containing_width = self.containing_block().unwrap().layout_info().width;
+		let containing_block = self.containing_block().unwrap();
+		let (containing_width, containing_height) = {
+			let containing_layout = containing_block.layout_info();
+			(containing_layout.width, containing_layout.definite_height())
+		};
 		let padding = BoxClass::get_padding_for_non_replaced_elements(computed_values, containing_width);
 		let margin = BoxClass::get_margin_for_non_replaced_elements(computed_values, containing_width);
 		let mut layout_info = self.layout_info_mut();
@@ -194,28 +209,31 @@ impl Box for InlineLevelBox {
 		layout_info.padding = padding;
 
 		match self.formatting_context_type() {
-			FormattingContextType::BlockFormattingContext => {
-				let width = match computed_values.get_width() {
-					Size::Auto => layout_info.intrinsic_size.preferred_width.min(
-						layout_info
-							.intrinsic_size
-							.preferred_minimum_width
-							.max(containing_width - layout_info.horizontal_sides()),
-					),
-					Size::LengthPercentage(length_percentage) => length_percentage.to_used_value(containing_width),
-					Size::ExtremumLength(_) => {
-						not_supported!()
-					},
-				};
-				let height = match computed_values.get_height() {
-					Size::LengthPercentage(length_percentage) => match &length_percentage.0 {
-						LengthPercentage::AbsoluteLength(value) => Pixel::new(*value),
-						_ => PIXEL_ZERO,
-					},
-					_ => PIXEL_ZERO,
-				};
+			FormattingContextType::BlockFormattingContext
+			| FormattingContextType::FlexFormattingContext
+			| FormattingContextType::GridFormattingContext
+			| FormattingContextType::TableFormattingContext => {
+				// an inline-block with an auto width shrinks to fit its content
+				// https://www.w3.org/TR/CSS22/visudet.html#inlineblock-width
+				let auto_width = layout_info
+					.intrinsic_size
+					.fit_content(containing_width - layout_info.horizontal_sides());
+				let width = layout_info.get_used_width(computed_values, containing_width, auto_width);
+				let (mut width, height) = BlockLevelBox::get_used_size(
+					&layout_info,
+					computed_values,
+					width,
+					containing_width,
+					containing_height,
+				);
+				if BoxClass::is_table(self) {
+					// a table is never narrower than its content
+					// https://www.w3.org/TR/CSS22/tables.html#auto-table-layout
+					width = width.max(layout_info.intrinsic_size.min_content_width);
+				}
 				layout_info.width = width;
-				layout_info.height = height;
+				layout_info.set_height(height);
+				let height = layout_info.height;
 				drop(layout_info);
 
 				let mut fragment = self.create_fragment();
@@ -226,7 +244,7 @@ impl Box for InlineLevelBox {
 
 				let mut lines = establisher.lines_mut();
 				if lines.len() == 0 {
-					lines.push(Line::new());
+					lines.push(BoxClass::new_line(establisher.as_ref(), &[]));
 				}
 				let latest_line = lines.last().unwrap();
 
@@ -259,6 +277,15 @@ impl Box for InlineLevelBox {
 					);
 					parent.add_child_fragment(fragment.clone());
 				}
+				// an atomic inline isn't a space, the spaces after it are kept
+				parent.formatting_context().after_collapsible_space.set(false);
+
+				match self.formatting_context_type() {
+					FormattingContextType::FlexFormattingContext => FlexContainer::new(self).layout_items(),
+					FormattingContextType::GridFormattingContext => GridContainer::new(self).layout_items(),
+					FormattingContextType::TableFormattingContext => TableContainer::new(self).layout_items(),
+					_ => {},
+				}
 			},
 			FormattingContextType::InlineFormattingContext => {
 				drop(layout_info);
@@ -272,7 +299,7 @@ impl Box for InlineLevelBox {
 
 				let mut lines = establisher.lines_mut();
 				if lines.len() == 0 {
-					lines.push(Line::new());
+					lines.push(BoxClass::new_line(establisher.as_ref(), &[]));
 				}
 				let latest_line = lines.last().unwrap();
 				if parent.id() == establisher.id() {
@@ -284,17 +311,18 @@ impl Box for InlineLevelBox {
 
 	fn revisit_layout(&self, _context: &mut VisitingContext) {
 		match self.formatting_context_type() {
-			FormattingContextType::BlockFormattingContext => {
-				let fragments = self.fragments();
-				assert_eq!(fragments.len(), 1);
-				let fragment = fragments.last().unwrap();
-
-				let mut layout_info = self.layout_info_mut();
-				if layout_info.height == PIXEL_ZERO {
-					let height = BoxClass::get_block_height(self);
-					layout_info.height = height;
-					fragment.borrow_mut().set_height(height);
+			FormattingContextType::BlockFormattingContext
+			| FormattingContextType::FlexFormattingContext
+			| FormattingContextType::GridFormattingContext
+			| FormattingContextType::TableFormattingContext => {
+				match self.formatting_context_type() {
+					FormattingContextType::FlexFormattingContext => FlexContainer::new(self).arrange_items(),
+					FormattingContextType::GridFormattingContext => GridContainer::new(self).arrange_items(),
+					FormattingContextType::TableFormattingContext => TableContainer::new(self).arrange_items(),
+					_ => {},
 				}
+				assert_eq!(self.fragments().len(), 1);
+				BoxClass::fit_height_to_content(self);
 			},
 			FormattingContextType::InlineFormattingContext => {
 				let mut fragments = self.fragments.borrow_mut();
@@ -309,14 +337,6 @@ impl Box for InlineLevelBox {
 				if fragments.len() >= 2 && fragments[0].borrow().total_width() == PIXEL_ZERO {
 					fragments.remove(0);
 				}
-
-				for fragment in fragments.iter() {
-					let mut child_height = PIXEL_ZERO;
-					for child_fragment in &fragment.borrow().children {
-						child_height += child_fragment.borrow().height();
-					}
-					fragment.borrow_mut().set_height(child_height);
-				}
 			},
 		}
 	}
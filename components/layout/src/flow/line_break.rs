@@ -0,0 +1,204 @@
+use std::ops::Range;
+
+use css::computed_values::ComputedValues;
+use css::properties::longhands::overflow_wrap::OverflowWrap;
+use css::properties::longhands::white_space::WhiteSpace;
+use css::properties::longhands::word_break::WordBreak;
+use xi_unicode::LineBreakIterator;
+
+/// `tab-size` isn't supported, a preserved tab advances to the next multiple of 8 spaces.
+/// https://drafts.csswg.org/css-text/#tab-size-property
+const TAB_SIZE: usize = 8;
+
+/// The text between two break opportunities, it keeps the spaces which follow it. A forced break after the text is
+/// marked by `is_hard`, the segment break itself isn't a part of `text`. `offset` is the byte offset of `text` in the
+/// whole content.
+#[derive(Clone, Copy)]
+pub struct Segment<'a> {
+	pub text: &'a str,
+	pub offset: usize,
+	pub is_hard: bool,
+}
+
+impl<'a> Segment<'a> {
+	/// The bytes of the segment without the spaces at its end.
+	pub fn fitting_range(&self) -> Range<usize> {
+		self.offset..self.offset + trim_hanging_spaces(self.text).len()
+	}
+
+	pub fn end(&self) -> usize {
+		self.offset + self.text.len()
+	}
+}
+
+/// Spaces and tabs are collapsed by `normal`, `nowrap` and `pre-line`.
+/// https://drafts.csswg.org/css-text/#white-space-property
+pub fn collapses_spaces(white_space: &WhiteSpace) -> bool {
+	matches!(
+		white_space,
+		WhiteSpace::Normal | WhiteSpace::Nowrap | WhiteSpace::PreLine
+	)
+}
+
+/// Lines are only broken at preserved segment breaks by `nowrap` and `pre`.
+pub fn wraps_lines(white_space: &WhiteSpace) -> bool {
+	!matches!(white_space, WhiteSpace::Nowrap | WhiteSpace::Pre)
+}
+
+/// Whether `content` only has spaces, tabs and segment breaks which are all removed by the white space processing at
+/// the start of a line.
+pub fn is_collapsible_white_space(content: &str, white_space: &WhiteSpace) -> bool {
+	content.chars().all(|ch| matches!(ch, ' ' | '\t' | '\n'))
+		&& match white_space {
+			WhiteSpace::Normal | WhiteSpace::Nowrap => true,
+			WhiteSpace::PreLine => !content.contains('\n'),
+			WhiteSpace::Pre | WhiteSpace::PreWrap => false,
+		}
+}
+
+/// Collapses or preserves spaces, tabs and segment breaks, the collapsible spaces at the start of `content` are removed
+/// when `after_collapsible_space` (the content before it ends with one, or a line starts there).
+/// https://drafts.csswg.org/css-text/#white-space-phase-1
+pub fn process_white_space(content: &str, white_space: &WhiteSpace, after_collapsible_space: bool) -> String {
+	let mut result = String::with_capacity(content.len());
+	if collapses_spaces(white_space) {
+		let preserves_segment_breaks = matches!(white_space, WhiteSpace::PreLine);
+		let mut after_space = after_collapsible_space;
+		for ch in content.chars() {
+			match ch {
+				// spaces and tabs around a preserved segment break are removed
+				'\n' if preserves_segment_breaks => {
+					if result.ends_with(' ') {
+						result.pop();
+					}
+					result.push('\n');
+					after_space = true;
+				},
+				// a segment break which isn't preserved is transformed into a space
+				' ' | '\t' | '\n' => {
+					if !after_space {
+						result.push(' ');
+					}
+					after_space = true;
+				},
+				_ => {
+					result.push(ch);
+					after_space = false;
+				},
+			}
+		}
+	} else {
+		let mut column = 0;
+		for ch in content.chars() {
+			match ch {
+				'\t' => {
+					let spaces = TAB_SIZE - column % TAB_SIZE;
+					result.extend(std::iter::repeat(' ').take(spaces));
+					column += spaces;
+				},
+				'\n' => {
+					result.push(ch);
+					column = 0;
+				},
+				_ => {
+					result.push(ch);
+					column += 1;
+				},
+			}
+		}
+	}
+	result
+}
+
+/// Whether the next text starts after a collapsible space or at the start of a line once `content` is laid out.
+pub fn ends_with_collapsible_space(content: &str, white_space: &WhiteSpace) -> bool {
+	content.ends_with('\n') || (collapses_spaces(white_space) && content.ends_with(' '))
+}
+
+/// Collapsible spaces at the end of a line are removed, preserved spaces hang so they aren't measured when fitting
+/// the text into a line.
+/// https://drafts.csswg.org/css-text/#white-space-phase-2
+pub fn trim_hanging_spaces(text: &str) -> &str {
+	text.trim_end_matches(' ')
+}
+
+/// Splits `content` at its line break opportunities, which come from the Unicode line breaking algorithm and are
+/// restricted or extended by `white-space` and `word-break`.
+/// https://drafts.csswg.org/css-text/#line-breaking
+/// https://www.unicode.org/reports/tr14/
+pub fn get_segments<'a>(content: &'a str, computed_values: &ComputedValues) -> Vec<Segment<'a>> {
+	let wraps = wraps_lines(computed_values.get_white_space());
+	let word_break = computed_values.get_word_break();
+	let mut segments = vec![];
+	let (mut start, mut last) = (0, 0);
+	for (offset, _) in LineBreakIterator::new(content) {
+		let text = &content[last..offset];
+		last = offset;
+		// the end of the text is reported as a hard break, only preserved segment breaks force a line break
+		let is_hard = text.ends_with('\n');
+		if !is_hard && offset < content.len() {
+			if !wraps {
+				continue;
+			}
+			// words of letters and numbers are kept together, the other opportunities stay
+			if matches!(word_break, WordBreak::KeepAll) && text.chars().last().map_or(false, char::is_alphanumeric) {
+				continue;
+			}
+		}
+		let text = &content[start..offset];
+		segments.push(Segment {
+			text: text.strip_suffix('\n').unwrap_or(text),
+			offset: start,
+			is_hard,
+		});
+		start = offset;
+	}
+	if start < content.len() {
+		segments.push(Segment {
+			text: &content[start..],
+			offset: start,
+			is_hard: false,
+		});
+	}
+	if wraps && matches!(word_break, WordBreak::BreakAll) {
+		segments = segments.into_iter().flat_map(split_characters).collect();
+	}
+	segments
+}
+
+/// Breaks a segment at every character, the spaces after a character stay with it.
+pub fn split_characters(segment: Segment) -> Vec<Segment> {
+	let mut pieces = vec![];
+	let mut start = 0;
+	for (index, ch) in segment.text.char_indices() {
+		if index > start && ch != ' ' {
+			pieces.push(Segment {
+				text: &segment.text[start..index],
+				offset: segment.offset + start,
+				is_hard: false,
+			});
+			start = index;
+		}
+	}
+	pieces.push(Segment {
+		text: &segment.text[start..],
+		offset: segment.offset + start,
+		is_hard: segment.is_hard,
+	});
+	pieces
+}
+
+/// A word which doesn't fit in a line on its own is broken at an arbitrary point.
+/// https://drafts.csswg.org/css-text/#overflow-wrap-property
+pub fn breaks_overflowing_words(computed_values: &ComputedValues) -> bool {
+	wraps_lines(computed_values.get_white_space())
+		&& (!matches!(computed_values.get_overflow_wrap(), OverflowWrap::Normal)
+			|| matches!(computed_values.get_word_break(), WordBreak::BreakWord))
+}
+
+/// Unlike `overflow-wrap: break-word`, the breaks of `anywhere` are considered for the min-content size.
+pub fn breaks_anywhere_for_min_content(computed_values: &ComputedValues) -> bool {
+	wraps_lines(computed_values.get_white_space())
+		&& (matches!(computed_values.get_overflow_wrap(), OverflowWrap::Anywhere)
+			|| matches!(computed_values.get_word_break(), WordBreak::BreakWord))
+}
@@ -0,0 +1,362 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use css::computed_values::ComputedValues;
+use css::properties::longhands::vertical_align::VerticalAlignKeyword;
+use css::values::computed::text::VerticalAlign;
+use css::values::generics::length::GenericLengthPercentageNumberOrNormal;
+use css::values::specified::layout::Overflow;
+use css::values::{Pixel, PIXEL_ZERO};
+use dom::global_scope::GlobalScope;
+
+use super::fragment::{BoxFragment, Fragment, FragmentClass, Line};
+use crate::text::TextUI;
+
+/// An empty inline box with the first available font and the line height of an element. Every line box starts with
+/// the strut of the box establishing it, every inline box has its own one which its content is aligned to.
+/// https://www.w3.org/TR/CSS22/visudet.html#strut
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Strut {
+	pub ascent: Pixel,
+	pub descent: Pixel,
+	pub line_height: Pixel,
+	pub font_size: Pixel,
+}
+
+impl Strut {
+	pub fn new(computed_values: &ComputedValues) -> Self {
+		let font_size = computed_values.get_font_size();
+		let metrics = TextUI::cached_font_metrics(
+			computed_values.get_font_families(),
+			font_size,
+			computed_values.get_font_weight(),
+			computed_values.get_font_style(),
+		);
+		let line_height = match computed_values.get_line_height() {
+			GenericLengthPercentageNumberOrNormal::LengthPercentage(length) => length.0,
+			GenericLengthPercentageNumberOrNormal::Number(number) => number * font_size,
+			// `normal` is the height which the font recommends between its baselines
+			GenericLengthPercentageNumberOrNormal::Normal => metrics.ascent + metrics.descent + metrics.line_gap,
+		};
+		Strut {
+			ascent: Pixel::new(metrics.ascent),
+			descent: Pixel::new(metrics.descent),
+			line_height: Pixel::new(line_height),
+			font_size: Pixel::new(font_size),
+		}
+	}
+
+	/// The height of the content area, which is the height of the fragments of a non-replaced inline box.
+	pub fn content_height(&self) -> Pixel {
+		self.ascent + self.descent
+	}
+
+	/// The leading is added half above the ascent and half below the descent, it is negative when the line height is
+	/// smaller than the content area.
+	/// https://www.w3.org/TR/CSS22/visudet.html#leading
+	fn above_baseline(&self) -> Pixel {
+		self.ascent + (self.line_height - self.content_height()) / 2.0
+	}
+
+	fn below_baseline(&self) -> Pixel {
+		self.line_height - self.above_baseline()
+	}
+}
+
+/// The height of a line box and the distance from its top to its baseline, `placements` are the vertical positions of
+/// its fragments and the fragments inside its inline boxes.
+#[derive(Default)]
+pub struct LineBox {
+	pub height: Pixel,
+	pub baseline: Pixel,
+	placements: Vec<(Rc<RefCell<dyn Fragment>>, Pixel)>,
+}
+
+impl LineBox {
+	/// A fragment in the line is placed from the top of the line box, a fragment inside an inline box from the top of
+	/// its content box.
+	pub fn place_fragments(&self) {
+		for (fragment, y) in self.placements.iter() {
+			fragment.borrow_mut().set_y(*y);
+		}
+	}
+}
+
+/// The position of the margin box of a fragment, `origin` is the top of the content box of its parent or `None` when
+/// the fragment is in the line itself. Both are measured from the baseline of the line's strut.
+struct Placement {
+	fragment: Rc<RefCell<dyn Fragment>>,
+	top: Pixel,
+	origin: Option<Pixel>,
+}
+
+impl Placement {
+	fn y(&self, line_top: Pixel) -> Pixel {
+		self.top - self.origin.unwrap_or(line_top)
+	}
+}
+
+/// A box aligned with `vertical-align: top` or `bottom` with its descendants, measured from its own baseline.
+struct AlignedSubtree {
+	is_top: bool,
+	top: Pixel,
+	bottom: Pixel,
+	placement: Placement,
+	descendants: Vec<Placement>,
+}
+
+/// `top` and `bottom` are the layout bounds of the content which is aligned to baselines, the boxes aligned to the top
+/// or the bottom of the line box are left for `aligned_subtrees`. Inside an aligned subtree they stay on the baseline.
+struct LineContext {
+	top: Pixel,
+	bottom: Pixel,
+	placements: Vec<Placement>,
+	aligned_subtrees: Vec<AlignedSubtree>,
+	is_aligned_subtree: bool,
+}
+
+impl LineContext {
+	fn new(top: Pixel, bottom: Pixel, is_aligned_subtree: bool) -> Self {
+		LineContext {
+			top,
+			bottom,
+			placements: vec![],
+			aligned_subtrees: vec![],
+			is_aligned_subtree,
+		}
+	}
+
+	fn extend(&mut self, top: Pixel, bottom: Pixel) {
+		self.top = self.top.min(top);
+		self.bottom = self.bottom.max(bottom);
+	}
+}
+
+/// The line box is as tall as the layout bounds of its content from the top one to the bottom one, then it is made
+/// taller for the aligned subtrees which don't fit in it.
+/// https://www.w3.org/TR/CSS22/visudet.html#line-height
+pub fn align_line(line: &Line) -> LineBox {
+	let fragments = line.fragments();
+	if fragments.iter().all(is_phantom) {
+		return LineBox::default();
+	}
+	let mut context = LineContext::new(-line.strut.above_baseline(), line.strut.below_baseline(), false);
+	align_fragments(&fragments, &line.strut, PIXEL_ZERO, None, &mut context);
+
+	let (mut top, mut bottom) = (context.top, context.bottom);
+	for subtree in context.aligned_subtrees.iter() {
+		let height = subtree.bottom - subtree.top;
+		if height <= bottom - top {
+			continue;
+		}
+		if subtree.is_top {
+			bottom = top + height;
+		} else {
+			top = bottom - height;
+		}
+	}
+
+	let mut placements: Vec<(Rc<RefCell<dyn Fragment>>, Pixel)> = context
+		.placements
+		.iter()
+		.map(|placement| (placement.fragment.clone(), placement.y(top)))
+		.collect();
+	for subtree in context.aligned_subtrees.into_iter() {
+		let shift = if subtree.is_top {
+			top - subtree.top
+		} else {
+			bottom - subtree.bottom
+		};
+		let placement = Placement {
+			top: subtree.placement.top + shift,
+			..subtree.placement
+		};
+		placements.push((placement.fragment.clone(), placement.y(top)));
+		// the descendants move with the subtree, they keep their positions in their parents
+		for descendant in subtree.descendants.iter() {
+			placements.push((descendant.fragment.clone(), descendant.y(top)));
+		}
+	}
+	LineBox {
+		height: bottom - top,
+		baseline: -top,
+		placements,
+	}
+}
+
+/// The baseline of an inline-block is the baseline of its last line box, it is the bottom margin edge when there is no
+/// line box or its `overflow` isn't visible. Returns the distance from the top of its margin box.
+/// https://www.w3.org/TR/CSS22/visudet.html#propdef-vertical-align
+fn atomic_baseline(fragment: &BoxFragment, computed_values: &ComputedValues) -> Pixel {
+	let is_overflow_visible = matches!(computed_values.get_overflow_x(), Overflow::Visible)
+		&& matches!(computed_values.get_overflow_y(), Overflow::Visible);
+	match last_baseline(&fragment.lines.borrow(), &fragment.children) {
+		Some(baseline) if is_overflow_visible => fragment.margin.top + fragment.padding.top + baseline,
+		_ => fragment.total_height(),
+	}
+}
+
+/// The baseline of the last line box in a content box, looking into the in-flow children when it has no line box.
+fn last_baseline(lines: &[Line], children: &[Rc<RefCell<dyn Fragment>>]) -> Option<Pixel> {
+	if let Some(line) = lines.iter().rev().find(|line| !line.fragments().is_empty()) {
+		return Some(line.y() + line.baseline());
+	}
+	children.iter().rev().find_map(|child| {
+		let child = child.borrow();
+		match child.class() {
+			FragmentClass::BoxFragment => {
+				let box_fragment = child.as_box_fragment();
+				last_baseline(&box_fragment.lines.borrow(), &box_fragment.children)
+					.map(|baseline| child.rect_y() + baseline)
+			},
+			FragmentClass::AnonymousFragment => {
+				let anonymous_fragment = child.as_anonymous_fragment();
+				last_baseline(&anonymous_fragment.lines.borrow(), &anonymous_fragment.children)
+					.map(|baseline| child.rect_y() + baseline)
+			},
+			FragmentClass::TextFragment => None,
+		}
+	})
+}
+
+/// A line without text, preserved white space, atomic inlines or inline boxes with horizontal margins, padding or
+/// borders is treated as a zero-height line box.
+/// https://www.w3.org/TR/CSS22/visuren.html#phantom-line-box
+fn is_phantom(fragment: &Rc<RefCell<dyn Fragment>>) -> bool {
+	let fragment = fragment.borrow();
+	match fragment.class() {
+		FragmentClass::BoxFragment => {
+			let box_fragment = fragment.as_box_fragment();
+			box_fragment.strut.is_some()
+				&& box_fragment.margin.left + box_fragment.padding.left + box_fragment.right_sides() == PIXEL_ZERO
+				&& box_fragment.children.iter().all(is_phantom)
+		},
+		FragmentClass::TextFragment => false,
+		FragmentClass::AnonymousFragment => true,
+	}
+}
+
+/// Aligns `fragments` to their parent, whose baseline is at `baseline`. Text is on the baseline of its parent with the
+/// leading of the parent's line height, inline boxes are moved by their `vertical-align` and their content is aligned
+/// to them.
+fn align_fragments(
+	fragments: &[Rc<RefCell<dyn Fragment>>],
+	parent: &Strut,
+	baseline: Pixel,
+	origin: Option<Pixel>,
+	context: &mut LineContext,
+) {
+	for fragment in fragments {
+		let borrowed = fragment.borrow();
+		match borrowed.class() {
+			FragmentClass::TextFragment => {
+				let shaped_text = &borrowed.as_text_fragment().shaped_text;
+				let (ascent, descent) = (Pixel::new(shaped_text.ascent), Pixel::new(shaped_text.descent()));
+				let above = ascent + (parent.line_height - ascent - descent) / 2.0;
+				context.extend(baseline - above, baseline - above + parent.line_height);
+				context.placements.push(Placement {
+					fragment: fragment.clone(),
+					top: baseline - ascent,
+					origin,
+				});
+			},
+			FragmentClass::BoxFragment => {
+				let box_fragment = borrowed.as_box_fragment();
+				let computed_values = GlobalScope::get_or_init_computed_values(box_fragment.dom_node.id());
+				// an inline box is aligned by the line height around its content area, an atomic inline by its margin box
+				let (above, below, baseline_offset) = match box_fragment.strut {
+					Some(strut) => (
+						strut.above_baseline(),
+						strut.below_baseline(),
+						box_fragment.margin.top + box_fragment.padding.top + strut.ascent,
+					),
+					None => {
+						let atomic_baseline = atomic_baseline(box_fragment, computed_values);
+						(
+							atomic_baseline,
+							box_fragment.total_height() - atomic_baseline,
+							atomic_baseline,
+						)
+					},
+				};
+				let line_height = || match box_fragment.strut {
+					Some(strut) => strut.line_height,
+					None => Strut::new(computed_values).line_height,
+				};
+				let vertical_align = computed_values.get_vertical_align();
+				let shifted_baseline = shift_baseline(vertical_align, parent, baseline, above, below, line_height)
+					.or_else(|| context.is_aligned_subtree.then(|| baseline));
+				match shifted_baseline {
+					Some(box_baseline) => {
+						context.extend(box_baseline - above, box_baseline + below);
+						context.placements.push(Placement {
+							fragment: fragment.clone(),
+							top: box_baseline - baseline_offset,
+							origin,
+						});
+						if let Some(strut) = &box_fragment.strut {
+							let content_top = box_baseline - strut.ascent;
+							align_fragments(&box_fragment.children, strut, box_baseline, Some(content_top), context);
+						}
+					},
+					None => {
+						let mut subtree_context = LineContext::new(-above, below, true);
+						if let Some(strut) = &box_fragment.strut {
+							let content_top = -strut.ascent;
+							align_fragments(
+								&box_fragment.children,
+								strut,
+								PIXEL_ZERO,
+								Some(content_top),
+								&mut subtree_context,
+							);
+						}
+						context.aligned_subtrees.push(AlignedSubtree {
+							is_top: matches!(vertical_align, VerticalAlign::Keyword(VerticalAlignKeyword::Top)),
+							top: subtree_context.top,
+							bottom: subtree_context.bottom,
+							placement: Placement {
+								fragment: fragment.clone(),
+								top: -baseline_offset,
+								origin,
+							},
+							descendants: subtree_context.placements,
+						});
+					},
+				}
+			},
+			// anonymous boxes are block containers, they aren't in line boxes
+			FragmentClass::AnonymousFragment => {},
+		}
+	}
+}
+
+/// Where the baseline of a box goes from the baseline of its parent, `above` and `below` are the parts of its layout
+/// bounds around its baseline, `line_height` is only resolved for a percentage. Returns `None` when the box is aligned
+/// to the line box instead.
+/// https://www.w3.org/TR/CSS22/visudet.html#propdef-vertical-align
+fn shift_baseline(
+	vertical_align: &VerticalAlign,
+	parent: &Strut,
+	baseline: Pixel,
+	above: Pixel,
+	below: Pixel,
+	line_height: impl FnOnce() -> Pixel,
+) -> Option<Pixel> {
+	// the x-height of the parent is taken as half of its font size
+	let x_height = parent.font_size / 2.0;
+	let shifted_baseline = match vertical_align {
+		VerticalAlign::Keyword(keyword) => match keyword {
+			VerticalAlignKeyword::Baseline => baseline,
+			VerticalAlignKeyword::Sub => baseline + parent.font_size / 5.0,
+			VerticalAlignKeyword::Super => baseline - parent.font_size / 3.0,
+			VerticalAlignKeyword::TextTop => baseline - parent.ascent + above,
+			VerticalAlignKeyword::TextBottom => baseline + parent.descent - below,
+			VerticalAlignKeyword::Middle => baseline - x_height / 2.0 + (above - below) / 2.0,
+			VerticalAlignKeyword::Top | VerticalAlignKeyword::Bottom => return None,
+		},
+		// a length raises the box, a percentage is relative to its own line height
+		VerticalAlign::LengthPercentage(length_percentage) => baseline - length_percentage.to_used_value(line_height()),
+	};
+	Some(shifted_baseline)
+}
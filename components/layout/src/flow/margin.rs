@@ -0,0 +1,38 @@
+use css::values::{Pixel, PIXEL_ZERO};
+
+/// Adjoining vertical margins, they collapse into the largest positive margin plus the most negative one.
+/// https://www.w3.org/TR/CSS22/box.html#collapsing-margins
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CollapsedMargin {
+	positive: Pixel,
+	negative: Pixel,
+}
+
+impl Default for CollapsedMargin {
+	fn default() -> Self {
+		Self {
+			positive: PIXEL_ZERO,
+			negative: PIXEL_ZERO,
+		}
+	}
+}
+
+impl CollapsedMargin {
+	pub fn new(value: Pixel) -> Self {
+		CollapsedMargin {
+			positive: value.max(PIXEL_ZERO),
+			negative: value.min(PIXEL_ZERO),
+		}
+	}
+
+	pub fn adjoin(&self, other: &CollapsedMargin) -> Self {
+		CollapsedMargin {
+			positive: self.positive.max(other.positive),
+			negative: self.negative.min(other.negative),
+		}
+	}
+
+	pub fn solve(&self) -> Pixel {
+		self.positive + self.negative
+	}
+}
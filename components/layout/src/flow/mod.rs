@@ -1,7 +1,18 @@
+pub mod bidi;
 pub mod block;
 pub mod boxes;
+pub mod flex;
+pub mod float;
 pub mod formatting_context;
 pub mod fragment;
+pub mod grid;
 pub mod inline;
+pub mod line_break;
+pub mod line_height;
+pub mod margin;
+pub mod multicol;
+pub mod position;
+pub mod table;
+pub mod text_align;
 pub mod text_run;
 pub mod tree;
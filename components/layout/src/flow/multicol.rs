@@ -0,0 +1,274 @@
+use std::rc::Rc;
+
+use common::not_reached;
+use css::computed_values::ComputedValues;
+use css::properties::longhands::column_count::ColumnCount;
+use css::values::generics::length::{GenericLengthPercentageOrAuto, GenericLengthPercentageOrNormal};
+use css::values::specified::layout::LineStyle;
+use css::values::{CSSPixel, Pixel, PIXEL_ZERO};
+use dom::global_scope::GlobalScope;
+use euclid::{Point2D, Rect, Size2D};
+
+use super::boxes::{Box, BoxClass};
+use super::fragment::Fragment;
+use super::table::get_line_width;
+
+/// A piece of content which goes into a column as a whole, either an in-flow block-level child or a line box of an
+/// anonymous child. `top` and `height` are in the content box of the multi-column container before it is fragmented.
+struct ColumnPiece {
+	item: Rc<dyn Box>,
+	line: Option<usize>,
+	top: Pixel,
+	height: Pixel,
+}
+
+impl ColumnPiece {
+	fn bottom(&self) -> Pixel {
+		self.top + self.height
+	}
+}
+
+/// https://drafts.csswg.org/css-multicol/#the-multi-column-model
+pub struct MulticolContainer<'a> {
+	source: &'a dyn Box,
+	computed_values: &'a ComputedValues,
+}
+
+impl<'a> MulticolContainer<'a> {
+	pub fn new(source: &'a dyn Box) -> Self {
+		let dom_node = match source.class() {
+			BoxClass::Block => source.as_block_level_box().dom_node(),
+			_ => not_reached!(),
+		};
+		MulticolContainer {
+			source,
+			computed_values: GlobalScope::get_or_init_computed_values(dom_node.id()),
+		}
+	}
+
+	/// A block container whose `column-count` or `column-width` is not `auto` lays its content out in columns.
+	pub fn is_multicol(computed_values: &ComputedValues) -> bool {
+		!matches!(computed_values.get_column_count(), ColumnCount::Auto)
+			|| !matches!(computed_values.get_column_width(), GenericLengthPercentageOrAuto::Auto)
+	}
+
+	/// `normal` is 1em in multi-column containers, a percentage resolves against the content box width.
+	/// https://drafts.csswg.org/css-multicol/#column-gap
+	fn gap(&self, available_width: Option<Pixel>) -> Pixel {
+		match self.computed_values.get_column_gap() {
+			GenericLengthPercentageOrNormal::LengthPercentage(value) => match available_width {
+				Some(available_width) => value.to_used_value(available_width),
+				None => value.to_fixed_used_value().unwrap_or(PIXEL_ZERO),
+			},
+			GenericLengthPercentageOrNormal::Normal => Pixel::new(self.computed_values.get_font_size()),
+		}
+	}
+
+	fn column_width(&self) -> Option<Pixel> {
+		match self.computed_values.get_column_width() {
+			GenericLengthPercentageOrAuto::LengthPercentage(value) => Some(Pixel::new(value.0)),
+			GenericLengthPercentageOrAuto::Auto => None,
+		}
+	}
+
+	fn column_count(&self) -> Option<usize> {
+		match self.computed_values.get_column_count() {
+			ColumnCount::Integer(value) => Some(value.get().max(1) as usize),
+			ColumnCount::Auto => None,
+		}
+	}
+
+	/// The number of columns, their width and the gap between them.
+	/// https://drafts.csswg.org/css-multicol/#pseudo-algorithm
+	fn get_columns(&self, available_width: Pixel) -> (usize, Pixel, Pixel) {
+		let gap = self.gap(Some(available_width));
+		let count = match (self.column_count(), self.column_width()) {
+			(Some(count), None) => {
+				let width = ((available_width - gap * (count - 1) as f32) / count as f32).max(PIXEL_ZERO);
+				return (count, width, gap);
+			},
+			(count, Some(width)) => {
+				let fit = ((available_width + gap).get() / (width + gap).get().max(f32::EPSILON)).floor() as usize;
+				count.map_or(fit, |count| count.min(fit)).max(1)
+			},
+			(None, None) => 1,
+		};
+		let width = ((available_width + gap) / count as f32 - gap).max(PIXEL_ZERO);
+		(count, width, gap)
+	}
+
+	/// The content of every column is measured as if the container had a single column.
+	/// https://drafts.csswg.org/css-multicol/#cw
+	pub fn intrinsic_widths(&self) -> (Pixel, Pixel) {
+		let (mut column_minimum_width, mut column_width) = (PIXEL_ZERO, PIXEL_ZERO);
+		for child in self
+			.source
+			.children()
+			.into_iter()
+			.filter(|child| !BoxClass::is_absolutely_positioned(child.as_ref()))
+		{
+			let layout_info = child.layout_info();
+			column_minimum_width = column_minimum_width
+				.max(layout_info.intrinsic_size.preferred_minimum_width + layout_info.horizontal_sides());
+			column_width =
+				column_width.max(layout_info.intrinsic_size.preferred_width + layout_info.horizontal_sides());
+		}
+		if let Some(width) = self.column_width() {
+			column_width = column_width.max(width);
+		}
+		let count = self.column_count().unwrap_or(1);
+		let gaps = self.gap(None) * (count - 1) as f32;
+		let minimum_width = column_minimum_width * count as f32 + gaps;
+		(minimum_width, (column_width * count as f32 + gaps).max(minimum_width))
+	}
+
+	/// The content flows into a single column box first, its children get the width of a column.
+	pub fn layout_items(&self) {
+		let mut layout_info = self.source.layout_info_mut();
+		let (_, column_width, _) = self.get_columns(layout_info.width);
+		layout_info.width = column_width;
+	}
+
+	/// Moves the pieces of the content into the columns once they are laid out, then sets the column rules.
+	/// https://drafts.csswg.org/css-multicol/#cf
+	pub fn arrange_items(&self) {
+		let fragment_width = self.source.as_block_level_box().fragment().width();
+		let mut layout_info = self.source.layout_info_mut();
+		layout_info.width = fragment_width;
+		let specified_height = layout_info.definite_height();
+		drop(layout_info);
+
+		let (count, column_width, gap) = self.get_columns(fragment_width);
+		let pieces = self.collect_pieces();
+		let mut height = self.balance(&pieces, count);
+		if let Some(specified_height) = specified_height {
+			// content which doesn't fit goes into overflow columns in the inline direction
+			height = height.min(specified_height);
+		}
+		let starts = fill_columns(&pieces, height);
+		for (column, start) in starts.iter().enumerate() {
+			let end = starts.get(column + 1).cloned().unwrap_or(pieces.len());
+			let top = pieces[*start].top;
+			let dx = (column_width + gap) * column as f32;
+			for piece in pieces[*start..end].iter() {
+				match piece.line {
+					Some(index) => {
+						let lines = piece.item.lines();
+						let line = &lines[index];
+						line.set_band(line.x() + dx, line.available_width());
+						line.set_y(line.y() - top);
+					},
+					None => {
+						let mut fragment = piece.item.as_block_level_box().fragment_mut();
+						let (x, y) = (fragment.x(), fragment.y());
+						fragment.set_x(x + dx);
+						fragment.set_y(y - top);
+					},
+				}
+			}
+		}
+		if specified_height.is_none() {
+			BoxClass::set_content_height(self.source, height);
+			height = self.source.layout_info().height;
+		}
+		let column_rules = self.get_column_rules(starts.len(), column_width, gap, height);
+		self.source.as_block_level_box().fragment_mut().column_rules = column_rules;
+	}
+
+	/// In-flow block-level children can't be broken, the line boxes of anonymous children are.
+	fn collect_pieces(&self) -> Vec<ColumnPiece> {
+		let mut pieces = vec![];
+		for child in self.source.children() {
+			match child.class() {
+				BoxClass::Block if !BoxClass::is_out_of_flow(child.as_ref()) => {
+					let fragment = child.as_block_level_box().fragment();
+					let (top, height) = (fragment.y(), fragment.total_height());
+					drop(fragment);
+					pieces.push(ColumnPiece {
+						item: child,
+						line: None,
+						top,
+						height,
+					});
+				},
+				BoxClass::Anonymous => {
+					let y = child.as_anonymous_box().fragment().y();
+					let lines: Vec<(Pixel, Pixel)> =
+						child.lines().iter().map(|line| (line.y(), line.height())).collect();
+					for (index, (line_y, line_height)) in lines.into_iter().enumerate() {
+						pieces.push(ColumnPiece {
+							item: child.clone(),
+							line: Some(index),
+							top: y + line_y,
+							height: line_height,
+						});
+					}
+				},
+				_ => {},
+			}
+		}
+		pieces
+	}
+
+	/// The shortest column height which fits the pieces into `count` columns, starting from an even share of the
+	/// content and growing by the least amount which moves a piece back into the column before it.
+	/// https://drafts.csswg.org/css-multicol/#cf
+	fn balance(&self, pieces: &[ColumnPiece], count: usize) -> Pixel {
+		let (first, last) = match (pieces.first(), pieces.last()) {
+			(Some(first), Some(last)) => (first, last),
+			_ => return PIXEL_ZERO,
+		};
+		let tallest = pieces.iter().fold(PIXEL_ZERO, |height, piece| height.max(piece.height));
+		let mut height = ((last.bottom() - first.top) / count as f32).max(tallest);
+		loop {
+			let starts = fill_columns(pieces, height);
+			if starts.len() <= count {
+				return height;
+			}
+			let mut stretch: Option<Pixel> = None;
+			for (column, start) in starts.iter().enumerate().skip(1) {
+				let overflow = pieces[*start].bottom() - pieces[starts[column - 1]].top - height;
+				stretch = Some(stretch.map_or(overflow, |stretch| stretch.min(overflow)));
+			}
+			height += stretch.unwrap();
+		}
+	}
+
+	/// Rules are drawn in the middle of the gaps between columns which have content, they are as tall as the columns.
+	/// https://drafts.csswg.org/css-multicol/#column-gaps-and-rules
+	fn get_column_rules(
+		&self,
+		columns: usize,
+		column_width: Pixel,
+		gap: Pixel,
+		height: Pixel,
+	) -> Vec<Rect<Pixel, CSSPixel>> {
+		let rule_width = get_line_width(self.computed_values.get_column_rule_width());
+		if matches!(
+			self.computed_values.get_column_rule_style(),
+			LineStyle::None | LineStyle::Hidden
+		) || rule_width == PIXEL_ZERO
+		{
+			return vec![];
+		}
+		(1..columns)
+			.map(|column| {
+				let x = (column_width + gap) * column as f32 - gap / 2.0 - rule_width / 2.0;
+				Rect::new(Point2D::new(x, PIXEL_ZERO), Size2D::new(rule_width, height))
+			})
+			.collect()
+	}
+}
+
+/// The index of the first piece of every column, a column is closed once the next piece reaches past `height`. A
+/// piece which is taller than a column still starts a column of its own.
+fn fill_columns(pieces: &[ColumnPiece], height: Pixel) -> Vec<usize> {
+	let mut starts: Vec<usize> = vec![];
+	for (index, piece) in pieces.iter().enumerate() {
+		match starts.last() {
+			Some(start) if piece.bottom() - pieces[*start].top <= height => {},
+			_ => starts.push(index),
+		}
+	}
+	starts
+}
@@ -0,0 +1,227 @@
+use css::computed_values::ComputedValues;
+use css::values::computed::length::{LengthPercentageOrAuto, Size};
+use css::values::{CSSPixel, Pixel, PIXEL_ZERO};
+use euclid::{Rect, Vector2D};
+
+use super::boxes::BoxClass;
+use super::fragment::{IntrinsicSize, Sides};
+
+/// The used values of `top`, `right`, `bottom` and `left`, auto is `None`.
+/// https://drafts.csswg.org/css-position/#insets
+#[derive(Debug)]
+pub struct Insets {
+	pub top: Option<Pixel>,
+	pub right: Option<Pixel>,
+	pub bottom: Option<Pixel>,
+	pub left: Option<Pixel>,
+}
+
+impl Insets {
+	/// Horizontal insets are resolved against `width` and vertical ones against `height`.
+	pub fn new(computed_values: &ComputedValues, width: Pixel, height: Pixel) -> Self {
+		Insets {
+			top: to_used_value(computed_values.get_top(), height),
+			right: to_used_value(computed_values.get_right(), width),
+			bottom: to_used_value(computed_values.get_bottom(), height),
+			left: to_used_value(computed_values.get_left(), width),
+		}
+	}
+
+	/// `left` wins over `right` and `top` wins over `bottom` when both are set.
+	/// https://drafts.csswg.org/css-position/#relpos-insets
+	pub fn relative_offset(&self) -> Vector2D<Pixel, CSSPixel> {
+		let x = match (self.left, self.right) {
+			(Some(left), _) => left,
+			(None, Some(right)) => PIXEL_ZERO - right,
+			(None, None) => PIXEL_ZERO,
+		};
+		let y = match (self.top, self.bottom) {
+			(Some(top), _) => top,
+			(None, Some(bottom)) => PIXEL_ZERO - bottom,
+			(None, None) => PIXEL_ZERO,
+		};
+		Vector2D::new(x, y)
+	}
+
+	/// `rect` is the border box of a sticky positioned box, `containing_rect` is the content box of its containing
+	/// block and `scrollport` is the visible part of the document, all in the same coordinate space.
+	/// https://drafts.csswg.org/css-position/#stickypos-insets
+	pub fn sticky_offset(
+		&self,
+		rect: &Rect<Pixel, CSSPixel>,
+		containing_rect: &Rect<Pixel, CSSPixel>,
+		scrollport: &Rect<Pixel, CSSPixel>,
+	) -> Vector2D<Pixel, CSSPixel> {
+		let x = sticky_shift(
+			(rect.min_x(), rect.max_x()),
+			(containing_rect.min_x(), containing_rect.max_x()),
+			(scrollport.min_x(), scrollport.max_x()),
+			(self.left, self.right),
+		);
+		let y = sticky_shift(
+			(rect.min_y(), rect.max_y()),
+			(containing_rect.min_y(), containing_rect.max_y()),
+			(scrollport.min_y(), scrollport.max_y()),
+			(self.top, self.bottom),
+		);
+		Vector2D::new(x, y)
+	}
+}
+
+/// One axis of an absolutely positioned box, the positions are inside the padding box of its containing block.
+/// https://www.w3.org/TR/CSS22/visudet.html#abs-non-replaced-width
+/// https://www.w3.org/TR/CSS22/visudet.html#abs-non-replaced-height
+pub struct AbsoluteAxis {
+	start: Option<Pixel>,
+	end: Option<Pixel>,
+	size: Option<Pixel>,
+	margin_start: Option<Pixel>,
+	margin_end: Option<Pixel>,
+	padding: Pixel,
+	available_size: Pixel,
+	static_position: Pixel,
+}
+
+impl AbsoluteAxis {
+	pub fn horizontal(
+		computed_values: &ComputedValues,
+		insets: &Insets,
+		padding: &Sides,
+		intrinsic_size: &IntrinsicSize,
+		containing_width: Pixel,
+		static_position: Pixel,
+	) -> Self {
+		let size = match computed_values.get_width() {
+			Size::Auto => None,
+			Size::LengthPercentage(length_percentage) => Some(BoxClass::get_content_size(
+				computed_values,
+				length_percentage.to_used_value(containing_width),
+				padding.left + padding.right,
+			)),
+			Size::ExtremumLength(extremum) => Some(intrinsic_size.resolve_extremum(extremum, containing_width)),
+		};
+		AbsoluteAxis {
+			start: insets.left,
+			end: insets.right,
+			size,
+			margin_start: to_used_value(computed_values.get_margin_left(), containing_width),
+			margin_end: to_used_value(computed_values.get_margin_right(), containing_width),
+			padding: padding.left + padding.right,
+			available_size: containing_width,
+			static_position,
+		}
+	}
+
+	/// Vertical margins and paddings are resolved against the width of the containing block as well.
+	pub fn vertical(
+		computed_values: &ComputedValues,
+		insets: &Insets,
+		padding: &Sides,
+		containing_width: Pixel,
+		containing_height: Pixel,
+		static_position: Pixel,
+	) -> Self {
+		let size = match computed_values.get_height() {
+			Size::LengthPercentage(length_percentage) => Some(BoxClass::get_content_size(
+				computed_values,
+				length_percentage.to_used_value(containing_height),
+				padding.top + padding.bottom,
+			)),
+			// the intrinsic heights of a block container are the height of its content, like `auto`
+			Size::Auto | Size::ExtremumLength(_) => None,
+		};
+		AbsoluteAxis {
+			start: insets.top,
+			end: insets.bottom,
+			size,
+			margin_start: to_used_value(computed_values.get_margin_top(), containing_width),
+			margin_end: to_used_value(computed_values.get_margin_bottom(), containing_width),
+			padding: padding.top + padding.bottom,
+			available_size: containing_height,
+			static_position,
+		}
+	}
+
+	/// Replaces the size from the style, when it is limited by the min and max sizes.
+	pub fn set_size(&mut self, size: Pixel) {
+		self.size = Some(size);
+	}
+
+	/// Returns the position of the margin box, the content size and both margins. `content_size` gives the size for
+	/// an auto size from the space which is left for the content box, it is shrink-to-fit for widths and the height
+	/// of the content for heights.
+	pub fn solve<F>(&self, content_size: F) -> (Pixel, Pixel, Pixel, Pixel)
+	where
+		F: FnOnce(Pixel) -> Pixel,
+	{
+		let mut margin_start = self.margin_start.unwrap_or(PIXEL_ZERO);
+		let mut margin_end = self.margin_end.unwrap_or(PIXEL_ZERO);
+		let sides = margin_start + self.padding + margin_end;
+		match (self.start, self.size, self.end) {
+			(None, None, None) => {
+				let size = content_size(self.available_size - self.static_position - sides);
+				(self.static_position, size, margin_start, margin_end)
+			},
+			(None, Some(size), None) => (self.static_position, size, margin_start, margin_end),
+			(None, None, Some(end)) => {
+				let size = content_size(self.available_size - end - sides);
+				(self.available_size - end - size - sides, size, margin_start, margin_end)
+			},
+			(None, Some(size), Some(end)) => (self.available_size - end - size - sides, size, margin_start, margin_end),
+			(Some(start), None, None) => {
+				let size = content_size(self.available_size - start - sides);
+				(start, size, margin_start, margin_end)
+			},
+			(Some(start), None, Some(end)) => {
+				let size = (self.available_size - start - end - sides).max(PIXEL_ZERO);
+				(start, size, margin_start, margin_end)
+			},
+			(Some(start), Some(size), None) => (start, size, margin_start, margin_end),
+			(Some(start), Some(size), Some(end)) => {
+				// auto margins share the space which is left, otherwise `end` is ignored
+				let free_space = self.available_size - start - end - size - self.padding;
+				match (self.margin_start, self.margin_end) {
+					(None, None) if free_space >= PIXEL_ZERO => {
+						margin_start = free_space / 2.0;
+						margin_end = free_space / 2.0;
+					},
+					(None, None) => margin_end = free_space,
+					(None, Some(_)) => margin_start = free_space - margin_end,
+					(Some(_), None) => margin_end = free_space - margin_start,
+					(Some(_), Some(_)) => {},
+				}
+				(start, size, margin_start, margin_end)
+			},
+		}
+	}
+}
+
+fn to_used_value(value: &LengthPercentageOrAuto, base_value: Pixel) -> Option<Pixel> {
+	match value {
+		LengthPercentageOrAuto::Auto => None,
+		_ => Some(value.to_used_value(base_value, PIXEL_ZERO)),
+	}
+}
+
+/// How far a sticky box moves along one axis to stay `insets` inside the scrollport, without leaving its containing
+/// block. The start inset wins when both apply.
+fn sticky_shift(
+	(start, end): (Pixel, Pixel),
+	(containing_start, containing_end): (Pixel, Pixel),
+	(scrollport_start, scrollport_end): (Pixel, Pixel),
+	(inset_start, inset_end): (Option<Pixel>, Option<Pixel>),
+) -> Pixel {
+	if let Some(inset) = inset_start {
+		let limit = scrollport_start + inset;
+		if start < limit {
+			return (limit - start).min(containing_end - end).max(PIXEL_ZERO);
+		}
+	}
+	if let Some(inset) = inset_end {
+		let limit = scrollport_end - inset;
+		if end > limit {
+			return (limit - end).max(containing_start - start).min(PIXEL_ZERO);
+		}
+	}
+	PIXEL_ZERO
+}
@@ -0,0 +1,879 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use common::not_reached;
+use css::computed_values::ComputedValues;
+use css::properties::longhands::border_collapse::BorderCollapse;
+use css::properties::longhands::caption_side::CaptionSide;
+use css::properties::longhands::display::{Display, DisplayBasic, DisplayInside, DisplayInternal, DisplayLegacy};
+use css::properties::longhands::table_layout::TableLayout;
+use css::properties::longhands::vertical_align::VerticalAlignKeyword;
+use css::values::computed::length::Size;
+use css::values::computed::line::LineWidth;
+use css::values::computed::text::VerticalAlign;
+use css::values::specified::layout::LineStyle;
+use css::values::{CSSFloat, Pixel, PIXEL_ZERO};
+use dom::global_scope::{GlobalScope, NodeRef};
+use dom::htmltablecellelement::HTMLTableCellElement;
+use dom::htmltablecolelement::HTMLTableColElement;
+use dom::inheritance::Castable;
+use dom::nodetype::{ElementTypeId, HTMLElementTypeId, NodeTypeId};
+
+use super::boxes::{Box, BoxClass};
+use super::fragment::{Fragment, Sides};
+
+/// The part which a box plays in a table, anonymous table boxes hold the display of the part they stand for.
+/// https://www.w3.org/TR/CSS22/tables.html#table-display
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TablePart {
+	Table,
+	Caption,
+	HeaderGroup,
+	RowGroup,
+	FooterGroup,
+	Row,
+	Cell,
+	ColumnGroup,
+	Column,
+}
+
+impl TablePart {
+	pub fn from_display(display: &Display) -> Option<TablePart> {
+		match display {
+			Display::Basic(DisplayBasic {
+				inside: Some(DisplayInside::Table),
+				..
+			})
+			| Display::Legacy(DisplayLegacy::InlineTable) => Some(TablePart::Table),
+			Display::Internal(DisplayInternal::TableCaption) => Some(TablePart::Caption),
+			Display::Internal(DisplayInternal::TableHeaderGroup) => Some(TablePart::HeaderGroup),
+			Display::Internal(DisplayInternal::TableRowGroup) => Some(TablePart::RowGroup),
+			Display::Internal(DisplayInternal::TableFooterGroup) => Some(TablePart::FooterGroup),
+			Display::Internal(DisplayInternal::TableRow) => Some(TablePart::Row),
+			Display::Internal(DisplayInternal::TableCell) => Some(TablePart::Cell),
+			Display::Internal(DisplayInternal::TableColumnGroup) => Some(TablePart::ColumnGroup),
+			Display::Internal(DisplayInternal::TableColumn) => Some(TablePart::Column),
+			_ => None,
+		}
+	}
+
+	pub fn of(source: &dyn Box) -> Option<TablePart> {
+		let dom_node = match source.class() {
+			BoxClass::Block => source.as_block_level_box().dom_node(),
+			BoxClass::Inline => source.as_inline_level_box().dom_node(),
+			_ => return None,
+		};
+		TablePart::from_display(GlobalScope::get_or_init_computed_values(dom_node.id()).get_display())
+	}
+
+	/// A table with collapsed borders has no padding, rows, columns and their groups never have one.
+	/// https://www.w3.org/TR/CSS22/box.html#padding-properties
+	pub fn has_padding(computed_values: &ComputedValues) -> bool {
+		match TablePart::from_display(computed_values.get_display()) {
+			Some(TablePart::Table) => matches!(computed_values.get_border_collapse(), BorderCollapse::Separate),
+			Some(part) => !part.is_track(),
+			None => true,
+		}
+	}
+
+	pub fn is_row_group(&self) -> bool {
+		matches!(
+			self,
+			TablePart::HeaderGroup | TablePart::RowGroup | TablePart::FooterGroup
+		)
+	}
+
+	/// Rows, columns and their groups are laid out in the formatting context of their table.
+	pub fn is_track(&self) -> bool {
+		self.is_row_group() || matches!(self, TablePart::Row | TablePart::ColumnGroup | TablePart::Column)
+	}
+
+	/// https://www.w3.org/TR/CSS22/tables.html#anonymous-boxes
+	pub fn is_proper_parent_of(&self, child: TablePart) -> bool {
+		match self {
+			TablePart::Table => child != TablePart::Table && child != TablePart::Cell,
+			TablePart::Row => child == TablePart::Cell,
+			TablePart::ColumnGroup => child == TablePart::Column,
+			_ if self.is_row_group() => child == TablePart::Row,
+			_ => false,
+		}
+	}
+}
+
+/// A cell and the slots of the table grid which it covers.
+struct TableCell {
+	cell: Rc<dyn Box>,
+	row: usize,
+	column: usize,
+	row_span: usize,
+	column_span: usize,
+}
+
+/// The parts of a table in the order they are laid out, header groups come before other rows and footer groups after
+/// them.
+/// https://www.w3.org/TR/CSS22/tables.html#table-layout
+struct TableGrid {
+	captions: Vec<Rc<dyn Box>>,
+	rows: Vec<Rc<dyn Box>>,
+	row_groups: Vec<(Rc<dyn Box>, Range<usize>)>,
+	columns: Vec<(Rc<dyn Box>, Range<usize>)>,
+	column_groups: Vec<(Rc<dyn Box>, Range<usize>)>,
+	cells: Vec<TableCell>,
+	column_count: usize,
+}
+
+impl TableGrid {
+	fn new() -> Self {
+		TableGrid {
+			captions: vec![],
+			rows: vec![],
+			row_groups: vec![],
+			columns: vec![],
+			column_groups: vec![],
+			cells: vec![],
+			column_count: 0,
+		}
+	}
+
+	/// Places the cells of `rows` in the first slots which aren't covered by cells from the rows above, a row span
+	/// doesn't reach past the last of `rows`.
+	/// https://html.spec.whatwg.org/multipage/tables.html#forming-a-table
+	fn add_rows(&mut self, rows: Vec<Rc<dyn Box>>) {
+		let first_row = self.rows.len();
+		let row_count = rows.len();
+		// how many more rows each column is covered for
+		let mut covered_rows: Vec<usize> = vec![];
+		for (index, row) in rows.into_iter().enumerate() {
+			let mut column = 0;
+			for cell in row
+				.children()
+				.into_iter()
+				.filter(|child| TablePart::of(child.as_ref()) == Some(TablePart::Cell))
+			{
+				while covered_rows.get(column).map_or(false, |rows| *rows > 0) {
+					column += 1;
+				}
+				let (column_span, row_span) = get_spans(cell.as_block_level_box().dom_node());
+				let row_span = match row_span {
+					0 => row_count - index,
+					row_span => row_span.min(row_count - index),
+				};
+				if covered_rows.len() < column + column_span {
+					covered_rows.resize(column + column_span, 0);
+				}
+				for rows in covered_rows[column..column + column_span].iter_mut() {
+					*rows = row_span;
+				}
+				self.cells.push(TableCell {
+					cell,
+					row: first_row + index,
+					column,
+					row_span,
+					column_span,
+				});
+				column += column_span;
+			}
+			for rows in covered_rows.iter_mut() {
+				*rows = rows.saturating_sub(1);
+			}
+			self.rows.push(row);
+		}
+		self.column_count = self.column_count.max(covered_rows.len());
+	}
+
+	/// The index of the cell covering each slot, row by row.
+	fn get_slots(&self) -> Vec<Vec<Option<usize>>> {
+		let mut slots = vec![vec![None; self.column_count]; self.rows.len()];
+		for (index, cell) in self.cells.iter().enumerate() {
+			for row in slots[cell.row..cell.row + cell.row_span].iter_mut() {
+				for slot in row[cell.column..cell.column + cell.column_span].iter_mut() {
+					*slot = Some(index);
+				}
+			}
+		}
+		slots
+	}
+}
+
+/// The space around columns and rows, `columns[i]` is before the column `i` and the last one is after the last
+/// column, rows work the same way. It comes from `border-spacing` in the separated borders model and from the
+/// widths of the collapsed borders in the collapsing borders model.
+struct TableGaps {
+	columns: Vec<Pixel>,
+	rows: Vec<Pixel>,
+}
+
+/// Lays out a table and its parts, the table box wraps its captions and the table grid.
+/// - `layout_items`, once the table knows its own width, sizes the columns and gives the captions, rows, cells and
+///   columns their widths.
+/// - `arrange_items`, after the parts are laid out, sizes the rows and places the captions and the parts of the grid.
+///
+/// https://www.w3.org/TR/CSS22/tables.html
+pub struct TableContainer<'a> {
+	source: &'a dyn Box,
+	computed_values: &'a ComputedValues,
+}
+
+impl<'a> TableContainer<'a> {
+	pub fn new(source: &'a dyn Box) -> Self {
+		let dom_node = match source.class() {
+			BoxClass::Block => source.as_block_level_box().dom_node(),
+			BoxClass::Inline => source.as_inline_level_box().dom_node(),
+			_ => not_reached!(),
+		};
+		TableContainer {
+			source,
+			computed_values: GlobalScope::get_or_init_computed_values(dom_node.id()),
+		}
+	}
+
+	fn is_collapsed(&self) -> bool {
+		matches!(self.computed_values.get_border_collapse(), BorderCollapse::Collapse)
+	}
+
+	/// https://www.w3.org/TR/CSS22/tables.html#width-layout
+	fn is_fixed(&self) -> bool {
+		matches!(self.computed_values.get_table_layout(), TableLayout::Fixed)
+			&& !matches!(self.computed_values.get_width(), Size::Auto)
+	}
+
+	/// The widths of the table box which fit its columns and captions.
+	/// https://www.w3.org/TR/CSS22/tables.html#auto-table-layout
+	pub fn intrinsic_widths(&self) -> (Pixel, Pixel) {
+		let grid = self.collect_grid();
+		let gaps = self.get_gaps(&grid);
+		let gaps_width = sum(&gaps.columns);
+		let (mut minimum_width, mut width) = if self.is_fixed() {
+			let width = self
+				.get_fixed_columns(&grid)
+				.iter()
+				.fold(gaps_width, |width, column| width + column.unwrap_or(PIXEL_ZERO));
+			(width, width)
+		} else {
+			self.get_column_measures(&grid, &gaps).iter().fold(
+				(gaps_width, gaps_width),
+				|(minimum_width, width), (column_minimum, column_maximum)| {
+					(minimum_width + *column_minimum, width + *column_maximum)
+				},
+			)
+		};
+		for caption in grid.captions.iter() {
+			let layout_info = caption.layout_info();
+			minimum_width =
+				minimum_width.max(layout_info.intrinsic_size.preferred_minimum_width + layout_info.horizontal_sides());
+		}
+		width = width.max(minimum_width);
+		(minimum_width, width)
+	}
+
+	/// Sizes the columns, then gives the captions, rows, cells and columns their widths, it runs once the table
+	/// knows its own width.
+	pub fn layout_items(&self) {
+		let grid = self.collect_grid();
+		let gaps = self.get_gaps(&grid);
+		let width = self.source.layout_info().width;
+		let column_widths = self.get_column_widths(&grid, &gaps, width);
+		let column_positions = get_positions(&column_widths, &gaps.columns);
+		let tracks_width = get_span_size(&column_widths, &column_positions, 0..grid.column_count);
+
+		// a caption is laid out like a block-level box inside the table box
+		for caption in grid.captions.iter() {
+			let computed_values = BoxClass::item_computed_values(caption).unwrap();
+			let margin = BoxClass::get_margin_for_non_replaced_elements(computed_values, width);
+			let padding = BoxClass::get_padding_for_non_replaced_elements(computed_values, width);
+			let caption_width = match computed_values.get_width() {
+				Size::LengthPercentage(length_percentage) => length_percentage.to_used_value(width),
+				_ => width - margin.left - padding.left - padding.right - margin.right,
+			};
+			set_width(caption, caption_width.max(PIXEL_ZERO), margin, padding);
+		}
+		for row in grid.rows.iter() {
+			set_width(row, tracks_width, Sides::default(), Sides::default());
+		}
+		for (row_group, _) in grid.row_groups.iter() {
+			set_width(row_group, tracks_width, Sides::default(), Sides::default());
+		}
+		for (column, range) in grid.columns.iter().chain(grid.column_groups.iter()) {
+			let column_width = get_span_size(&column_widths, &column_positions, range.clone());
+			set_width(column, column_width, Sides::default(), Sides::default());
+		}
+		for cell in grid.cells.iter() {
+			let computed_values = BoxClass::item_computed_values(&cell.cell).unwrap();
+			let padding = BoxClass::get_padding_for_non_replaced_elements(computed_values, width);
+			let cell_width = get_span_size(
+				&column_widths,
+				&column_positions,
+				cell.column..cell.column + cell.column_span,
+			);
+			set_width(
+				&cell.cell,
+				(cell_width - padding.left - padding.right).max(PIXEL_ZERO),
+				Sides::default(),
+				padding,
+			);
+		}
+	}
+
+	/// Sizes the rows, places the captions above or below the table grid and the parts of the grid inside it, it
+	/// runs once the parts are laid out. The table gets the height of its captions and rows, a specified height
+	/// is shared by the rows.
+	/// https://www.w3.org/TR/CSS22/tables.html#height-layout
+	pub fn arrange_items(&self) {
+		let grid = self.collect_grid();
+		let gaps = self.get_gaps(&grid);
+		let (width, specified_height) = {
+			let layout_info = self.source.layout_info();
+			(layout_info.width, layout_info.height)
+		};
+		let column_widths = self.get_column_widths(&grid, &gaps, width);
+		let column_positions = get_positions(&column_widths, &gaps.columns);
+
+		let mut captions_height = PIXEL_ZERO;
+		for caption in grid.captions.iter() {
+			captions_height += caption.layout_info().total_height();
+		}
+		let mut row_heights = self.get_row_heights(&grid, &gaps);
+		let rows_height = sum(&row_heights) + sum(&gaps.rows);
+		let free_space = specified_height - captions_height - rows_height;
+		if free_space > PIXEL_ZERO && !row_heights.is_empty() {
+			let extra_height = free_space / row_heights.len() as CSSFloat;
+			for height in row_heights.iter_mut() {
+				*height += extra_height;
+			}
+		}
+		let row_positions = get_positions(&row_heights, &gaps.rows);
+
+		// https://www.w3.org/TR/CSS22/tables.html#caption-position
+		let mut y = PIXEL_ZERO;
+		for caption in grid.captions.iter().filter(|caption| !is_bottom_caption(caption)) {
+			y += place_caption(caption, y);
+		}
+		let grid_y = y;
+		y += sum(&row_heights) + sum(&gaps.rows);
+		for caption in grid.captions.iter().filter(|caption| is_bottom_caption(caption)) {
+			y += place_caption(caption, y);
+		}
+
+		let tracks_x = column_positions.first().cloned().unwrap_or(PIXEL_ZERO);
+		let tracks_width = get_span_size(&column_widths, &column_positions, 0..grid.column_count);
+		let tracks_y = grid_y + row_positions.first().cloned().unwrap_or(PIXEL_ZERO);
+		let tracks_height = get_span_size(&row_heights, &row_positions, 0..grid.rows.len());
+		for (row_group, range) in grid.row_groups.iter() {
+			BoxClass::place_item(
+				row_group,
+				tracks_x,
+				grid_y + row_positions.get(range.start).cloned().unwrap_or(PIXEL_ZERO),
+				tracks_width,
+				get_span_size(&row_heights, &row_positions, range.clone()),
+				Sides::default(),
+			);
+		}
+		for (index, row) in grid.rows.iter().enumerate() {
+			let (parent_x, parent_y) = self.get_parent_position(row);
+			BoxClass::place_item(
+				row,
+				tracks_x - parent_x,
+				grid_y + row_positions[index] - parent_y,
+				tracks_width,
+				row_heights[index],
+				Sides::default(),
+			);
+		}
+		for (column_group, range) in grid.column_groups.iter() {
+			BoxClass::place_item(
+				column_group,
+				column_positions[range.start],
+				tracks_y,
+				get_span_size(&column_widths, &column_positions, range.clone()),
+				tracks_height,
+				Sides::default(),
+			);
+		}
+		for (column, range) in grid.columns.iter() {
+			let (parent_x, parent_y) = self.get_parent_position(column);
+			BoxClass::place_item(
+				column,
+				column_positions[range.start] - parent_x,
+				tracks_y - parent_y,
+				get_span_size(&column_widths, &column_positions, range.clone()),
+				tracks_height,
+				Sides::default(),
+			);
+		}
+		for cell in grid.cells.iter() {
+			let padding = cell.cell.layout_info().padding;
+			let width = get_span_size(
+				&column_widths,
+				&column_positions,
+				cell.column..cell.column + cell.column_span,
+			);
+			let height = get_span_size(&row_heights, &row_positions, cell.row..cell.row + cell.row_span);
+			let content_height = get_cell_content_height(&cell.cell);
+			BoxClass::place_item(
+				&cell.cell,
+				column_positions[cell.column] - tracks_x,
+				PIXEL_ZERO,
+				(width - padding.left - padding.right).max(PIXEL_ZERO),
+				(height - padding.top - padding.bottom).max(PIXEL_ZERO),
+				Sides::default(),
+			);
+			align_cell_content(&cell.cell, height - padding.top - padding.bottom - content_height);
+		}
+
+		BoxClass::set_content_height(self.source, y.max(specified_height));
+	}
+
+	/// The position of the content box of the parent of a part relative to the content box of the table.
+	fn get_parent_position(&self, part: &Rc<dyn Box>) -> (Pixel, Pixel) {
+		match part.parent() {
+			Some(parent) if parent.id() != self.source.id() => {
+				let fragment = parent.as_block_level_box().fragment();
+				(fragment.x(), fragment.y())
+			},
+			_ => (PIXEL_ZERO, PIXEL_ZERO),
+		}
+	}
+
+	fn collect_grid(&self) -> TableGrid {
+		let mut grid = TableGrid::new();
+		let mut header_groups = vec![];
+		let mut row_groups = vec![];
+		let mut footer_groups = vec![];
+		let mut column = 0;
+		for child in self.source.children() {
+			match TablePart::of(child.as_ref()) {
+				Some(TablePart::Caption) => grid.captions.push(child),
+				Some(TablePart::Column) => {
+					let span = get_column_span(child.as_block_level_box().dom_node());
+					grid.columns.push((child, column..column + span));
+					column += span;
+				},
+				Some(TablePart::ColumnGroup) => {
+					let start = column;
+					let columns: Vec<Rc<dyn Box>> = child
+						.children()
+						.into_iter()
+						.filter(|column| TablePart::of(column.as_ref()) == Some(TablePart::Column))
+						.collect();
+					if columns.is_empty() {
+						column += get_column_span(child.as_block_level_box().dom_node());
+					}
+					for column_box in columns {
+						let span = get_column_span(column_box.as_block_level_box().dom_node());
+						grid.columns.push((column_box, column..column + span));
+						column += span;
+					}
+					grid.column_groups.push((child, start..column));
+				},
+				Some(TablePart::HeaderGroup) => header_groups.push(child),
+				Some(TablePart::FooterGroup) => footer_groups.push(child),
+				Some(TablePart::RowGroup) | Some(TablePart::Row) => row_groups.push(child),
+				_ => {},
+			}
+		}
+
+		// consecutive rows which are not inside a row group are placed like one
+		let mut rows = vec![];
+		for child in header_groups.into_iter().chain(row_groups).chain(footer_groups) {
+			if TablePart::of(child.as_ref()) == Some(TablePart::Row) {
+				rows.push(child);
+				continue;
+			}
+			grid.add_rows(std::mem::take(&mut rows));
+			let start = grid.rows.len();
+			grid.add_rows(
+				child
+					.children()
+					.into_iter()
+					.filter(|row| TablePart::of(row.as_ref()) == Some(TablePart::Row))
+					.collect(),
+			);
+			grid.row_groups.push((child, start..grid.rows.len()));
+		}
+		grid.add_rows(rows);
+		grid.column_count = grid.column_count.max(column);
+		grid
+	}
+
+	fn get_gaps(&self, grid: &TableGrid) -> TableGaps {
+		if self.is_collapsed() {
+			return self.get_collapsed_gaps(grid);
+		}
+		// https://www.w3.org/TR/CSS22/tables.html#separated-borders
+		let spacing = self.computed_values.get_border_spacing();
+		let (horizontal_spacing, vertical_spacing) = (Pixel::new(spacing.0 .0), Pixel::new(spacing.1 .0));
+		TableGaps {
+			columns: vec![
+				if grid.column_count > 0 {
+					horizontal_spacing
+				} else {
+					PIXEL_ZERO
+				};
+				grid.column_count + 1
+			],
+			rows: vec![
+				if grid.rows.is_empty() {
+					PIXEL_ZERO
+				} else {
+					vertical_spacing
+				};
+				grid.rows.len() + 1
+			],
+		}
+	}
+
+	/// A grid line is as wide as the widest border which is collapsed along it, borders of the cells and of the table
+	/// are taken into account.
+	/// https://www.w3.org/TR/CSS22/tables.html#collapsing-borders
+	fn get_collapsed_gaps(&self, grid: &TableGrid) -> TableGaps {
+		let slots = grid.get_slots();
+		let cell_values = |index: usize| BoxClass::item_computed_values(&grid.cells[index].cell).unwrap();
+		let table = self.computed_values;
+
+		let mut columns = vec![PIXEL_ZERO; grid.column_count + 1];
+		for row in slots.iter() {
+			for (line, gap) in columns.iter_mut().enumerate() {
+				let before = if line > 0 { row[line - 1] } else { None };
+				let after = row.get(line).cloned().flatten();
+				// the inside of a cell spanning several columns
+				if before.is_some() && before == after {
+					continue;
+				}
+				let mut borders = vec![];
+				if let Some(index) = before {
+					let values: &ComputedValues = cell_values(index);
+					borders.push((values.get_border_right_style(), values.get_border_right_width()));
+				}
+				if let Some(index) = after {
+					let values: &ComputedValues = cell_values(index);
+					borders.push((values.get_border_left_style(), values.get_border_left_width()));
+				}
+				if line == 0 {
+					borders.push((table.get_border_left_style(), table.get_border_left_width()));
+				}
+				if line == grid.column_count {
+					borders.push((table.get_border_right_style(), table.get_border_right_width()));
+				}
+				*gap = gap.max(resolve_collapsed_border(&borders));
+			}
+		}
+
+		let mut rows = vec![PIXEL_ZERO; grid.rows.len() + 1];
+		for column in 0..grid.column_count {
+			for (line, gap) in rows.iter_mut().enumerate() {
+				let before = if line > 0 { slots[line - 1][column] } else { None };
+				let after = slots.get(line).and_then(|row| row[column]);
+				if before.is_some() && before == after {
+					continue;
+				}
+				let mut borders = vec![];
+				if let Some(index) = before {
+					let values: &ComputedValues = cell_values(index);
+					borders.push((values.get_border_bottom_style(), values.get_border_bottom_width()));
+				}
+				if let Some(index) = after {
+					let values: &ComputedValues = cell_values(index);
+					borders.push((values.get_border_top_style(), values.get_border_top_width()));
+				}
+				if line == 0 {
+					borders.push((table.get_border_top_style(), table.get_border_top_width()));
+				}
+				if line == grid.rows.len() {
+					borders.push((table.get_border_bottom_style(), table.get_border_bottom_width()));
+				}
+				*gap = gap.max(resolve_collapsed_border(&borders));
+			}
+		}
+		TableGaps { columns, rows }
+	}
+
+	/// The minimum and maximum width of each column. Cells spanning a single column are measured first, a cell
+	/// spanning several columns widens them evenly when they are too narrow for it.
+	/// https://www.w3.org/TR/CSS22/tables.html#auto-table-layout
+	fn get_column_measures(&self, grid: &TableGrid, gaps: &TableGaps) -> Vec<(Pixel, Pixel)> {
+		let mut measures = vec![(PIXEL_ZERO, PIXEL_ZERO); grid.column_count];
+		for (column, range) in grid.columns.iter() {
+			if let Some(width) = get_fixed_width(column) {
+				for measure in measures[range.clone()].iter_mut() {
+					*measure = (measure.0.max(width), measure.1.max(width));
+				}
+			}
+		}
+		let mut cells: Vec<&TableCell> = grid.cells.iter().collect();
+		cells.sort_by_key(|cell| cell.column_span);
+		for cell in cells {
+			let range = cell.column..cell.column + cell.column_span;
+			let inner_gaps = sum(&gaps.columns[cell.column + 1..range.end]);
+			let (cell_minimum, cell_maximum) = get_cell_measure(&cell.cell);
+			let spanned_minimum = measures[range.clone()]
+				.iter()
+				.fold(inner_gaps, |width, measure| width + measure.0);
+			if cell_minimum > spanned_minimum {
+				let extra_width = (cell_minimum - spanned_minimum) / cell.column_span as CSSFloat;
+				for measure in measures[range.clone()].iter_mut() {
+					measure.0 += extra_width;
+				}
+			}
+			let spanned_maximum = measures[range.clone()]
+				.iter()
+				.fold(inner_gaps, |width, measure| width + measure.1);
+			if cell_maximum > spanned_maximum {
+				let extra_width = (cell_maximum - spanned_maximum) / cell.column_span as CSSFloat;
+				for measure in measures[range].iter_mut() {
+					measure.1 += extra_width;
+				}
+			}
+		}
+		for measure in measures.iter_mut() {
+			measure.1 = measure.1.max(measure.0);
+		}
+		measures
+	}
+
+	/// The widths of the columns which are set by `col` elements, or else by the cells of the first row.
+	/// https://www.w3.org/TR/CSS22/tables.html#fixed-table-layout
+	fn get_fixed_columns(&self, grid: &TableGrid) -> Vec<Option<Pixel>> {
+		let mut widths = vec![None; grid.column_count];
+		for (column, range) in grid.columns.iter() {
+			if let Some(width) = get_fixed_width(column) {
+				for column_width in widths[range.clone()].iter_mut() {
+					*column_width = Some(width);
+				}
+			}
+		}
+		for cell in grid.cells.iter().filter(|cell| cell.row == 0) {
+			if let Some(width) = get_fixed_width(&cell.cell) {
+				let padding = cell.cell.layout_info().padding;
+				let width = (width + padding.left + padding.right) / cell.column_span as CSSFloat;
+				for column_width in widths[cell.column..cell.column + cell.column_span].iter_mut() {
+					if column_width.is_none() {
+						*column_width = Some(width);
+					}
+				}
+			}
+		}
+		widths
+	}
+
+	/// Shares the width of the table between its columns.
+	fn get_column_widths(&self, grid: &TableGrid, gaps: &TableGaps, width: Pixel) -> Vec<Pixel> {
+		let available_width = width - sum(&gaps.columns);
+		if grid.column_count == 0 {
+			return vec![];
+		}
+		let count = grid.column_count as CSSFloat;
+		if self.is_fixed() {
+			// columns without a width share the space which is left, or all of them do when there is none
+			let fixed_columns = self.get_fixed_columns(grid);
+			let fixed_width = fixed_columns
+				.iter()
+				.fold(PIXEL_ZERO, |width, column| width + column.unwrap_or(PIXEL_ZERO));
+			let auto_count = fixed_columns.iter().filter(|column| column.is_none()).count();
+			let free_space = (available_width - fixed_width).max(PIXEL_ZERO);
+			return fixed_columns
+				.iter()
+				.map(|column| match column {
+					Some(width) if auto_count == 0 => *width + free_space / count,
+					Some(width) => *width,
+					None => free_space / auto_count as CSSFloat,
+				})
+				.collect();
+		}
+
+		let measures = self.get_column_measures(grid, gaps);
+		let (minimum_width, maximum_width) = measures
+			.iter()
+			.fold((PIXEL_ZERO, PIXEL_ZERO), |(minimum_width, maximum_width), measure| {
+				(minimum_width + measure.0, maximum_width + measure.1)
+			});
+		measures
+			.iter()
+			.map(|(minimum, maximum)| {
+				if available_width >= maximum_width {
+					// the space beyond the maximum widths goes to the columns in proportion to them
+					let free_space = available_width - maximum_width;
+					if maximum_width > PIXEL_ZERO {
+						*maximum + free_space * (maximum.get() / maximum_width.get())
+					} else {
+						free_space / count
+					}
+				} else if available_width > minimum_width {
+					let ratio = (available_width - minimum_width).get() / (maximum_width - minimum_width).get();
+					*minimum + (*maximum - *minimum) * ratio
+				} else {
+					*minimum
+				}
+			})
+			.collect()
+	}
+
+	/// Rows are as tall as their cells, a cell spanning several rows makes the last of them taller when they are too
+	/// short for it.
+	fn get_row_heights(&self, grid: &TableGrid, gaps: &TableGaps) -> Vec<Pixel> {
+		let mut heights: Vec<Pixel> = grid.rows.iter().map(|row| row.layout_info().height).collect();
+		let mut cells: Vec<&TableCell> = grid.cells.iter().collect();
+		cells.sort_by_key(|cell| cell.row_span);
+		for cell in cells {
+			let padding = cell.cell.layout_info().padding;
+			let fixed_height = cell.cell.layout_info().height;
+			let height = fixed_height.max(get_cell_content_height(&cell.cell)) + padding.top + padding.bottom;
+			let range = cell.row..cell.row + cell.row_span;
+			let spanned_height = sum(&heights[range.clone()]) + sum(&gaps.rows[cell.row + 1..range.end]);
+			if height > spanned_height {
+				heights[range.end - 1] += height - spanned_height;
+			}
+		}
+		heights
+	}
+}
+
+fn sum(sizes: &[Pixel]) -> Pixel {
+	sizes.iter().fold(PIXEL_ZERO, |total, size| total + *size)
+}
+
+/// The start of each track from the gaps before it, `gaps` has one more item than `sizes`.
+fn get_positions(sizes: &[Pixel], gaps: &[Pixel]) -> Vec<Pixel> {
+	let mut position = PIXEL_ZERO;
+	let mut positions = Vec::with_capacity(sizes.len());
+	for (size, gap) in sizes.iter().zip(gaps.iter()) {
+		position += *gap;
+		positions.push(position);
+		position += *size;
+	}
+	positions
+}
+
+/// The size from the start of the first track in `range` to the end of the last one.
+fn get_span_size(sizes: &[Pixel], positions: &[Pixel], range: Range<usize>) -> Pixel {
+	if range.start >= range.end || range.end > sizes.len() {
+		return PIXEL_ZERO;
+	}
+	positions[range.end - 1] + sizes[range.end - 1] - positions[range.start]
+}
+
+fn set_width(part: &Rc<dyn Box>, width: Pixel, margin: Sides, padding: Sides) {
+	let mut layout_info = part.layout_info_mut();
+	layout_info.margin = margin;
+	layout_info.padding = padding;
+	layout_info.width = width;
+}
+
+fn get_fixed_width(part: &Rc<dyn Box>) -> Option<Pixel> {
+	BoxClass::item_computed_values(part).and_then(|computed_values| computed_values.get_width().to_fixed_used_value())
+}
+
+/// The minimum and maximum width of a cell including its padding, a cell with a fixed width doesn't grow past it and
+/// isn't narrower than its content.
+fn get_cell_measure(cell: &Rc<dyn Box>) -> (Pixel, Pixel) {
+	let is_fixed = get_fixed_width(cell).is_some();
+	let layout_info = cell.layout_info();
+	let sides = layout_info.padding.left + layout_info.padding.right;
+	let minimum = layout_info
+		.intrinsic_size
+		.preferred_minimum_width
+		.max(layout_info.intrinsic_size.min_content_width);
+	let maximum = if is_fixed {
+		minimum
+	} else {
+		layout_info.intrinsic_size.preferred_width.max(minimum)
+	};
+	(minimum + sides, maximum + sides)
+}
+
+fn get_cell_content_height(cell: &Rc<dyn Box>) -> Pixel {
+	BoxClass::get_block_height(cell.as_ref())
+}
+
+/// Moves the content of a cell down when it is aligned to the middle or the bottom of a taller cell.
+/// https://www.w3.org/TR/CSS22/tables.html#height-layout
+fn align_cell_content(cell: &Rc<dyn Box>, free_space: Pixel) {
+	let computed_values = BoxClass::item_computed_values(cell).unwrap();
+	let offset = match computed_values.get_vertical_align() {
+		VerticalAlign::Keyword(VerticalAlignKeyword::Middle) => free_space / 2.0,
+		VerticalAlign::Keyword(VerticalAlignKeyword::Bottom) => free_space,
+		_ => PIXEL_ZERO,
+	};
+	if offset <= PIXEL_ZERO {
+		return;
+	}
+	for child in cell.children() {
+		match child.class() {
+			BoxClass::Block if !BoxClass::is_out_of_flow(child.as_ref()) => {
+				let mut fragment = child.as_block_level_box().fragment_mut();
+				let y = fragment.y();
+				fragment.set_y(y + offset);
+			},
+			BoxClass::Anonymous => {
+				let mut fragment = child.as_anonymous_box().fragment_mut();
+				let y = fragment.y();
+				fragment.set_y(y + offset);
+			},
+			_ => {},
+		}
+	}
+}
+
+fn is_bottom_caption(caption: &Rc<dyn Box>) -> bool {
+	matches!(
+		BoxClass::item_computed_values(caption).unwrap().get_caption_side(),
+		CaptionSide::Bottom
+	)
+}
+
+/// Returns the height of the caption's margin box.
+fn place_caption(caption: &Rc<dyn Box>, y: Pixel) -> Pixel {
+	let layout_info = caption.layout_info();
+	let (width, height, margin, total_height) = (
+		layout_info.width,
+		layout_info.height,
+		layout_info.margin,
+		layout_info.total_height(),
+	);
+	drop(layout_info);
+	BoxClass::place_item(caption, PIXEL_ZERO, y, width, height, margin);
+	total_height
+}
+
+/// `colspan` and `rowspan` of a `td` or `th` element, other cells span a single slot.
+fn get_spans(dom_node: NodeRef) -> (usize, usize) {
+	match dom_node.node_type_id() {
+		NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLTableCellElement)) => {
+			let element = dom_node.downcast::<HTMLTableCellElement>();
+			(element.col_span() as usize, element.row_span() as usize)
+		},
+		_ => (1, 1),
+	}
+}
+
+fn get_column_span(dom_node: NodeRef) -> usize {
+	match dom_node.node_type_id() {
+		NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLTableColElement)) => {
+			dom_node.downcast::<HTMLTableColElement>().span() as usize
+		},
+		_ => 1,
+	}
+}
+
+/// https://drafts.csswg.org/css-backgrounds/#border-width
+pub fn get_line_width(width: &LineWidth) -> Pixel {
+	match width {
+		LineWidth::Thin => Pixel::new(1.0),
+		LineWidth::Medium => Pixel::new(3.0),
+		LineWidth::Thick => Pixel::new(5.0),
+		LineWidth::Length(length) => Pixel::new(length.0),
+	}
+}
+
+/// A hidden border wins over all others, otherwise the widest border wins, `none` has no width.
+/// https://www.w3.org/TR/CSS22/tables.html#border-conflict-resolution
+fn resolve_collapsed_border(borders: &[(&LineStyle, &LineWidth)]) -> Pixel {
+	if borders.iter().any(|(style, _)| matches!(style, LineStyle::Hidden)) {
+		return PIXEL_ZERO;
+	}
+	borders
+		.iter()
+		.filter(|(style, _)| !matches!(style, LineStyle::None))
+		.fold(PIXEL_ZERO, |width, (_, line_width)| {
+			width.max(get_line_width(line_width))
+		})
+}
@@ -0,0 +1,280 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use css::properties::longhands::direction::Direction;
+use css::properties::longhands::text_align::TextAlign;
+use css::properties::longhands::text_justify::TextJustify;
+use css::values::{Pixel, PIXEL_ZERO};
+
+use super::boxes::{Box, BoxClass};
+use super::formatting_context::FormattingContextType;
+use super::fragment::{Fragment, FragmentClass, Line, TextFragment};
+
+/// The characters which separate words, the spaces between words are stretched by `text-justify: inter-word`.
+/// https://drafts.csswg.org/css-text/#word-separator
+const WORD_SEPARATORS: &[char] = &[
+	'\u{0020}',
+	'\u{00A0}',
+	'\u{1361}',
+	'\u{10100}',
+	'\u{10101}',
+	'\u{1039F}',
+	'\u{1091F}',
+];
+
+/// The indentation of the line after `lines`. The first line of a block container is indented, an anonymous block box
+/// only has it when it is the first child of its element. `each-line` indents the lines after forced breaks too, and
+/// `hanging` indents all the other lines instead.
+/// https://drafts.csswg.org/css-text/#text-indent-property
+pub fn get_indent(establisher: &dyn Box, lines: &[Line]) -> Pixel {
+	let text_indent = BoxClass::get_computed_values(establisher).get_text_indent();
+	let is_indented = match lines.last() {
+		Some(line) => text_indent.each_line && line.ends_with_forced_break.get(),
+		None if establisher.class() == BoxClass::Anonymous => establisher
+			.parent()
+			.and_then(|parent| parent.get_first_child())
+			.map_or(true, |first_child| first_child.id() == establisher.id()),
+		None => true,
+	};
+	if is_indented != text_indent.hanging {
+		text_indent.indent.to_used_value(establisher.layout_info().width)
+	} else {
+		PIXEL_ZERO
+	}
+}
+
+/// Whether the lines of `source` start on the right.
+pub fn has_rtl_direction(source: &dyn Box) -> bool {
+	matches!(BoxClass::get_computed_values(source).get_direction(), Direction::Rtl)
+}
+
+/// Places the content of every line of the inline formatting context which `establisher` establishes inside the line,
+/// a justified line is stretched to fill it. The last line and the lines ended by forced breaks are aligned like
+/// `text-align-last: auto`, they are justified only with `justify-all`.
+/// https://drafts.csswg.org/css-text/#text-align-property
+pub fn align_lines(establisher: &dyn Box) {
+	if establisher.formatting_context_type() != FormattingContextType::InlineFormattingContext {
+		return;
+	}
+	let text_align = physical_text_align(establisher);
+	let text_justify = BoxClass::get_computed_values(establisher).get_text_justify().clone();
+	let is_rtl = has_rtl_direction(establisher);
+	let lines = establisher.lines();
+	for (index, line) in lines.iter().enumerate() {
+		let is_last = index + 1 == lines.len() || line.ends_with_forced_break.get();
+		align_line(line, &text_align, &text_justify, is_last, is_rtl);
+	}
+}
+
+/// `start` and `end` are resolved with `direction`, `match-parent` takes the value of the parent element resolved with
+/// the direction of the parent.
+fn physical_text_align(source: &dyn Box) -> TextAlign {
+	let is_rtl = has_rtl_direction(source);
+	match BoxClass::get_computed_values(source).get_text_align() {
+		TextAlign::Start if is_rtl => TextAlign::Right,
+		TextAlign::End if is_rtl => TextAlign::Left,
+		TextAlign::Start => TextAlign::Left,
+		TextAlign::End => TextAlign::Right,
+		TextAlign::MatchParent => {
+			// an anonymous box has the style of its parent element
+			let mut parent = source.parent();
+			if source.class() == BoxClass::Anonymous {
+				parent = parent.and_then(|parent| parent.parent());
+			}
+			match parent {
+				Some(parent) => physical_text_align(parent.as_ref()),
+				None if is_rtl => TextAlign::Right,
+				None => TextAlign::Left,
+			}
+		},
+		text_align => text_align.clone(),
+	}
+}
+
+/// Moves the fragments of `line` by the same distance, the content which overflows the line is aligned to the start.
+fn align_line(line: &Line, text_align: &TextAlign, text_justify: &TextJustify, is_last: bool, is_rtl: bool) {
+	let fragments = line.fragments();
+	if fragments.is_empty() {
+		return;
+	}
+	let start = if is_rtl { TextAlign::Right } else { TextAlign::Left };
+	let text_align = match text_align {
+		TextAlign::Justify if is_last => start,
+		TextAlign::Justify | TextAlign::JustifyAll => {
+			if justify(&fragments, line.available_width() - line.width(), text_justify) {
+				TextAlign::Left
+			} else {
+				start
+			}
+		},
+		text_align => text_align.clone(),
+	};
+
+	let free_space = line.available_width() - line.width();
+	let offset = if free_space < PIXEL_ZERO {
+		if is_rtl {
+			free_space
+		} else {
+			PIXEL_ZERO
+		}
+	} else {
+		match text_align {
+			TextAlign::Right => free_space,
+			TextAlign::Center => free_space / 2.0,
+			_ => PIXEL_ZERO,
+		}
+	};
+	// the fragments start from the left of the line until they are aligned
+	let left = fragments
+		.iter()
+		.map(|fragment| fragment.borrow().x())
+		.fold(Pixel::new(f32::MAX), |left, x| left.min(x));
+	for fragment in fragments.iter() {
+		let x = fragment.borrow().x();
+		fragment.borrow_mut().set_x(x + offset - left);
+	}
+}
+
+/// Distributes `free_space` equally over the justification opportunities of the line, which are the word separators or
+/// with `inter-character` the gaps after every character. The end of the line isn't an opportunity. Returns false
+/// when the line can't be justified.
+/// https://drafts.csswg.org/css-text/#justify-algos
+fn justify(fragments: &[Rc<RefCell<dyn Fragment>>], free_space: Pixel, text_justify: &TextJustify) -> bool {
+	if free_space <= PIXEL_ZERO || matches!(text_justify, TextJustify::None) {
+		return false;
+	}
+	let mut text_fragments = vec![];
+	collect_text_fragments(fragments, &mut text_fragments);
+	let last_index = text_fragments.len().saturating_sub(1);
+	let opportunities: Vec<Vec<usize>> = text_fragments
+		.iter()
+		.enumerate()
+		.map(|(index, fragment)| {
+			get_opportunities(fragment.borrow().as_text_fragment(), index == last_index, text_justify)
+		})
+		.collect();
+	let count: usize = opportunities.iter().map(|clusters| clusters.len()).sum();
+	if count == 0 {
+		return false;
+	}
+	expand_fragments(fragments, &mut opportunities.into_iter(), free_space / count as f32);
+	true
+}
+
+/// The text fragments in logical order, including the ones in inline boxes.
+fn collect_text_fragments(
+	fragments: &[Rc<RefCell<dyn Fragment>>],
+	text_fragments: &mut Vec<Rc<RefCell<dyn Fragment>>>,
+) {
+	for fragment in fragments {
+		let borrowed = fragment.borrow();
+		match borrowed.class() {
+			FragmentClass::TextFragment => text_fragments.push(fragment.clone()),
+			FragmentClass::BoxFragment if borrowed.as_box_fragment().strut.is_some() => {
+				collect_text_fragments(&borrowed.as_box_fragment().children, text_fragments)
+			},
+			_ => {},
+		}
+	}
+}
+
+/// The clusters of the glyphs which are stretched, the spaces which hang at the end of the line are left out.
+fn get_opportunities(fragment: &TextFragment, is_last: bool, text_justify: &TextJustify) -> Vec<usize> {
+	let content = if is_last {
+		fragment.content.trim_end()
+	} else {
+		fragment.content.as_str()
+	};
+	let mut clusters: Vec<usize> = fragment
+		.shaped_text
+		.runs
+		.iter()
+		.flat_map(|run| run.glyphs.iter())
+		.map(|glyph| glyph.cluster)
+		.filter(|cluster| *cluster < content.len())
+		.collect();
+	clusters.sort_unstable();
+	clusters.dedup();
+	match text_justify {
+		TextJustify::InterCharacter => {
+			if is_last {
+				clusters.pop();
+			}
+			clusters
+		},
+		_ => clusters
+			.into_iter()
+			.filter(|cluster| content[*cluster..].starts_with(WORD_SEPARATORS))
+			.collect(),
+	}
+}
+
+/// Widens the glyphs at the opportunities by `extra`, the fragments after a wider one in visual order move along and
+/// the inline boxes grow with their content. Returns how much wider `fragments` are.
+fn expand_fragments(
+	fragments: &[Rc<RefCell<dyn Fragment>>],
+	opportunities: &mut impl Iterator<Item = Vec<usize>>,
+	extra: Pixel,
+) -> Pixel {
+	let mut expansions = Vec::with_capacity(fragments.len());
+	for fragment in fragments {
+		let mut fragment = fragment.borrow_mut();
+		let expansion = match fragment.class() {
+			FragmentClass::TextFragment => {
+				expand_text_fragment(fragment.as_text_fragment_mut(), opportunities.next().unwrap(), extra)
+			},
+			FragmentClass::BoxFragment if fragment.as_box_fragment().strut.is_some() => {
+				let box_fragment = fragment.as_box_fragment_mut();
+				let expansion = expand_fragments(&box_fragment.children, opportunities, extra);
+				box_fragment.set_width(box_fragment.width() + expansion);
+				expansion
+			},
+			_ => PIXEL_ZERO,
+		};
+		expansions.push(expansion);
+	}
+
+	let mut order: Vec<usize> = (0..fragments.len()).collect();
+	order.sort_by(|a, b| {
+		let (a, b) = (fragments[*a].borrow().x(), fragments[*b].borrow().x());
+		a.partial_cmp(&b).unwrap()
+	});
+	let mut shift = PIXEL_ZERO;
+	for index in order {
+		let mut fragment = fragments[index].borrow_mut();
+		let x = fragment.x();
+		fragment.set_x(x + shift);
+		shift += expansions[index];
+	}
+	shift
+}
+
+fn expand_text_fragment(fragment: &mut TextFragment, clusters: Vec<usize>, extra: Pixel) -> Pixel {
+	let mut expansion = PIXEL_ZERO;
+	for cluster in clusters {
+		let glyph = fragment
+			.shaped_text
+			.runs
+			.iter_mut()
+			.flat_map(|run| run.glyphs.iter_mut())
+			.find(|glyph| glyph.cluster == cluster);
+		if let Some(glyph) = glyph {
+			glyph.advance += extra.get();
+			expansion += extra;
+		}
+	}
+	let mut x = 0.0;
+	for glyph in fragment
+		.shaped_text
+		.runs
+		.iter_mut()
+		.flat_map(|run| run.glyphs.iter_mut())
+	{
+		glyph.x = x;
+		x += glyph.advance;
+	}
+	fragment.shaped_text.width = x;
+	let width = fragment.width();
+	fragment.set_width(width + expansion);
+	expansion
+}
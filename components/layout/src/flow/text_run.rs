@@ -1,4 +1,6 @@
 use std::cell::{Ref, RefCell, RefMut};
+use std::collections::VecDeque;
+use std::ops::Range;
 use std::rc::{Rc, Weak};
 
 use common::not_reached;
@@ -6,14 +8,17 @@ use css::values::{Pixel, PIXEL_ZERO};
 use dom::characterdata::CharacterData;
 use dom::global_scope::{GlobalScope, NodeRef};
 use dom::inheritance::Castable;
-use regex::Regex;
 use uuid::Uuid;
 
 use super::boxes::{Box, BoxClass, SimpleBoxIterator};
 use super::formatting_context::{FormattingContext, FormattingContextType};
-use super::fragment::{Fragment, LayoutInfo, Line, TextFragment};
+use super::fragment::{Fragment, LayoutInfo, TextFragment};
+use super::line_break::{
+	breaks_anywhere_for_min_content, breaks_overflowing_words, collapses_spaces, ends_with_collapsible_space,
+	get_segments, is_collapsible_white_space, process_white_space, split_characters, trim_hanging_spaces, Segment,
+};
 use super::tree::VisitingContext;
-use crate::text::TextUI;
+use crate::text::{ShapedText, TextUI};
 
 /// https://www.w3.org/TR/CSS22/visuren.html#inline-boxes
 pub struct TextRun {
@@ -51,21 +56,58 @@ impl TextRun {
 		self.fragments.borrow()
 	}
 
-	pub fn create_fragment(&self, content: String) -> TextFragment {
-		TextFragment::new(self.dom_node(), content)
+	pub fn fragments_mut(&self) -> RefMut<Vec<Rc<RefCell<TextFragment>>>> {
+		self.fragments.borrow_mut()
 	}
 
-	fn split_paragraph(
+	pub fn create_fragment(&self, content: String, shaped_text: ShapedText) -> TextFragment {
+		TextFragment::new(self.dom_node(), content, shaped_text)
+	}
+
+	/// Whether the text only has spaces which are removed by the white space processing.
+	pub fn is_collapsible_white_space(&self) -> bool {
+		let content = self.dom_node.downcast::<CharacterData>().data();
+		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.parent_node().unwrap().id());
+		is_collapsible_white_space(content.as_str(), computed_values.get_white_space())
+	}
+
+	/// Whether in-flow content of the inline formatting context comes before (or after) this text run, the collapsible
+	/// spaces at the start and the end of the whole content are removed.
+	/// https://drafts.csswg.org/css-text/#white-space-phase-2
+	fn has_adjacent_content(&self, after: bool) -> bool {
+		let establisher = self.formatting_context().established_by();
+		let mut id = self.id;
+		for ancestor in self.ancestors() {
+			let children = ancestor.children();
+			let index = children.iter().position(|child| child.id() == id).unwrap();
+			let mut siblings = if after {
+				children[index + 1..].iter()
+			} else {
+				children[..index].iter()
+			};
+			if siblings.any(|child| is_inline_content(child.as_ref())) {
+				return true;
+			}
+			if ancestor.id() == establisher.id() {
+				break;
+			}
+			id = ancestor.id();
+		}
+		false
+	}
+
+	fn place_fragment(
 		&self,
+		content: &str,
+		shaped_text: ShapedText,
 		width: Pixel,
-		height: Pixel,
-		parts: &Vec<&str>,
 		parent_current_width: Pixel,
 		in_current_line: bool,
 		parent: Rc<dyn Box>,
 		establisher: Rc<dyn Box>,
 	) {
-		let mut fragment = self.create_fragment(parts.join(""));
+		let height = Pixel::new(shaped_text.height);
+		let mut fragment = self.create_fragment(content.to_string(), shaped_text);
 		fragment.set_width(width);
 		fragment.set_height(height);
 
@@ -77,7 +119,7 @@ impl TextRun {
 
 			let mut lines = establisher.lines_mut();
 			if lines.len() == 0 {
-				lines.push(Line::new());
+				lines.push(BoxClass::new_line(establisher.as_ref(), &[]));
 			}
 			let latest_line = lines.last().unwrap();
 			if parent.id() == establisher.id() {
@@ -99,6 +141,14 @@ impl TextRun {
 	}
 }
 
+/// Floats, absolutely positioned boxes and collapsible spaces don't separate the text around them.
+fn is_inline_content(source: &dyn Box) -> bool {
+	match source.class() {
+		BoxClass::TextRun => !source.as_text_run().is_collapsible_white_space(),
+		_ => !BoxClass::is_out_of_flow(source),
+	}
+}
+
 impl Box for TextRun {
 	fn id(&self) -> uuid::Uuid {
 		self.id
@@ -182,103 +232,182 @@ impl Box for TextRun {
 		false
 	}
 
+	/// The min-content width is the widest piece between two break opportunities, the max-content width is the widest
+	/// line between two forced breaks.
+	/// https://drafts.csswg.org/css-sizing-3/#intrinsic-sizes
 	fn prepare_layout(&self) {
-		let text_ui = TextUI::new();
-		let content = self.dom_node.downcast::<CharacterData>().data();
+		let data = self.dom_node.downcast::<CharacterData>().data();
 		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.parent_node().unwrap().id());
-		let family_names = computed_values.get_font_families();
-		let font_size = computed_values.get_font_size();
-		let (width, height) = text_ui.measure_size(content.as_str(), family_names, font_size);
+		let text_ui = TextUI::with_font_style(
+			computed_values.get_font_weight(),
+			computed_values.get_font_style().clone(),
+		);
+		let white_space = computed_values.get_white_space();
+
+		let mut content = process_white_space(data.as_str(), white_space, !self.has_adjacent_content(false));
+		if collapses_spaces(white_space) && !self.has_adjacent_content(true) {
+			content.truncate(trim_hanging_spaces(&content).len());
+		}
+		let shaped_text = text_ui.shape(
+			content.as_str(),
+			computed_values.get_font_families(),
+			computed_values.get_font_size(),
+		);
+		let measure = |range: Range<usize>| Pixel::new(shaped_text.width_of(range));
 		let mut layout_info = self.layout_info.borrow_mut();
-		layout_info.intrinsic_size.preferred_width = Pixel::new(width);
-		layout_info.intrinsic_size.preferred_height = Pixel::new(height);
-
-		let regex = Regex::new(r"\s").unwrap();
-		for word in regex.split(content.as_str()) {
-			let (width, _) = text_ui.measure_size(word, family_names, font_size);
-			layout_info.intrinsic_size.preferred_minimum_width = layout_info
-				.intrinsic_size
-				.preferred_minimum_width
-				.max(Pixel::new(width));
+		let (mut preferred_width, mut offset) = (PIXEL_ZERO, 0);
+		for line in content.split('\n') {
+			preferred_width = preferred_width.max(measure(offset..offset + trim_hanging_spaces(line).len()));
+			offset += line.len() + 1;
+		}
+		layout_info.intrinsic_size.max_content_width = preferred_width;
+		layout_info.intrinsic_size.preferred_width = preferred_width;
+		layout_info.intrinsic_size.preferred_height =
+			Pixel::new(shaped_text.height) * content.split('\n').count() as f32;
+
+		let mut segments = get_segments(content.as_str(), computed_values);
+		if breaks_anywhere_for_min_content(computed_values) {
+			segments = segments.into_iter().flat_map(split_characters).collect();
 		}
+		let preferred_minimum_width = segments
+			.iter()
+			.fold(PIXEL_ZERO, |width, segment| width.max(measure(segment.fitting_range())));
+		layout_info.intrinsic_size.min_content_width = preferred_minimum_width;
+		layout_info.intrinsic_size.preferred_minimum_width = preferred_minimum_width;
 	}
 
+	/// The text is processed by `white-space` and shaped, then it is broken into lines at the break opportunities.
+	/// Every fragment keeps the glyphs of its characters, so it is painted with the advances it is measured with.
+	/// https://drafts.csswg.org/css-text/#white-space-processing
+	/// https://drafts.csswg.org/css-text/#line-breaking
 	fn visit_layout(&self) {
-		let text_ui = TextUI::new();
 		let parent = self.parent().unwrap();
-		let (parent_current_width, parent_leftover_width, parent_max_width) =
-			BoxClass::get_parent_width(parent.clone());
-		let layout_info = self.layout_info.borrow();
+		let formatting_context = parent.formatting_context();
+		let establisher = formatting_context.established_by();
+		let (parent_current_width, parent_leftover_width, _) = BoxClass::get_parent_width(parent.clone());
 
-		let establisher = parent.formatting_context().established_by();
-
-		let content = self.dom_node.downcast::<CharacterData>().data();
+		let data = self.dom_node.downcast::<CharacterData>().data();
 		let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.parent_node().unwrap().id());
-		let family_names = computed_values.get_font_families();
-		let font_size = computed_values.get_font_size();
-
-		if layout_info.intrinsic_size.preferred_width <= parent_leftover_width {
-			let mut fragment = self.create_fragment(content.to_string());
-			fragment.set_width(layout_info.intrinsic_size.preferred_width);
-			fragment.set_height(layout_info.intrinsic_size.preferred_height);
-			fragment.set_x(parent_current_width);
-
-			let fragment = Rc::new(RefCell::new(fragment));
-			self.add_fragment(fragment.clone());
-			parent.add_child_fragment(fragment.clone());
-
-			let mut lines = establisher.lines_mut();
-			if lines.len() == 0 {
-				lines.push(Line::new());
-			}
-			let latest_line = lines.last().unwrap();
-			if parent.id() == establisher.id() {
-				latest_line.add_fragment(fragment.clone());
-			}
-			BoxClass::update_ancestors_width(fragment.clone(), establisher.clone(), self.ancestors());
-		} else {
-			let mut max_width = parent_leftover_width;
-			let mut width = PIXEL_ZERO;
-			let mut height = PIXEL_ZERO;
-			let mut parts: Vec<&str> = vec![];
-			let mut in_current_line = true;
-
-			let regex = Regex::new(r"\s").unwrap();
-			for word in split_keep(&regex, content.as_ref()) {
-				let bounds = text_ui.measure_size(word, family_names, font_size);
-				let word_width = Pixel::new(bounds.0);
-
-				if width + word_width <= max_width {
-					width += word_width;
-					height = height.max(Pixel::new(bounds.1));
-				} else {
-					self.split_paragraph(
-						width,
-						height,
-						&parts,
+		let text_ui = TextUI::with_font_style(
+			computed_values.get_font_weight(),
+			computed_values.get_font_style().clone(),
+		);
+		let white_space = computed_values.get_white_space();
+
+		let mut content = process_white_space(
+			data.as_str(),
+			white_space,
+			formatting_context.after_collapsible_space.get(),
+		);
+		if collapses_spaces(white_space) && !self.has_adjacent_content(true) {
+			content.truncate(trim_hanging_spaces(&content).len());
+		}
+		if content.is_empty() {
+			return;
+		}
+		formatting_context
+			.after_collapsible_space
+			.set(ends_with_collapsible_space(content.as_str(), white_space));
+
+		let shaped_text = text_ui.shape(
+			content.as_str(),
+			computed_values.get_font_families(),
+			computed_values.get_font_size(),
+		);
+		let measure = |range: Range<usize>| Pixel::new(shaped_text.width_of(range));
+		let mut segments: VecDeque<Segment> = get_segments(content.as_str(), computed_values).into();
+		let breaks_overflowing_words = breaks_overflowing_words(computed_values);
+		let mut is_line_empty = establisher
+			.lines()
+			.last()
+			.map_or(true, |line| line.width() == PIXEL_ZERO);
+		let mut max_width = parent_leftover_width;
+		let mut width = PIXEL_ZERO;
+		let mut part = 0..0;
+		let mut in_current_line = true;
+		let mut is_after_hard_break = false;
+
+		while let Some(segment) = segments.pop_front() {
+			let fitting_width = measure(segment.fitting_range());
+			if width + fitting_width > max_width && !(part.is_empty() && is_line_empty) {
+				// the line is broken at the opportunity before the segment, the spaces at its end hang or are removed
+				if !part.is_empty() {
+					let trimmed = part.start..part.start + trim_hanging_spaces(&content[part.clone()]).len();
+					let part = if collapses_spaces(white_space) {
+						trimmed.clone()
+					} else {
+						part.clone()
+					};
+					self.place_fragment(
+						&content[part.clone()],
+						shaped_text.slice(part),
+						measure(trimmed),
 						parent_current_width,
 						in_current_line,
 						parent.clone(),
 						establisher.clone(),
 					);
-
-					max_width = parent_max_width;
-					width = word_width;
-					height = Pixel::new(bounds.1);
-					parts.clear();
-					in_current_line = false;
 				}
-				parts.push(word);
+				max_width = BoxClass::get_next_line_width(parent.clone());
+				width = PIXEL_ZERO;
+				part = segment.offset..segment.offset;
+				in_current_line = false;
+				is_line_empty = true;
 			}
-			self.split_paragraph(
+			if width + fitting_width > max_width && part.is_empty() && breaks_overflowing_words {
+				let pieces = split_characters(segment);
+				if pieces.len() > 1 {
+					for piece in pieces.into_iter().rev() {
+						segments.push_front(piece);
+					}
+					continue;
+				}
+			}
+			if part.is_empty() {
+				part = segment.offset..segment.end();
+			} else {
+				part.end = segment.end();
+			}
+			width += measure(segment.offset..segment.end());
+			is_after_hard_break = segment.is_hard;
+
+			if is_after_hard_break {
+				let mut line_text = shaped_text.slice(part.clone());
+				// an empty line is as tall as the text
+				if part.is_empty() {
+					line_text.ascent = shaped_text.ascent;
+					line_text.height = shaped_text.height;
+				}
+				self.place_fragment(
+					&content[part.clone()],
+					line_text,
+					width,
+					parent_current_width,
+					in_current_line,
+					parent.clone(),
+					establisher.clone(),
+				);
+				if let Some(line) = establisher.lines().last() {
+					line.ends_with_forced_break.set(true);
+				}
+				max_width = BoxClass::get_next_line_width(parent.clone());
+				width = PIXEL_ZERO;
+				part = segment.end()..segment.end();
+				in_current_line = false;
+				is_line_empty = true;
+			}
+		}
+		// the content after a trailing forced break starts on a new line
+		if !part.is_empty() || is_after_hard_break {
+			self.place_fragment(
+				&content[part.clone()],
+				shaped_text.slice(part),
 				width,
-				height,
-				&parts,
 				parent_current_width,
 				in_current_line,
 				parent,
 				establisher,
-			)
+			);
 		}
 	}
 
@@ -299,19 +428,3 @@ impl Box for TextRun {
 		self
 	}
 }
-
-fn split_keep<'a>(r: &Regex, text: &'a str) -> Vec<&'a str> {
-	let mut result = Vec::new();
-	let mut last = 0;
-	for (index, matched) in text.match_indices(r) {
-		if last != index {
-			result.push(&text[last..index]);
-		}
-		result.push(matched);
-		last = index + matched.len();
-	}
-	if last < text.len() {
-		result.push(&text[last..]);
-	}
-	result
-}
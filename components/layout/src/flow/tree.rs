@@ -1,20 +1,30 @@
 use std::collections::VecDeque;
 use std::rc::Rc;
 
-use common::not_supported;
-use css::properties::longhands::display::{DisplayInside, DisplayOutside};
+use common::{not_reached, not_supported};
+use css::properties::longhands::display::{
+	Display, DisplayBasic, DisplayInside, DisplayInternal, DisplayLegacy, DisplayOutside,
+};
+use css::properties::longhands::position::Position;
 use css::values::{Pixel, PIXEL_ZERO};
-use dom::global_scope::NodeRef;
+use dom::characterdata::CharacterData;
+use dom::global_scope::{GlobalScope, NodeRef};
+use dom::inheritance::Castable;
 use dom::node::Node;
 use dom::nodetype::NodeTypeId;
+use dom::window::Window;
+use euclid::{Point2D, Rect, Size2D, Vector2D};
 
 use super::block::BlockLevelBox;
 use super::boxes::{Box, BoxClass};
 use super::formatting_context::FormattingContextType;
 use super::fragment::LayoutInfo;
+use super::line_break::is_collapsible_white_space;
+use super::position::Insets;
+use super::table::TablePart;
 use crate::flow::inline::InlineLevelBox;
 use crate::flow::text_run::TextRun;
-use crate::style_tree::{StyleTree, StyleTreeNode};
+use crate::style_tree::{cascade_anonymous_box, StyleTree, StyleTreeNode};
 
 pub struct BoxTree {
 	pub root: Rc<dyn Box>,
@@ -32,8 +42,7 @@ impl BoxTree {
 			.dom_node
 			.window()
 			.expect("dom has to belong to a window")
-			.viewport()
-			.clone();
+			.viewport();
 		let initial_containing_block = BoxClass::new_with_formatting_context(
 			FormattingContextType::BlockFormattingContext,
 			|formatting_context| {
@@ -44,6 +53,7 @@ impl BoxTree {
 				block.set_layout_info(LayoutInfo {
 					width: Pixel::new(viewport.width()),
 					height: Pixel::new(viewport.height()),
+					has_definite_height: true,
 					..Default::default()
 				});
 				block.set_fragment(block.create_fragment());
@@ -83,10 +93,66 @@ impl BoxTree {
 		- if there is mixed between block and inline level boxes
 		  reuse its parent context and wrap its children inline level box into annonymous block level box
 		| else establish a inline formatting context
+	- if inner is flex -> establish a new flex formatting context
+	- if inner is grid -> establish a new grid formatting context
+	- children of a flex or grid container
+	  (https://drafts.csswg.org/css-flexbox-1/#flex-items, https://drafts.csswg.org/css-grid/#grid-items)
+		- whitespace-only text is not rendered, other text is wrapped in an anonymous item
+		- elements are blockified and establish a new formatting context for their children
+	- floats (https://www.w3.org/TR/CSS22/visuren.html#floats)
+		- are blockified and establish a new formatting context for their children
+		- stay with the inline content around them, in the same anonymous block level box
+	- absolutely positioned boxes (https://www.w3.org/TR/CSS22/visuren.html#absolute-positioning)
+		- are blockified and establish a new formatting context for their children, like floats
+		- their containing block is the nearest positioned ancestor, or the initial containing block
+	- if inner is table -> establish a new table formatting context (https://www.w3.org/TR/CSS22/tables.html)
+		- rows, columns and their groups share the formatting context of their table
+		- captions and cells establish a new block formatting context
+		- a part without its proper parent is wrapped in an anonymous table, row or cell box
+		  (https://www.w3.org/TR/CSS22/tables.html#anonymous-boxes), whitespace-only text between parts is not rendered
+	- text keeps its spaces and segment breaks for the white space processing in layout
+	  (https://drafts.csswg.org/css-text/#white-space-processing), whitespace-only text which would be collapsed
+	  is not rendered when it isn't next to inline content of a block formatting context
+	- a block container with column-count or column-width establishes a new block formatting context
+	  (https://drafts.csswg.org/css-multicol/#the-multi-column-model), its inline content is wrapped in anonymous boxes
 	*/
 	fn construct_node(style_node: Rc<StyleTreeNode>, parent_box: Rc<dyn Box>) {
-		let (outside, inside) = style_node.get_display();
-		let visual_box = match outside {
+		let parent_box = match BoxTree::get_table_parent(&style_node, parent_box) {
+			Some(parent_box) => parent_box,
+			None => return,
+		};
+		let is_element = style_node.dom_node.node_type_id().is_element();
+		let is_absolutely_positioned = style_node.is_absolutely_positioned();
+		let is_flex_or_grid_item = !is_absolutely_positioned
+			&& matches!(
+				parent_box.formatting_context_type(),
+				FormattingContextType::FlexFormattingContext | FormattingContextType::GridFormattingContext
+			);
+		if is_flex_or_grid_item && BoxTree::is_whitespace_text(&style_node) {
+			return;
+		}
+		if parent_box.formatting_context_type() == FormattingContextType::BlockFormattingContext
+			&& parent_box
+				.get_last_child()
+				.map_or(true, |last_child| last_child.class() != BoxClass::Anonymous)
+			&& BoxTree::is_collapsible_whitespace_text(&style_node)
+		{
+			return;
+		}
+		let is_float = !is_flex_or_grid_item && style_node.is_float();
+		let (outside, inside) = match style_node.get_display() {
+			(DisplayOutside::Inline, inside)
+				if (is_flex_or_grid_item || is_float || is_absolutely_positioned) && is_element =>
+			{
+				(DisplayOutside::Block, inside)
+			},
+			display => display,
+		};
+		let visual_box: Rc<dyn Box> = match outside {
+			_ if style_node.get_table_part().map_or(false, |part| part.is_track()) => Rc::new(BlockLevelBox::new(
+				style_node.dom_node.clone(),
+				parent_box.formatting_context(),
+			)),
 			DisplayOutside::Inline => match inside {
 				DisplayInside::Flow => {
 					let node: Rc<dyn Box> = if style_node.dom_node.node_type_id().is_element() {
@@ -106,11 +172,33 @@ impl BoxTree {
 					FormattingContextType::BlockFormattingContext,
 					|formatting_context| Rc::new(InlineLevelBox::new(style_node.dom_node.clone(), formatting_context)),
 				),
+				DisplayInside::Flex => BoxClass::new_with_formatting_context(
+					FormattingContextType::FlexFormattingContext,
+					|formatting_context| Rc::new(InlineLevelBox::new(style_node.dom_node.clone(), formatting_context)),
+				),
+				DisplayInside::Grid => BoxClass::new_with_formatting_context(
+					FormattingContextType::GridFormattingContext,
+					|formatting_context| Rc::new(InlineLevelBox::new(style_node.dom_node.clone(), formatting_context)),
+				),
+				DisplayInside::Table => BoxClass::new_with_formatting_context(
+					FormattingContextType::TableFormattingContext,
+					|formatting_context| Rc::new(InlineLevelBox::new(style_node.dom_node.clone(), formatting_context)),
+				),
 				_ => not_supported!(),
 			},
 			DisplayOutside::Block => match inside {
 				DisplayInside::Flow => {
-					if !style_node.is_contain_all_inline_children() {
+					if style_node.is_multicol_container()
+						|| (!style_node.is_contain_all_inline_children()
+							&& (is_flex_or_grid_item || is_float || is_absolutely_positioned))
+					{
+						BoxClass::new_with_formatting_context(
+							FormattingContextType::BlockFormattingContext,
+							|formatting_context| {
+								Rc::new(BlockLevelBox::new(style_node.dom_node.clone(), formatting_context))
+							},
+						)
+					} else if !style_node.is_contain_all_inline_children() {
 						Rc::new(BlockLevelBox::new(
 							style_node.dom_node.clone(),
 							parent_box.formatting_context(),
@@ -128,6 +216,18 @@ impl BoxTree {
 					FormattingContextType::BlockFormattingContext,
 					|formatting_context| Rc::new(BlockLevelBox::new(style_node.dom_node.clone(), formatting_context)),
 				),
+				DisplayInside::Flex => BoxClass::new_with_formatting_context(
+					FormattingContextType::FlexFormattingContext,
+					|formatting_context| Rc::new(BlockLevelBox::new(style_node.dom_node.clone(), formatting_context)),
+				),
+				DisplayInside::Grid => BoxClass::new_with_formatting_context(
+					FormattingContextType::GridFormattingContext,
+					|formatting_context| Rc::new(BlockLevelBox::new(style_node.dom_node.clone(), formatting_context)),
+				),
+				DisplayInside::Table => BoxClass::new_with_formatting_context(
+					FormattingContextType::TableFormattingContext,
+					|formatting_context| Rc::new(BlockLevelBox::new(style_node.dom_node.clone(), formatting_context)),
+				),
 				_ => not_supported!(),
 			},
 			_ => not_supported!(),
@@ -141,6 +241,120 @@ impl BoxTree {
 		}
 	}
 
+	/// The box which `style_node` goes into, anonymous table boxes are generated between `parent_box` and it when it is
+	/// not a proper child of `parent_box`. Returns `None` when `style_node` is not rendered.
+	/// https://www.w3.org/TR/CSS22/tables.html#anonymous-boxes
+	fn get_table_parent(style_node: &StyleTreeNode, parent_box: Rc<dyn Box>) -> Option<Rc<dyn Box>> {
+		let part = style_node.get_table_part();
+		match (TablePart::of(parent_box.as_ref()), part) {
+			(Some(parent_part), Some(part)) if parent_part.is_proper_parent_of(part) => Some(parent_box),
+			// columns and their groups have no content
+			(Some(TablePart::Column), _) | (Some(TablePart::ColumnGroup), _) => None,
+			(Some(parent_part), _) if parent_part == TablePart::Table || parent_part.is_row_group() => {
+				if BoxTree::is_whitespace_text(style_node) {
+					return None;
+				}
+				let row = BoxTree::get_anonymous_part(style_node, parent_box, TablePart::Row);
+				BoxTree::get_table_parent(style_node, row)
+			},
+			(Some(TablePart::Row), _) => {
+				if BoxTree::is_whitespace_text(style_node) {
+					return None;
+				}
+				let cell = BoxTree::get_anonymous_part(style_node, parent_box, TablePart::Cell);
+				BoxTree::get_table_parent(style_node, cell)
+			},
+			(_, Some(part)) if part != TablePart::Table => {
+				let table = BoxTree::get_anonymous_part(style_node, parent_box, TablePart::Table);
+				BoxTree::get_table_parent(style_node, table)
+			},
+			_ => Some(parent_box),
+		}
+	}
+
+	/// Consecutive children which need the same anonymous box share it, so the last child of `parent_box` is reused
+	/// when it is an anonymous box for `part`. A new anonymous box inherits from `parent_box`, `style_node` is the child
+	/// which it is generated for.
+	fn get_anonymous_part(style_node: &StyleTreeNode, parent_box: Rc<dyn Box>, part: TablePart) -> Rc<dyn Box> {
+		if let Some(last_child) = parent_box.get_last_child() {
+			let is_anonymous = match last_child.class() {
+				BoxClass::Block => !last_child.as_block_level_box().dom_node().node_type_id().is_element(),
+				BoxClass::Inline => !last_child.as_inline_level_box().dom_node().node_type_id().is_element(),
+				_ => false,
+			};
+			if is_anonymous && TablePart::of(last_child.as_ref()) == Some(part) {
+				return last_child;
+			}
+		}
+
+		let dom_node = NodeRef(Rc::new(Node::new(NodeTypeId::Document, None)));
+		let computed_values = GlobalScope::get_or_init_computed_values(dom_node.id());
+		let device = style_node
+			.dom_node
+			.window()
+			.expect("dom has to belong to a window")
+			.device();
+		let parent_style = BoxClass::get_computed_values(parent_box.as_ref());
+		cascade_anonymous_box(computed_values, parent_style, &device);
+		let anonymous_box: Rc<dyn Box> = match part {
+			TablePart::Table
+				if parent_box.formatting_context_type() == FormattingContextType::InlineFormattingContext =>
+			{
+				computed_values.set_display(Display::Legacy(DisplayLegacy::InlineTable));
+				BoxClass::new_with_formatting_context(
+					FormattingContextType::TableFormattingContext,
+					|formatting_context| Rc::new(InlineLevelBox::new(dom_node.clone(), formatting_context)),
+				)
+			},
+			TablePart::Table => {
+				computed_values.set_display(Display::Basic(DisplayBasic {
+					outside: Some(DisplayOutside::Block),
+					inside: Some(DisplayInside::Table),
+				}));
+				BoxClass::new_with_formatting_context(
+					FormattingContextType::TableFormattingContext,
+					|formatting_context| Rc::new(BlockLevelBox::new(dom_node.clone(), formatting_context)),
+				)
+			},
+			TablePart::Row => {
+				computed_values.set_display(Display::Internal(DisplayInternal::TableRow));
+				Rc::new(BlockLevelBox::new(dom_node.clone(), parent_box.formatting_context()))
+			},
+			TablePart::Cell => {
+				computed_values.set_display(Display::Internal(DisplayInternal::TableCell));
+				BoxClass::new_with_formatting_context(
+					FormattingContextType::BlockFormattingContext,
+					|formatting_context| Rc::new(BlockLevelBox::new(dom_node.clone(), formatting_context)),
+				)
+			},
+			_ => not_reached!(),
+		};
+		BoxClass::append_child(parent_box, anonymous_box.clone());
+		BoxClass::set_containing_box(anonymous_box.clone());
+		anonymous_box
+	}
+
+	fn is_whitespace_text(style_node: &StyleTreeNode) -> bool {
+		!style_node.dom_node.node_type_id().is_element()
+			&& style_node
+				.dom_node
+				.downcast::<CharacterData>()
+				.data()
+				.chars()
+				.all(char::is_whitespace)
+	}
+
+	fn is_collapsible_whitespace_text(style_node: &StyleTreeNode) -> bool {
+		if style_node.dom_node.node_type_id().is_element() {
+			return false;
+		}
+		let computed_values = GlobalScope::get_or_init_computed_values(style_node.dom_node.parent_node().unwrap().id());
+		is_collapsible_white_space(
+			style_node.dom_node.downcast::<CharacterData>().data().as_str(),
+			computed_values.get_white_space(),
+		)
+	}
+
 	pub fn log(&self) {
 		self.log_node(self.root.clone(), 0);
 	}
@@ -181,6 +395,7 @@ impl BoxTree {
 	pub fn compute_layout(&self) {
 		self.prepare_layout();
 		self.visit_layout();
+		self.layout_positioned_boxes();
 	}
 
 	pub fn prepare_layout(&self) {
@@ -203,6 +418,113 @@ impl BoxTree {
 		}
 		node.revisit_layout(parent_context);
 	}
+
+	/// Positioned boxes are moved once the normal flow is laid out, a containing block is always placed before the
+	/// boxes inside it.
+	/// https://www.w3.org/TR/CSS22/visuren.html#choose-position
+	pub fn layout_positioned_boxes(&self) {
+		self.layout_positioned_node(self.root.clone());
+		self.update_scroll_offsets();
+	}
+
+	fn layout_positioned_node(&self, node: Rc<dyn Box>) {
+		match BoxClass::get_position(node.as_ref()) {
+			Position::Relative => {
+				let containing_block = node.containing_block().unwrap();
+				let containing_layout = containing_block.layout_info();
+				let computed_values = GlobalScope::get_or_init_computed_values(BoxTree::dom_node(node.as_ref()).id());
+				let offset =
+					Insets::new(computed_values, containing_layout.width, containing_layout.height).relative_offset();
+				match node.class() {
+					BoxClass::Block => node.as_block_level_box().fragment_mut().offset = offset,
+					BoxClass::Inline => {
+						for fragment in node.as_inline_level_box().fragments().iter() {
+							fragment.borrow_mut().offset = offset;
+						}
+					},
+					_ => not_reached!(),
+				}
+			},
+			Position::Absolute | Position::Fixed if node.class() == BoxClass::Block && node.parent().is_some() => {
+				node.as_block_level_box().place_absolute();
+			},
+			_ => {},
+		}
+		for child in node.children() {
+			self.layout_positioned_node(child);
+		}
+	}
+
+	/// Scrolls the document and moves fixed and sticky positioned boxes with the viewport.
+	pub fn scroll_to(&self, x: f32, y: f32) {
+		self.window().scroll_to(x, y);
+		self.update_scroll_offsets();
+	}
+
+	/// https://drafts.csswg.org/css-position/#fixed-pos
+	/// https://drafts.csswg.org/css-position/#sticky-pos
+	fn update_scroll_offsets(&self) {
+		let viewport = self.window().viewport();
+		let scrollport = Rect::new(
+			Point2D::new(Pixel::new(viewport.min_x()), Pixel::new(viewport.min_y())),
+			Size2D::new(Pixel::new(viewport.width()), Pixel::new(viewport.height())),
+		);
+		let mut nodes = vec![self.root.clone()];
+		while let Some(node) = nodes.pop() {
+			nodes.extend(node.children());
+			if node.class() != BoxClass::Block {
+				continue;
+			}
+			let block = node.as_block_level_box();
+			match BoxClass::get_position(node.as_ref()) {
+				Position::Fixed => block.fragment_mut().offset = Vector2D::new(scrollport.min_x(), scrollport.min_y()),
+				Position::Sticky => {
+					let containing_block = node.containing_block().unwrap();
+					let (containing_x, containing_y) =
+						BoxClass::get_offset_from(containing_block.clone(), self.initial_containing_block.as_ref());
+					let containing_layout = containing_block.layout_info();
+					let containing_rect = Rect::new(
+						Point2D::new(containing_x, containing_y),
+						Size2D::new(containing_layout.width, containing_layout.height),
+					);
+					drop(containing_layout);
+
+					let (x, y) = BoxClass::get_offset_from(node.clone(), self.initial_containing_block.as_ref());
+					let layout_info = node.layout_info();
+					let padding = layout_info.padding;
+					let rect = Rect::new(
+						Point2D::new(x - padding.left, y - padding.top),
+						Size2D::new(
+							layout_info.width + padding.left + padding.right,
+							layout_info.height + padding.top + padding.bottom,
+						),
+					);
+					drop(layout_info);
+
+					let computed_values = GlobalScope::get_or_init_computed_values(block.dom_node().id());
+					let insets = Insets::new(computed_values, scrollport.width(), scrollport.height());
+					block.fragment_mut().offset = insets.sticky_offset(&rect, &containing_rect, &scrollport);
+				},
+				_ => {},
+			}
+		}
+	}
+
+	fn window(&self) -> Rc<Window> {
+		self.root
+			.as_block_level_box()
+			.dom_node()
+			.window()
+			.expect("dom has to belong to a window")
+	}
+
+	fn dom_node(node: &dyn Box) -> NodeRef {
+		match node.class() {
+			BoxClass::Block => node.as_block_level_box().dom_node(),
+			BoxClass::Inline => node.as_inline_level_box().dom_node(),
+			_ => not_reached!(),
+		}
+	}
 }
 
 pub struct VisitingContext {
@@ -1,62 +1,177 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::Read;
+use std::cmp::Ordering;
+use std::fs;
 use std::sync::Arc;
 
-use font_kit::family_name::FamilyName;
-use font_kit::properties::Properties;
+use css::css_writer::ToCss;
+use css::properties::longhands::font_style::FontStyle;
+use css::stylesheets::font_face_rule::{FontFaceRule, FontFaceStyle, Source};
+use css::values::CSSFloat;
+use font_kit::handle::Handle;
 use font_kit::source::SystemSource;
+use glyph_brush::ab_glyph::FontRef;
 use once_cell::sync::Lazy;
 
-use crate::text::FALLBACK;
+use crate::text::TextUI;
 
-pub fn load_cached_font(family_names: &Vec<String>) -> (&'static str, &'static [u8]) {
-	static mut FONT_CACHED: Lazy<HashMap<String, Arc<Vec<u8>>>> = Lazy::new(|| HashMap::new());
+/// Formats which can be read by `ab_glyph`, sources with other format hints are skipped.
+const SUPPORTED_FORMATS: [&str; 5] = [
+	"truetype",
+	"opentype",
+	"collection",
+	"truetype-variations",
+	"opentype-variations",
+];
 
+/// A font loaded from a @font-face rule, it is shared with the `LoadedFont`s made from it.
+pub struct WebFont {
+	pub family_name: String,
+	pub rule: FontFaceRule,
+	pub bytes: Arc<Vec<u8>>,
+	/// The serialized rule, used to not load the same face twice and to cache the loaded face.
+	pub key: String,
+}
+
+static mut WEB_FONTS: Lazy<Vec<Arc<WebFont>>> = Lazy::new(|| Vec::new());
+
+/// Load the faces which are not registered yet, a face is skipped if none of its sources can be loaded.
+pub fn register_font_faces<'a>(font_faces: impl Iterator<Item = &'a FontFaceRule>) {
 	unsafe {
-		let fonts = &mut FONT_CACHED;
-		let mut matched_font_name = None;
-		for family_name in family_names {
-			if let Some(_) = fonts.get(family_name) {
-				matched_font_name = Some(family_name);
-			} else {
-				let font_family = match family_name.as_str() {
-					"serif" => FamilyName::Serif,
-					"sans-serif" => FamilyName::SansSerif,
-					"monospace" => FamilyName::Monospace,
-					"cursive" => FamilyName::Cursive,
-					"fantasy" => FamilyName::Fantasy,
-					_ => FamilyName::Title(family_name.to_string()),
-				};
-				let system_source = SystemSource::new();
-				let handle = system_source.select_best_match(&[font_family], &Properties::default());
-				match handle {
-					Ok(value) => {
-						let bytes = match value {
-							font_kit::handle::Handle::Path { path, .. } => {
-								let mut buf = Vec::new();
-								let mut reader = File::open(path).expect("Read font");
-								let _ = reader.read_to_end(&mut buf);
-								buf
-							},
-							font_kit::handle::Handle::Memory { bytes, .. } => bytes.as_ref().clone(),
-						};
-						fonts.insert(family_name.to_string(), Arc::new(bytes));
-						matched_font_name = Some(family_name);
-					},
-					Err(_) => (),
-				};
+		let web_fonts = &mut WEB_FONTS;
+		for rule in font_faces {
+			let key = rule.to_css_string();
+			if web_fonts.iter().any(|web_font| web_font.key == key) {
+				continue;
+			}
+			let family_name = match &rule.family {
+				Some(family) => family.name(),
+				None => continue,
+			};
+			if let Some(bytes) = load_font_face(rule) {
+				web_fonts.push(Arc::new(WebFont {
+					family_name,
+					rule: rule.clone(),
+					bytes: Arc::new(bytes),
+					key,
+				}));
+				TextUI::clear_font_metrics();
 			}
 		}
-		if let Some(font_name) = matched_font_name {
-			for key in fonts.keys() {
-				if key == font_name {
-					return (key, fonts.get(font_name).unwrap());
+	}
+}
+
+/// Find the registered face of a family which covers `ch` and is the closest to `font_weight` and `font_style`, faces
+/// registered later win like declarations later in the cascade.
+/// https://drafts.csswg.org/css-fonts/#font-style-matching
+pub fn find_web_font(
+	family_name: &str,
+	font_weight: CSSFloat,
+	font_style: &FontStyle,
+	ch: char,
+) -> Option<Arc<WebFont>> {
+	let family_name = family_name.trim_matches('"');
+	let distance = |web_font: &WebFont| {
+		(
+			style_distance(&web_font.rule, font_style),
+			weight_distance(&web_font.rule, font_weight),
+		)
+	};
+	unsafe {
+		WEB_FONTS
+			.iter()
+			.rev()
+			.filter(|web_font| {
+				web_font.family_name.eq_ignore_ascii_case(family_name) && web_font.rule.contains_char(ch)
+			})
+			.min_by(|a, b| distance(a).partial_cmp(&distance(b)).unwrap_or(Ordering::Equal))
+			.cloned()
+	}
+}
+
+/// A family which is defined by @font-face rules hides the installed family with the same name.
+pub fn is_web_font_family(family_name: &str) -> bool {
+	let family_name = family_name.trim_matches('"');
+	unsafe {
+		WEB_FONTS
+			.iter()
+			.any(|web_font| web_font.family_name.eq_ignore_ascii_case(family_name))
+	}
+}
+
+/// Italic and oblique faces are used for each other before a normal face is used.
+fn style_distance(rule: &FontFaceRule, font_style: &FontStyle) -> u8 {
+	match (rule.style.as_ref().unwrap_or(&FontFaceStyle::Normal), font_style) {
+		(FontFaceStyle::Normal, FontStyle::Normal)
+		| (FontFaceStyle::Italic, FontStyle::Italic)
+		| (FontFaceStyle::Oblique(..), FontStyle::Oblique(_)) => 0,
+		(FontFaceStyle::Normal, _) | (_, FontStyle::Normal) => 2,
+		_ => 1,
+	}
+}
+
+fn weight_distance(rule: &FontFaceRule, font_weight: CSSFloat) -> CSSFloat {
+	match &rule.weight {
+		Some(weight) if weight.contains(font_weight) => 0.0,
+		Some(weight) => (weight.0 - font_weight).abs().min((weight.1 - font_weight).abs()),
+		None => (font_weight - 400.0).abs(),
+	}
+}
+
+fn load_font_face(rule: &FontFaceRule) -> Option<Vec<u8>> {
+	for source in rule.sources.as_ref()? {
+		let bytes = match source {
+			Source::Url(source) => {
+				let supported = source.format_hints.is_empty()
+					|| source
+						.format_hints
+						.iter()
+						.any(|hint| SUPPORTED_FORMATS.contains(&hint.to_ascii_lowercase().as_str()));
+				if !supported {
+					continue;
 				}
+				source
+					.url
+					.url()
+					.and_then(|url| url.to_file_path().ok())
+					.and_then(|path| fs::read(path).ok())
+			},
+			Source::Local(name) => load_local_font(&name.name()),
+		};
+		if let Some(bytes) = bytes {
+			if FontRef::try_from_slice(&bytes).is_ok() {
+				return Some(bytes);
 			}
-			("fallback", FALLBACK)
-		} else {
-			("fallback", FALLBACK)
 		}
 	}
+	None
+}
+
+/// `local()` names a single face by its full name or its postscript name.
+/// https://drafts.csswg.org/css-fonts/#local-font-fallback
+fn load_local_font(name: &str) -> Option<Vec<u8>> {
+	let source = SystemSource::new();
+	if let Some(bytes) = source.select_by_postscript_name(name).ok().and_then(handle_bytes) {
+		return Some(bytes);
+	}
+	// a full name starts with the name of its family, like "Lato Bold"
+	let families = source.all_families().ok()?;
+	let lowercase_name = name.to_lowercase();
+	families
+		.iter()
+		.filter(|family| lowercase_name.starts_with(&family.to_lowercase()))
+		.find_map(|family| {
+			let family = source.select_family_by_name(family).ok()?;
+			let handle = family.fonts().iter().find(|handle| {
+				handle
+					.load()
+					.map_or(false, |font| font.full_name().eq_ignore_ascii_case(name))
+			})?;
+			handle_bytes(handle.clone())
+		})
+}
+
+fn handle_bytes(handle: Handle) -> Option<Vec<u8>> {
+	match handle {
+		Handle::Path { path, .. } => fs::read(path).ok(),
+		Handle::Memory { bytes, .. } => Some(bytes.as_ref().clone()),
+	}
 }
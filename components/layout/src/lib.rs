@@ -5,5 +5,6 @@ pub mod display_list;
 pub mod flow;
 pub mod fonts;
 pub mod rule_colectors;
+pub mod style_bloom;
 pub mod style_tree;
 pub mod text;
@@ -2,15 +2,45 @@ use std::ops::Deref;
 
 use css::selectors::select::Selectors;
 use css::stylesheets::origin::Origin;
-use css::stylist::{Rule, Stylist};
+use css::stylist::{CascadeData, Stylist};
 use dom::global_scope::NodeRef;
+use html5ever::{local_name, namespace_url, ns, LocalName};
+use selectors::bloom::BloomFilter;
 use selectors::context::{MatchingContext, MatchingMode};
 use selectors::matching::{matches_selector, ElementSelectorFlags};
 
 use crate::applicable_declaration_block::ApplicableDeclarationBlock;
 
-pub fn collect_rules(element: NodeRef, stylist: &Stylist) -> Vec<ApplicableDeclarationBlock> {
-	let mut matching_context = MatchingContext::new(MatchingMode::Normal, None, None, stylist.quirks_mode());
+/// The parts of an element `SelectorMap` buckets rules by.
+struct ElementKeys {
+	id: Option<String>,
+	classes: Vec<String>,
+	local_name: LocalName,
+}
+
+impl ElementKeys {
+	fn new(element: &NodeRef) -> Self {
+		ElementKeys {
+			id: element
+				.get_attribute(&ns!(), &local_name!("id"))
+				.map(|attr| attr.value().to_string()),
+			classes: element
+				.get_attribute(&ns!(), &local_name!("class"))
+				.and_then(|attr| attr.as_tokens())
+				.unwrap_or_default(),
+			local_name: element.local_name(),
+		}
+	}
+}
+
+/// `bloom_filter` holds the ancestors of `element`, see `StyleBloom`.
+pub fn collect_rules(
+	element: NodeRef,
+	stylist: &Stylist,
+	bloom_filter: &BloomFilter,
+) -> Vec<ApplicableDeclarationBlock> {
+	let mut matching_context =
+		MatchingContext::new(MatchingMode::Normal, Some(bloom_filter), None, stylist.quirks_mode());
 
 	// Apply the selector flags. We should be in sequential mode
 	// already, so we can directly apply the parent flags.
@@ -31,10 +61,12 @@ pub fn collect_rules(element: NodeRef, stylist: &Stylist) -> Vec<ApplicableDecla
 	if let Some(style) = element.style_attribute().borrow().deref() {
 		applicable_declarations.push(ApplicableDeclarationBlock::from_style(style));
 	}
+	let keys = ElementKeys::new(&element);
 	collect_from_origin(
 		&element,
 		&mut applicable_declarations,
-		stylist.user_agent_cascade_data().rules(),
+		&keys,
+		stylist.user_agent_cascade_data(),
 		&mut matching_context,
 		&mut set_selector_flags,
 		Origin::UserAgent,
@@ -42,7 +74,17 @@ pub fn collect_rules(element: NodeRef, stylist: &Stylist) -> Vec<ApplicableDecla
 	collect_from_origin(
 		&element,
 		&mut applicable_declarations,
-		stylist.author_cascade_data().rules(),
+		&keys,
+		stylist.user_cascade_data(),
+		&mut matching_context,
+		&mut set_selector_flags,
+		Origin::User,
+	);
+	collect_from_origin(
+		&element,
+		&mut applicable_declarations,
+		&keys,
+		stylist.author_cascade_data(),
 		&mut matching_context,
 		&mut set_selector_flags,
 		Origin::Author,
@@ -53,16 +95,24 @@ pub fn collect_rules(element: NodeRef, stylist: &Stylist) -> Vec<ApplicableDecla
 fn collect_from_origin<F>(
 	element: &NodeRef,
 	applicable_declarations: &mut Vec<ApplicableDeclarationBlock>,
-	rules: &Vec<Rule>,
+	keys: &ElementKeys,
+	cascade_data: &CascadeData,
 	context: &mut MatchingContext<Selectors>,
 	flags_setter: &mut F,
 	origin: Origin,
 ) where
 	F: FnMut(&NodeRef, ElementSelectorFlags),
 {
-	for rule in rules {
-		if matches_selector(&rule.selector, 0, Some(&rule.hashes), element, context, flags_setter) {
-			applicable_declarations.push(ApplicableDeclarationBlock::from_rule(rule, origin));
-		}
-	}
+	cascade_data.rules().for_each_candidate(
+		keys.id.as_deref(),
+		&keys.classes,
+		&keys.local_name,
+		context.quirks_mode(),
+		|rule| {
+			if matches_selector(&rule.selector, 0, Some(&rule.hashes), element, context, flags_setter) {
+				let layer_order = cascade_data.layer_order(rule.layer_id);
+				applicable_declarations.push(ApplicableDeclarationBlock::from_rule(rule, origin, layer_order));
+			}
+		},
+	);
 }
@@ -0,0 +1,59 @@
+use css::values::Ident;
+use dom::global_scope::NodeRef;
+use html5ever::{local_name, namespace_url, ns};
+use precomputed_hash::PrecomputedHash;
+use selectors::bloom::{BloomFilter, BLOOM_HASH_MASK};
+
+/// A counting bloom filter of the ancestors of the element being matched, which lets
+/// `matches_selector` reject descendant and child selectors without walking up the tree.
+///
+/// An element is pushed before its children are matched and popped once they are done.
+pub struct StyleBloom {
+	filter: Box<BloomFilter>,
+}
+
+impl StyleBloom {
+	pub fn new() -> Self {
+		StyleBloom {
+			filter: Box::new(BloomFilter::new()),
+		}
+	}
+
+	pub fn filter(&self) -> &BloomFilter {
+		&self.filter
+	}
+
+	pub fn push(&mut self, element: &NodeRef) {
+		each_element_hash(element, |hash| self.filter.insert_hash(hash));
+	}
+
+	pub fn pop(&mut self, element: &NodeRef) {
+		each_element_hash(element, |hash| self.filter.remove_hash(hash));
+	}
+}
+
+impl Default for StyleBloom {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// The same hashes `AncestorHashes` takes from a selector: local name, namespace, id and classes.
+fn each_element_hash<F>(element: &NodeRef, mut f: F)
+where
+	F: FnMut(u32),
+{
+	f(element.local_name().precomputed_hash() & BLOOM_HASH_MASK);
+	f(element.namespace().precomputed_hash() & BLOOM_HASH_MASK);
+	if let Some(id) = element.get_attribute(&ns!(), &local_name!("id")) {
+		f(Ident(id.value().to_string()).precomputed_hash() & BLOOM_HASH_MASK);
+	}
+	if let Some(classes) = element
+		.get_attribute(&ns!(), &local_name!("class"))
+		.and_then(|attr| attr.as_tokens())
+	{
+		for class in classes {
+			f(Ident(class).precomputed_hash() & BLOOM_HASH_MASK);
+		}
+	}
+}
@@ -1,25 +1,37 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::hash::Hash;
 use std::rc::{Rc, Weak};
 
+use common::url::BrowserUrl;
 use common::{not_reached, not_supported};
-use css::computed_values::{ComputedValues, PropertyCascade, StyleContext};
+use css::computed_values::{CascadePriority, ComputedValues, PropertyCascade, StyleContext};
+use css::media_queries::device::Device;
 use css::media_queries::media_list::MediaList;
-use css::properties::declaration::{PropertyDeclaration, WideKeywordDeclaration};
+use css::properties::custom_properties::{self, CustomDeclarationValue, CustomPropertiesMap, Name};
+use css::properties::declaration::{CustomDeclaration, PropertyDeclaration, WideKeywordDeclaration};
 use css::properties::longhand_id::{LonghandId, LonghandIdPhaseIterator, PhaseOrder};
 use css::properties::longhands;
 use css::properties::longhands::display::{DisplayBasic, DisplayInside, DisplayOutside};
+use css::properties::longhands::font_size::DEFAULT_FONT_SIZE;
 use css::properties::property_id::CSSWideKeyword;
 use css::stylesheets::origin::Origin;
 use css::stylesheets::stylesheet::Stylesheet;
+use css::stylesheets::stylesheet_loader::{FilesystemStylesheetLoader, ImportContext};
 use css::stylist::Stylist;
+use css::values::CSSFloat;
 use dom::global_scope::{GlobalScope, NodeRef};
 use dom::node::SimpleNodeIterator;
 use dom::window::Window;
 use selectors::context::QuirksMode;
 
-use crate::applicable_declaration_block::{ApplicableDeclarationBlock, StyleSource};
+use crate::applicable_declaration_block::ApplicableDeclarationBlock;
+use crate::flow::multicol::MulticolContainer;
+use crate::flow::table::TablePart;
+use crate::fonts;
 use crate::rule_colectors::collect_rules;
+use crate::style_bloom::StyleBloom;
 
 /// https://chromium.googlesource.com/chromium/blink/+/refs/heads/main/Source/core/css/html.css
 /// https://trac.webkit.org/browser/trunk/Source/WebCore/css/html.css
@@ -33,9 +45,10 @@ pub struct StyleTree {
 
 impl StyleTree {
 	pub fn new(dom_node: NodeRef, quirks_mode: QuirksMode) -> Self {
+		let window = dom_node.window().unwrap();
 		Self {
-			window: dom_node.window().unwrap(),
-			stylist: RefCell::new(Stylist::new(quirks_mode)),
+			stylist: RefCell::new(Stylist::new(window.device(), quirks_mode)),
+			window,
 			root: Rc::new(StyleTreeNode::new(dom_node, None)),
 		}
 	}
@@ -50,79 +63,103 @@ impl StyleTree {
 
 	pub fn import_user_agent(&self) {
 		let content = include_str!("./html.css");
-		let stylesheet = Stylesheet::from_str(
+		let url = BrowserUrl::from_file_path(concat!(env!("CARGO_MANIFEST_DIR"), "/src/html.css")).ok();
+		let import_context = ImportContext::new(&FilesystemStylesheetLoader, url);
+		let stylesheet = Stylesheet::from_str_with_loader(
 			&content,
 			Origin::UserAgent,
 			Rc::new(MediaList::empty()),
+			Some(&import_context),
 			Some(self.window.error_reporter()),
 			selectors::context::QuirksMode::NoQuirks,
 			0,
 		);
 		self.stylist.borrow_mut().add_stylesheet(&stylesheet, Origin::UserAgent);
+		fonts::register_font_faces(self.stylist.borrow().font_faces());
 	}
 
 	pub fn add_stylesheet(&self, stylesheet: &Stylesheet) {
 		self.stylist.borrow_mut().add_stylesheet(&stylesheet, Origin::Author);
+		fonts::register_font_faces(self.stylist.borrow().font_faces());
+	}
+
+	pub fn add_user_stylesheet(&self, stylesheet: &Stylesheet) {
+		self.stylist.borrow_mut().add_stylesheet(&stylesheet, Origin::User);
+		fonts::register_font_faces(self.stylist.borrow().font_faces());
 	}
 
 	pub fn match_rules(&self) {
-		self.match_rule_for_node(self.root.clone());
+		let mut bloom = StyleBloom::new();
+		self.match_rule_for_node(self.root.clone(), &mut bloom);
 	}
 
-	fn match_rule_for_node(&self, style_node: Rc<StyleTreeNode>) {
-		let rules = collect_rules(style_node.dom_node.clone(), self.stylist());
+	fn match_rule_for_node(&self, style_node: Rc<StyleTreeNode>, bloom: &mut StyleBloom) {
+		let rules = collect_rules(style_node.dom_node.clone(), self.stylist(), bloom.filter());
 		*style_node.rules.borrow_mut() = rules;
 
+		bloom.push(&style_node.dom_node);
+
 		let mut dom_child = style_node.dom_node.first_child();
 		while let Some(noderef_child) = &dom_child {
 			let style_child = Rc::new(StyleTreeNode::new(noderef_child.clone(), Some(style_node.clone())));
 
 			style_node.append_child(style_child.clone());
 			if noderef_child.node_type_id().is_element() {
-				self.match_rule_for_node(style_child.clone());
+				self.match_rule_for_node(style_child.clone(), bloom);
 			}
 
 			dom_child = dom_child.map(|d| d.next_sibling()).flatten();
 		}
+		bloom.pop(&style_node.dom_node);
 	}
 
 	pub fn cascade(&self) {
-		self.cascade_node(self.root.clone(), &ComputedValues::default())
+		self.cascade_node(self.root.clone(), &ComputedValues::default(), None)
 	}
 
-	fn cascade_node(&self, style_node: Rc<StyleTreeNode>, parent_style: &ComputedValues) {
-		let mut author_data: HashMap<LonghandId, PropertyCascade> = HashMap::new();
-		let mut useragent_data: HashMap<LonghandId, PropertyCascade> = HashMap::new();
+	/// `root_font_size` is the font size of the root element, `None` while the root element is cascaded.
+	fn cascade_node(
+		&self,
+		style_node: Rc<StyleTreeNode>,
+		parent_style: &ComputedValues,
+		root_font_size: Option<CSSFloat>,
+	) {
+		let mut custom_cascade_data: HashMap<&Name, Vec<PropertyCascade>> = HashMap::new();
 		let rules = style_node.rules.borrow();
 		for declaration in rules.iter() {
-			let block = match &declaration.source {
-				StyleSource::StyleRule(style) => &style.block,
-				StyleSource::DeclarationBlock(block) => block,
-			};
-			for (importance, property) in block.properties() {
-				match declaration.origin {
-					Origin::UserAgent => {
-						cascade_in_origin(&mut useragent_data, property, importance, declaration.specificity);
-					},
-					Origin::Author => {
-						cascade_in_origin(&mut author_data, property, importance, declaration.specificity);
-					},
+			for (importance, property) in declaration.block().properties() {
+				if let PropertyDeclaration::Custom(custom) = property {
+					let priority = declaration.priority(importance);
+					cascade_in_origin(&mut custom_cascade_data, &custom.name, property, priority);
 				}
 			}
 		}
 		let mut computed_values = GlobalScope::get_or_init_computed_values(style_node.dom_node.id());
+		computed_values.set_custom_properties(cascade_custom_properties(
+			&custom_cascade_data,
+			parent_style.get_custom_properties(),
+		));
 		let mut context = StyleContext {
 			parent_style,
-			author_data,
-			useragent_data,
+			cascade_data: collect_cascade_data(&rules, None),
 			computed_values: &mut computed_values,
+			device: self.stylist().device(),
+			// `rem` in the font size of the root element refers to the initial font size
+			// https://drafts.csswg.org/css-values/#rem
+			root_font_size: root_font_size.unwrap_or(DEFAULT_FONT_SIZE),
 		};
 		apply_properties(LonghandId::ids(PhaseOrder::Early), &mut context);
+		let root_font_size = root_font_size.unwrap_or_else(|| context.computed_values.get_font_size());
+		context.root_font_size = root_font_size;
+		// the writing mode and the direction are known, so logical properties can join the cascade of the physical
+		// properties they map to
+		let cascade_data = collect_cascade_data(&rules, Some(&*context.computed_values));
+		context.cascade_data = cascade_data;
 		apply_properties(LonghandId::ids(PhaseOrder::Other), &mut context);
 
 		let mut child = style_node.first_child.borrow().as_ref().map(|n| n.clone());
 		while let Some(noderef) = child {
-			self.cascade_node(noderef.clone(), computed_values);
+			self.cascade_node(noderef.clone(), computed_values, Some(root_font_size));
 			child = if let Some(child) = noderef.next_sibling.borrow().as_ref() {
 				Some(child.clone())
 			} else {
@@ -218,13 +255,48 @@ impl StyleTreeNode {
 		let children_iter = self.get_visible_children_iter();
 		for child in children_iter {
 			let (outside, _) = child.get_display();
-			if outside != DisplayOutside::Inline {
+			// floats and absolutely positioned boxes are taken out of flow, inline content keeps flowing around them
+			if outside != DisplayOutside::Inline && !child.is_float() && !child.is_absolutely_positioned() {
 				return false;
 			}
 		}
 		true
 	}
 
+	/// https://www.w3.org/TR/CSS22/tables.html#table-display
+	pub fn get_table_part(&self) -> Option<TablePart> {
+		if self.dom_node.node_type_id().is_element() {
+			TablePart::from_display(GlobalScope::get_or_init_computed_values(self.dom_node.id()).get_display())
+		} else {
+			None
+		}
+	}
+
+	/// https://drafts.csswg.org/css-multicol/#the-multi-column-model
+	pub fn is_multicol_container(&self) -> bool {
+		self.dom_node.node_type_id().is_element()
+			&& MulticolContainer::is_multicol(GlobalScope::get_or_init_computed_values(self.dom_node.id()))
+	}
+
+	/// https://www.w3.org/TR/CSS22/visuren.html#absolute-positioning
+	pub fn is_absolutely_positioned(&self) -> bool {
+		self.dom_node.node_type_id().is_element()
+			&& matches!(
+				GlobalScope::get_or_init_computed_values(self.dom_node.id()).get_position(),
+				longhands::position::Position::Absolute | longhands::position::Position::Fixed
+			)
+	}
+
+	/// An absolutely positioned box doesn't float.
+	pub fn is_float(&self) -> bool {
+		self.dom_node.node_type_id().is_element()
+			&& !self.is_absolutely_positioned()
+			&& !matches!(
+				GlobalScope::get_or_init_computed_values(self.dom_node.id()).get_float(),
+				longhands::float::Float::None
+			)
+	}
+
 	pub fn get_display(&self) -> (DisplayOutside, DisplayInside) {
 		if self.dom_node.node_type_id().is_element() {
 			let computed_values = GlobalScope::get_or_init_computed_values(self.dom_node.id());
@@ -242,6 +314,23 @@ impl StyleTreeNode {
 				{
 					(DisplayOutside::Inline, DisplayInside::FlowRoot)
 				},
+				longhands::display::Display::Legacy(legacy)
+					if *legacy == longhands::display::DisplayLegacy::InlineFlex =>
+				{
+					(DisplayOutside::Inline, DisplayInside::Flex)
+				},
+				longhands::display::Display::Legacy(legacy)
+					if *legacy == longhands::display::DisplayLegacy::InlineGrid =>
+				{
+					(DisplayOutside::Inline, DisplayInside::Grid)
+				},
+				longhands::display::Display::Legacy(legacy)
+					if *legacy == longhands::display::DisplayLegacy::InlineTable =>
+				{
+					(DisplayOutside::Inline, DisplayInside::Table)
+				},
+				// the parts of a table are laid out by the table, see `get_table_part`
+				longhands::display::Display::Internal(_) => (DisplayOutside::Block, DisplayInside::FlowRoot),
 				_ => not_supported!(),
 			}
 		} else {
@@ -250,66 +339,160 @@ impl StyleTreeNode {
 	}
 }
 
-fn cascade_in_origin<'a, 'b>(
-	cascade_data: &'a mut HashMap<LonghandId, PropertyCascade<'b>>,
+/// The style of an anonymous box, which inherits the inherited properties from the box it is generated in and has the
+/// initial value of the others.
+/// https://www.w3.org/TR/CSS22/visuren.html#anonymous
+pub fn cascade_anonymous_box(computed_values: &mut ComputedValues, parent_style: &ComputedValues, device: &Device) {
+	computed_values.set_custom_properties(parent_style.get_custom_properties().cloned());
+	let mut context = StyleContext {
+		parent_style,
+		cascade_data: HashMap::new(),
+		computed_values,
+		device,
+		// an anonymous box has no declaration which could refer to the font size of the root element
+		root_font_size: DEFAULT_FONT_SIZE,
+	};
+	apply_properties(LonghandId::ids(PhaseOrder::Early), &mut context);
+	apply_properties(LonghandId::ids(PhaseOrder::Other), &mut context);
+}
+
+/// Keep the declaration with the highest priority of each origin, the winner across origins is
+/// only picked once `revert` can be resolved.
+fn cascade_in_origin<'a, 'b, K: Eq + Hash>(
+	cascade_data: &'a mut HashMap<K, Vec<PropertyCascade<'b>>>,
+	key: K,
 	property: &'b PropertyDeclaration,
-	importance: bool,
-	specificity: u32,
+	priority: CascadePriority,
 ) {
-	if let Some(cascade) = cascade_data.get(&property.longhand_id()) {
-		if (cascade.importance && !importance) || (cascade.specificity > specificity) {
-			return;
+	let cascades = cascade_data.entry(key).or_insert_with(Vec::new);
+	let origin = priority.level.origin();
+	match cascades
+		.iter_mut()
+		.find(|cascade| cascade.priority.level.origin() == origin)
+	{
+		Some(cascade) => {
+			if priority >= cascade.priority {
+				*cascade = PropertyCascade { priority, property };
+			}
+		},
+		None => cascades.push(PropertyCascade { priority, property }),
+	}
+}
+
+/// The winning declaration of each origin for every longhand. With `computed_values`, a logical property is cascaded
+/// under the physical property it maps to, so the later or more specific of the two wins.
+/// https://drafts.csswg.org/css-logical/#box
+fn collect_cascade_data<'a>(
+	rules: &'a [ApplicableDeclarationBlock],
+	computed_values: Option<&ComputedValues>,
+) -> HashMap<LonghandId, Vec<PropertyCascade<'a>>> {
+	let mut cascade_data = HashMap::new();
+	for declaration in rules.iter() {
+		for (importance, property) in declaration.block().properties() {
+			if let PropertyDeclaration::Custom(_) = property {
+				continue;
+			}
+			let longhand_id = match computed_values {
+				Some(computed_values) => property.longhand_id().to_physical(computed_values),
+				None => property.longhand_id(),
+			};
+			cascade_in_origin(
+				&mut cascade_data,
+				longhand_id,
+				property,
+				declaration.priority(importance),
+			);
 		}
 	}
-	cascade_data.insert(
-		property.longhand_id(),
-		PropertyCascade {
-			specificity,
-			importance,
-			property,
+	cascade_data
+}
+
+fn is_revert(property: &PropertyDeclaration) -> bool {
+	match property {
+		PropertyDeclaration::CSSWideKeyword(WideKeywordDeclaration { keyword, .. }) => {
+			*keyword == CSSWideKeyword::Revert
 		},
-	);
+		PropertyDeclaration::Custom(CustomDeclaration {
+			value: CustomDeclarationValue::CSSWideKeyword(keyword),
+			..
+		}) => *keyword == CSSWideKeyword::Revert,
+		_ => false,
+	}
+}
+
+/// Return the winning declaration across origins, from the highest priority. `revert` rolls back
+/// to the origin below, `Err(())` means every origin was reverted.
+///
+/// https://drafts.csswg.org/css-cascade-4/#default
+fn cascaded_value<'a, 'b, T, F>(cascades: Option<&'b Vec<PropertyCascade<'a>>>, mut map: F) -> Result<Option<T>, ()>
+where
+	F: FnMut(&'a PropertyDeclaration) -> T,
+	T: std::ops::Deref<Target = PropertyDeclaration>,
+{
+	let cascades = match cascades {
+		Some(cascades) => cascades,
+		None => return Ok(None),
+	};
+	let mut sorted: Vec<&PropertyCascade> = cascades.iter().collect();
+	sorted.sort_by(|a, b| b.priority.cmp(&a.priority));
+	for cascade in sorted {
+		let property = map(cascade.property);
+		if !is_revert(&property) {
+			return Ok(Some(property));
+		}
+	}
+	Err(())
 }
 
-fn get_declaration_from_useragent<'a>(
-	cascade_data: &HashMap<LonghandId, PropertyCascade<'a>>,
-	longhand_id: &LonghandId,
-	unset: &'a PropertyDeclaration,
-) -> Option<&'a PropertyDeclaration> {
-	let useragent_property = cascade_data.get(longhand_id).map(|cascade| cascade.property);
-	if let Some(property) = useragent_property {
-		Some(match property {
-			PropertyDeclaration::CSSWideKeyword(WideKeywordDeclaration { keyword, .. })
-				if *keyword == CSSWideKeyword::Revert =>
-			{
-				unset
+/// Custom properties cascade on their own, before any longhand, so var() functions
+/// can be substituted when longhands are applied.
+fn cascade_custom_properties<'a>(
+	custom_cascade_data: &HashMap<&'a Name, Vec<PropertyCascade<'a>>>,
+	inherited: Option<&Rc<CustomPropertiesMap>>,
+) -> Option<Rc<CustomPropertiesMap>> {
+	let mut declarations: HashMap<&Name, &CustomDeclarationValue> = HashMap::new();
+	for (name, cascades) in custom_cascade_data.iter() {
+		match cascaded_value(Some(cascades), |property| property) {
+			Ok(Some(PropertyDeclaration::Custom(custom))) => {
+				declarations.insert(*name, &custom.value);
 			},
-			value => value,
-		})
-	} else {
-		None
+			Ok(Some(_)) => not_reached!(),
+			// a reverted custom property behaves as if it isn't declared
+			Ok(None) | Err(()) => {},
+		}
+	}
+	custom_properties::cascade(inherited, declarations.into_iter())
+}
+
+fn substitute_variables<'a>(
+	property: &'a PropertyDeclaration,
+	custom_properties: Option<&CustomPropertiesMap>,
+) -> Cow<'a, PropertyDeclaration> {
+	match property {
+		PropertyDeclaration::WithVariables(declaration) => {
+			Cow::Owned(declaration.substitute_variables(custom_properties))
+		},
+		_ => Cow::Borrowed(property),
 	}
 }
 
 fn apply_properties<'a>(longhands_iter: LonghandIdPhaseIterator, context: &'a mut StyleContext) {
+	let custom_properties = context.computed_values.get_custom_properties().cloned();
 	for longhand_id in longhands_iter {
 		let unset = PropertyDeclaration::CSSWideKeyword(WideKeywordDeclaration {
 			id: longhand_id,
 			keyword: CSSWideKeyword::Unset,
 		});
-		let author_property = context.author_data.get(&longhand_id).map(|cascade| cascade.property);
-		let declaration = if let Some(property) = author_property {
-			match property {
-				PropertyDeclaration::CSSWideKeyword(WideKeywordDeclaration { keyword, .. })
-					if *keyword == CSSWideKeyword::Revert =>
-				{
-					get_declaration_from_useragent(&context.useragent_data, &longhand_id, &unset)
-				},
-				value => Some(value),
-			}
-		} else {
-			get_declaration_from_useragent(&context.useragent_data, &longhand_id, &unset)
+		let property = cascaded_value(context.cascade_data.get(&longhand_id), |property| {
+			substitute_variables(property, custom_properties.as_deref())
+		});
+		// reverting the user agent origin rolls back to the initial or inherited value
+		let declaration = match &property {
+			Ok(property) => property.as_deref(),
+			Err(()) => Some(&unset),
 		};
-		longhand_id.cascade(declaration, context)
+		// a logical declaration which wins the cascade of a physical property writes into it
+		let declared_id = declaration.map_or(longhand_id, |declaration| declaration.longhand_id());
+		declared_id.cascade(declaration, context)
 	}
 }
@@ -1,109 +1,402 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::fmt;
 use std::fs::File;
 use std::io::Read;
+use std::ops::Range;
+use std::sync::Arc;
 
+use css::properties::longhands::font_style::FontStyle;
+use cssparser::ToCss;
 use font_kit::family_name::FamilyName;
-use font_kit::properties::Properties;
+use font_kit::properties::{Properties, Style, Weight};
 use font_kit::source::SystemSource;
-use glyph_brush::ab_glyph::{Font, FontArc, GlyphId};
-use glyph_brush::{FontId, GlyphCruncher};
+use glyph_brush::ab_glyph::{Font, FontArc, GlyphId, ScaleFont};
+use once_cell::sync::Lazy;
+use rustybuzz::UnicodeBuffer;
 
-pub struct TextUI {
-	brush: RefCell<glyph_brush::GlyphBrush<()>>,
-	font_map: RefCell<HashMap<String, FontId>>,
-}
+use crate::fonts::{find_web_font, is_web_font_family, WebFont};
 
 pub const FALLBACK: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
 
+/// A font with the data it is parsed from, `name` is the family name it is loaded for. `web_font` is the @font-face
+/// rule it comes from, if any.
+#[derive(Clone)]
+pub struct LoadedFont {
+	pub name: String,
+	/// Tells the face apart from the other faces of its family, the serialized rule of a web font or the family name,
+	/// weight and style a system font is selected by.
+	pub key: String,
+	pub font: FontArc,
+	pub data: Arc<Vec<u8>>,
+	pub web_font: Option<Arc<WebFont>>,
+}
+
+impl fmt::Debug for LoadedFont {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("LoadedFont").field("name", &self.name).finish()
+	}
+}
+
+/// A glyph placed at `x` from the start of the text, `cluster` is the byte offset of the first character it comes
+/// from. Its offsets move it from the pen position without moving the glyphs after it.
+#[derive(Clone, Debug)]
+pub struct ShapedGlyph {
+	pub id: GlyphId,
+	pub cluster: usize,
+	pub x: f32,
+	pub x_offset: f32,
+	pub y_offset: f32,
+	pub advance: f32,
+}
+
+/// Consecutive characters which are shaped with the same font, `range` is their bytes in the text.
+#[derive(Clone, Debug)]
+pub struct GlyphRun {
+	pub font: LoadedFont,
+	pub font_size: f32,
+	pub range: Range<usize>,
+	pub ascent: f32,
+	pub height: f32,
+	pub glyphs: Vec<ShapedGlyph>,
+}
+
+impl GlyphRun {
+	/// A run which is shaped right to left has its glyphs in visual order, so their clusters go down.
+	pub fn is_rtl(&self) -> bool {
+		match (self.glyphs.first(), self.glyphs.last()) {
+			(Some(first), Some(last)) => first.cluster > last.cluster,
+			_ => false,
+		}
+	}
+}
+
+/// The metrics of a font at a size, the descent goes down from the baseline.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FontMetrics {
+	pub ascent: f32,
+	pub descent: f32,
+	pub line_gap: f32,
+}
+
+/// The metrics of the first available font of a family list at a font size, they are shared by every line box, inline
+/// box and atomic inline with the same font so the fonts are only loaded once.
+static mut FONT_METRICS: Lazy<HashMap<(Vec<String>, u32, u32, String), FontMetrics>> = Lazy::new(|| HashMap::new());
+
+/// The glyph runs of a text in visual order, they are measured and painted with the same advances.
+#[derive(Clone, Debug, Default)]
+pub struct ShapedText {
+	pub runs: Vec<GlyphRun>,
+	pub width: f32,
+	pub ascent: f32,
+	pub height: f32,
+}
+
+impl ShapedText {
+	/// The deepest descent of the fonts which the text is shaped with.
+	pub fn descent(&self) -> f32 {
+		self.runs
+			.iter()
+			.map(|run| run.height - run.ascent)
+			.fold(self.height - self.ascent, f32::max)
+	}
+
+	/// The advance of the glyphs which come from the characters in `range`.
+	pub fn width_of(&self, range: Range<usize>) -> f32 {
+		self.runs
+			.iter()
+			.flat_map(|run| run.glyphs.iter())
+			.filter(|glyph| range.contains(&glyph.cluster))
+			.map(|glyph| glyph.advance)
+			.sum()
+	}
+
+	/// The glyphs which come from the characters in `range`, placed from the start of the slice. Slicing keeps the
+	/// kerning and the ligatures of the whole text.
+	pub fn slice(&self, range: Range<usize>) -> ShapedText {
+		let mut sliced = ShapedText::default();
+		let mut x = 0.0;
+		for run in self.runs.iter() {
+			let mut glyphs = vec![];
+			for glyph in run.glyphs.iter().filter(|glyph| range.contains(&glyph.cluster)) {
+				glyphs.push(ShapedGlyph {
+					x,
+					cluster: glyph.cluster - range.start,
+					..glyph.clone()
+				});
+				x += glyph.advance;
+			}
+			if glyphs.is_empty() {
+				continue;
+			}
+			sliced.ascent = sliced.ascent.max(run.ascent);
+			sliced.height = sliced.height.max(run.height);
+			sliced.runs.push(GlyphRun {
+				font: run.font.clone(),
+				font_size: run.font_size,
+				range: run.range.start.max(range.start) - range.start..run.range.end.min(range.end) - range.start,
+				ascent: run.ascent,
+				height: run.height,
+				glyphs,
+			});
+		}
+		sliced.width = x;
+		sliced
+	}
+
+	/// The glyphs placed in the visual order of a bidi level run. The shaping direction is guessed from the script of a
+	/// run, so a run which is shaped in the other direction (e.g. overridden by `unicode-bidi`) has its glyphs reversed.
+	/// https://www.unicode.org/reports/tr9/#L2
+	pub fn to_visual_order(&self, is_rtl: bool) -> ShapedText {
+		let mut runs = self.runs.clone();
+		if is_rtl {
+			runs.reverse();
+		}
+		let mut x = 0.0;
+		for run in runs.iter_mut() {
+			if run.is_rtl() != is_rtl {
+				run.glyphs.reverse();
+			}
+			for glyph in run.glyphs.iter_mut() {
+				glyph.x = x;
+				x += glyph.advance;
+			}
+		}
+		ShapedText {
+			runs,
+			width: x,
+			ascent: self.ascent,
+			height: self.height,
+		}
+	}
+}
+
+/// Loads the faces of the font families which are the closest to `font_weight` and `font_style`.
+pub struct TextUI {
+	fonts: RefCell<Vec<LoadedFont>>,
+	font_map: RefCell<HashMap<String, usize>>,
+	font_weight: f32,
+	font_style: FontStyle,
+}
+
 impl TextUI {
 	pub fn new() -> Self {
+		Self::with_font_style(400.0, FontStyle::Normal)
+	}
+
+	pub fn with_font_style(font_weight: f32, font_style: FontStyle) -> Self {
 		let font = FontArc::try_from_slice(FALLBACK).expect("default font doesn't exist");
-		let measure_brush = glyph_brush::GlyphBrushBuilder::using_font(font).build();
 		Self {
-			brush: RefCell::new(measure_brush),
+			fonts: RefCell::new(vec![LoadedFont {
+				name: "fallback".to_string(),
+				key: "fallback".to_string(),
+				font,
+				data: Arc::new(FALLBACK.to_vec()),
+				web_font: None,
+			}]),
 			font_map: RefCell::new(Default::default()),
+			font_weight,
+			font_style,
 		}
 	}
 
 	pub fn measure_size<T: AsRef<str>>(&self, content: &str, family_names: &[T], font_size: f32) -> (f32, f32) {
-		self.measure_size_in_bounded(content, family_names, font_size, (f32::MAX, f32::MAX))
+		let shaped = self.shape(content, family_names, font_size);
+		(shaped.width, shaped.height)
+	}
+
+	/// Shapes every run of `content` with its font, so kerning, ligatures and the contextual forms of complex scripts
+	/// are applied. A run is shaped in the direction which is guessed from its script, the bidi reordering puts it
+	/// in the direction of its embedding level once the lines are laid out.
+	/// https://drafts.csswg.org/css-text/#shaping
+	pub fn shape<T: AsRef<str>>(&self, content: &str, family_names: &[T], font_size: f32) -> ShapedText {
+		let mut shaped = ShapedText::default();
+		for (range, font_index) in self.matching_fonts(content, family_names) {
+			let font = self.fonts.borrow()[font_index].clone();
+			let scaled = font.font.as_scaled(font_size);
+			let mut glyphs: Vec<ShapedGlyph> = vec![];
+			match rustybuzz::Face::from_slice(&font.data, 0) {
+				Some(face) => {
+					let mut buffer = UnicodeBuffer::new();
+					buffer.push_str(&content[range.clone()]);
+					buffer.guess_segment_properties();
+					let output = rustybuzz::shape(&face, &[], buffer);
+					// font units go up, CSS pixels go down
+					let (h_scale, v_scale) = (scaled.h_scale_factor(), scaled.v_scale_factor());
+					for (info, position) in output.glyph_infos().iter().zip(output.glyph_positions()) {
+						let advance = position.x_advance as f32 * h_scale;
+						glyphs.push(ShapedGlyph {
+							id: GlyphId(info.glyph_id as u16),
+							cluster: range.start + info.cluster as usize,
+							x: shaped.width,
+							x_offset: position.x_offset as f32 * h_scale,
+							y_offset: -position.y_offset as f32 * v_scale,
+							advance,
+						});
+						shaped.width += advance;
+					}
+				},
+				// a face which can't be shaped maps every character to a glyph, only kerning is applied
+				None => {
+					for (index, ch) in content[range.clone()].char_indices() {
+						let id = scaled.glyph_id(ch);
+						if let Some(previous) = glyphs.last_mut() {
+							let kerning = scaled.kern(previous.id, id);
+							previous.advance += kerning;
+							shaped.width += kerning;
+						}
+						let advance = scaled.h_advance(id);
+						glyphs.push(ShapedGlyph {
+							id,
+							cluster: range.start + index,
+							x: shaped.width,
+							x_offset: 0.0,
+							y_offset: 0.0,
+							advance,
+						});
+						shaped.width += advance;
+					}
+				},
+			}
+			let (ascent, height) = (scaled.ascent(), scaled.height());
+			shaped.ascent = shaped.ascent.max(ascent);
+			shaped.height = shaped.height.max(height);
+			shaped.runs.push(GlyphRun {
+				font,
+				font_size,
+				range,
+				ascent,
+				height,
+				glyphs,
+			});
+		}
+		shaped
+	}
+
+	/// The metrics of the first available font, which is the first family that can be loaded for a space.
+	/// https://drafts.csswg.org/css-fonts/#first-available-font
+	pub fn font_metrics<T: AsRef<str>>(&self, family_names: &[T], font_size: f32) -> FontMetrics {
+		let font_index = family_names
+			.iter()
+			.find_map(|family_name| self.load_font(family_name.as_ref(), ' '))
+			.unwrap_or(0);
+		let font = self.fonts.borrow()[font_index].font.clone();
+		let scaled = font.as_scaled(font_size);
+		FontMetrics {
+			ascent: scaled.ascent(),
+			descent: -scaled.descent(),
+			line_gap: scaled.line_gap(),
+		}
 	}
 
-	pub fn measure_size_in_bounded<T: AsRef<str>>(
-		&self,
-		content: &str,
-		family_names: &[T],
+	/// `font_metrics` looked up in the cache shared by every `TextUI`.
+	pub fn cached_font_metrics(
+		family_names: &[String],
 		font_size: f32,
-		bounds: (f32, f32),
-	) -> (f32, f32) {
-		let groups = self.matching_fonts(content, family_names);
-		let section = wgpu_glyph::Section {
-			text: groups
-				.iter()
-				.map(|(text, font_id)| wgpu_glyph::Text {
-					text,
-					scale: font_size.into(),
-					font_id: font_id.clone(),
-					extra: wgpu_glyph::Extra::default(),
-				})
-				.collect(),
-			bounds,
-			..Default::default()
-		};
-		if let Some(rect) = self.brush.borrow_mut().glyph_bounds(section) {
-			(rect.width(), rect.height())
-		} else {
-			(0.0, 0.0)
+		font_weight: f32,
+		font_style: &FontStyle,
+	) -> FontMetrics {
+		let key = (
+			family_names.to_vec(),
+			font_size.to_bits(),
+			font_weight.to_bits(),
+			font_style.to_css_string(),
+		);
+		unsafe {
+			if let Some(metrics) = FONT_METRICS.get(&key) {
+				return *metrics;
+			}
+			let metrics =
+				TextUI::with_font_style(font_weight, font_style.clone()).font_metrics(family_names, font_size);
+			FONT_METRICS.insert(key, metrics);
+			metrics
+		}
+	}
+
+	/// A registered web font can become the first available font of a family, the cached metrics are measured again.
+	pub fn clear_font_metrics() {
+		unsafe {
+			FONT_METRICS.clear();
 		}
 	}
 
 	/*
 	- for each character in text, finding a font in font names array (in that order)
-	  which (load that font if not loading yet, implementing cache system for font) supports that character
-	- construct array (element is [string slice, font])
-	  if current font is the same last array, modify last string slice
+	  which (load that font if not loading yet, implementing cache system for font) supports that character,
+	  the fallback font is used when none of them supports it
+	- construct array (element is [byte range, font])
+	  if the current font supports the character too, extend the last range
 	 */
-	pub fn matching_fonts<T: AsRef<str>>(&self, content: &str, family_names: &[T]) -> Vec<(String, FontId)> {
-		let mut latest_font = None;
-		let mut styles: Vec<(Vec<char>, FontId)> = Vec::with_capacity(1);
-		for ch in content.chars() {
-			if let Some(ref font) = latest_font {
-				if let Some((segment, _)) = styles.last_mut() {
-					if self.is_character_supported_by_font(ch, font) {
-						segment.push(ch);
-						continue;
-					}
+	pub fn matching_fonts<T: AsRef<str>>(&self, content: &str, family_names: &[T]) -> Vec<(Range<usize>, usize)> {
+		let mut runs: Vec<(Range<usize>, usize)> = Vec::with_capacity(1);
+		for (index, ch) in content.char_indices() {
+			let end = index + ch.len_utf8();
+			if let Some((range, font_index)) = runs.last_mut() {
+				if self.is_character_supported_by_font(ch, *font_index) {
+					range.end = end;
+					continue;
 				}
 			}
-			let font_id = self.load_font(family_names);
-			latest_font = self.get_font_in_brush(font_id);
-			styles.push((vec![ch], font_id))
+			let font_index = self.find_font(ch, family_names);
+			match runs.last_mut() {
+				Some((range, last_font_index)) if *last_font_index == font_index => range.end = end,
+				_ => runs.push((index..end, font_index)),
+			}
 		}
-		styles
-			.iter()
-			.map(|(text, font)| (text.iter().collect::<String>(), font.clone()))
-			.collect::<Vec<(String, FontId)>>()
+		runs
 	}
 
-	pub fn load_font<T: AsRef<str>>(&self, family_names: &[T]) -> FontId {
+	fn find_font<T: AsRef<str>>(&self, ch: char, family_names: &[T]) -> usize {
+		let mut first_font_index = None;
 		for family_name in family_names {
-			if let Some(font) = self.font_map.borrow().get(family_name.as_ref()) {
-				return font.clone();
-			}
-			if let Some(font) = self.load_system_font(family_name.as_ref()) {
-				return font;
+			if let Some(font_index) = self.load_font(family_name.as_ref(), ch) {
+				if self.is_character_supported_by_font(ch, font_index) {
+					return font_index;
+				}
+				first_font_index.get_or_insert(font_index);
 			}
 		}
-		FontId(0)
+		if self.is_character_supported_by_font(ch, 0) {
+			0
+		} else {
+			first_font_index.unwrap_or(0)
+		}
 	}
 
-	// TODO: support external font (via font-face)
-	pub fn load_external_font(_family_name: &str) -> Option<FontArc> {
-		todo!()
+	/// Faces registered by @font-face rules are preferred over system fonts, the face of a family which is used for `ch`
+	/// depends on its `unicode-range`.
+	pub fn load_font(&self, family_name: &str, ch: char) -> Option<usize> {
+		let web_font = find_web_font(family_name, self.font_weight, &self.font_style, ch);
+		let key = match &web_font {
+			Some(web_font) => web_font.key.clone(),
+			None => {
+				let font_style = self.font_style.to_css_string();
+				format!("{} {} {}", family_name, self.font_weight, font_style)
+			},
+		};
+		if let Some(font_index) = self.font_map.borrow().get(&key) {
+			return Some(*font_index);
+		}
+		let data = match &web_font {
+			Some(web_font) => web_font.bytes.clone(),
+			// a family defined by @font-face rules hides the installed family with the same name
+			None if is_web_font_family(family_name) => return None,
+			None => Arc::new(self.load_system_font(family_name)?),
+		};
+		let font = FontArc::try_from_vec(data.as_ref().clone()).ok()?;
+		let mut fonts = self.fonts.borrow_mut();
+		fonts.push(LoadedFont {
+			name: family_name.to_string(),
+			key: key.clone(),
+			font,
+			data,
+			web_font,
+		});
+		self.font_map.borrow_mut().insert(key, fonts.len() - 1);
+		Some(fonts.len() - 1)
 	}
 
-	pub fn load_system_font(&self, family_name: &str) -> Option<FontId> {
+	pub fn load_system_font(&self, family_name: &str) -> Option<Vec<u8>> {
 		let font_family = match family_name {
 			"serif" => FamilyName::Serif,
 			"sans-serif" => FamilyName::SansSerif,
@@ -113,40 +406,41 @@ impl TextUI {
 			_ => FamilyName::Title(family_name.to_string()),
 		};
 		let system_source = SystemSource::new();
-		let handle = system_source.select_best_match(&[font_family], &Properties::default());
-		let bytes = match handle {
+		let mut properties = Properties::new();
+		properties
+			.weight(Weight(self.font_weight))
+			.style(match self.font_style {
+				FontStyle::Normal => Style::Normal,
+				FontStyle::Italic => Style::Italic,
+				FontStyle::Oblique(_) => Style::Oblique,
+			});
+		let handle = system_source.select_best_match(&[font_family], &properties);
+		match handle {
 			Ok(value) => match value {
 				font_kit::handle::Handle::Path { path, .. } => {
 					let mut buf = Vec::new();
 					let mut reader = File::open(path).expect("Read font");
 					let _ = reader.read_to_end(&mut buf);
-					buf
+					Some(buf)
 				},
-				font_kit::handle::Handle::Memory { bytes, .. } => bytes.as_ref().clone(),
-			},
-			Err(_) => {
-				return None;
+				font_kit::handle::Handle::Memory { bytes, .. } => Some(bytes.as_ref().clone()),
 			},
-		};
-		if let Ok(font) = FontArc::try_from_vec(bytes) {
-			let font_id = self.brush.borrow_mut().add_font(font.clone());
-			self.font_map.borrow_mut().insert(family_name.to_string(), font_id);
-			Some(font_id)
-		} else {
-			None
+			Err(_) => None,
 		}
 	}
 
-	pub fn get_font_in_brush(&self, font_id: FontId) -> Option<FontArc> {
-		if let Some(font) = self.brush.borrow().fonts().get(font_id.0) {
-			Some(font.clone())
-		} else {
-			None
-		}
+	pub fn get_font(&self, font_index: usize) -> Option<LoadedFont> {
+		self.fonts.borrow().get(font_index).cloned()
 	}
 
-	// GlyphId 0 is a special glyph representing a missing character
-	pub fn is_character_supported_by_font(&self, ch: char, font: &FontArc) -> bool {
-		font.glyph_id(ch) != GlyphId(0)
+	// GlyphId 0 is a special glyph representing a missing character, a web font is only used in its `unicode-range`
+	pub fn is_character_supported_by_font(&self, ch: char, font_index: usize) -> bool {
+		let fonts = self.fonts.borrow();
+		let font = &fonts[font_index];
+		font.font.glyph_id(ch) != GlyphId(0)
+			&& font
+				.web_font
+				.as_ref()
+				.map_or(true, |web_font| web_font.rule.contains_char(ch))
 	}
 }
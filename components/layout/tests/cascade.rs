@@ -12,22 +12,32 @@ mod cascade {
 	pub mod border_top_color;
 	pub mod border_top_style;
 	pub mod border_top_width;
+	pub mod cascade_order;
 	pub mod color;
+	pub mod custom_properties;
 	pub mod display;
 	pub mod font_families;
 	pub mod font_size;
+	pub mod font_weight;
 	pub mod height;
+	pub mod layers;
 	pub mod margin_bottom;
+	pub mod margin_inline_start;
 	pub mod margin_left;
 	pub mod margin_right;
 	pub mod margin_top;
 	pub mod max_height;
 	pub mod max_width;
+	pub mod media;
 	pub mod min_height;
 	pub mod min_width;
+	pub mod opacity;
 	pub mod padding_bottom;
 	pub mod padding_left;
 	pub mod padding_right;
 	pub mod padding_top;
+	pub mod selector_matching;
+	pub mod text_align;
+	pub mod top;
 	pub mod width;
 }
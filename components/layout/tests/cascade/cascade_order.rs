@@ -0,0 +1,163 @@
+use std::rc::Rc;
+
+use css::values::specified::color::RGBA;
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, construct_tree_with_user_stylesheet, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn source_order() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test" class="a b"></p>"#,
+		r#"
+.b { color: blue; }
+.a { color: red; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn specificity_over_source_order() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test" class="a"></p>"#,
+		r#"
+#test { color: red; }
+p.a { color: blue; }
+p { color: green; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn style_attribute_over_specificity() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test" class="a" style="color: blue"></p>"#,
+		r#"
+#test.a { color: red; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(0, 0, 255));
+}
+
+#[test]
+#[serial]
+fn important_over_style_attribute() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test" style="color: blue"></p>"#,
+		r#"
+p { color: red !important; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn important_style_attribute() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test" style="color: blue !important"></p>"#,
+		r#"
+#test { color: red !important; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(0, 0, 255));
+}
+
+#[test]
+#[serial]
+fn important_over_specificity() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+p { color: red !important; }
+#test { color: blue; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn user_over_user_agent() {
+	let tree = Rc::new(construct_tree_with_user_stylesheet(
+		r#"<p id="test"></p>"#,
+		r#"
+html { color: red; }
+        "#,
+		r#""#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn author_over_user() {
+	let tree = Rc::new(construct_tree_with_user_stylesheet(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { color: red; }
+        "#,
+		r#"
+p { color: blue; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(0, 0, 255));
+}
+
+#[test]
+#[serial]
+fn user_important_over_author_important() {
+	let tree = Rc::new(construct_tree_with_user_stylesheet(
+		r#"<p id="test" style="color: green !important"></p>"#,
+		r#"
+p { color: red !important; }
+        "#,
+		r#"
+#test { color: blue !important; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn revert_to_user() {
+	let tree = Rc::new(construct_tree_with_user_stylesheet(
+		r#"<p id="test"></p>"#,
+		r#"
+p { color: green; }
+        "#,
+		r#"
+#test { color: revert; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(0, 128, 0));
+}
@@ -0,0 +1,136 @@
+use std::rc::Rc;
+
+use css::values::computed::length::{LengthPercentage, LengthPercentageOrAuto, Size};
+use css::values::generics::number::NonNegative;
+use css::values::specified::color::RGBA;
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn from_author() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test"></div>"#,
+		r#"
+#test { --size: 100px; width: var(--size); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(100.0)))
+	);
+}
+
+#[test]
+#[serial]
+fn inherited() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test1"><div id="test2"></div></div>"#,
+		r#"
+#test1 { --primary: red; }
+#test2 { color: var(--primary); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test2").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn override_inherited() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test1"><div id="test2"></div></div>"#,
+		r#"
+#test1 { --size: 100px; --double: calc(var(--size) * 2); }
+#test2 { --size: 20px; width: var(--double); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test2").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(200.0)))
+	);
+}
+
+#[test]
+#[serial]
+fn fallback() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test"></div>"#,
+		r#"
+#test { width: var(--missing, var(--also-missing, 50px)); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(50.0)))
+	);
+}
+
+#[test]
+#[serial]
+fn cycle() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test"></div>"#,
+		r#"
+#test { --a: var(--b); --b: var(--a); --c: var(--a, 10px); width: var(--a, 30px); height: var(--c); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(30.0)))
+	);
+	assert_eq!(
+		computed_values.get_height().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(10.0)))
+	);
+}
+
+#[test]
+#[serial]
+fn invalid_at_computed_value_time() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test1"><div id="test2"></div></div>"#,
+		r#"
+#test1 { color: red; }
+#test2 { --size: 10px; color: var(--size); width: var(--missing); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test2").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+	assert_eq!(computed_values.get_width().clone(), Size::Auto);
+}
+
+#[test]
+#[serial]
+fn shorthand() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test"></div>"#,
+		r#"
+#test { --vertical: 10px; margin: var(--vertical) 20px; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_margin_top().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(10.0))
+	);
+	assert_eq!(
+		computed_values.get_margin_left().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(20.0))
+	);
+}
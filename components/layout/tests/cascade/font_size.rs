@@ -68,3 +68,19 @@ fn inherited() {
 	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
 	assert_eq!(computed_values.get_font_size(), 24.0);
 }
+
+#[test]
+#[serial]
+fn root_font_relative() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test1"><span id="test2">Totoland</span></p>"#,
+		r#"
+html { font-size: 2rem; }
+#test1 { font-size: 10px; }
+#test2 { font-size: 0.5rem; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test2").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_font_size(), DEFAULT_FONT_SIZE);
+}
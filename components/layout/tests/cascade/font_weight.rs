@@ -0,0 +1,46 @@
+use std::rc::Rc;
+
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn default() {
+	let tree = Rc::new(construct_tree(r#"<p id="test"></p>"#, r#""#));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_font_weight(), 400.0);
+}
+
+#[test]
+#[serial]
+fn bolder() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test1"><span id="test2">Totoland</span></p>"#,
+		r#"
+#test1 { font-weight: 300; }
+#test2 { font-weight: bolder; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test2").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_font_weight(), 400.0);
+}
+
+#[test]
+#[serial]
+fn inherited() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test1"><span id="test2">Totoland</span></p>"#,
+		r#"
+#test1 { font-weight: bold; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test2").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_font_weight(), 700.0);
+}
@@ -0,0 +1,101 @@
+use std::rc::Rc;
+
+use css::values::specified::color::RGBA;
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn later_layer_wins() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+@layer theme, base;
+@layer theme {
+	p { color: red; }
+}
+@layer base {
+	#test { color: blue; }
+}
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(0, 0, 255));
+}
+
+#[test]
+#[serial]
+fn layer_over_specificity() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+@layer base {
+	#test { color: blue; }
+}
+@layer theme {
+	p { color: red; }
+}
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn unlayered_over_layered() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+p { color: red; }
+@layer base {
+	#test { color: blue; }
+}
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn important_reverses_layer_order() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+@layer base {
+	p { color: red !important; }
+}
+@layer theme {
+	p { color: blue !important; }
+}
+#test { color: green !important; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+}
+
+#[test]
+#[serial]
+fn style_attribute_over_layers() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test" style="color: blue"></p>"#,
+		r#"
+@layer base {
+	#test { color: red; }
+}
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(0, 0, 255));
+}
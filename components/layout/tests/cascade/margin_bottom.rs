@@ -16,7 +16,7 @@ fn default() {
 	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
 	assert_eq!(
 		computed_values.get_margin_bottom().clone(),
-		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(0.0))
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(16.0))
 	);
 }
 
@@ -0,0 +1,99 @@
+use std::rc::Rc;
+
+use css::values::computed::length::{LengthPercentage, LengthPercentageOrAuto};
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn ltr() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { margin-inline-start: 10px; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_margin_left().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(10.0))
+	);
+}
+
+#[test]
+#[serial]
+fn rtl() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { direction: rtl; margin-inline-start: 10px; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_margin_right().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(10.0))
+	);
+	assert_eq!(
+		computed_values.get_margin_left().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(0.0))
+	);
+}
+
+#[test]
+#[serial]
+fn overridden_by_later_physical() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { margin-inline-start: 10px; margin-left: 20px; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_margin_left().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(20.0))
+	);
+}
+
+#[test]
+#[serial]
+fn overrides_earlier_physical() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { margin-left: 20px; margin-inline-start: 10px; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_margin_left().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(10.0))
+	);
+}
+
+#[test]
+#[serial]
+fn more_specific_physical_wins() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { margin-left: 20px; }
+p { margin-inline-start: 10px; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_margin_left().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(20.0))
+	);
+}
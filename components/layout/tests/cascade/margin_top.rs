@@ -2,6 +2,7 @@ use std::rc::Rc;
 
 use css::values::computed::length::{LengthPercentage, LengthPercentageOrAuto};
 use dom::global_scope::GlobalScope;
+use dom::window;
 use serial_test::serial;
 use setup::{construct_tree, find_dom};
 
@@ -16,7 +17,7 @@ fn default() {
 	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
 	assert_eq!(
 		computed_values.get_margin_top().clone(),
-		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(0.0))
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(16.0))
 	);
 }
 
@@ -53,3 +54,39 @@ fn non_inherited() {
 		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(0.0))
 	);
 }
+
+#[test]
+#[serial]
+fn font_relative() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="parent"><p id="test"></p></div>"#,
+		r#"
+#parent { font-size: 10px; }
+#test { font-size: 2em; margin-top: 1.5em; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_font_size(), 20.0);
+	assert_eq!(
+		computed_values.get_margin_top().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(30.0))
+	);
+}
+
+#[test]
+#[serial]
+fn viewport_percentage() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { margin-top: 10vw; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_margin_top().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(window::DEFAULT_WIDTH * 0.1))
+	);
+}
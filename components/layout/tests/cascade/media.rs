@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use css::values::computed::length::{LengthPercentage, Size};
+use css::values::generics::number::NonNegative;
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn matching_media() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test"></div>"#,
+		r#"
+#test { width: 50px; }
+@media screen and (min-width: 1000px) {
+	#test { width: 100px; }
+}
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(100.0)))
+	);
+}
+
+#[test]
+#[serial]
+fn not_matching_media() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test"></div>"#,
+		r#"
+#test { width: 50px; }
+@media (max-width: 600px) {
+	#test { width: 100px; }
+}
+@media print {
+	#test { width: 200px; }
+}
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(50.0)))
+	);
+}
@@ -0,0 +1,62 @@
+use std::rc::Rc;
+
+use css::values::specified::color::RGBA;
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn descendant_of_ancestor() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="outer" class="card"><section><p id="test"></p></section></div>"#,
+		r#"
+.card section > p { color: red; }
+#outer p { background-color: blue; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+	assert_eq!(
+		computed_values.get_background_color().clone(),
+		RGBA::from_rgb(0, 0, 255)
+	);
+}
+
+#[test]
+#[serial]
+fn sibling_is_not_an_ancestor() {
+	let tree = Rc::new(construct_tree(
+		r#"<div><div class="card"></div><p id="test"></p></div>"#,
+		r#"
+p { color: blue; }
+.card p { color: red; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(0, 0, 255));
+}
+
+#[test]
+#[serial]
+fn universal_and_pseudo_class_rules() {
+	let tree = Rc::new(construct_tree(
+		r#"<div class="card"><p id="test" class="note"></p></div>"#,
+		r#"
+* { color: red; }
+.card :first-child { background-color: blue; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_color().clone(), RGBA::from_rgb(255, 0, 0));
+	assert_eq!(
+		computed_values.get_background_color().clone(),
+		RGBA::from_rgb(0, 0, 255)
+	);
+}
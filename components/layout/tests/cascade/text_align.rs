@@ -0,0 +1,46 @@
+use std::rc::Rc;
+
+use css::properties::longhands::text_align::TextAlign;
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn default() {
+	let tree = Rc::new(construct_tree(r#"<p id="test"></p>"#, r#""#));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert!(matches!(computed_values.get_text_align(), TextAlign::Start));
+}
+
+#[test]
+#[serial]
+fn from_author() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { text-align: center; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert!(matches!(computed_values.get_text_align(), TextAlign::Center));
+}
+
+#[test]
+#[serial]
+fn inherited() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test1"><span id="test2">Totoland</span></p>"#,
+		r#"
+#test1 { text-align: right; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test2").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert!(matches!(computed_values.get_text_align(), TextAlign::Right));
+}
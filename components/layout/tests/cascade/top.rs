@@ -0,0 +1,35 @@
+use std::rc::Rc;
+
+use css::values::computed::length::{LengthPercentage, LengthPercentageOrAuto};
+use dom::global_scope::GlobalScope;
+use serial_test::serial;
+use setup::{construct_tree, find_dom};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn default() {
+	let tree = Rc::new(construct_tree(r#"<p id="test"></p>"#, r#""#));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(computed_values.get_top().clone(), LengthPercentageOrAuto::Auto);
+}
+
+#[test]
+#[serial]
+fn from_author() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { position: relative; top: 2em; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_top().clone(),
+		LengthPercentageOrAuto::LengthPercentage(LengthPercentage::AbsoluteLength(32.0))
+	);
+}
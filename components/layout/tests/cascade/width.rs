@@ -48,3 +48,72 @@ fn non_inherited() {
 	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
 	assert_eq!(computed_values.get_width().clone(), Size::Auto);
 }
+
+#[test]
+#[serial]
+fn from_author_calc_without_percentage() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { width: calc(10px + 2 * 10px); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(30.0)))
+	);
+}
+
+#[test]
+#[serial]
+fn from_author_calc_with_font_relative_length() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { font-size: 20px; width: calc(2em + 10px); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(50.0)))
+	);
+}
+
+#[test]
+#[serial]
+fn from_author_clamp_with_font_relative_length() {
+	let tree = Rc::new(construct_tree(
+		r#"<p id="test"></p>"#,
+		r#"
+#test { width: clamp(1em, 10px, 3ch); }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(16.0)))
+	);
+}
+
+#[test]
+#[serial]
+fn from_author_with_root_font_relative_length() {
+	let tree = Rc::new(construct_tree(
+		r#"<div id="test"></div>"#,
+		r#"
+html { font-size: 10px; }
+#test { font-size: 20px; width: 2rem; }
+        "#,
+	));
+	let dom = find_dom(&tree, "test").unwrap();
+	let computed_values = GlobalScope::get_or_init_computed_values(dom.id());
+	assert_eq!(
+		computed_values.get_width().clone(),
+		Size::LengthPercentage(NonNegative(LengthPercentage::AbsoluteLength(20.0)))
+	);
+}
@@ -1,4 +1,17 @@
 mod flow {
+	pub mod bidi;
 	pub mod block;
+	pub mod box_sizing;
+	pub mod flex;
+	pub mod float;
+	pub mod grid;
+	pub mod height;
 	pub mod inline;
+	pub mod intrinsic;
+	pub mod line_height;
+	pub mod multicol;
+	pub mod position;
+	pub mod table;
+	pub mod text;
+	pub mod text_align;
 }
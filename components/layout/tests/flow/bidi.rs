@@ -0,0 +1,88 @@
+use css::values::Pixel;
+use layout::flow::bidi::visual_order;
+use layout::flow::boxes::Box;
+use layout::flow::fragment::Fragment;
+use layout::flow::tree::BoxTree;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+fn get_x(tree: &BoxTree, id: &str) -> Pixel {
+	find_box(tree, id).unwrap().as_inline_level_box().fragments()[0]
+		.borrow()
+		.x()
+}
+
+#[test]
+fn sequences_are_reversed_from_the_highest_level() {
+	assert_eq!(visual_order(&[0, 1, 1, 2, 0]), vec![0, 3, 2, 1, 4]);
+	assert_eq!(visual_order(&[2, 2]), vec![0, 1]);
+	assert_eq!(visual_order(&[1, 2, 2, 1]), vec![3, 1, 2, 0]);
+}
+
+#[test]
+#[serial]
+fn left_to_right_content_keeps_its_order() {
+	let tree = construct_tree(r#"<div><span id="a">abc</span><span id="b">!</span></div>"#, r#""#);
+	assert!(get_x(&tree, "a") < get_x(&tree, "b"));
+}
+
+#[test]
+#[serial]
+fn right_to_left_text_is_reversed() {
+	let tree = construct_tree(r#"<div><span id="a">שלום</span> <span id="b">עולם</span></div>"#, r#""#);
+	assert!(get_x(&tree, "b") < get_x(&tree, "a"));
+}
+
+#[test]
+#[serial]
+fn neutrals_take_the_paragraph_direction() {
+	let tree = construct_tree(
+		r#"<div dir="rtl"><span id="a">abc</span><span id="b">!</span></div>"#,
+		r#""#,
+	);
+	assert!(get_x(&tree, "b") < get_x(&tree, "a"));
+}
+
+#[test]
+#[serial]
+fn auto_direction_comes_from_the_first_strong_character() {
+	let tree = construct_tree(
+		r#"<div dir="auto"><span id="a">שלום</span><span id="b">!</span></div>"#,
+		r#""#,
+	);
+	assert!(get_x(&tree, "b") < get_x(&tree, "a"));
+
+	let tree = construct_tree(r#"<div><span id="a">שלום</span><span id="b">!</span></div>"#, r#""#);
+	assert!(get_x(&tree, "a") < get_x(&tree, "b"));
+}
+
+#[test]
+#[serial]
+fn bdo_overrides_the_direction_of_its_text() {
+	let tree = construct_tree(r#"<div><bdo id="t" dir="rtl">abc</bdo></div>"#, r#""#);
+	let node = find_box(&tree, "t").unwrap();
+	let children = node.children();
+	let fragments = children[0].as_text_run().fragments();
+	let fragment = fragments[0].borrow();
+	assert_eq!(fragment.bidi_level, 1);
+	assert_eq!(fragment.shaped_text.runs[0].glyphs[0].cluster, 2);
+}
+
+#[test]
+#[serial]
+fn text_fragments_are_split_at_level_changes() {
+	let tree = construct_tree(r#"<div id="t">abc שלום</div>"#, r#""#);
+	let node = find_box(&tree, "t").unwrap();
+	let children = node.children();
+	let fragments = children[0].as_text_run().fragments();
+	let contents: Vec<String> = fragments
+		.iter()
+		.map(|fragment| fragment.borrow().content.clone())
+		.collect();
+	assert_eq!(contents, vec!["abc ", "שלום"]);
+	assert_eq!(fragments[1].borrow().bidi_level, 1);
+}
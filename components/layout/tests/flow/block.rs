@@ -41,7 +41,9 @@ fn block_level_with_auto_width_and_include_margin_padding() {
 	assert_eq!(fragment.width(), Pixel::new(window::DEFAULT_WIDTH - 300.0));
 	assert_eq!(fragment.height(), Pixel::new(height));
 	assert_eq!(fragment.x(), PIXEL_ZERO);
-	assert_eq!(fragment.y(), PIXEL_ZERO);
+	// the top margin collapses with the one of body, the border box stays at the top of body's content box
+	assert_eq!(fragment.y(), Pixel::new(-50.0));
+	assert_eq!(fragment.rect_y(), Pixel::new(100.0));
 }
 
 #[test]
@@ -62,6 +64,45 @@ fn block_level_with_percentage_width() {
 	assert_eq!(fragment.width(), Pixel::new(window::DEFAULT_WIDTH * 0.6));
 }
 
+#[test]
+#[serial]
+fn block_level_with_calc_width() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world</div>"#,
+		r#"#test { width: calc(100% - 20px); }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	let fragment = node.as_block_level_box().fragment();
+	assert_eq!(fragment.width(), Pixel::new(window::DEFAULT_WIDTH - 20.0));
+}
+
+#[test]
+#[serial]
+fn block_level_with_relative_calc_width() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world</div>"#,
+		r#"#test { width: calc(50vw - 1em); }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	let fragment = node.as_block_level_box().fragment();
+	assert_eq!(
+		fragment.width(),
+		Pixel::new(window::DEFAULT_WIDTH * 0.5 - DEFAULT_FONT_SIZE)
+	);
+}
+
+#[test]
+#[serial]
+fn block_level_with_clamp_width() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world</div>"#,
+		r#"#test { width: clamp(100px, 50%, 200px); }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	let fragment = node.as_block_level_box().fragment();
+	assert_eq!(fragment.width(), Pixel::new(200.0));
+}
+
 #[test]
 #[serial]
 fn block_level_with_auto_width_left_right_auto_to_zero() {
@@ -157,7 +198,7 @@ fn block_level_with_auto_height_with_margin_padding_children() {
 	);
 	let node = find_box(&tree, "test").unwrap();
 	let fragment = node.as_block_level_box().fragment();
-	assert_eq!(fragment.height(), Pixel::new(150.0));
+	assert_eq!(fragment.height(), Pixel::new(140.0));
 }
 
 #[test]
@@ -262,7 +303,7 @@ fn block_level_as_second_child_position() {
 	let node = find_box(&tree, "test2").unwrap();
 	let fragment = node.as_block_level_box().fragment();
 	assert_eq!(fragment.x(), Pixel::new(0.0));
-	assert_eq!(fragment.y(), Pixel::new(90.0));
+	assert_eq!(fragment.y(), Pixel::new(75.0));
 }
 
 #[test]
@@ -371,3 +412,112 @@ fn block_box_contains_multilines_with_inline_block() {
 	assert_eq!(second_line.width(), Pixel::new(200.0));
 	assert_eq!(second_line.y(), Pixel::new(height1));
 }
+
+#[test]
+#[serial]
+fn adjacent_sibling_margins_collapse() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#a { height: 10px; margin-bottom: 20px; }
+		#b { height: 10px; margin-top: 30px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().rect_y(), Pixel::new(40.0));
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(50.0));
+}
+
+#[test]
+#[serial]
+fn negative_margins_collapse() {
+	let tree = construct_tree(
+		r#"<div id="a"></div><div id="b"></div><div id="c"></div>"#,
+		r#"#a, #b, #c { height: 10px; }
+		#a { margin-bottom: 20px; }
+		#b { margin-top: -5px; margin-bottom: -10px; }
+		#c { margin-top: -5px; }"#,
+	);
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().rect_y(), Pixel::new(25.0));
+	assert_eq!(c.as_block_level_box().fragment().rect_y(), Pixel::new(25.0));
+}
+
+#[test]
+#[serial]
+fn parent_and_first_child_margins_collapse() {
+	let tree = construct_tree(
+		r#"<div id="before"></div><div id="container"><div id="a"></div></div>"#,
+		r#"#before { height: 10px; }
+		#container { margin-top: 10px; }
+		#a { height: 10px; margin-top: 30px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let a = find_box(&tree, "a").unwrap();
+	assert_eq!(container.as_block_level_box().fragment().rect_y(), Pixel::new(40.0));
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(10.0));
+	assert_eq!(a.as_block_level_box().fragment().rect_y(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn padding_border_and_flow_root_prevent_collapsing_with_first_child() {
+	for style in ["padding-top: 1px", "border-top: 1px solid", "display: flow-root"].iter() {
+		let tree = construct_tree(
+			r#"<div id="before"></div><div id="container"><div id="a"></div></div>"#,
+			&format!(
+				r#"#before {{ height: 10px; }}
+				#container {{ margin-top: 10px; {} }}
+				#a {{ height: 10px; margin-top: 30px; }}"#,
+				style
+			),
+		);
+		let container = find_box(&tree, "container").unwrap();
+		let a = find_box(&tree, "a").unwrap();
+		let container_fragment = container.as_block_level_box().fragment();
+		assert_eq!(container_fragment.y() + container_fragment.margin.top, Pixel::new(20.0));
+		assert_eq!(a.as_block_level_box().fragment().rect_y(), Pixel::new(30.0));
+	}
+}
+
+#[test]
+#[serial]
+fn parent_and_last_child_margins_collapse() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div></div><div id="after"></div>"#,
+		r#"#a { height: 10px; margin-bottom: 30px; }
+		#after { height: 10px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let after = find_box(&tree, "after").unwrap();
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(10.0));
+	assert_eq!(after.as_block_level_box().fragment().rect_y(), Pixel::new(40.0));
+}
+
+#[test]
+#[serial]
+fn fixed_height_prevents_collapsing_with_last_child() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div></div><div id="after"></div>"#,
+		r#"#container { height: 10px; }
+		#a { height: 10px; margin-bottom: 30px; }
+		#after { height: 10px; }"#,
+	);
+	let after = find_box(&tree, "after").unwrap();
+	assert_eq!(after.as_block_level_box().fragment().rect_y(), Pixel::new(10.0));
+}
+
+#[test]
+#[serial]
+fn empty_block_margins_collapse_through() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="empty"></div><div id="b"></div></div>"#,
+		r#"#a { height: 10px; margin-bottom: 10px; }
+		#empty { margin: 20px 0 15px; }
+		#b { height: 10px; margin-top: 5px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().rect_y(), Pixel::new(30.0));
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(40.0));
+}
@@ -0,0 +1,124 @@
+use css::values::Pixel;
+use serial_test::serial;
+
+use self::setup::{construct_tree, get_layout_info};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn border_box_width_and_height_include_the_padding() {
+	let tree = construct_tree(
+		r#"<div id="a"></div><div id="b"></div>"#,
+		r#"
+div { width: 200px; height: 100px; padding: 10px 20px; }
+#a { box-sizing: border-box; }
+        "#,
+	);
+	let layout_info = get_layout_info(&tree, "a").unwrap();
+	assert_eq!(layout_info.width, Pixel::new(160.0));
+	assert_eq!(layout_info.height, Pixel::new(80.0));
+	let layout_info = get_layout_info(&tree, "b").unwrap();
+	assert_eq!(layout_info.width, Pixel::new(200.0));
+	assert_eq!(layout_info.height, Pixel::new(100.0));
+}
+
+#[test]
+#[serial]
+fn border_box_percentage_and_min_max_widths() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="a"></div><div id="b"></div></div>"#,
+		r#"
+* { box-sizing: border-box; }
+#parent { width: 400px; }
+#a { width: 50%; padding: 0 10px; }
+#b { max-width: 100px; padding: 0 10px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().width, Pixel::new(180.0));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().width, Pixel::new(80.0));
+}
+
+#[test]
+#[serial]
+fn border_box_is_never_negative() {
+	let tree = construct_tree(
+		r#"<div id="test"></div>"#,
+		r#"#test { box-sizing: border-box; width: 10px; padding: 0 20px; }"#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().width, Pixel::new(0.0));
+}
+
+#[test]
+#[serial]
+fn border_box_inline_block() {
+	let tree = construct_tree(
+		r#"<div><span id="test"></span></div>"#,
+		r#"#test { display: inline-block; box-sizing: border-box; width: 100px; height: 50px; padding: 5px; }"#,
+	);
+	let layout_info = get_layout_info(&tree, "test").unwrap();
+	assert_eq!(layout_info.width, Pixel::new(90.0));
+	assert_eq!(layout_info.height, Pixel::new(40.0));
+}
+
+#[test]
+#[serial]
+fn aspect_ratio_gives_the_height_from_the_width() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="a"></div><div id="b"></div></div>"#,
+		r#"
+#parent { width: 400px; }
+#a { aspect-ratio: 2 / 1; }
+#b { aspect-ratio: 1; width: 100px; padding: 10px; box-sizing: border-box; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().height, Pixel::new(200.0));
+	let layout_info = get_layout_info(&tree, "b").unwrap();
+	assert_eq!(layout_info.width, Pixel::new(80.0));
+	assert_eq!(layout_info.height, Pixel::new(80.0));
+}
+
+#[test]
+#[serial]
+fn aspect_ratio_gives_the_width_from_a_fixed_height() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="test"></div></div>"#,
+		r#"
+#parent { width: 400px; }
+#test { aspect-ratio: 2; height: 50px; max-width: 80px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().width, Pixel::new(80.0));
+}
+
+#[test]
+#[serial]
+fn aspect_ratio_height_is_clamped() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="a"></div><div id="b"></div></div>"#,
+		r#"
+#parent { width: 400px; }
+#a { aspect-ratio: 1; max-height: 100px; }
+#b { aspect-ratio: 10; min-height: 60px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().height, Pixel::new(100.0));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().height, Pixel::new(60.0));
+}
+
+#[test]
+#[serial]
+fn aspect_ratio_height_grows_with_the_content() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="a"><div class="content"></div></div><div id="b"><div class="content"></div></div></div>"#,
+		r#"
+#parent { width: 400px; }
+#a, #b { width: 100px; aspect-ratio: 4; }
+#b { overflow: hidden; }
+.content { height: 50px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().height, Pixel::new(50.0));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().height, Pixel::new(25.0));
+}
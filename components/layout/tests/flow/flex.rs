@@ -0,0 +1,196 @@
+use css::values::{Pixel, PIXEL_ZERO};
+use layout::flow::fragment::Fragment;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn flex_grow_distributes_free_space() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; width: 600px; }
+		#a { width: 100px; flex-grow: 1; }
+		#b { width: 100px; flex-grow: 3; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(200.0));
+	assert_eq!(b.as_block_level_box().fragment().width(), Pixel::new(400.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn flex_shorthand_ignores_width() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; width: 600px; }
+		#a { width: 500px; flex: 1; min-width: 0; }
+		#b { flex: 2; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(200.0));
+	assert_eq!(b.as_block_level_box().fragment().width(), Pixel::new(400.0));
+}
+
+#[test]
+#[serial]
+fn flex_shrink_is_scaled_by_base_size() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; width: 300px; }
+		#a { width: 200px; min-width: 0; }
+		#b { width: 200px; min-width: 0; flex-shrink: 3; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(175.0));
+	assert_eq!(b.as_block_level_box().fragment().width(), Pixel::new(125.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(175.0));
+}
+
+#[test]
+#[serial]
+fn flex_shrink_stops_at_min_width() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; width: 300px; }
+		#a { width: 200px; min-width: 180px; }
+		#b { width: 200px; min-width: 0; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(180.0));
+	assert_eq!(b.as_block_level_box().fragment().width(), Pixel::new(120.0));
+}
+
+#[test]
+#[serial]
+fn justify_content_center() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; width: 600px; justify-content: center; }
+		#a, #b { width: 100px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(200.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(300.0));
+}
+
+#[test]
+#[serial]
+fn justify_content_space_between() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { display: flex; width: 600px; justify-content: space-between; }
+		#a, #b, #c { width: 100px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(250.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(500.0));
+}
+
+#[test]
+#[serial]
+fn align_items_stretch_and_center() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; width: 600px; height: 200px; }
+		#a { width: 100px; height: 50px; align-self: center; }
+		#b { width: 100px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().y(), Pixel::new(75.0));
+	assert_eq!(a.as_block_level_box().fragment().height(), Pixel::new(50.0));
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().height(), Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn flex_wrap_breaks_lines() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { display: flex; flex-wrap: wrap; width: 250px; }
+		#a, #b, #c { width: 100px; height: 50px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().y(), Pixel::new(50.0));
+}
+
+#[test]
+#[serial]
+fn flex_direction_column() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; flex-direction: column; width: 400px; height: 300px; }
+		#a { height: 100px; flex-grow: 1; }
+		#b { height: 100px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(400.0));
+	assert_eq!(a.as_block_level_box().fragment().height(), Pixel::new(200.0));
+	assert_eq!(b.as_block_level_box().fragment().y(), Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn flex_direction_row_reverse() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; flex-direction: row-reverse; width: 600px; }
+		#a, #b { width: 100px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(500.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(400.0));
+}
+
+#[test]
+#[serial]
+fn order_changes_item_position() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; width: 600px; }
+		#a { width: 100px; order: 1; }
+		#b { width: 100px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn auto_margin_takes_free_space() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flex; width: 600px; justify-content: center; }
+		#a { width: 100px; }
+		#b { width: 100px; margin-left: auto; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().margin.left, Pixel::new(400.0));
+}
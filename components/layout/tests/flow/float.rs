@@ -0,0 +1,145 @@
+use css::values::{Pixel, PIXEL_ZERO};
+use layout::flow::boxes::Box;
+use layout::flow::fragment::Fragment;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn float_left_and_right() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { width: 300px; }
+		#a { float: left; width: 100px; height: 50px; }
+		#b { float: right; width: 80px; height: 30px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(a.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(220.0));
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(container.as_block_level_box().fragment().height(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn floats_stack_until_they_do_not_fit() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { width: 250px; }
+		#a, #b, #c { float: left; width: 100px; height: 20px; }"#,
+	);
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().y(), Pixel::new(20.0));
+}
+
+#[test]
+#[serial]
+fn float_width_shrinks_to_fit() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"><div id="b"></div></div></div>"#,
+		r#"#container { width: 300px; }
+		#a { float: right; padding: 0 10px; }
+		#b { width: 60px; height: 10px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(60.0));
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(220.0));
+	assert_eq!(a.as_block_level_box().fragment().height(), Pixel::new(10.0));
+}
+
+#[test]
+#[serial]
+fn clear_moves_block_below_floats() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#a { float: left; width: 100px; height: 50px; }
+		#b { height: 10px; }
+		#c { clear: left; height: 10px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().y(), Pixel::new(50.0));
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(60.0));
+}
+
+#[test]
+#[serial]
+fn clear_right_ignores_left_floats() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#a { float: left; width: 100px; height: 50px; }
+		#b { clear: right; height: 10px; }"#,
+	);
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn flow_root_contains_its_floats() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: flow-root; }
+		#a { float: left; width: 100px; height: 50px; }
+		#b { height: 10px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(50.0));
+}
+
+#[test]
+#[serial]
+fn inline_block_contains_its_floats() {
+	let tree = construct_tree(
+		r#"<div><span id="container"><div id="a"></div></span></div>"#,
+		r#"#container { display: inline-block; width: 200px; }
+		#a { float: left; width: 100px; height: 40px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let layout_info = container.layout_info();
+	assert_eq!(layout_info.height, Pixel::new(40.0));
+}
+
+#[test]
+#[serial]
+fn lines_are_shortened_by_left_float() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="image"></div>hello</div>"#,
+		r#"#container { width: 200px; }
+		#image { float: left; width: 50px; height: 100px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let lines = container.lines();
+	assert_eq!(lines.len(), 1);
+	assert_eq!(lines[0].x(), Pixel::new(50.0));
+	assert_eq!(lines[0].available_width(), Pixel::new(150.0));
+}
+
+#[test]
+#[serial]
+fn lines_are_shortened_by_right_float() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="image"></div>hello</div>"#,
+		r#"#container { width: 200px; }
+		#image { float: right; width: 50px; height: 100px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let image = find_box(&tree, "image").unwrap();
+	let lines = container.lines();
+	assert_eq!(lines[0].x(), PIXEL_ZERO);
+	assert_eq!(lines[0].available_width(), Pixel::new(150.0));
+	assert_eq!(image.as_block_level_box().fragment().x(), Pixel::new(150.0));
+}
@@ -0,0 +1,204 @@
+use css::values::{Pixel, PIXEL_ZERO};
+use layout::flow::fragment::Fragment;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn fixed_and_flexible_columns() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { display: grid; width: 400px; grid-template-columns: 100px 1fr 2fr; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().width(), Pixel::new(100.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(200.0));
+	assert_eq!(c.as_block_level_box().fragment().width(), Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn gaps_between_tracks() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { display: grid; grid-template-columns: 100px 100px; column-gap: 20px; row-gap: 10px; }
+		#a, #b, #c { height: 50px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(120.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().y(), Pixel::new(60.0));
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(110.0));
+}
+
+#[test]
+#[serial]
+fn repeat_auto_fill_fits_the_container() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div><div id="d"></div></div>"#,
+		r#"#container { display: grid; width: 350px; column-gap: 10px; grid-template-columns: repeat(auto-fill, 100px); }
+		#a, #b, #c, #d { height: 20px; }"#,
+	);
+	let c = find_box(&tree, "c").unwrap();
+	let d = find_box(&tree, "d").unwrap();
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(220.0));
+	assert_eq!(d.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(d.as_block_level_box().fragment().y(), Pixel::new(20.0));
+}
+
+#[test]
+#[serial]
+fn minmax_columns_share_free_space() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: grid; width: 300px; grid-template-columns: minmax(50px, 100px) 1fr; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().width(), Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn explicit_placement() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: grid; grid-template-columns: 100px 100px 100px; grid-template-rows: 50px 50px; }
+		#a { grid-column: 2 / 4; grid-row: 2; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(a.as_block_level_box().fragment().y(), Pixel::new(50.0));
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(200.0));
+	assert_eq!(a.as_block_level_box().fragment().height(), Pixel::new(50.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn spanning_items_wrap_to_the_next_row() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { display: grid; grid-template-columns: 100px 100px 100px; }
+		#a { grid-column: span 2; }
+		#a, #b, #c { height: 30px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(200.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(200.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().y(), Pixel::new(30.0));
+}
+
+#[test]
+#[serial]
+fn named_areas() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="main"></div><div id="head"></div></div>"#,
+		r#"#container { display: grid; grid-template-columns: 100px 200px; grid-template-rows: 50px 100px;
+			grid-template-areas: "head head" "side main"; }
+		#head { grid-area: head; }
+		#main { grid-area: main; }"#,
+	);
+	let head = find_box(&tree, "head").unwrap();
+	let main = find_box(&tree, "main").unwrap();
+	assert_eq!(head.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(head.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(head.as_block_level_box().fragment().width(), Pixel::new(300.0));
+	assert_eq!(main.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(main.as_block_level_box().fragment().y(), Pixel::new(50.0));
+	assert_eq!(main.as_block_level_box().fragment().width(), Pixel::new(200.0));
+	assert_eq!(main.as_block_level_box().fragment().height(), Pixel::new(100.0));
+}
+
+#[test]
+#[serial]
+fn auto_flow_column() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { display: grid; grid-auto-flow: column; grid-template-rows: 50px 50px; grid-auto-columns: 100px; }"#,
+	);
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().y(), Pixel::new(50.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(c.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().width(), Pixel::new(100.0));
+}
+
+#[test]
+#[serial]
+fn auto_flow_dense_fills_holes() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { display: grid; grid-auto-flow: row dense; grid-template-columns: 100px 100px 100px; }
+		#a, #b { grid-column: span 2; }
+		#a, #b, #c { height: 20px; }"#,
+	);
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().y(), Pixel::new(20.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(200.0));
+	assert_eq!(c.as_block_level_box().fragment().y(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn self_alignment_inside_area() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div></div>"#,
+		r#"#container { display: grid; grid-template-columns: 200px; grid-template-rows: 100px; }
+		#a { width: 50px; height: 20px; justify-self: center; align-self: end; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(75.0));
+	assert_eq!(a.as_block_level_box().fragment().y(), Pixel::new(80.0));
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(50.0));
+}
+
+#[test]
+#[serial]
+fn justify_content_center_moves_tracks() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { display: grid; width: 600px; grid-template-columns: 100px 100px; justify-content: center; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(200.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(300.0));
+}
+
+#[test]
+#[serial]
+fn auto_rows_fit_their_items() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#container { display: grid; grid-template-columns: 100px 100px; }
+		#a { height: 30px; }
+		#b { height: 60px; }
+		#c { height: 10px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(c.as_block_level_box().fragment().y(), Pixel::new(60.0));
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(70.0));
+}
@@ -0,0 +1,132 @@
+use css::values::{Pixel, PIXEL_ZERO};
+use serial_test::serial;
+
+use self::setup::{construct_tree, get_layout_info};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn percentage_of_an_auto_height_is_auto() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="test"><div class="content"></div></div></div>"#,
+		r#"
+#test { height: 50%; }
+.content { height: 30px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, Pixel::new(30.0));
+	assert!(!get_layout_info(&tree, "test").unwrap().has_definite_height);
+}
+
+#[test]
+#[serial]
+fn percentage_of_a_percentage_height() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="a"><div id="b"></div></div></div>"#,
+		r#"
+#parent { height: 200px; }
+#a { height: 50%; }
+#b { height: 50%; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().height, Pixel::new(100.0));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().height, Pixel::new(50.0));
+}
+
+#[test]
+#[serial]
+fn zero_height_is_not_auto() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="test"><div class="content"></div></div></div>"#,
+		r#"
+#parent { height: 100px; }
+#test { height: 0; }
+.content { height: 30px; }
+        "#,
+	);
+	let layout_info = get_layout_info(&tree, "test").unwrap();
+	assert_eq!(layout_info.height, PIXEL_ZERO);
+	assert!(layout_info.has_definite_height);
+
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="test"><div class="content"></div></div></div>"#,
+		r#"
+#parent { height: 0; }
+#test { height: 50%; }
+.content { height: 30px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn min_and_max_height_limit_a_specified_height() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="a"></div><div id="b"></div></div>"#,
+		r#"
+#parent { height: 400px; }
+#a { height: 100px; max-height: 10%; }
+#b { height: 100px; max-height: 50px; min-height: 80px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().height, Pixel::new(40.0));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().height, Pixel::new(80.0));
+}
+
+#[test]
+#[serial]
+fn min_and_max_height_limit_an_auto_height() {
+	let tree = construct_tree(
+		r#"<div id="a"><div class="content"></div></div><div id="b"><div class="content"></div></div>"#,
+		r#"
+#a { max-height: 20px; }
+#b { min-height: 50px; }
+.content { height: 30px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().height, Pixel::new(20.0));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().height, Pixel::new(50.0));
+}
+
+#[test]
+#[serial]
+fn percentage_min_height_of_an_auto_height_is_ignored() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="test"><div class="content"></div></div></div>"#,
+		r#"
+#test { min-height: 50%; }
+.content { height: 30px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, Pixel::new(30.0));
+}
+
+#[test]
+#[serial]
+fn absolutely_positioned_percentage_height_uses_the_padding_box() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="test"></div><div class="content"></div></div>"#,
+		r#"
+#parent { position: relative; padding: 10px 0; }
+#test { position: absolute; height: 50%; }
+.content { height: 80px; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, Pixel::new(50.0));
+}
+
+#[test]
+#[serial]
+fn absolutely_positioned_height_is_limited() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="test"></div></div>"#,
+		r#"
+#parent { position: relative; height: 200px; }
+#test { position: absolute; top: 0; bottom: 0; max-height: 25%; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, Pixel::new(50.0));
+}
@@ -0,0 +1,133 @@
+use css::properties::longhands::font_size::DEFAULT_FONT_SIZE;
+use css::values::Pixel;
+use layout::text::TextUI;
+use serial_test::serial;
+
+use self::setup::{construct_tree, get_layout_info};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+fn measure_width(text: &str) -> Pixel {
+	let (width, _) = TextUI::new().measure_size(text, &vec!["system-ui"], DEFAULT_FONT_SIZE);
+	Pixel::new(width)
+}
+
+#[test]
+#[serial]
+fn min_content_is_the_widest_word() {
+	let tree = construct_tree(
+		r#"<div id="test">hello hello</div>"#,
+		r#"#test { width: min-content; }"#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().width, measure_width("hello"));
+}
+
+#[test]
+#[serial]
+fn max_content_is_the_widest_line() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world</div>"#,
+		r#"#test { width: max-content; }"#,
+	);
+	assert_eq!(
+		get_layout_info(&tree, "test").unwrap().width,
+		measure_width("hello world")
+	);
+}
+
+#[test]
+#[serial]
+fn fit_content_is_clamped_by_the_content() {
+	let tree = construct_tree(
+		r#"<div id="a">hello hello</div><div id="b">hello hello</div>"#,
+		r#"
+#a { width: fit-content(1000px); }
+#b { width: fit-content(0px); }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().width, measure_width("hello hello"));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().width, measure_width("hello"));
+}
+
+#[test]
+#[serial]
+fn min_and_max_width_accept_sizing_keywords() {
+	let tree = construct_tree(
+		r#"<div id="a">hello world</div><div id="b">hello hello</div>"#,
+		r#"
+#a { width: 10px; min-width: max-content; }
+#b { max-width: min-content; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().width, measure_width("hello world"));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().width, measure_width("hello"));
+}
+
+#[test]
+#[serial]
+fn auto_width_limited_by_max_width_resolves_auto_margins() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="test"></div></div>"#,
+		r#"
+#parent { width: 400px; }
+#test { max-width: 100px; margin: 0 auto; }
+        "#,
+	);
+	let layout_info = get_layout_info(&tree, "test").unwrap();
+	assert_eq!(layout_info.width, Pixel::new(100.0));
+	assert_eq!(layout_info.margin.left, Pixel::new(150.0));
+	assert_eq!(layout_info.margin.right, Pixel::new(150.0));
+}
+
+#[test]
+#[serial]
+fn inline_block_shrinks_to_fit() {
+	let tree = construct_tree(
+		r#"<div id="parent"><span id="a">hello world</span></div>"#,
+		r#"
+#parent { width: 400px; }
+#a { display: inline-block; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().width, measure_width("hello world"));
+
+	let tree = construct_tree(
+		r#"<div id="parent"><span id="a">hello hello hello hello</span></div>"#,
+		r#"
+#parent { width: 60px; }
+#a { display: inline-block; }
+        "#,
+	);
+	let width = get_layout_info(&tree, "a").unwrap().width;
+	assert_eq!(width, Pixel::new(60.0).max(measure_width("hello")));
+}
+
+#[test]
+#[serial]
+fn absolutely_positioned_box_shrinks_to_fit() {
+	let tree = construct_tree(
+		r#"<div id="parent"><div id="a">hello world</div><div id="b">hello world</div></div>"#,
+		r#"
+#parent { position: relative; width: 400px; }
+#a { position: absolute; }
+#b { position: absolute; left: 0; right: 0; width: min-content; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "a").unwrap().width, measure_width("hello world"));
+	assert_eq!(get_layout_info(&tree, "b").unwrap().width, measure_width("hello"));
+}
+
+#[test]
+#[serial]
+fn fixed_width_is_the_contribution_of_a_child() {
+	let tree = construct_tree(
+		r#"<div id="test"><div>hello</div><div id="child">hello world hello world</div></div>"#,
+		r#"
+#test { float: left; }
+#child { width: 30px; padding: 0 5px; }
+        "#,
+	);
+	let width = get_layout_info(&tree, "test").unwrap().width;
+	assert_eq!(width, Pixel::new(40.0).max(measure_width("hello")));
+}
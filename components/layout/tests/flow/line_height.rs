@@ -0,0 +1,122 @@
+use css::properties::longhands::font_size::DEFAULT_FONT_SIZE;
+use css::values::{Pixel, PIXEL_ZERO};
+use layout::flow::fragment::Fragment;
+use layout::flow::tree::BoxTree;
+use layout::text::TextUI;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box, get_layout_info};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+fn get_y(tree: &BoxTree, id: &str) -> Pixel {
+	find_box(tree, id).unwrap().as_inline_level_box().fragments()[0]
+		.borrow()
+		.y()
+}
+
+/// The distance from the baseline of a strut to the bottom of its line height.
+fn below_baseline(line_height: f32) -> Pixel {
+	let metrics = TextUI::new().font_metrics(&vec!["system-ui"], DEFAULT_FONT_SIZE);
+	Pixel::new(line_height - metrics.ascent - (line_height - metrics.ascent - metrics.descent) / 2.0)
+}
+
+#[test]
+#[serial]
+fn line_is_as_tall_as_line_height() {
+	let tree = construct_tree(r#"<div id="test">hello world</div>"#, r#"#test { line-height: 40px; }"#);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, Pixel::new(40.0));
+
+	let tree = construct_tree(
+		r#"<div id="test">hello world</div>"#,
+		r#"#test { font-size: 20px; line-height: 2; }"#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, Pixel::new(40.0));
+}
+
+#[test]
+#[serial]
+fn line_with_only_empty_inline_boxes_has_no_height() {
+	let tree = construct_tree(r#"<div id="test"><span></span></div>"#, r#""#);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn inline_block_sits_on_the_baseline() {
+	let tree = construct_tree(
+		r#"<div id="test">hello <span id="a"></span></div>"#,
+		r#"
+#test { line-height: 20px; }
+#a { display: inline-block; height: 50px; }
+        "#,
+	);
+	assert_eq!(get_y(&tree, "a"), PIXEL_ZERO);
+	assert_eq!(
+		get_layout_info(&tree, "test").unwrap().height,
+		Pixel::new(50.0) + below_baseline(20.0)
+	);
+}
+
+#[test]
+#[serial]
+fn boxes_aligned_to_the_top_and_the_bottom_of_the_line() {
+	let tree = construct_tree(
+		r#"<div id="test">hello <span id="a"></span><span id="b"></span><span id="c"></span></div>"#,
+		r#"
+#test { line-height: 20px; }
+#a { display: inline-block; height: 30px; vertical-align: top; }
+#b { display: inline-block; height: 50px; vertical-align: bottom; }
+#c { display: inline-block; height: 10px; vertical-align: bottom; }
+        "#,
+	);
+	assert_eq!(get_layout_info(&tree, "test").unwrap().height, Pixel::new(50.0));
+	assert_eq!(get_y(&tree, "a"), PIXEL_ZERO);
+	assert_eq!(get_y(&tree, "b"), PIXEL_ZERO);
+	assert_eq!(get_y(&tree, "c"), Pixel::new(40.0));
+}
+
+#[test]
+#[serial]
+fn length_raises_the_baseline() {
+	let tree = construct_tree(
+		r#"<div>hello <span id="a">a</span><span id="b">b</span><span id="c">c</span></div>"#,
+		r#"
+div { line-height: 20px; }
+#b { vertical-align: 10px; }
+#c { vertical-align: -50%; }
+        "#,
+	);
+	assert_eq!(get_y(&tree, "b"), get_y(&tree, "a") - Pixel::new(10.0));
+	assert_eq!(get_y(&tree, "c"), get_y(&tree, "a") + Pixel::new(10.0));
+}
+
+#[test]
+#[serial]
+fn text_top_and_text_bottom_follow_the_parent_content_area() {
+	let tree = construct_tree(
+		r#"<div>hello <span id="a">a</span><span id="b">b</span><span id="c">c</span></div>"#,
+		r#"
+#b { vertical-align: text-top; }
+#c { vertical-align: text-bottom; }
+        "#,
+	);
+	// with the same font and line height, the boxes fill the parent's content area like text on the baseline does
+	assert_eq!(get_y(&tree, "b"), get_y(&tree, "a"));
+	assert_eq!(get_y(&tree, "c"), get_y(&tree, "a"));
+}
+
+#[test]
+#[serial]
+fn sub_and_super_move_the_baseline() {
+	let tree = construct_tree(
+		r#"<div>hello <span id="a">a</span><span id="b">b</span><span id="c">c</span></div>"#,
+		r#"
+#b { vertical-align: super; }
+#c { vertical-align: sub; }
+        "#,
+	);
+	assert!(get_y(&tree, "b") < get_y(&tree, "a"));
+	assert!(get_y(&tree, "c") > get_y(&tree, "a"));
+}
@@ -0,0 +1,94 @@
+use css::values::{Pixel, PIXEL_ZERO};
+use layout::flow::boxes::Box;
+use layout::flow::fragment::Fragment;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn column_count_shares_width() {
+	let tree = construct_tree(
+		r#"<div id="m"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#m { width: 300px; column-count: 3; column-gap: 30px; }
+		#a, #b, #c { height: 20px; }"#,
+	);
+	let m = find_box(&tree, "m").unwrap();
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(m.as_block_level_box().fragment().height(), Pixel::new(20.0));
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(80.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(110.0));
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(220.0));
+}
+
+#[test]
+#[serial]
+fn column_width_fits_columns() {
+	let tree = construct_tree(
+		r#"<div id="m"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#m { width: 320px; column-width: 100px; column-gap: 10px; }
+		#a, #b { height: 30px; }"#,
+	);
+	let m = find_box(&tree, "m").unwrap();
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(m.as_block_level_box().fragment().height(), Pixel::new(30.0));
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(100.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(110.0));
+}
+
+#[test]
+#[serial]
+fn balancing_grows_columns_to_fit_content() {
+	let tree = construct_tree(
+		r#"<div id="m"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#m { width: 200px; columns: 2; column-gap: 0; }
+		#a, #c { height: 10px; }
+		#b { height: 30px; }"#,
+	);
+	let m = find_box(&tree, "m").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(m.as_block_level_box().fragment().height(), Pixel::new(40.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().y(), Pixel::new(10.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(100.0));
+	assert_eq!(c.as_block_level_box().fragment().y(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn specified_height_overflows_into_extra_columns() {
+	let tree = construct_tree(
+		r#"<div id="m"><div id="a"></div><div id="b"></div><div id="c"></div></div>"#,
+		r#"#m { width: 200px; height: 20px; column-count: 2; column-gap: 0; }
+		#a, #b, #c { height: 20px; }"#,
+	);
+	let m = find_box(&tree, "m").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(m.as_block_level_box().fragment().height(), Pixel::new(20.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(200.0));
+	assert_eq!(c.as_block_level_box().fragment().y(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn column_rules_are_centered_in_gaps() {
+	let tree = construct_tree(
+		r#"<div id="m"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#m { width: 210px; column-count: 2; column-gap: 10px; column-rule: 2px solid red; }
+		#a, #b { height: 20px; }"#,
+	);
+	let m = find_box(&tree, "m").unwrap();
+	let fragment = m.as_block_level_box().fragment();
+	assert_eq!(fragment.column_rules.len(), 1);
+	assert_eq!(fragment.column_rules[0].origin.x, Pixel::new(104.0));
+	assert_eq!(fragment.column_rules[0].size.width, Pixel::new(2.0));
+	assert_eq!(fragment.column_rules[0].size.height, Pixel::new(20.0));
+}
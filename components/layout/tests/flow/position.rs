@@ -0,0 +1,139 @@
+use css::values::{Pixel, PIXEL_ZERO};
+use layout::flow::boxes::{Box, BoxClass};
+use layout::flow::fragment::Fragment;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn relative_moves_box_without_affecting_flow() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#a { position: relative; left: 10px; top: 5px; height: 10px; }
+		#b { height: 10px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().offset.x, Pixel::new(10.0));
+	assert_eq!(a.as_block_level_box().fragment().offset.y, Pixel::new(5.0));
+	assert_eq!(a.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(b.as_block_level_box().fragment().y(), Pixel::new(10.0));
+}
+
+#[test]
+#[serial]
+fn relative_with_right_and_bottom() {
+	let tree = construct_tree(
+		r#"<div id="a"></div>"#,
+		r#"#a { position: relative; right: 10px; bottom: 5px; height: 10px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().offset.x, Pixel::new(-10.0));
+	assert_eq!(a.as_block_level_box().fragment().offset.y, Pixel::new(-5.0));
+}
+
+#[test]
+#[serial]
+fn absolute_with_top_and_left() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#container { position: relative; width: 300px; height: 200px; padding: 10px; }
+		#a { position: absolute; top: 20px; left: 30px; width: 50px; height: 40px; }
+		#b { height: 10px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(20.0));
+	assert_eq!(a.as_block_level_box().fragment().y(), Pixel::new(10.0));
+	assert_eq!(b.as_block_level_box().fragment().y(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn absolute_with_right_and_bottom() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div></div>"#,
+		r#"#container { position: relative; width: 300px; height: 200px; }
+		#a { position: absolute; right: 10px; bottom: 20px; width: 50px; height: 40px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), Pixel::new(240.0));
+	assert_eq!(a.as_block_level_box().fragment().y(), Pixel::new(140.0));
+}
+
+#[test]
+#[serial]
+fn absolute_stretches_between_insets() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div></div>"#,
+		r#"#container { position: relative; width: 300px; height: 200px; }
+		#a { position: absolute; top: 0; bottom: 50px; left: 10px; right: 20px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let layout_info = a.layout_info();
+	assert_eq!(layout_info.width, Pixel::new(270.0));
+	assert_eq!(layout_info.height, Pixel::new(150.0));
+}
+
+#[test]
+#[serial]
+fn absolute_auto_margins_center_box() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div></div>"#,
+		r#"#container { position: relative; width: 300px; height: 200px; }
+		#a { position: absolute; left: 0; right: 0; width: 100px; height: 10px; margin: 0 auto; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	assert_eq!(a.layout_info().margin.left, Pixel::new(100.0));
+	assert_eq!(a.layout_info().margin.right, Pixel::new(100.0));
+}
+
+#[test]
+#[serial]
+fn absolute_stays_at_static_position() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="b"></div><div id="a"></div></div>"#,
+		r#"#container { position: relative; width: 300px; }
+		#a { position: absolute; width: 10px; height: 10px; }
+		#b { height: 30px; }"#,
+	);
+	let container = find_box(&tree, "container").unwrap();
+	let a = find_box(&tree, "a").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().x(), PIXEL_ZERO);
+	assert_eq!(a.as_block_level_box().fragment().y(), Pixel::new(30.0));
+	assert_eq!(container.as_block_level_box().fragment().height(), Pixel::new(30.0));
+}
+
+#[test]
+#[serial]
+fn fixed_moves_with_viewport() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div></div>"#,
+		r#"#container { position: relative; height: 2000px; }
+		#a { position: fixed; top: 0; width: 10px; height: 10px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	assert_eq!(a.containing_block().unwrap().id(), tree.initial_containing_block.id());
+	assert_eq!(a.as_block_level_box().fragment().offset.y, PIXEL_ZERO);
+	tree.scroll_to(0.0, 100.0);
+	assert_eq!(a.as_block_level_box().fragment().offset.y, Pixel::new(100.0));
+}
+
+#[test]
+#[serial]
+fn sticky_sticks_to_top_of_viewport() {
+	let tree = construct_tree(
+		r#"<div id="container"><div id="a"></div></div>"#,
+		r#"#container { height: 2000px; }
+		#a { position: sticky; top: 0; height: 50px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let (_, y) = BoxClass::get_offset_from(a.clone(), tree.initial_containing_block.as_ref());
+	assert_eq!(a.as_block_level_box().fragment().offset.y, PIXEL_ZERO);
+	tree.scroll_to(0.0, 200.0);
+	assert_eq!(a.as_block_level_box().fragment().offset.y, Pixel::new(200.0) - y);
+}
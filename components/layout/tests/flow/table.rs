@@ -0,0 +1,178 @@
+use css::properties::longhands::direction::Direction;
+use css::values::{Pixel, PIXEL_ZERO};
+use layout::flow::boxes::{Box, BoxClass};
+use layout::flow::fragment::Fragment;
+use layout::flow::table::TablePart;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+#[serial]
+fn auto_columns_share_extra_width_in_proportion() {
+	let tree = construct_tree(
+		r#"<table id="t"><tr><td id="a"></td><td id="b"></td></tr></table>"#,
+		r#"#t { width: 300px; border-spacing: 0; }
+		#a { width: 100px; }
+		#b { width: 50px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(200.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(200.0));
+	assert_eq!(b.as_block_level_box().fragment().width(), Pixel::new(100.0));
+}
+
+#[test]
+#[serial]
+fn border_spacing_separates_cells() {
+	let tree = construct_tree(
+		r#"<table id="t"><tr><td id="a"></td><td id="b"></td></tr></table>"#,
+		r#"#t { border-spacing: 10px 5px; }
+		#a, #b { width: 50px; height: 20px; }"#,
+	);
+	let t = find_box(&tree, "t").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(t.as_block_level_box().fragment().width(), Pixel::new(130.0));
+	assert_eq!(t.as_block_level_box().fragment().height(), Pixel::new(30.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(60.0));
+}
+
+#[test]
+#[serial]
+fn cells_span_columns_and_rows() {
+	let tree = construct_tree(
+		r#"<table id="t">
+			<tr><td id="a" colspan="2"></td><td id="b" rowspan="2"></td></tr>
+			<tr><td id="c"></td><td id="d"></td></tr>
+		</table>"#,
+		r#"#t { border-spacing: 0; }
+		td { height: 10px; }
+		#b { width: 25px; }
+		#c { width: 30px; }
+		#d { width: 20px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let d = find_box(&tree, "d").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().width(), Pixel::new(50.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(50.0));
+	assert_eq!(b.as_block_level_box().fragment().height(), Pixel::new(20.0));
+	assert_eq!(d.as_block_level_box().fragment().x(), Pixel::new(30.0));
+}
+
+#[test]
+#[serial]
+fn cells_without_row_are_wrapped_in_anonymous_row() {
+	let tree = construct_tree(
+		r#"<div id="t"><div id="a"></div><div id="b"></div></div>"#,
+		r#"#t { display: table; border-spacing: 0; }
+		#a { display: table-cell; width: 30px; }
+		#b { display: table-cell; width: 20px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let row = a.parent().unwrap();
+	assert_eq!(TablePart::of(row.as_ref()), Some(TablePart::Row));
+	assert_eq!(row.id(), b.parent().unwrap().id());
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(30.0));
+}
+
+#[test]
+#[serial]
+fn anonymous_table_boxes_inherit_from_their_parent() {
+	let tree = construct_tree(
+		r#"<div id="t"><span id="a"></span><span id="b"></span></div>"#,
+		r#"#t { direction: rtl; border-spacing: 5px; }
+		#a, #b { display: table-cell; width: 30px; height: 10px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	let row = a.parent().unwrap();
+	assert_eq!(TablePart::of(row.as_ref()), Some(TablePart::Row));
+	assert!(matches!(
+		BoxClass::get_computed_values(row.as_ref()).get_direction(),
+		Direction::Rtl
+	));
+	// the anonymous table separates its cells with the inherited border spacing
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(40.0));
+}
+
+#[test]
+#[serial]
+fn caption_is_placed_below_grid() {
+	let tree = construct_tree(
+		r#"<table id="t"><caption id="c"></caption><tr><td id="a"></td></tr></table>"#,
+		r#"#t { border-spacing: 0; }
+		#c { height: 20px; caption-side: bottom; }
+		#a { width: 100px; height: 30px; }"#,
+	);
+	let t = find_box(&tree, "t").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(c.as_block_level_box().fragment().y(), Pixel::new(30.0));
+	assert_eq!(c.as_block_level_box().fragment().width(), Pixel::new(100.0));
+	assert_eq!(t.as_block_level_box().fragment().height(), Pixel::new(50.0));
+}
+
+#[test]
+#[serial]
+fn fixed_layout_shares_remaining_width() {
+	let tree = construct_tree(
+		r#"<table id="t"><tr><td id="a"></td><td id="b"></td><td id="c"></td></tr></table>"#,
+		r#"#t { table-layout: fixed; width: 300px; border-spacing: 0; }
+		#a { width: 100px; }"#,
+	);
+	let b = find_box(&tree, "b").unwrap();
+	let c = find_box(&tree, "c").unwrap();
+	assert_eq!(b.as_block_level_box().fragment().width(), Pixel::new(100.0));
+	assert_eq!(c.as_block_level_box().fragment().x(), Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn collapsed_borders_take_widest_border() {
+	let tree = construct_tree(
+		r#"<table id="t"><tr><td id="a"></td><td id="b"></td></tr></table>"#,
+		r#"#t { border-collapse: collapse; border-left-style: solid; border-left-width: 4px;
+			border-right-style: solid; border-right-width: 4px; }
+		#a { width: 50px; border-right-style: solid; border-right-width: 6px; }
+		#b { width: 50px; }"#,
+	);
+	let t = find_box(&tree, "t").unwrap();
+	let b = find_box(&tree, "b").unwrap();
+	assert_eq!(t.as_block_level_box().fragment().width(), Pixel::new(114.0));
+	assert_eq!(b.as_block_level_box().fragment().x(), Pixel::new(56.0));
+}
+
+#[test]
+#[serial]
+fn cell_content_is_aligned_to_middle() {
+	let tree = construct_tree(
+		r#"<table id="t"><tr><td id="a"><div id="x"></div></td><td id="b"></td></tr></table>"#,
+		r#"#t { border-spacing: 0; }
+		#x { height: 10px; }
+		#b { height: 30px; }"#,
+	);
+	let a = find_box(&tree, "a").unwrap();
+	let x = find_box(&tree, "x").unwrap();
+	assert_eq!(a.as_block_level_box().fragment().height(), Pixel::new(30.0));
+	assert_eq!(x.as_block_level_box().fragment().y(), Pixel::new(10.0));
+}
+
+#[test]
+#[serial]
+fn specified_height_is_shared_by_rows() {
+	let tree = construct_tree(
+		r#"<table id="t"><tr id="r1"><td></td></tr><tr id="r2"><td></td></tr></table>"#,
+		r#"#t { height: 100px; border-spacing: 0; }
+		td { height: 10px; }"#,
+	);
+	let r1 = find_box(&tree, "r1").unwrap();
+	let r2 = find_box(&tree, "r2").unwrap();
+	assert_eq!(r1.as_block_level_box().fragment().y(), PIXEL_ZERO);
+	assert_eq!(r1.as_block_level_box().fragment().height(), Pixel::new(50.0));
+	assert_eq!(r2.as_block_level_box().fragment().y(), Pixel::new(50.0));
+}
@@ -0,0 +1,121 @@
+use css::values::Pixel;
+use layout::flow::boxes::Box;
+use layout::flow::fragment::Fragment;
+use layout::flow::tree::BoxTree;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+fn get_contents(tree: &BoxTree, id: &str) -> Vec<String> {
+	let node = find_box(tree, id).unwrap();
+	let mut contents = vec![];
+	for child in node.children() {
+		for fragment in child.as_text_run().fragments().iter() {
+			contents.push(fragment.borrow().content.clone());
+		}
+	}
+	contents
+}
+
+#[test]
+#[serial]
+fn spaces_and_segment_breaks_collapse() {
+	let tree = construct_tree("<div id=\"t\">  hello \n\t world  </div>", r#""#);
+	let node = find_box(&tree, "t").unwrap();
+	assert_eq!(node.lines().len(), 1);
+	assert_eq!(get_contents(&tree, "t"), vec!["hello world"]);
+}
+
+#[test]
+#[serial]
+fn whitespace_between_blocks_is_not_rendered() {
+	let tree = construct_tree("<div id=\"t\">\n  <div></div>\n  <div></div>\n</div>", r#""#);
+	let node = find_box(&tree, "t").unwrap();
+	assert_eq!(node.children().len(), 2);
+}
+
+#[test]
+#[serial]
+fn pre_preserves_segment_breaks_and_spaces() {
+	let tree = construct_tree("<pre id=\"t\">a  b\ncd</pre>", r#""#);
+	let node = find_box(&tree, "t").unwrap();
+	assert_eq!(node.lines().len(), 2);
+	assert_eq!(get_contents(&tree, "t"), vec!["a  b", "cd"]);
+}
+
+#[test]
+#[serial]
+fn lines_break_at_spaces() {
+	let tree = construct_tree(r#"<div id="t">hello world</div>"#, r#"#t { width: 10px; }"#);
+	let node = find_box(&tree, "t").unwrap();
+	assert_eq!(node.lines().len(), 2);
+	assert_eq!(get_contents(&tree, "t"), vec!["hello", "world"]);
+}
+
+#[test]
+#[serial]
+fn nowrap_keeps_text_in_one_line() {
+	let tree = construct_tree(
+		r#"<div id="t">hello world</div>"#,
+		r#"#t { width: 10px; white-space: nowrap; }"#,
+	);
+	let node = find_box(&tree, "t").unwrap();
+	assert_eq!(node.lines().len(), 1);
+}
+
+#[test]
+#[serial]
+fn break_all_breaks_between_characters() {
+	let tree = construct_tree(
+		r#"<div id="t">abc</div>"#,
+		r#"#t { width: 1px; word-break: break-all; }"#,
+	);
+	assert_eq!(get_contents(&tree, "t"), vec!["a", "b", "c"]);
+}
+
+#[test]
+#[serial]
+fn overflow_wrap_breaks_long_words() {
+	let tree = construct_tree(
+		r#"<div id="t">ab cd</div>"#,
+		r#"#t { width: 1px; overflow-wrap: anywhere; }"#,
+	);
+	assert_eq!(get_contents(&tree, "t"), vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+#[serial]
+fn fragments_are_measured_with_their_glyphs() {
+	let tree = construct_tree(r#"<div id="t">hello world</div>"#, r#""#);
+	let node = find_box(&tree, "t").unwrap();
+	let children = node.children();
+	let fragments = children[0].as_text_run().fragments();
+	let fragment = fragments[0].borrow();
+	assert_eq!(fragment.width(), Pixel::new(fragment.shaped_text.width));
+	assert_eq!(
+		fragment
+			.shaped_text
+			.runs
+			.iter()
+			.map(|run| run.glyphs.len())
+			.sum::<usize>(),
+		11
+	);
+}
+
+#[test]
+#[serial]
+fn glyph_clusters_start_at_each_line() {
+	let tree = construct_tree(r#"<div id="t">hello world</div>"#, r#"#t { width: 10px; }"#);
+	let node = find_box(&tree, "t").unwrap();
+	let children = node.children();
+	let fragments = children[0].as_text_run().fragments();
+	let fragment = fragments[1].borrow();
+	let glyphs = &fragment.shaped_text.runs[0].glyphs;
+	assert_eq!(glyphs[0].cluster, 0);
+	assert_eq!(glyphs[0].x, 0.0);
+	assert_eq!(fragment.width(), Pixel::new(fragment.shaped_text.width));
+}
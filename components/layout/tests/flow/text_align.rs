@@ -0,0 +1,162 @@
+use css::values::{Pixel, PIXEL_ZERO};
+use layout::flow::boxes::Box;
+use layout::flow::fragment::Fragment;
+use layout::flow::tree::BoxTree;
+use serial_test::serial;
+
+use self::setup::{construct_tree, find_box};
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+fn get_fragment_x(tree: &BoxTree, id: &str) -> Pixel {
+	find_box(tree, id).unwrap().as_inline_level_box().fragments()[0]
+		.borrow()
+		.x()
+}
+
+fn get_fragment_width(tree: &BoxTree, id: &str) -> Pixel {
+	find_box(tree, id).unwrap().as_inline_level_box().fragments()[0]
+		.borrow()
+		.total_width()
+}
+
+fn assert_approximately_eq(left: Pixel, right: Pixel) {
+	assert!((left - right).get().abs() < 0.01, "{:?} != {:?}", left, right);
+}
+
+#[test]
+#[serial]
+fn left_and_start_keep_content_at_the_start() {
+	for text_align in ["left", "start"] {
+		let tree = construct_tree(
+			r#"<div><span id="test">hello</span></div>"#,
+			&format!("div {{ width: 200px; text-align: {}; }}", text_align),
+		);
+		assert_eq!(get_fragment_x(&tree, "test"), PIXEL_ZERO);
+	}
+}
+
+#[test]
+#[serial]
+fn right_and_end_move_content_to_the_end() {
+	for text_align in ["right", "end"] {
+		let tree = construct_tree(
+			r#"<div><span id="test">hello</span></div>"#,
+			&format!("div {{ width: 200px; text-align: {}; }}", text_align),
+		);
+		let width = get_fragment_width(&tree, "test");
+		assert_eq!(get_fragment_x(&tree, "test"), Pixel::new(200.0) - width);
+	}
+}
+
+#[test]
+#[serial]
+fn center_splits_the_free_space() {
+	let tree = construct_tree(
+		r#"<div><span id="test">hello</span></div>"#,
+		r#"div { width: 200px; text-align: center; }"#,
+	);
+	let width = get_fragment_width(&tree, "test");
+	assert_eq!(get_fragment_x(&tree, "test"), (Pixel::new(200.0) - width) / 2.0);
+}
+
+#[test]
+#[serial]
+fn start_is_on_the_right_in_right_to_left_content() {
+	let tree = construct_tree(
+		r#"<div dir="rtl"><span id="test">hello</span></div>"#,
+		r#"div { width: 200px; }"#,
+	);
+	let width = get_fragment_width(&tree, "test");
+	assert_eq!(get_fragment_x(&tree, "test"), Pixel::new(200.0) - width);
+}
+
+#[test]
+#[serial]
+fn justify_fills_every_line_except_the_last_one() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world hello world hello world</div>"#,
+		r#"#test { width: 100px; text-align: justify; }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	let lines = node.lines();
+	assert!(lines.len() > 1);
+	for line in lines[..lines.len() - 1].iter() {
+		assert_approximately_eq(line.width(), Pixel::new(100.0));
+	}
+	assert!(lines.last().unwrap().width() < Pixel::new(100.0));
+}
+
+#[test]
+#[serial]
+fn justify_all_fills_the_last_line() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world</div>"#,
+		r#"#test { width: 200px; text-align: justify-all; }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	assert_approximately_eq(node.lines()[0].width(), Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn text_justify_none_is_not_justified() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world</div>"#,
+		r#"#test { width: 200px; text-align: justify-all; text-justify: none; }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	assert!(node.lines()[0].width() < Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn inter_character_justifies_a_single_word() {
+	let tree = construct_tree(
+		r#"<div id="test">hello</div>"#,
+		r#"#test { width: 200px; text-align: justify-all; text-justify: inter-character; }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	assert_approximately_eq(node.lines()[0].width(), Pixel::new(200.0));
+}
+
+#[test]
+#[serial]
+fn text_indent_moves_the_first_line() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world hello world hello world</div>"#,
+		r#"#test { width: 100px; text-indent: 20px; }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	let lines = node.lines();
+	assert_eq!(lines[0].x(), Pixel::new(20.0));
+	assert_eq!(lines[0].available_width(), Pixel::new(80.0));
+	assert_eq!(lines[1].x(), PIXEL_ZERO);
+}
+
+#[test]
+#[serial]
+fn hanging_indent_moves_the_other_lines() {
+	let tree = construct_tree(
+		r#"<div id="test">hello world hello world hello world</div>"#,
+		r#"#test { width: 100px; text-indent: 10% hanging; }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	let lines = node.lines();
+	assert_eq!(lines[0].x(), PIXEL_ZERO);
+	assert_eq!(lines[1].x(), Pixel::new(10.0));
+}
+
+#[test]
+#[serial]
+fn each_line_indents_the_lines_after_forced_breaks() {
+	let tree = construct_tree(
+		"<div id=\"test\">hello\nworld</div>",
+		r#"#test { width: 200px; white-space: pre; text-indent: 20px each-line; }"#,
+	);
+	let node = find_box(&tree, "test").unwrap();
+	let lines = node.lines();
+	assert_eq!(lines[0].x(), Pixel::new(20.0));
+	assert_eq!(lines[1].x(), Pixel::new(20.0));
+}
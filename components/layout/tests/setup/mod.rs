@@ -50,6 +50,10 @@ impl ParseErrorReporter for TestingErrorReporter {
 }
 
 pub fn construct_tree(html: &str, css: &str) -> BoxTree {
+	construct_tree_with_user_stylesheet(html, "", css)
+}
+
+pub fn construct_tree_with_user_stylesheet(html: &str, user_css: &str, css: &str) -> BoxTree {
 	GlobalScope::clear();
 	let sink = DomParser::new();
 
@@ -60,6 +64,14 @@ pub fn construct_tree(html: &str, css: &str) -> BoxTree {
 
 	let error_reporter = TestingErrorReporter::new();
 	let media = Rc::new(MediaList::empty());
+	let user_stylesheet = Stylesheet::from_str(
+		user_css,
+		Origin::User,
+		media.clone(),
+		Some(&error_reporter),
+		QuirksMode::NoQuirks,
+		0,
+	);
 	let stylesheet = Stylesheet::from_str(
 		css,
 		Origin::UserAgent,
@@ -71,6 +83,7 @@ pub fn construct_tree(html: &str, css: &str) -> BoxTree {
 	let root = output.document.upcast().first_child().unwrap();
 	let style_tree = Rc::new(StyleTree::new(NodeRef(root.clone()), QuirksMode::NoQuirks));
 	style_tree.import_user_agent();
+	style_tree.add_user_stylesheet(&user_stylesheet);
 	style_tree.add_stylesheet(&stylesheet);
 	style_tree.match_rules();
 	style_tree.cascade();
@@ -1,10 +1,13 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
+use common::url::BrowserUrl;
 use css::error_reporting::{ContextualParseError, ParseErrorReporter};
 use css::media_queries::media_list::MediaList;
 use css::stylesheets::origin::Origin;
 use css::stylesheets::stylesheet::Stylesheet;
+use css::stylesheets::stylesheet_loader::{FilesystemStylesheetLoader, ImportContext};
 use cssparser::SourceLocation;
 use dom::global_scope::{GlobalScope, NodeRef};
 use dom::inheritance::Castable;
@@ -17,13 +20,15 @@ use iced_winit::futures::task::SpawnExt;
 use iced_winit::winit::dpi::LogicalSize;
 use iced_winit::winit::event::Event;
 use iced_winit::winit::event_loop::{ControlFlow, EventLoop};
-use iced_winit::{futures, mouse, winit, Background, Debug, Font, Point, Rectangle, Size};
+use iced_winit::{futures, mouse, winit, Background, Debug, Point, Rectangle, Size};
 use layout::display_list::builder::DisplayListBuilder;
-use layout::display_list::display_item::{DisplayItem, LayoutRect};
+use layout::display_list::display_item::{DisplayItem, LayoutRect, TextDisplayItem};
 use layout::flow::tree::BoxTree;
-use layout::fonts;
 use layout::style_tree::StyleTree;
+use layout::text::FALLBACK;
 use selectors::context::QuirksMode;
+use wgpu_glyph::ab_glyph::{point, FontArc, Glyph, PxScale, Rect};
+use wgpu_glyph::{Extra, FontId, GlyphBrush, GlyphBrushBuilder, SectionGlyph};
 
 #[derive(Debug)]
 pub struct CSSError {
@@ -61,6 +66,52 @@ fn to_rectangle(bounds: &LayoutRect) -> Rectangle {
 	)
 }
 
+/// Queues the glyphs of a text display item, they are placed on the baseline of the text.
+fn queue_glyphs(
+	glyph_brush: &mut GlyphBrush<()>,
+	glyph_fonts: &mut HashMap<String, FontId>,
+	text: &TextDisplayItem,
+	scale_factor: f32,
+) {
+	let x = text.bounds.min_x().get();
+	let baseline = text.bounds.min_y().get() + text.shaped_text.ascent;
+	let mut glyphs = vec![];
+	for run in text.shaped_text.runs.iter() {
+		let font_id = *glyph_fonts
+			.entry(run.font.key.clone())
+			.or_insert_with(|| glyph_brush.add_font(run.font.font.clone()));
+		for glyph in run.glyphs.iter() {
+			glyphs.push(SectionGlyph {
+				section_index: 0,
+				byte_index: glyph.cluster,
+				glyph: Glyph {
+					id: glyph.id,
+					scale: PxScale::from(run.font_size * scale_factor),
+					position: point(
+						(x + glyph.x + glyph.x_offset) * scale_factor,
+						(baseline + glyph.y_offset) * scale_factor,
+					),
+				},
+				font_id,
+			});
+		}
+	}
+	let color = [
+		text.color.red as f32 / 255.0,
+		text.color.green as f32 / 255.0,
+		text.color.blue as f32 / 255.0,
+		text.color.alpha,
+	];
+	glyph_brush.queue_pre_positioned(
+		glyphs,
+		vec![Extra { color, z: 0.0 }],
+		Rect {
+			min: point(0.0, 0.0),
+			max: point(f32::INFINITY, f32::INFINITY),
+		},
+	);
+}
+
 fn main() {
 	GlobalScope::clear();
 	let sink = DomParser::new();
@@ -87,7 +138,12 @@ fn main() {
 
 	let error_reporter = TestingErrorReporter::new();
 	let media = Rc::new(MediaList::empty());
-	let stylesheet = Stylesheet::from_str(
+	// relative @import urls are resolved against the working directory
+	let url = std::env::current_dir()
+		.ok()
+		.and_then(|directory| BrowserUrl::from_file_path(directory.join("index.css")).ok());
+	let import_context = ImportContext::new(&FilesystemStylesheetLoader, url);
+	let stylesheet = Stylesheet::from_str_with_loader(
 		r#"
 .a, .b, .c, .d, .e, .f, .g { padding: 12px; }
 .a { background-color: #ff0000; }
@@ -100,6 +156,7 @@ fn main() {
         "#,
 		Origin::UserAgent,
 		media,
+		Some(&import_context),
 		Some(&error_reporter),
 		QuirksMode::NoQuirks,
 		0,
@@ -180,6 +237,11 @@ fn main() {
 	// Initialize iced
 	let debug = Debug::new();
 	let mut renderer = Renderer::new(Backend::new(&mut device, Settings::default(), format));
+	// text is painted with the glyphs shaped in layout
+	let fallback_font = FontArc::try_from_slice(FALLBACK).expect("Load fallback font");
+	let mut glyph_brush = GlyphBrushBuilder::using_font(fallback_font).build(&device, format);
+	let mut glyph_fonts: HashMap<String, FontId> = HashMap::new();
+	glyph_fonts.insert("fallback".to_string(), FontId(0));
 
 	// Run event loop
 	event_loop.run(move |event, _, control_flow| {
@@ -236,24 +298,24 @@ fn main() {
 									border_color: Color::TRANSPARENT,
 								},
 								DisplayItem::Text(text) => {
-									let (font_name, font_bytes) = fonts::load_cached_font(&text.font_families);
-									Primitive::Text {
-										content: text.content.clone(),
-										bounds: to_rectangle(&text.bounds),
-										color: Color::from_rgba8(
-											text.color.red,
-											text.color.green,
-											text.color.blue,
-											text.color.alpha,
-										),
-										font: Font::External {
-											name: font_name,
-											bytes: font_bytes,
-										},
-										horizontal_alignment: iced_winit::alignment::Horizontal::Left,
-										size: text.font_size,
-										vertical_alignment: iced_winit::alignment::Vertical::Top,
-									}
+									queue_glyphs(
+										&mut glyph_brush,
+										&mut glyph_fonts,
+										text,
+										window.scale_factor() as f32,
+									);
+									let size = window.inner_size();
+									glyph_brush
+										.draw_queued(
+											&device,
+											&mut staging_belt,
+											&mut encoder,
+											&view,
+											size.width,
+											size.height,
+										)
+										.expect("Draw text");
+									continue;
 								},
 							};
 							backend.draw(